@@ -92,6 +92,7 @@ from daft.udf import udf
 from daft.viz import register_viz_hook
 
 to_struct = Expression.to_struct
+coalesce = Expression.coalesce
 
 __all__ = [
     "from_pylist",
@@ -135,4 +136,5 @@ __all__ = [
     "sql",
     "sql_expr",
     "to_struct",
+    "coalesce",
 ]
@@ -350,6 +350,8 @@ def set_execution_config(
     default_morsel_size: int | None = None,
     shuffle_algorithm: str | None = None,
     pre_shuffle_merge_threshold: int | None = None,
+    cross_join_max_rows: int | None = None,
+    hash_join_spill_threshold_bytes: int | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control various aspects of Daft execution. These configuration values
     are used when a Dataframe is executed (e.g. calls to `.write_*`, `.collect()` or `.show()`)
@@ -388,6 +390,9 @@ def set_execution_config(
         default_morsel_size: Default size of morsels used for the new local executor. Defaults to 131072 rows.
         shuffle_algorithm: The shuffle algorithm to use. Defaults to "map_reduce". Other options are "pre_shuffle_merge".
         pre_shuffle_merge_threshold: Memory threshold in bytes for pre-shuffle merge. Defaults to 1GB
+        cross_join_max_rows: Maximum total number of rows a cross join may produce before erroring. Defaults to 100,000,000.
+        hash_join_spill_threshold_bytes: Size in bytes past which the native executor spills the build side of a hash join to disk.
+            Defaults to None, which keeps the build side in memory.
     """
     # Replace values in the DaftExecutionConfig with user-specified overrides
     ctx = get_context()
@@ -415,6 +420,8 @@ def set_execution_config(
             default_morsel_size=default_morsel_size,
             shuffle_algorithm=shuffle_algorithm,
             pre_shuffle_merge_threshold=pre_shuffle_merge_threshold,
+            cross_join_max_rows=cross_join_max_rows,
+            hash_join_spill_threshold_bytes=hash_join_spill_threshold_bytes,
         )
 
         ctx._daft_execution_config = new_daft_execution_config
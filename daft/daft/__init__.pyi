@@ -78,6 +78,7 @@ class ImageFormat(Enum):
     TIFF: int
     GIF: int
     BMP: int
+    WEBP: int
 
     @staticmethod
     def from_format_string(mode: str) -> ImageFormat:
@@ -97,6 +98,10 @@ class JoinType(Enum):
     Outer: int
     Semi: int
     Anti: int
+    RightSemi: int
+    RightAnti: int
+    Cross: int
+    AsOf: int
 
     @staticmethod
     def from_join_type_str(join_type: str) -> JoinType:
@@ -318,6 +323,7 @@ class CsvConvertOptions:
     column_names: list[str] | None
     schema: PySchema | None
     predicate: PyExpr | None
+    ignore_missing_columns: bool
 
     def __init__(
         self,
@@ -326,6 +332,7 @@ class CsvConvertOptions:
         column_names: list[str] | None = None,
         schema: PySchema | None = None,
         predicate: PyExpr | None = None,
+        ignore_missing_columns: bool = False,
     ): ...
 
 class CsvParseOptions:
@@ -656,8 +663,10 @@ class NativeStorageConfig:
     # Whether or not to use a multithreaded tokio runtime for processing I/O
     multithreaded_io: bool
     io_config: IOConfig
+    # Maximum number of glob paths to expand concurrently, defaulting to 64 when unset
+    glob_concurrency: int | None
 
-    def __init__(self, multithreaded_io: bool, io_config: IOConfig): ...
+    def __init__(self, multithreaded_io: bool, io_config: IOConfig, glob_concurrency: int | None = None): ...
 
 class PythonStorageConfig:
     """
@@ -1054,9 +1063,12 @@ class PyExpr:
     def approx_percentiles(self, percentiles: float | list[float]) -> PyExpr: ...
     def mean(self) -> PyExpr: ...
     def stddev(self) -> PyExpr: ...
+    def variance(self, ddof: int) -> PyExpr: ...
     def min(self) -> PyExpr: ...
     def max(self) -> PyExpr: ...
     def any_value(self, ignore_nulls: bool) -> PyExpr: ...
+    def first(self, ignore_nulls: bool) -> PyExpr: ...
+    def last(self, ignore_nulls: bool) -> PyExpr: ...
     def agg_list(self) -> PyExpr: ...
     def agg_concat(self) -> PyExpr: ...
     def __add__(self, other: PyExpr) -> PyExpr: ...
@@ -1201,6 +1213,7 @@ def sql_expr(sql: str) -> PyExpr: ...
 def list_sql_functions() -> list[SQLFunctionStub]: ...
 def utf8_count_matches(expr: PyExpr, patterns: PyExpr, whole_words: bool, case_sensitive: bool) -> PyExpr: ...
 def to_struct(inputs: list[PyExpr]) -> PyExpr: ...
+def coalesce(inputs: list[PyExpr]) -> PyExpr: ...
 def connect_start(addr: str = "sc://0.0.0.0:0") -> ConnectionHandle: ...
 
 class ConnectionHandle:
@@ -1217,7 +1230,9 @@ def log2(expr: PyExpr) -> PyExpr: ...
 def log10(expr: PyExpr) -> PyExpr: ...
 def log(expr: PyExpr, base: float) -> PyExpr: ...
 def ln(expr: PyExpr) -> PyExpr: ...
+def pow(expr: PyExpr, exponent: PyExpr) -> PyExpr: ...
 def round(expr: PyExpr, decimal: int) -> PyExpr: ...
+def clip(expr: PyExpr, lower: PyExpr | None, upper: PyExpr | None) -> PyExpr: ...
 def sign(expr: PyExpr) -> PyExpr: ...
 def sqrt(expr: PyExpr) -> PyExpr: ...
 def sin(expr: PyExpr) -> PyExpr: ...
@@ -1269,6 +1284,7 @@ def dt_month(expr: PyExpr) -> PyExpr: ...
 def dt_year(expr: PyExpr) -> PyExpr: ...
 def dt_day_of_week(expr: PyExpr) -> PyExpr: ...
 def dt_truncate(expr: PyExpr, interval: str, relative_to: PyExpr) -> PyExpr: ...
+def dt_strftime(expr: PyExpr, format: str) -> PyExpr: ...
 
 # ---
 # expr.list namespace
@@ -1279,12 +1295,13 @@ def list_value_counts(expr: PyExpr) -> PyExpr: ...
 def list_join(expr: PyExpr, delimiter: PyExpr) -> PyExpr: ...
 def list_count(expr: PyExpr, mode: CountMode) -> PyExpr: ...
 def list_get(expr: PyExpr, idx: PyExpr, default: PyExpr) -> PyExpr: ...
+def list_index_of(expr: PyExpr, value: PyExpr) -> PyExpr: ...
 def list_sum(expr: PyExpr) -> PyExpr: ...
 def list_mean(expr: PyExpr) -> PyExpr: ...
 def list_min(expr: PyExpr) -> PyExpr: ...
 def list_max(expr: PyExpr) -> PyExpr: ...
 def list_slice(expr: PyExpr, start: PyExpr, end: PyExpr | None = None) -> PyExpr: ...
-def list_chunk(expr: PyExpr, size: int) -> PyExpr: ...
+def list_chunk(expr: PyExpr, size: int, keep_remainder: bool = False) -> PyExpr: ...
 
 # ---
 # expr.utf8 namespace
@@ -1311,11 +1328,15 @@ def utf8_find(expr: PyExpr, substr: PyExpr) -> PyExpr: ...
 def utf8_rpad(expr: PyExpr, length: PyExpr, pad: PyExpr) -> PyExpr: ...
 def utf8_lpad(expr: PyExpr, length: PyExpr, pad: PyExpr) -> PyExpr: ...
 def utf8_repeat(expr: PyExpr, n: PyExpr) -> PyExpr: ...
+def utf8_concat_str(inputs: list[PyExpr], separator: str, skip_nulls: bool = False) -> PyExpr: ...
 def utf8_like(expr: PyExpr, pattern: PyExpr) -> PyExpr: ...
 def utf8_ilike(expr: PyExpr, pattern: PyExpr) -> PyExpr: ...
 def utf8_substr(expr: PyExpr, start: PyExpr, length: PyExpr) -> PyExpr: ...
 def utf8_to_date(expr: PyExpr, format: str) -> PyExpr: ...
 def utf8_to_datetime(expr: PyExpr, format: str, timezone: str | None = None) -> PyExpr: ...
+def utf8_strptime(
+    expr: PyExpr, format: str, timeunit: PyTimeUnit, timezone: str | None, raise_on_error: bool
+) -> PyExpr: ...
 def utf8_normalize(
     expr: PyExpr, remove_punct: bool, lowercase: bool, nfd_unicode: bool, white_space: bool
 ) -> PyExpr: ...
@@ -1356,7 +1377,15 @@ class PySeries:
     def filter(self, mask: PySeries) -> PySeries: ...
     def sort(self, descending: bool, nulls_first: bool) -> PySeries: ...
     def argsort(self, descending: bool, nulls_first: bool) -> PySeries: ...
-    def hash(self, seed: PySeries | None = None) -> PySeries: ...
+    def rank(self, descending: bool, method: str) -> PySeries: ...
+    def n_unique(self, ignore_nulls: bool = False) -> int: ...
+    def is_unique(self) -> PySeries: ...
+    def mode(self) -> PySeries: ...
+    def hash(
+        self,
+        seed: PySeries | None = None,
+        hash_function: Literal["default", "murmur3_32", "xxhash64"] = "default",
+    ) -> PySeries: ...
     def minhash(
         self,
         num_hashes: int,
@@ -1368,11 +1397,18 @@ class PySeries:
     def count(self, mode: CountMode) -> PySeries: ...
     def sum(self) -> PySeries: ...
     def mean(self) -> PySeries: ...
+    def cumsum(self) -> PySeries: ...
+    def cummax(self) -> PySeries: ...
+    def cummin(self) -> PySeries: ...
     def stddev(self) -> PySeries: ...
     def min(self) -> PySeries: ...
     def max(self) -> PySeries: ...
     def agg_list(self) -> PySeries: ...
     def cast(self, dtype: PyDataType) -> PySeries: ...
+    def cast_strict(self, dtype: PyDataType) -> PySeries: ...
+    def cast_string_to_numeric(
+        self, dtype: PyDataType, decimal_sep: str = ".", thousands_sep: str | None = None
+    ) -> PySeries: ...
     def ceil(self) -> PySeries: ...
     def floor(self) -> PySeries: ...
     def sign(self) -> PySeries: ...
@@ -1397,6 +1433,7 @@ class PySeries:
     def log(self, base: float) -> PySeries: ...
     def ln(self) -> PySeries: ...
     def exp(self) -> PySeries: ...
+    def pow(self, exponent: PySeries) -> PySeries: ...
     @staticmethod
     def concat(series: list[PySeries]) -> PySeries: ...
     def __len__(self) -> int: ...
@@ -1431,6 +1468,9 @@ class PySeries:
     def utf8_substr(self, start: PySeries, length: PySeries | None = None) -> PySeries: ...
     def utf8_to_date(self, format: str) -> PySeries: ...
     def utf8_to_datetime(self, format: str, timezone: str | None = None) -> PySeries: ...
+    def utf8_strptime(
+        self, format: str, timeunit: PyTimeUnit, timezone: str | None = None, raise_on_error: bool = False
+    ) -> PySeries: ...
     def utf8_normalize(self, remove_punct: bool, lowercase: bool, nfd_unicode: bool, white_space: bool) -> PySeries: ...
     def utf8_count_matches(self, patterns: PySeries, whole_word: bool, case_sensitive: bool) -> PySeries: ...
     def is_nan(self) -> PySeries: ...
@@ -1447,6 +1487,9 @@ class PySeries:
     def dt_year(self) -> PySeries: ...
     def dt_day_of_week(self) -> PySeries: ...
     def dt_truncate(self, interval: str, relative_to: PySeries) -> PySeries: ...
+    def dt_strftime(self, format: str) -> PySeries: ...
+    def dt_convert_timezone(self, tz: str) -> PySeries: ...
+    def dt_replace_timezone(self, tz: str | None = None) -> PySeries: ...
     def partitioning_days(self) -> PySeries: ...
     def partitioning_hours(self) -> PySeries: ...
     def partitioning_months(self) -> PySeries: ...
@@ -1461,7 +1504,9 @@ class PySeries:
     def if_else(self, other: PySeries, predicate: PySeries) -> PySeries: ...
     def is_null(self) -> PySeries: ...
     def not_null(self) -> PySeries: ...
+    def null_count(self) -> int: ...
     def fill_null(self, fill_value: PySeries) -> PySeries: ...
+    def clip(self, lower: PySeries | None = None, upper: PySeries | None = None) -> PySeries: ...
     def murmur3_32(self) -> PySeries: ...
     def to_str_values(self) -> PySeries: ...
     def _debug_bincode_serialize(self) -> bytes: ...
@@ -1498,13 +1543,16 @@ class PyTable:
         right_on: list[PyExpr],
         is_sorted: bool,
     ) -> PyTable: ...
-    def explode(self, to_explode: list[PyExpr]) -> PyTable: ...
+    def explode(self, to_explode: list[PyExpr], index_column: str | None = None) -> PyTable: ...
     def head(self, num: int) -> PyTable: ...
     def sample_by_fraction(self, fraction: float, with_replacement: bool, seed: int | None) -> PyTable: ...
     def sample_by_size(self, size: int, with_replacement: bool, seed: int | None) -> PyTable: ...
     def quantiles(self, num: int) -> PyTable: ...
-    def partition_by_hash(self, exprs: list[PyExpr], num_partitions: int) -> list[PyTable]: ...
+    def partition_by_hash(
+        self, exprs: list[PyExpr], num_partitions: int, seed: int | None = None
+    ) -> list[PyTable]: ...
     def partition_by_random(self, num_partitions: int, seed: int) -> list[PyTable]: ...
+    def partition_by_round_robin(self, num_partitions: int, start: int) -> list[PyTable]: ...
     def partition_by_range(
         self, partition_keys: list[PyExpr], boundaries: PyTable, descending: list[bool]
     ) -> list[PyTable]: ...
@@ -1578,7 +1626,7 @@ class PyMicroPartition:
         right_on: list[PyExpr],
         is_sorted: bool,
     ) -> PyMicroPartition: ...
-    def explode(self, to_explode: list[PyExpr]) -> PyMicroPartition: ...
+    def explode(self, to_explode: list[PyExpr], index_column: str | None = None) -> PyMicroPartition: ...
     def unpivot(
         self,
         ids: list[PyExpr],
@@ -1590,8 +1638,11 @@ class PyMicroPartition:
     def sample_by_fraction(self, fraction: float, with_replacement: bool, seed: int | None) -> PyMicroPartition: ...
     def sample_by_size(self, size: int, with_replacement: bool, seed: int | None) -> PyMicroPartition: ...
     def quantiles(self, num: int) -> PyMicroPartition: ...
-    def partition_by_hash(self, exprs: list[PyExpr], num_partitions: int) -> list[PyMicroPartition]: ...
+    def partition_by_hash(
+        self, exprs: list[PyExpr], num_partitions: int, seed: int | None = None
+    ) -> list[PyMicroPartition]: ...
     def partition_by_random(self, num_partitions: int, seed: int) -> list[PyMicroPartition]: ...
+    def partition_by_round_robin(self, num_partitions: int, start: int) -> list[PyMicroPartition]: ...
     def partition_by_range(
         self, partition_keys: list[PyExpr], boundaries: PyTable, descending: list[bool]
     ) -> list[PyMicroPartition]: ...
@@ -1833,6 +1884,8 @@ class PyDaftExecutionConfig:
         enable_ray_tracing: bool | None = None,
         shuffle_algorithm: str | None = None,
         pre_shuffle_merge_threshold: int | None = None,
+        cross_join_max_rows: int | None = None,
+        hash_join_spill_threshold_bytes: int | None = None,
     ) -> PyDaftExecutionConfig: ...
     @property
     def scan_tasks_min_size_bytes(self) -> int: ...
@@ -1874,6 +1927,10 @@ class PyDaftExecutionConfig:
     def pre_shuffle_merge_threshold(self) -> int: ...
     @property
     def enable_ray_tracing(self) -> bool: ...
+    @property
+    def cross_join_max_rows(self) -> int: ...
+    @property
+    def hash_join_spill_threshold_bytes(self) -> int | None: ...
 
 class PyDaftPlanningConfig:
     @staticmethod
@@ -1784,7 +1784,7 @@ class DataFrame:
             on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on [use if the keys on the left and right side match.]. Defaults to None.
             left_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on left DataFrame. Defaults to None.
             right_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on right DataFrame. Defaults to None.
-            how (str, optional): what type of join to perform; currently "inner", "left", "right", "outer", "anti", and "semi" are supported. Defaults to "inner".
+            how (str, optional): what type of join to perform; currently "inner", "left", "right", "outer", "anti", "semi", "right_anti", and "right_semi" are supported, where "left_anti" and "left_semi" are accepted as aliases of "anti" and "semi". Defaults to "inner".
             strategy (Optional[str]): The join strategy (algorithm) to use; currently "hash", "sort_merge", "broadcast", and None are supported, where None
                 chooses the join strategy automatically during query optimization. The default is None.
             suffix (Optional[str], optional): Suffix to add to the column names in case of a name collision. Defaults to "".
@@ -2138,6 +2138,10 @@ class DataFrame:
             return expr.mean()
         elif op == "any_value":
             return expr.any_value()
+        elif op == "first":
+            return expr.first()
+        elif op == "last":
+            return expr.last()
         elif op == "list":
             return expr.agg_list()
         elif op == "concat":
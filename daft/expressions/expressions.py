@@ -20,6 +20,7 @@ import daft.daft as native
 from daft import context
 from daft.daft import CountMode, ImageFormat, ImageMode, ResourceRequest, bind_stateful_udfs
 from daft.daft import PyExpr as _PyExpr
+from daft.daft import coalesce as _coalesce
 from daft.daft import col as _col
 from daft.daft import date_lit as _date_lit
 from daft.daft import decimal_lit as _decimal_lit
@@ -344,6 +345,47 @@ class Expression:
                 raise TypeError("expected Expression or str as input for to_struct")
         return Expression._from_pyexpr(_to_struct(pyinputs))
 
+    @staticmethod
+    def coalesce(*inputs: Expression | builtins.str | Any) -> Expression:
+        """Returns the first non-null value across the inputs for each row.
+
+        The output type is the supertype of all input types.
+
+        Example:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"a": [1, None, None], "b": [10, 20, None]})
+            >>> df.select(daft.coalesce(col("a"), col("b"), 0)).show()
+            ╭───────╮
+            │ a     │
+            │ ---   │
+            │ Int64 │
+            ╞═══════╡
+            │ 1     │
+            ├╌╌╌╌╌╌╌┤
+            │ 20    │
+            ├╌╌╌╌╌╌╌┤
+            │ 0     │
+            ╰───────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            inputs: Expressions, column names or literal values to coalesce, in order of priority.
+
+        Returns:
+            An expression for the first non-null input of each row.
+        """
+        pyinputs = []
+        for x in inputs:
+            if isinstance(x, Expression):
+                pyinputs.append(x._expr)
+            elif isinstance(x, str):
+                pyinputs.append(col(x)._expr)
+            else:
+                pyinputs.append(lit(x)._expr)
+        return Expression._from_pyexpr(_coalesce(pyinputs))
+
     def __bool__(self) -> bool:
         raise ValueError(
             "Expressions don't have a truth value. "
@@ -631,13 +673,28 @@ class Expression:
     def round(self, decimals: int = 0) -> Expression:
         """The round of a numeric expression (``expr.round(decimals = 0)``)
 
+        Ties round to the nearest even digit, so ``2.5`` rounds to ``2`` and ``3.5`` rounds to ``4``.
+
         Args:
-            decimals: number of decimal places to round to. Defaults to 0.
+            decimals: number of decimal places to round to. Negative values round to the left of the
+                decimal point, e.g. ``1250.0`` with ``decimals=-2`` rounds to ``1200.0``. Defaults to 0.
         """
         assert isinstance(decimals, int)
         expr = native.round(self._expr, decimals)
         return Expression._from_pyexpr(expr)
 
+    def clip(self, lower: object = None, upper: object = None) -> Expression:
+        """Clamps a numeric expression into ``[lower, upper]`` (``expr.clip(lower, upper)``)
+
+        Args:
+            lower: lower bound, either a literal or an expression. Omitted or null bounds leave that side unbounded.
+            upper: upper bound, either a literal or an expression. Omitted or null bounds leave that side unbounded.
+        """
+        lower_expr = Expression._to_expression(lower)._expr if lower is not None else None
+        upper_expr = Expression._to_expression(upper)._expr if upper is not None else None
+        expr = native.clip(self._expr, lower_expr, upper_expr)
+        return Expression._from_pyexpr(expr)
+
     def sqrt(self) -> Expression:
         """The square root of a numeric expression (``expr.sqrt()``)"""
         expr = native.sqrt(self._expr)
@@ -746,6 +803,15 @@ class Expression:
         expr = native.exp(self._expr)
         return Expression._from_pyexpr(expr)
 
+    def pow(self, exponent: object) -> Expression:
+        """The elementwise power of a numeric expression as Float64 (``expr.pow(exponent)``)
+
+        Args:
+            exponent: the exponent, either a literal or an expression.
+        """
+        exponent_expr = Expression._to_expression(exponent)
+        return Expression._from_pyexpr(native.pow(self._expr, exponent_expr._expr))
+
     def bitwise_and(self, other: Expression) -> Expression:
         """Bitwise AND of two integer expressions (``expr.bitwise_and(other)``)"""
         expr = Expression._to_expression(other)
@@ -893,10 +959,24 @@ class Expression:
         return Expression._from_pyexpr(expr)
 
     def stddev(self) -> Expression:
-        """Calculates the standard deviation of the values in the expression"""
+        """Calculates the population standard deviation of the values in the expression
+
+        Like :meth:`variance`, this divides by the number of values. Use ``variance(ddof=1).sqrt()`` for the sample
+        standard deviation.
+        """
         expr = self._expr.stddev()
         return Expression._from_pyexpr(expr)
 
+    def variance(self, ddof: int = 0) -> Expression:
+        """Calculates the variance of the values in the expression
+
+        Args:
+            ddof: delta degrees of freedom. Defaults to 0 for the population variance, consistent with :meth:`stddev`.
+                Use 1 for the sample variance.
+        """
+        expr = self._expr.variance(ddof)
+        return Expression._from_pyexpr(expr)
+
     def min(self) -> Expression:
         """Calculates the minimum value in the expression"""
         expr = self._expr.min()
@@ -916,6 +996,24 @@ class Expression:
         expr = self._expr.any_value(ignore_nulls)
         return Expression._from_pyexpr(expr)
 
+    def first(self, ignore_nulls=False) -> Expression:
+        """Returns the first value in the expression, in the order rows are aggregated
+
+        Args:
+            ignore_nulls: whether to skip null values when selecting the value. Defaults to False.
+        """
+        expr = self._expr.first(ignore_nulls)
+        return Expression._from_pyexpr(expr)
+
+    def last(self, ignore_nulls=False) -> Expression:
+        """Returns the last value in the expression, in the order rows are aggregated
+
+        Args:
+            ignore_nulls: whether to skip null values when selecting the value. Defaults to False.
+        """
+        expr = self._expr.last(ignore_nulls)
+        return Expression._from_pyexpr(expr)
+
     def agg_list(self) -> Expression:
         """Aggregates the values in the expression into a list"""
         expr = self._expr.agg_list()
@@ -3058,6 +3156,18 @@ class ExpressionListNamespace(ExpressionNamespace):
         default_expr = lit(default)
         return Expression._from_pyexpr(native.list_get(self._expr, idx_expr._expr, default_expr._expr))
 
+    def index_of(self, value: object | Expression) -> Expression:
+        """Finds the index of the first element in each list that equals a value
+
+        Args:
+            value: value or column of values to search for in each list
+
+        Returns:
+            Expression: an Int64 expression with the zero-based index of the first match, or null if the value is not found
+        """
+        value_expr = Expression._to_expression(value)
+        return Expression._from_pyexpr(native.list_index_of(self._expr, value_expr._expr))
+
     def slice(self, start: int | Expression, end: int | Expression | None = None) -> Expression:
         """Gets a subset of each list
 
@@ -3072,17 +3182,20 @@ class ExpressionListNamespace(ExpressionNamespace):
         end_expr = Expression._to_expression(end)
         return Expression._from_pyexpr(native.list_slice(self._expr, start_expr._expr, end_expr._expr))
 
-    def chunk(self, size: int) -> Expression:
+    def chunk(self, size: int, keep_remainder: bool = False) -> Expression:
         """Splits each list into chunks of the given size
 
         Args:
             size: size of chunks to split the list into. Must be greater than 0
+            keep_remainder: whether to keep a final chunk with fewer than ``size`` elements. Defaults to False,
+                which drops those elements.
         Returns:
-            Expression: an expression with lists of fixed size lists of the type of the list values
+            Expression: an expression with lists of fixed size lists of the type of the list values, or lists of
+                lists if ``keep_remainder`` is set
         """
         if not (isinstance(size, int) and size > 0):
             raise ValueError(f"Invalid value for `size`: {size}")
-        return Expression._from_pyexpr(native.list_chunk(self._expr, size))
+        return Expression._from_pyexpr(native.list_chunk(self._expr, size, keep_remainder))
 
     def sum(self) -> Expression:
         """Sums each list. Empty lists and lists with all nulls yield null.
@@ -5,7 +5,7 @@ from typing import Any, Literal, TypeVar
 
 from daft.arrow_utils import ensure_array, ensure_chunked_array
 from daft.daft import CountMode, ImageFormat, ImageMode, PySeries, image
-from daft.datatype import DataType, _ensure_registered_super_ext_type
+from daft.datatype import DataType, TimeUnit, _ensure_registered_super_ext_type
 from daft.dependencies import np, pa, pd
 from daft.utils import pyarrow_supports_fixed_shape_tensor
 
@@ -163,6 +163,17 @@ class Series:
     def cast(self, dtype: DataType) -> Series:
         return Series._from_pyseries(self._series.cast(dtype._dtype))
 
+    def cast_strict(self, dtype: DataType) -> Series:
+        """Casts this Series to ``dtype``, raising an error instead of wrapping around when an integer value does not fit in the target integer type."""
+        return Series._from_pyseries(self._series.cast_strict(dtype._dtype))
+
+    def cast_string_to_numeric(self, dtype: DataType, decimal_sep: str = ".", thousands_sep: str | None = None) -> Series:
+        """Parses a string Series into the numeric ``dtype``, e.g. ``"1.234,56"`` with ``decimal_sep=","`` and ``thousands_sep="."``.
+
+        Thousands separators are removed and the decimal separator is normalized before parsing. Values that do not parse become null.
+        """
+        return Series._from_pyseries(self._series.cast_string_to_numeric(dtype._dtype, decimal_sep, thousands_sep))
+
     def _cast_to_python(self) -> Series:
         """Convert this Series into a Series of Python objects.
 
@@ -264,6 +275,21 @@ class Series:
 
         return Series._from_pyseries(self._series.argsort(descending, nulls_first))
 
+    def rank(self, descending: bool = False, method: str = "average") -> Series:
+        """Ranks each value starting from 1, with nulls ranked last regardless of ``descending``.
+
+        Args:
+            descending: whether larger values get lower ranks
+            method: how tied values are ranked; one of "dense", "min", "max", or "average"
+
+        Returns:
+            Series: UInt64 ranks, or Float64 ranks for the "average" method
+        """
+        if not isinstance(descending, bool):
+            raise TypeError(f"expected `descending` to be bool, got {type(descending)}")
+
+        return Series._from_pyseries(self._series.rank(descending, method))
+
     def sort(self, descending: bool = False, nulls_first: bool | None = None) -> Series:
         if not isinstance(descending, bool):
             raise TypeError(f"expected `descending` to be bool, got {type(descending)}")
@@ -271,11 +297,24 @@ class Series:
             nulls_first = descending
         return Series._from_pyseries(self._series.sort(descending, nulls_first))
 
-    def hash(self, seed: Series | None = None) -> Series:
+    def hash(
+        self,
+        seed: Series | None = None,
+        hash_function: Literal["default", "murmur3_32", "xxhash64"] = "default",
+    ) -> Series:
+        """Hashes each value of the series.
+
+        Args:
+            seed (optional): UInt64 seeds, one per row. Not supported by ``murmur3_32``.
+            hash_function: ``default`` for Daft's own hash, or ``murmur3_32`` (UInt32) and ``xxhash64`` (UInt64)
+                computed over the same bytes as Iceberg's bucket transform. The latter two propagate nulls.
+        """
         if not isinstance(seed, Series) and seed is not None:
             raise TypeError(f"expected `seed` to be Series, got {type(seed)}")
 
-        return Series._from_pyseries(self._series.hash(seed._series if seed is not None else None))
+        return Series._from_pyseries(
+            self._series.hash(seed._series if seed is not None else None, hash_function=hash_function)
+        )
 
     def murmur3_32(self) -> Series:
         return Series._from_pyseries(self._series.murmur3_32())
@@ -318,6 +357,18 @@ class Series:
     def round(self, decimal: int) -> Series:
         return Series._from_pyseries(self._series.round(decimal))
 
+    def clip(self, lower: Series | None = None, upper: Series | None = None) -> Series:
+        """Clamps each value into ``[lower, upper]``. A missing or null bound leaves that side unbounded."""
+        for bound in (lower, upper):
+            if bound is not None and not isinstance(bound, Series):
+                raise ValueError(f"expected another Series but got {type(bound)}")
+        return Series._from_pyseries(
+            self._series.clip(
+                lower._series if lower is not None else None,
+                upper._series if upper is not None else None,
+            )
+        )
+
     def sqrt(self) -> Series:
         return Series._from_pyseries(self._series.sqrt())
 
@@ -401,6 +452,12 @@ class Series:
         """The e^self of a numeric series"""
         return Series._from_pyseries(self._series.exp())
 
+    def pow(self, exponent: Series) -> Series:
+        """The elementwise self^exponent of a numeric series as Float64. A length-1 exponent is broadcast."""
+        if not isinstance(exponent, Series):
+            raise TypeError(f"expected another Series but got {type(exponent)}")
+        return Series._from_pyseries(self._series.pow(exponent._series))
+
     def __add__(self, other: object) -> Series:
         if not isinstance(other, Series):
             raise TypeError(f"expected another Series but got {type(other)}")
@@ -531,6 +588,21 @@ class Series:
         assert self._series is not None
         return Series._from_pyseries(self._series.sum())
 
+    def cumsum(self) -> Series:
+        """Running sum in order, with integers widened to 64 bits. Null positions stay null."""
+        assert self._series is not None
+        return Series._from_pyseries(self._series.cumsum())
+
+    def cummax(self) -> Series:
+        """Running maximum in order. Null positions stay null."""
+        assert self._series is not None
+        return Series._from_pyseries(self._series.cummax())
+
+    def cummin(self) -> Series:
+        """Running minimum in order. Null positions stay null."""
+        assert self._series is not None
+        return Series._from_pyseries(self._series.cummin())
+
     def shift_right(self, bits: Series) -> Series:
         if not isinstance(bits, Series):
             raise TypeError(f"expected another Series but got {type(bits)}")
@@ -560,6 +632,11 @@ class Series:
         assert self._series is not None
         return Series._from_pyseries(self._series.not_null())
 
+    def null_count(self) -> int:
+        """Returns the number of nulls in the Series. NaN floats are not counted as null."""
+        assert self._series is not None
+        return self._series.null_count()
+
     def fill_null(self, fill_value: object) -> Series:
         if not isinstance(fill_value, Series):
             raise ValueError(f"expected another Series but got {type(fill_value)}")
@@ -853,6 +930,24 @@ class SeriesStringNamespace(SeriesNamespace):
         assert self._series is not None
         return Series._from_pyseries(self._series.utf8_to_datetime(format, timezone))
 
+    def strptime(
+        self,
+        format: str,
+        timeunit: TimeUnit | str = "us",
+        timezone: str | None = None,
+        raise_on_error: bool = False,
+    ) -> Series:
+        if not isinstance(format, str):
+            raise ValueError(f"expected str for format but got {type(format)}")
+        if timezone is not None and not isinstance(timezone, str):
+            raise ValueError(f"expected str for timezone but got {type(timezone)}")
+        if isinstance(timeunit, str):
+            timeunit = TimeUnit.from_str(timeunit)
+        assert self._series is not None
+        return Series._from_pyseries(
+            self._series.utf8_strptime(format, timeunit._timeunit, timezone, raise_on_error)
+        )
+
     def substr(self, start: Series, length: Series | None = None) -> Series:
         if not isinstance(start, Series):
             raise ValueError(f"expected another Series but got {type(start)}")
@@ -913,6 +1008,21 @@ class SeriesDateNamespace(SeriesNamespace):
     def time(self) -> Series:
         return Series._from_pyseries(self._series.dt_time())
 
+    def strftime(self, format: str) -> Series:
+        if not isinstance(format, str):
+            raise ValueError(f"expected str for format but got {type(format)}")
+        return Series._from_pyseries(self._series.dt_strftime(format))
+
+    def convert_timezone(self, tz: str) -> Series:
+        if not isinstance(tz, str):
+            raise ValueError(f"expected str for tz but got {type(tz)}")
+        return Series._from_pyseries(self._series.dt_convert_timezone(tz))
+
+    def replace_timezone(self, tz: str | None = None) -> Series:
+        if tz is not None and not isinstance(tz, str):
+            raise ValueError(f"expected str for tz but got {type(tz)}")
+        return Series._from_pyseries(self._series.dt_replace_timezone(tz))
+
     def month(self) -> Series:
         return Series._from_pyseries(self._series.dt_month())
 
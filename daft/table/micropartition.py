@@ -248,10 +248,13 @@ class MicroPartition:
     def quantiles(self, num: int) -> MicroPartition:
         return MicroPartition._from_pymicropartition(self._micropartition.quantiles(num))
 
-    def explode(self, columns: ExpressionsProjection) -> MicroPartition:
-        """NOTE: Expressions here must be Explode expressions (Expression._explode())"""
+    def explode(self, columns: ExpressionsProjection, index_column: str | None = None) -> MicroPartition:
+        """NOTE: Expressions here must be Explode expressions (Expression._explode())
+
+        If `index_column` is given, a UInt64 column with that name is appended holding each element's position within its original list
+        """
         to_explode_pyexprs = [e._expr for e in columns]
-        return MicroPartition._from_pymicropartition(self._micropartition.explode(to_explode_pyexprs))
+        return MicroPartition._from_pymicropartition(self._micropartition.explode(to_explode_pyexprs, index_column))
 
     def unpivot(
         self, ids: ExpressionsProjection, values: ExpressionsProjection, variable_name: str, value_name: str
@@ -318,14 +321,16 @@ class MicroPartition:
             )
         )
 
-    def partition_by_hash(self, exprs: ExpressionsProjection, num_partitions: int) -> list[MicroPartition]:
+    def partition_by_hash(
+        self, exprs: ExpressionsProjection, num_partitions: int, seed: int | None = None
+    ) -> list[MicroPartition]:
         if not isinstance(num_partitions, int):
             raise TypeError(f"Expected a num_partitions to be int, got {type(num_partitions)}")
 
         pyexprs = [e._expr for e in exprs]
         return [
             MicroPartition._from_pymicropartition(t)
-            for t in self._micropartition.partition_by_hash(pyexprs, num_partitions)
+            for t in self._micropartition.partition_by_hash(pyexprs, num_partitions, seed)
         ]
 
     def partition_by_range(
@@ -352,6 +357,18 @@ class MicroPartition:
             for t in self._micropartition.partition_by_random(num_partitions, seed)
         ]
 
+    def partition_by_round_robin(self, num_partitions: int, start: int = 0) -> list[MicroPartition]:
+        if not isinstance(num_partitions, int):
+            raise TypeError(f"Expected a num_partitions to be int, got {type(num_partitions)}")
+
+        if not isinstance(start, int):
+            raise TypeError(f"Expected a start to be int, got {type(start)}")
+
+        return [
+            MicroPartition._from_pymicropartition(t)
+            for t in self._micropartition.partition_by_round_robin(num_partitions, start)
+        ]
+
     def partition_by_value(self, partition_keys: ExpressionsProjection) -> tuple[list[MicroPartition], MicroPartition]:
         exprs = [e._expr for e in partition_keys]
         pytables, values = self._micropartition.partition_by_value(exprs)
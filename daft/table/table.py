@@ -305,10 +305,13 @@ class Table:
     def quantiles(self, num: int) -> Table:
         return Table._from_pytable(self._table.quantiles(num))
 
-    def explode(self, columns: ExpressionsProjection) -> Table:
-        """NOTE: Expressions here must be Explode expressions (Expression._explode())"""
+    def explode(self, columns: ExpressionsProjection, index_column: str | None = None) -> Table:
+        """NOTE: Expressions here must be Explode expressions (Expression._explode())
+
+        If `index_column` is given, a UInt64 column with that name is appended holding each element's position within its original list
+        """
         to_explode_pyexprs = [e._expr for e in columns]
-        return Table._from_pytable(self._table.explode(to_explode_pyexprs))
+        return Table._from_pytable(self._table.explode(to_explode_pyexprs, index_column))
 
     def hash_join(
         self,
@@ -357,12 +360,14 @@ class Table:
             self._table.sort_merge_join(right._table, left_on=left_exprs, right_on=right_exprs, is_sorted=is_sorted)
         )
 
-    def partition_by_hash(self, exprs: ExpressionsProjection, num_partitions: int) -> list[Table]:
+    def partition_by_hash(
+        self, exprs: ExpressionsProjection, num_partitions: int, seed: int | None = None
+    ) -> list[Table]:
         if not isinstance(num_partitions, int):
             raise TypeError(f"Expected a num_partitions to be int, got {type(num_partitions)}")
 
         pyexprs = [e._expr for e in exprs]
-        return [Table._from_pytable(t) for t in self._table.partition_by_hash(pyexprs, num_partitions)]
+        return [Table._from_pytable(t) for t in self._table.partition_by_hash(pyexprs, num_partitions, seed)]
 
     def partition_by_range(
         self, partition_keys: ExpressionsProjection, boundaries: Table, descending: list[bool]
@@ -382,6 +387,15 @@ class Table:
 
         return [Table._from_pytable(t) for t in self._table.partition_by_random(num_partitions, seed)]
 
+    def partition_by_round_robin(self, num_partitions: int, start: int = 0) -> list[Table]:
+        if not isinstance(num_partitions, int):
+            raise TypeError(f"Expected a num_partitions to be int, got {type(num_partitions)}")
+
+        if not isinstance(start, int):
+            raise TypeError(f"Expected a start to be int, got {type(start)}")
+
+        return [Table._from_pytable(t) for t in self._table.partition_by_round_robin(num_partitions, start)]
+
     def partition_by_value(self, partition_keys: ExpressionsProjection) -> tuple[list[Table], Table]:
         exprs = [e._expr for e in partition_keys]
         pytables, values = self._table.partition_by_value(exprs)
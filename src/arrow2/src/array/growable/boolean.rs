@@ -75,6 +75,11 @@ impl<'a> Growable<'a> for GrowableBoolean<'a> {
         self.validity.extend_constant(additional, false);
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+        self.validity.reserve(additional);
+    }
+
     #[inline]
     fn len(&self) -> usize {
         self.values.len()
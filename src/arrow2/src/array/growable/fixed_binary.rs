@@ -78,6 +78,11 @@ impl<'a> Growable<'a> for GrowableFixedSizeBinary<'a> {
         self.validity.extend_constant(additional, false);
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(self.size * additional);
+        self.validity.reserve(additional);
+    }
+
     #[inline]
     fn len(&self) -> usize {
         self.values.len() / self.size
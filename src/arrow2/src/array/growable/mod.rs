@@ -44,6 +44,11 @@ pub trait Growable<'a> {
     /// Extends this [`Growable`] with null elements, disregarding the bound arrays
     fn extend_validity(&mut self, additional: usize);
 
+    /// Reserves capacity for at least `additional` more elements, so that a run of
+    /// [`Growable::extend`] calls does not reallocate incrementally.
+    /// Implementations that cannot size their buffers up front ignore this hint.
+    fn reserve(&mut self, _additional: usize) {}
+
     /// The current length of the [`Growable`].
     fn len(&self) -> usize;
 
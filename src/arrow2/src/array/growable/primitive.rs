@@ -54,6 +54,11 @@ impl<'a, T: NativeType> GrowablePrimitive<'a, T> {
         }
     }
 
+    /// The number of elements that can be held without reallocating the values buffer.
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
     #[inline]
     fn to(&mut self) -> PrimitiveArray<T> {
         let validity = std::mem::take(&mut self.validity);
@@ -79,6 +84,11 @@ impl<'a, T: NativeType> Growable<'a> for GrowablePrimitive<'a, T> {
         self.validity.extend_constant(additional, false);
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+        self.validity.reserve(additional);
+    }
+
     #[inline]
     fn len(&self) -> usize {
         self.values.len()
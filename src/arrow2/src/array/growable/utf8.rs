@@ -88,6 +88,11 @@ impl<'a, O: Offset> Growable<'a> for GrowableUtf8<'a, O> {
         self.validity.extend_constant(additional, false);
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        self.validity.reserve(additional);
+    }
+
     #[inline]
     fn len(&self) -> usize {
         self.offsets.len() - 1
@@ -60,6 +60,8 @@ pub struct DaftExecutionConfig {
     pub shuffle_algorithm: String,
     pub pre_shuffle_merge_threshold: usize,
     pub enable_ray_tracing: bool,
+    pub cross_join_max_rows: usize,
+    pub hash_join_spill_threshold_bytes: Option<usize>,
 }
 
 impl Default for DaftExecutionConfig {
@@ -86,6 +88,8 @@ impl Default for DaftExecutionConfig {
             shuffle_algorithm: "map_reduce".to_string(),
             pre_shuffle_merge_threshold: 1024 * 1024 * 1024, // 1GB
             enable_ray_tracing: false,
+            cross_join_max_rows: 100_000_000,
+            hash_join_spill_threshold_bytes: None,
         }
     }
 }
@@ -110,6 +110,8 @@ impl PyDaftExecutionConfig {
         shuffle_algorithm: Option<&str>,
         pre_shuffle_merge_threshold: Option<usize>,
         enable_ray_tracing: Option<bool>,
+        cross_join_max_rows: Option<usize>,
+        hash_join_spill_threshold_bytes: Option<usize>,
     ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
@@ -187,6 +189,12 @@ impl PyDaftExecutionConfig {
         if let Some(enable_ray_tracing) = enable_ray_tracing {
             config.enable_ray_tracing = enable_ray_tracing;
         }
+        if let Some(cross_join_max_rows) = cross_join_max_rows {
+            config.cross_join_max_rows = cross_join_max_rows;
+        }
+        if let Some(hash_join_spill_threshold_bytes) = hash_join_spill_threshold_bytes {
+            config.hash_join_spill_threshold_bytes = Some(hash_join_spill_threshold_bytes);
+        }
 
         Ok(Self {
             config: Arc::new(config),
@@ -287,6 +295,16 @@ impl PyDaftExecutionConfig {
     fn enable_ray_tracing(&self) -> PyResult<bool> {
         Ok(self.config.enable_ray_tracing)
     }
+
+    #[getter]
+    fn cross_join_max_rows(&self) -> PyResult<usize> {
+        Ok(self.config.cross_join_max_rows)
+    }
+
+    #[getter]
+    fn hash_join_spill_threshold_bytes(&self) -> PyResult<Option<usize>> {
+        Ok(self.config.hash_join_spill_threshold_bytes)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftExecutionConfig);
@@ -52,6 +52,11 @@ pub enum DaftError {
     NotImplemented(String),
     #[error("DaftError::CatalogError {0}")]
     CatalogError(String),
+    #[error("DaftError::UnexpectedProbeTableVariant expected {expected}, got {got}")]
+    UnexpectedProbeTableVariant {
+        expected: &'static str,
+        got: &'static str,
+    },
 }
 
 impl DaftError {
@@ -9,6 +9,7 @@ use daft_dsl::{
     optimization::split_conjuction,
     Expr, ExprRef, Operator,
 };
+use daft_schema::dtype::DataType;
 
 use crate::{PartitionField, PartitionTransform};
 
@@ -23,6 +24,26 @@ fn unalias(expr: ExprRef) -> DaftResult<ExprRef> {
     Ok(res.data)
 }
 
+/// Whether `iceberg_bucket` can be evaluated on a column of `dtype` (i.e. the dtype is murmur3 hashable).
+fn supports_iceberg_bucket(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Decimal128(..)
+            | DataType::Date
+            | DataType::Timestamp(..)
+            | DataType::Utf8
+            | DataType::Binary
+    ) || dtype.is_integer()
+}
+
+/// Whether `iceberg_truncate` can be evaluated on a column of `dtype`.
+fn supports_iceberg_truncate(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Decimal128(..) | DataType::Utf8 | DataType::Binary
+    ) || dtype.is_integer()
+}
+
 fn apply_partitioning_expr(expr: ExprRef, pfield: &PartitionField) -> Option<ExprRef> {
     use PartitionTransform::{
         Day, Hour, IcebergBucket, IcebergTruncate, Identity, Month, Void, Year,
@@ -40,14 +61,18 @@ fn apply_partitioning_expr(expr: ExprRef, pfield: &PartitionField) -> Option<Exp
         Some(Day) => Some(partitioning::days(expr)),
         Some(Hour) => Some(partitioning::hours(expr)),
         Some(Void) => Some(null_lit()),
-        Some(IcebergBucket(n)) => Some(partitioning::iceberg_bucket(
-            expr.cast(&pfield.source_field.as_ref().unwrap().dtype),
-            n as i32,
-        )),
-        Some(IcebergTruncate(w)) => Some(partitioning::iceberg_truncate(
-            expr.cast(&pfield.source_field.as_ref().unwrap().dtype),
-            w as i64,
-        )),
+        // If the source dtype is not supported by the transform, we leave the predicate as a data-only filter
+        // rather than producing a partition filter that would fail on evaluation.
+        Some(IcebergBucket(n)) => {
+            let source_dtype = &pfield.source_field.as_ref()?.dtype;
+            supports_iceberg_bucket(source_dtype)
+                .then(|| partitioning::iceberg_bucket(expr.cast(source_dtype), n as i32))
+        }
+        Some(IcebergTruncate(w)) => {
+            let source_dtype = &pfield.source_field.as_ref()?.dtype;
+            supports_iceberg_truncate(source_dtype)
+                .then(|| partitioning::iceberg_truncate(expr.cast(source_dtype), w as i64))
+        }
         _ => None,
     }
 }
@@ -308,6 +333,23 @@ pub fn rewrite_predicate_for_partitioning(
                     Ok(Transformed::no(expr))
                 }
             }
+            // IsIn
+            // Should only work for Identity, rewriting to a membership test on the partition column
+            Expr::IsIn(ref inner, ref items) => {
+                if let Expr::Column(col_name) = inner.as_ref()
+                    && let Some(pfield) = source_to_pfield.get(col_name.as_ref())
+                    && matches!(pfield.transform, Some(PartitionTransform::Identity))
+                    && let Some(new_items) = items
+                        .iter()
+                        .map(|item| apply_partitioning_expr(item.clone(), pfield))
+                        .collect::<Option<Vec<_>>>()
+                {
+                    return Ok(Transformed::yes(
+                        Expr::IsIn(col(pfield.field.name.as_str()), new_items).arced(),
+                    ));
+                }
+                Ok(Transformed::no(expr))
+            }
 
             Expr::IsNull(ref expr)
                 if let Expr::Column(col_name) = expr.as_ref()
@@ -357,3 +399,105 @@ pub fn rewrite_predicate_for_partitioning(
         needs_filter_op_preds,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_dsl::{col, functions::partitioning, lit, Expr, LiteralValue};
+    use daft_schema::{dtype::DataType, field::Field};
+
+    use super::rewrite_predicate_for_partitioning;
+    use crate::{PartitionField, PartitionTransform};
+
+    fn truncate_pfield(dtype: DataType, w: u64) -> DaftResult<PartitionField> {
+        PartitionField::new(
+            Field::new("a_trunc", dtype.clone()),
+            Some(Field::new("a", dtype)),
+            Some(PartitionTransform::IcebergTruncate(w)),
+        )
+    }
+
+    #[test]
+    fn test_rewrite_is_in_with_identity_partition() -> DaftResult<()> {
+        let pfield = PartitionField::new(
+            Field::new("a_part", DataType::Int64),
+            Some(Field::new("a", DataType::Int64)),
+            Some(PartitionTransform::Identity),
+        )?;
+        let predicate = col("a").is_in(vec![lit(1i64), lit(2i64)]);
+
+        let groups = rewrite_predicate_for_partitioning(&predicate, &[pfield])?;
+        // The membership test is rewritten onto the partition column.
+        assert_eq!(
+            groups.partition_only_filter,
+            vec![col("a_part").is_in(vec![
+                lit(1i64).cast(&DataType::Int64),
+                lit(2i64).cast(&DataType::Int64)
+            ])]
+        );
+        // The original predicate on the source column still filters the data.
+        assert_eq!(groups.data_only_filter, vec![predicate]);
+        assert!(groups.needing_filter_op.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rewrite_eq_with_string_truncate() -> DaftResult<()> {
+        let pfield = truncate_pfield(DataType::Utf8, 3)?;
+        let predicate = col("a").eq(lit("hello"));
+
+        let groups = rewrite_predicate_for_partitioning(&predicate, &[pfield])?;
+        assert_eq!(
+            groups.partition_only_filter,
+            vec![col("a_trunc").eq(partitioning::iceberg_truncate(
+                lit("hello").cast(&DataType::Utf8),
+                3
+            ))]
+        );
+        assert_eq!(groups.data_only_filter, vec![predicate]);
+        assert!(groups.needing_filter_op.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rewrite_eq_with_decimal_truncate() -> DaftResult<()> {
+        let dtype = DataType::Decimal128(10, 2);
+        let pfield = truncate_pfield(dtype.clone(), 100)?;
+        let value = Expr::Literal(LiteralValue::Decimal(12345, 10, 2)).arced();
+        let predicate = col("a").eq(value.clone());
+
+        let groups = rewrite_predicate_for_partitioning(&predicate, &[pfield])?;
+        assert_eq!(
+            groups.partition_only_filter,
+            vec![col("a_trunc").eq(partitioning::iceberg_truncate(value.cast(&dtype), 100))]
+        );
+        assert_eq!(groups.data_only_filter, vec![predicate]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rewrite_skips_unsupported_truncate_dtype() -> DaftResult<()> {
+        let pfield = truncate_pfield(DataType::Float64, 10)?;
+        let predicate = col("a").lt(lit(1.5));
+
+        let groups = rewrite_predicate_for_partitioning(&predicate, &[pfield])?;
+        assert!(groups.partition_only_filter.is_empty());
+        assert_eq!(groups.data_only_filter, vec![predicate]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rewrite_skips_unsupported_bucket_dtype() -> DaftResult<()> {
+        let pfield = PartitionField::new(
+            Field::new("a_bucket", DataType::Int32),
+            Some(Field::new("a", DataType::Boolean)),
+            Some(PartitionTransform::IcebergBucket(4)),
+        )?;
+        let predicate = col("a").eq(lit(true));
+
+        let groups = rewrite_predicate_for_partitioning(&predicate, &[pfield])?;
+        assert!(groups.partition_only_filter.is_empty());
+        assert_eq!(groups.data_only_filter, vec![predicate]);
+        Ok(())
+    }
+}
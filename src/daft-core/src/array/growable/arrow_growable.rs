@@ -36,6 +36,11 @@ where
         self.arrow2_growable.extend_validity(additional);
     }
 
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.arrow2_growable.reserve(additional);
+    }
+
     #[inline]
     fn build(&mut self) -> DaftResult<Series> {
         let arrow_array = self.arrow2_growable.as_box();
@@ -209,9 +214,38 @@ impl<'a> Growable for ArrowExtensionGrowable<'a> {
         self.child_growable.extend_validity(additional);
     }
     #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.child_growable.reserve(additional);
+    }
+    #[inline]
     fn build(&mut self) -> DaftResult<Series> {
         let arr = self.child_growable.as_box();
         let field = Arc::new(Field::new(self.name.clone(), self.dtype.clone()));
         Ok(ExtensionArray::from_arrow(field, arr)?.into_series())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_then_extend_does_not_reallocate() {
+        let array = Int64Array::from(("a", (0..100).collect::<Vec<i64>>()));
+        let mut growable = ArrowInt64Growable::new("a", &DataType::Int64, vec![&array], true, 0);
+
+        growable.reserve(250);
+        let capacity = growable.arrow2_growable.capacity();
+        assert!(capacity >= 250);
+
+        growable.extend(0, 0, 100);
+        growable.add_nulls(50);
+        growable.extend(0, 0, 100);
+        assert_eq!(growable.arrow2_growable.capacity(), capacity);
+
+        let result = growable.build().unwrap();
+        assert_eq!(result.len(), 250);
+        assert_eq!(result.i64().unwrap().get(99), Some(99));
+        assert_eq!(result.i64().unwrap().get(100), None);
+    }
+}
@@ -27,6 +27,10 @@ impl<'a> ArrowBitmapGrowable<'a> {
         self.mutable_bitmap.extend_constant(additional, false);
     }
 
+    pub fn reserve(&mut self, additional: usize) {
+        self.mutable_bitmap.reserve(additional);
+    }
+
     pub fn build(self) -> arrow2::bitmap::Bitmap {
         self.mutable_bitmap.into()
     }
@@ -76,6 +76,15 @@ impl<'a> Growable for FixedSizeListGrowable<'a> {
         }
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.child_growable
+            .reserve(additional * self.element_fixed_len);
+
+        if let Some(growable_validity) = &mut self.growable_validity {
+            growable_validity.reserve(additional);
+        }
+    }
+
     fn build(&mut self) -> DaftResult<Series> {
         let grown_validity = std::mem::take(&mut self.growable_validity);
 
@@ -87,6 +87,15 @@ impl<'a> Growable for ListGrowable<'a> {
         self.growable_offsets.extend_constant(additional);
     }
 
+    fn reserve(&mut self, additional: usize) {
+        // The number of child elements is unknown up front, so only the per-list buffers are reserved.
+        self.growable_offsets.reserve(additional);
+
+        if let Some(growable_validity) = &mut self.growable_validity {
+            growable_validity.reserve(additional);
+        }
+    }
+
     fn build(&mut self) -> DaftResult<Series> {
         let grown_offsets = std::mem::take(&mut self.growable_offsets);
         let grown_validity = std::mem::take(&mut self.growable_validity);
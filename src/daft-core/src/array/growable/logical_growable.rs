@@ -32,6 +32,10 @@ where
         self.physical_growable.add_nulls(additional);
     }
     #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.physical_growable.reserve(additional);
+    }
+    #[inline]
     fn build(&mut self) -> DaftResult<Series> {
         let physical_arr = self.physical_growable.build()?;
         let arr = LogicalArray::<L>::new(
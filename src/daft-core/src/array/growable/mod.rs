@@ -68,6 +68,10 @@ pub trait Growable {
     /// Extends this [`Growable`] with null elements
     fn add_nulls(&mut self, additional: usize);
 
+    /// Reserves capacity for at least `additional` more elements, so that a following run of
+    /// [`Growable::extend`] or [`Growable::add_nulls`] calls does not reallocate incrementally.
+    fn reserve(&mut self, additional: usize);
+
     /// Builds an array from the [`Growable`]
     fn build(&mut self) -> DaftResult<Series>;
 }
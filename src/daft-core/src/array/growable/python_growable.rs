@@ -56,6 +56,10 @@ impl<'a> Growable for PythonGrowable<'a> {
         }
     }
     #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.buffer.reserve(additional);
+    }
+    #[inline]
     fn build(&mut self) -> common_error::DaftResult<Series> {
         let mut buf: Vec<pyo3::PyObject> = vec![];
         swap(&mut self.buffer, &mut buf);
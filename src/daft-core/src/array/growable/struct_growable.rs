@@ -82,6 +82,16 @@ impl<'a> Growable for StructGrowable<'a> {
         }
     }
 
+    fn reserve(&mut self, additional: usize) {
+        for child_growable in &mut self.children_growables {
+            child_growable.reserve(additional);
+        }
+
+        if let Some(growable_validity) = &mut self.growable_validity {
+            growable_validity.reserve(additional);
+        }
+    }
+
     fn build(&mut self) -> DaftResult<Series> {
         let grown_validity = std::mem::take(&mut self.growable_validity);
 
@@ -6,7 +6,10 @@ use common_error::{DaftError, DaftResult};
 use super::{as_arrow::AsArrow, full::FullNull};
 use crate::{
     array::{DataArray, FixedSizeListArray},
-    datatypes::{DaftNumericType, DaftPrimitiveType, DataType, Field, Utf8Array},
+    datatypes::{
+        DaftIntegerType, DaftNumericType, DaftPrimitiveType, DataType, Field, NumericNative,
+        Utf8Array,
+    },
     kernels::utf8::add_utf8_arrays,
     prelude::Decimal128Array,
     series::Series,
@@ -227,6 +230,49 @@ where
     }
 }
 
+/// Integer division that rounds towards negative infinity, matching Python's `//`.
+fn floored_div<N: NumericNative>(l: N, r: N) -> N {
+    let quotient = l / r;
+    if l % r != N::zero() && ((l < N::zero()) != (r < N::zero())) {
+        quotient - N::one()
+    } else {
+        quotient
+    }
+}
+
+/// Integer remainder that takes the sign of the divisor, matching Python's `%`.
+fn floored_rem<N: NumericNative>(l: N, r: N) -> N {
+    let remainder = l % r;
+    if remainder != N::zero() && ((remainder < N::zero()) != (r < N::zero())) {
+        remainder + r
+    } else {
+        remainder
+    }
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftIntegerType,
+{
+    pub fn floor_div(&self, rhs: &Self) -> DaftResult<Self> {
+        arithmetic_helper(
+            self,
+            rhs,
+            |l, r| binary_with_nulls(l, r, floored_div),
+            floored_div,
+        )
+    }
+
+    pub fn floor_rem(&self, rhs: &Self) -> DaftResult<Self> {
+        arithmetic_helper(
+            self,
+            rhs,
+            |l, r| binary_with_nulls(l, r, floored_rem),
+            floored_rem,
+        )
+    }
+}
+
 fn div_with_nulls<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
 where
     T: arrow2::types::NativeType + Div<Output = T>,
@@ -9,13 +9,14 @@ pub fn idx_sort<I, F>(
     cmp: F,
     length: usize,
     descending: bool,
+    nulls_first: bool,
 ) -> PrimitiveArray<I>
 where
     I: Index,
     F: Fn(&I, &I) -> std::cmp::Ordering,
 {
     let (mut indices, start_idx, end_idx) =
-        generate_initial_indices::<I>(validity, length, descending);
+        generate_initial_indices::<I>(validity, length, nulls_first);
     let indices_slice = &mut indices.as_mut_slice()[start_idx..end_idx];
 
     if !descending {
@@ -56,10 +57,12 @@ where
     PrimitiveArray::<I>::new(data_type, indices.into(), None)
 }
 
+/// Returns the row indices with the null rows moved to the front (`nulls_first`) or back, along
+/// with the `[start, end)` range of the valid rows that still need sorting.
 fn generate_initial_indices<I>(
     validity: Option<&Bitmap>,
     length: usize,
-    descending: bool,
+    nulls_first: bool,
 ) -> (Vec<I>, usize, usize)
 where
     I: Index,
@@ -69,7 +72,7 @@ where
 
     if let Some(validity) = validity {
         let mut indices = vec![I::default(); length];
-        if descending {
+        if nulls_first {
             let mut nulls = 0;
             let mut valids = 0;
             validity
@@ -8,8 +8,8 @@ use super::{as_arrow::AsArrow, from_arrow::FromArrow, full::FullNull, DaftCompar
 use crate::{
     array::DataArray,
     datatypes::{
-        BinaryArray, BooleanArray, DaftArrowBackedType, DaftPrimitiveType, DataType, Field,
-        FixedSizeBinaryArray, NullArray, Utf8Array,
+        BinaryArray, BooleanArray, DaftArrowBackedType, DaftPrimitiveType, DataType,
+        Decimal128Array, Field, FixedSizeBinaryArray, NullArray, Utf8Array,
     },
     utils::arrow::arrow_bitmap_and_helper,
 };
@@ -337,6 +337,81 @@ where
     }
 }
 
+/// A decimal scalar to compare a [`Decimal128Array`] against, given as an unscaled `value` and its
+/// `scale`, i.e. the number `value * 10^-scale`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalScalar {
+    pub value: i128,
+    pub scale: usize,
+}
+
+impl DecimalScalar {
+    #[must_use]
+    pub fn new(value: i128, scale: usize) -> Self {
+        Self { value, scale }
+    }
+}
+
+impl Decimal128Array {
+    /// Rescales `rhs` to the scale of this array, erroring if that would drop nonzero digits or
+    /// overflow.
+    fn rescale_scalar(&self, rhs: DecimalScalar) -> DaftResult<i128> {
+        let DataType::Decimal128(_, scale) = self.data_type() else {
+            unreachable!("Decimal128Array should have a Decimal128 dtype")
+        };
+        let scale = *scale;
+        let factor = |diff: usize| {
+            u32::try_from(diff)
+                .ok()
+                .and_then(|diff| 10i128.checked_pow(diff))
+        };
+        if rhs.scale <= scale {
+            factor(scale - rhs.scale)
+                .and_then(|factor| rhs.value.checked_mul(factor))
+                .ok_or_else(|| {
+                    DaftError::ValueError(format!(
+                        "Decimal scalar {rhs:?} overflows when rescaled to scale {scale}"
+                    ))
+                })
+        } else {
+            match factor(rhs.scale - scale) {
+                Some(factor) if rhs.value % factor == 0 => Ok(rhs.value / factor),
+                _ => Err(DaftError::ValueError(format!(
+                    "Decimal scalar {rhs:?} cannot be rescaled to scale {scale} without losing precision"
+                ))),
+            }
+        }
+    }
+}
+
+impl DaftCompare<DecimalScalar> for Decimal128Array {
+    type Output = DaftResult<BooleanArray>;
+
+    fn equal(&self, rhs: DecimalScalar) -> Self::Output {
+        Ok(self.compare_to_scalar(self.rescale_scalar(rhs)?, comparison::eq_scalar))
+    }
+
+    fn not_equal(&self, rhs: DecimalScalar) -> Self::Output {
+        Ok(self.compare_to_scalar(self.rescale_scalar(rhs)?, comparison::neq_scalar))
+    }
+
+    fn lt(&self, rhs: DecimalScalar) -> Self::Output {
+        Ok(self.compare_to_scalar(self.rescale_scalar(rhs)?, comparison::lt_scalar))
+    }
+
+    fn lte(&self, rhs: DecimalScalar) -> Self::Output {
+        Ok(self.compare_to_scalar(self.rescale_scalar(rhs)?, comparison::lt_eq_scalar))
+    }
+
+    fn gt(&self, rhs: DecimalScalar) -> Self::Output {
+        Ok(self.compare_to_scalar(self.rescale_scalar(rhs)?, comparison::gt_scalar))
+    }
+
+    fn gte(&self, rhs: DecimalScalar) -> Self::Output {
+        Ok(self.compare_to_scalar(self.rescale_scalar(rhs)?, comparison::gt_eq_scalar))
+    }
+}
+
 impl DaftCompare<&Self> for BooleanArray {
     type Output = DaftResult<Self>;
 
@@ -1677,7 +1752,58 @@ impl DaftCompare<&[u8]> for FixedSizeBinaryArray {
 mod tests {
     use common_error::DaftResult;
 
-    use crate::{array::ops::DaftCompare, datatypes::Int64Array};
+    use super::DecimalScalar;
+    use crate::{
+        array::ops::DaftCompare,
+        datatypes::{DataType, Decimal128Array, Field, Int64Array},
+    };
+
+    fn decimal_array(values: Vec<i128>, precision: usize, scale: usize) -> Decimal128Array {
+        Decimal128Array::from_values_iter(
+            Field::new("price", DataType::Decimal128(precision, scale)),
+            values.into_iter(),
+        )
+    }
+
+    #[test]
+    fn compare_decimal_array_with_scalar_of_smaller_scale() -> DaftResult<()> {
+        // 1.50, 2.00, 2.25 compared against 2.0
+        let array = decimal_array(vec![150, 200, 225], 5, 2);
+        let rhs = DecimalScalar::new(20, 1);
+
+        let result: Vec<_> = array.gt(rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(false), Some(false), Some(true)]);
+        let result: Vec<_> = array.equal(rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(false), Some(true), Some(false)]);
+        let result: Vec<_> = array.lte(rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(true), Some(true), Some(false)]);
+        Ok(())
+    }
+
+    #[test]
+    fn compare_decimal_array_with_scalar_of_larger_scale() -> DaftResult<()> {
+        // 1.5, 2.0, 2.5 compared against 2.000
+        let array = decimal_array(vec![15, 20, 25], 3, 1);
+        let rhs = DecimalScalar::new(2000, 3);
+
+        let result: Vec<_> = array.lt(rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(true), Some(false), Some(false)]);
+        let result: Vec<_> = array.not_equal(rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(true), Some(false), Some(true)]);
+        let result: Vec<_> = array.gte(rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(false), Some(true), Some(true)]);
+        Ok(())
+    }
+
+    #[test]
+    fn compare_decimal_array_with_scalar_errors_on_precision_loss() {
+        let array = decimal_array(vec![15, 20, 25], 3, 1);
+        // 2.05 is not representable at scale 1
+        assert!(array.gt(DecimalScalar::new(205, 2)).is_err());
+        // rescaling to the column scale overflows i128
+        let array = decimal_array(vec![1], 38, 38);
+        assert!(array.equal(DecimalScalar::new(i128::MAX, 0)).is_err());
+    }
 
     #[test]
     fn equal_int64_array_with_scalar() -> DaftResult<()> {
@@ -0,0 +1,82 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{array::DataArray, datatypes::DaftNumericType};
+
+/// Addition for running sums, which reports integer overflow instead of wrapping.
+pub trait CumulativeAdd: Sized {
+    fn cumulative_add(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_cumulative_add {
+    ($($t:ty),*) => {
+        $(impl CumulativeAdd for $t {
+            fn cumulative_add(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+        })*
+    };
+}
+
+impl_checked_cumulative_add!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl CumulativeAdd for f32 {
+    fn cumulative_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+
+impl CumulativeAdd for f64 {
+    fn cumulative_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+
+impl<T: DaftNumericType> DataArray<T> {
+    /// Folds the valid values in order with `op`, emitting the running accumulator at each valid
+    /// position and null at each null position.
+    fn cumulative_op(
+        &self,
+        op: impl Fn(T::Native, T::Native) -> DaftResult<T::Native>,
+    ) -> DaftResult<Self> {
+        let mut acc: Option<T::Native> = None;
+        let values = self
+            .as_arrow()
+            .iter()
+            .map(|v| {
+                v.map(|&v| {
+                    let next = match acc {
+                        Some(acc) => op(acc, v)?,
+                        None => v,
+                    };
+                    acc = Some(next);
+                    Ok(next)
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Self::from_iter(self.field.clone(), values.into_iter()))
+    }
+
+    pub fn cumsum(&self) -> DaftResult<Self>
+    where
+        T::Native: CumulativeAdd,
+    {
+        self.cumulative_op(|acc, v| {
+            acc.cumulative_add(v).ok_or_else(|| {
+                DaftError::ComputeError(format!(
+                    "Overflow in cumsum of column \"{}\" with dtype {}",
+                    self.name(),
+                    self.data_type()
+                ))
+            })
+        })
+    }
+
+    pub fn cummax(&self) -> DaftResult<Self> {
+        self.cumulative_op(|acc, v| Ok(if v > acc { v } else { acc }))
+    }
+
+    pub fn cummin(&self) -> DaftResult<Self> {
+        self.cumulative_op(|acc, v| Ok(if v < acc { v } else { acc }))
+    }
+}
@@ -3,7 +3,10 @@ use std::sync::Arc;
 use arrow2::types::Index;
 use common_error::{DaftError, DaftResult};
 use daft_schema::{dtype::DataType, field::Field};
-use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
+use xxhash_rust::{
+    xxh3::{xxh3_64, xxh3_64_with_seed},
+    xxh64::xxh64,
+};
 
 use super::as_arrow::AsArrow;
 use crate::{
@@ -329,12 +332,8 @@ impl Decimal128Array {
             .expect("this should be a decimal array");
         let hashes = arr.into_iter().map(|d| {
             d.map(|d| {
-                let twos_compliment = u128::from_ne_bytes(d.to_ne_bytes());
-                let bits_needed = u128::BITS - twos_compliment.leading_zeros();
-                let bytes_needed = bits_needed.div_ceil(8) as usize;
-                let be_bytes = twos_compliment.to_be_bytes();
-                let unsigned =
-                    mur3::murmurhash3_x86_32(&be_bytes[(be_bytes.len() - bytes_needed)..], 0);
+                let (be_bytes, start) = decimal_hash_bytes(*d);
+                let unsigned = mur3::murmurhash3_x86_32(&be_bytes[start..], 0);
                 i32::from_ne_bytes(unsigned.to_ne_bytes())
             })
         });
@@ -369,3 +368,127 @@ fn murmur3_32_hash_from_iter_no_nulls<B: AsRef<[u8]>>(
         .collect::<Vec<_>>();
     Ok(Int32Array::from((name, hashes)))
 }
+
+/// The minimal big-endian bytes of a decimal's unscaled value, as a buffer and the offset where
+/// those bytes start.
+fn decimal_hash_bytes(d: i128) -> ([u8; 16], usize) {
+    let twos_compliment = u128::from_ne_bytes(d.to_ne_bytes());
+    let bits_needed = u128::BITS - twos_compliment.leading_zeros();
+    let bytes_needed = bits_needed.div_ceil(8) as usize;
+    let be_bytes = twos_compliment.to_be_bytes();
+    (be_bytes, be_bytes.len() - bytes_needed)
+}
+
+// xxhash64 hashes the same bytes as murmur3_32, so both agree on which values are equal.
+macro_rules! impl_int_xxhash64 {
+    ($ArrayT:ty) => {
+        impl $ArrayT {
+            pub fn xxhash64(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+                xxhash64_from_iter(
+                    self.name(),
+                    self.as_arrow()
+                        .iter()
+                        .map(|v| v.map(|v| (*v as i64).to_le_bytes())),
+                    seed,
+                )
+            }
+        }
+    };
+}
+
+impl_int_xxhash64!(Int8Array);
+impl_int_xxhash64!(Int16Array);
+impl_int_xxhash64!(Int32Array);
+impl_int_xxhash64!(Int64Array);
+
+impl_int_xxhash64!(UInt8Array);
+impl_int_xxhash64!(UInt16Array);
+impl_int_xxhash64!(UInt32Array);
+impl_int_xxhash64!(UInt64Array);
+
+impl Utf8Array {
+    pub fn xxhash64(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        xxhash64_from_iter(
+            self.name(),
+            self.as_arrow().iter().map(|v| v.map(str::as_bytes)),
+            seed,
+        )
+    }
+}
+
+impl BinaryArray {
+    pub fn xxhash64(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        xxhash64_from_iter(self.name(), self.as_arrow().iter(), seed)
+    }
+}
+
+impl FixedSizeBinaryArray {
+    pub fn xxhash64(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        xxhash64_from_iter(self.name(), self.as_arrow().iter(), seed)
+    }
+}
+
+impl DateArray {
+    pub fn xxhash64(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        self.physical.xxhash64(seed)
+    }
+}
+
+impl TimeArray {
+    pub fn xxhash64(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        let us = self.cast(&crate::datatypes::DataType::Time(
+            crate::datatypes::TimeUnit::Microseconds,
+        ))?;
+        us.time()?.physical.xxhash64(seed)
+    }
+}
+
+impl TimestampArray {
+    pub fn xxhash64(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        let us = self.cast(&crate::datatypes::DataType::Timestamp(
+            crate::datatypes::TimeUnit::Microseconds,
+            None,
+        ))?;
+        us.timestamp()?.physical.xxhash64(seed)
+    }
+}
+
+impl Decimal128Array {
+    pub fn xxhash64(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        let arr = self
+            .data()
+            .as_any()
+            .downcast_ref::<arrow2::array::PrimitiveArray<i128>>()
+            .expect("this should be a decimal array");
+        xxhash64_from_iter(
+            self.name(),
+            arr.iter().map(|d| {
+                d.map(|d| {
+                    let (be_bytes, start) = decimal_hash_bytes(*d);
+                    be_bytes[start..].to_vec()
+                })
+            }),
+            seed,
+        )
+    }
+}
+
+/// Hashes each non-null byte string with XXH64, using the matching row of `seed` (or 0) as the
+/// seed. Null inputs hash to null.
+fn xxhash64_from_iter<B: AsRef<[u8]>>(
+    name: &str,
+    byte_iter: impl Iterator<Item = Option<B>>,
+    seed: Option<&UInt64Array>,
+) -> DaftResult<UInt64Array> {
+    let hashes: Vec<Option<u64>> = match seed {
+        Some(seed) => byte_iter
+            .zip(seed.as_arrow().iter())
+            .map(|(b, s)| b.map(|b| xxh64(b.as_ref(), s.copied().unwrap_or(0))))
+            .collect(),
+        None => byte_iter.map(|b| b.map(|b| xxh64(b.as_ref(), 0))).collect(),
+    };
+    Ok(UInt64Array::from_iter(
+        Field::new(name, DataType::UInt64),
+        hashes.into_iter(),
+    ))
+}
@@ -1,7 +1,7 @@
 use std::{iter::repeat, sync::Arc};
 
 use arrow2::offset::OffsetsBuffer;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use indexmap::{
     map::{raw_entry_v1::RawEntryMut, RawEntryApiV1},
     IndexMap,
@@ -19,7 +19,7 @@ use crate::{
     kernels::search_sorted::build_is_valid,
     prelude::MapArray,
     series::{IntoSeries, Series},
-    utils::identity_hash_set::IdentityBuildHasher,
+    utils::{dyn_compare::build_dyn_compare, identity_hash_set::IdentityBuildHasher},
 };
 
 fn join_arrow_list_of_utf8s(
@@ -201,6 +201,53 @@ fn get_chunks_helper(
     }
 }
 
+/// Helper function that splits each list in the Series into consecutive chunks of a given `size`,
+/// where the final chunk of a list holds its remaining elements and may be shorter than `size`.
+///
+/// Since no elements are discarded, the chunks are a list array over the unchanged `flat_child`,
+/// with offsets placed every `size` elements within each parent list.
+///
+/// # Arguments
+///
+/// * `flat_child`  - The Series that we're extracting chunks from.
+/// * `field`       - The field of the parent list.
+/// * `validity`    - The parent list's validity.
+/// * `size`        - The size for each chunk.
+/// * `list_bounds` - The contiguous start and end offsets of each parent list within `flat_child`.
+fn get_chunks_with_remainder_helper(
+    flat_child: &Series,
+    field: Arc<Field>,
+    validity: Option<&arrow2::bitmap::Bitmap>,
+    size: usize,
+    list_bounds: impl Iterator<Item = (i64, i64)>,
+) -> DaftResult<Series> {
+    let mut list_bounds = list_bounds.peekable();
+    let first_start = list_bounds.peek().map_or(0, |(start, _)| *start);
+    let mut inner_offsets = vec![first_start];
+    let mut new_offsets = vec![0];
+    for (start, end) in list_bounds {
+        inner_offsets.extend(((start + size as i64)..end).step_by(size));
+        if end > start {
+            inner_offsets.push(end);
+        }
+        new_offsets.push(inner_offsets.len() as i64 - 1);
+    }
+    let inner_list_field = field.to_exploded_field()?.to_list_field()?;
+    let inner_list = ListArray::new(
+        inner_list_field.clone(),
+        flat_child.clone(),
+        OffsetsBuffer::try_from(inner_offsets)?,
+        None, // As in `get_chunks_helper`, this extra layer of lists has no validity of its own.
+    );
+    Ok(ListArray::new(
+        inner_list_field.to_list_field()?,
+        inner_list.into_series(),
+        OffsetsBuffer::try_from(new_offsets)?,
+        validity.cloned(), // Copy the parent's validity.
+    )
+    .into_series())
+}
+
 fn list_sort_helper(
     flat_child: &Series,
     offsets: &OffsetsBuffer<i64>,
@@ -571,6 +618,53 @@ impl ListArray {
         )
     }
 
+    /// Finds the zero-based index of the first element in each list that equals the corresponding
+    /// entry of `value`, which must have the list's element type. A length 1 `value` is compared
+    /// against every list. Null lists, null values and lists without a match yield null.
+    pub fn index_of(&self, value: &Series) -> DaftResult<Int64Array> {
+        if value.len() != 1 && value.len() != self.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected list index_of value to have length 1 or {}, got {}",
+                self.len(),
+                value.len()
+            )));
+        }
+        let compare = build_dyn_compare(self.child_data_type(), value.data_type(), false, false)?;
+        let child = self.flat_child.as_physical()?.to_arrow();
+        let value_arrow = value.as_physical()?.to_arrow();
+        let offsets = self.offsets();
+        let validity = self.validity();
+        let indices = (0..self.len())
+            .map(|i| {
+                if !validity.map_or(true, |v| v.get_bit(i)) {
+                    return None;
+                }
+                let value_idx = if value.len() == 1 { 0 } else { i };
+                let (start, end) = (offsets[i] as usize, offsets[i + 1] as usize);
+                (start..end)
+                    .position(|j| {
+                        compare(child.as_ref(), value_arrow.as_ref(), j, value_idx).is_eq()
+                    })
+                    .map(|idx| idx as i64)
+            })
+            .collect::<Vec<_>>();
+        Ok(Int64Array::from_iter(
+            Field::new(self.name(), DataType::Int64),
+            indices.into_iter(),
+        ))
+    }
+
+    /// Splits each list into chunks of `size`, keeping a shorter final chunk for any remainder.
+    pub fn get_chunks_with_remainder(&self, size: usize) -> DaftResult<Series> {
+        get_chunks_with_remainder_helper(
+            &self.flat_child,
+            self.field.clone(),
+            self.validity(),
+            size,
+            self.offsets().buffer().windows(2).map(|w| (w[0], w[1])),
+        )
+    }
+
     // Sorts the lists within a list column
     pub fn list_sort(&self, desc: &BooleanArray, nulls_first: &BooleanArray) -> DaftResult<Self> {
         let offsets = self.offsets();
@@ -808,6 +902,18 @@ impl FixedSizeListArray {
         )
     }
 
+    /// Splits each list into chunks of `size`, keeping a shorter final chunk for any remainder.
+    pub fn get_chunks_with_remainder(&self, size: usize) -> DaftResult<Series> {
+        let list_size = self.fixed_element_len() as i64;
+        get_chunks_with_remainder_helper(
+            &self.flat_child,
+            self.field.clone(),
+            self.validity(),
+            size,
+            (0..self.len() as i64).map(|i| (i * list_size, (i + 1) * list_size)),
+        )
+    }
+
     // Sorts the lists within a list column
     pub fn list_sort(&self, desc: &BooleanArray, nulls_first: &BooleanArray) -> DaftResult<Self> {
         let fixed_size = self.fixed_element_len();
@@ -17,6 +17,7 @@ mod comparison;
 mod concat;
 mod concat_agg;
 mod count;
+mod cumulative;
 mod exp;
 mod filter;
 mod float;
@@ -62,8 +63,11 @@ mod utf8;
 use std::hash::BuildHasher;
 
 use common_error::DaftResult;
+pub use comparison::DecimalScalar;
 pub use hll_sketch::HLL_SKETCH_DTYPE;
-pub use sort::{build_multi_array_bicompare, build_multi_array_compare};
+pub use sort::{
+    build_multi_array_bicompare, build_multi_array_compare, sort_multiple, top_n_multiple,
+};
 pub use utf8::{PadPlacement, Utf8NormalizeOptions};
 
 use crate::count_mode::CountMode;
@@ -204,6 +208,24 @@ pub trait DaftStddevAggable {
     fn grouped_stddev(&self, groups: &GroupIndices) -> Self::Output;
 }
 
+pub trait DaftVarianceAggable {
+    type Output;
+    fn variance(&self, ddof: u64) -> Self::Output;
+    fn grouped_variance(&self, groups: &GroupIndices, ddof: u64) -> Self::Output;
+}
+
+pub trait DaftVarianceStateAggable {
+    type Output;
+    fn variance_state(&self) -> Self::Output;
+    fn grouped_variance_state(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftMergeVarianceAggable {
+    type Output;
+    fn merge_variance(&self, ddof: u64) -> Self::Output;
+    fn grouped_merge_variance(&self, groups: &GroupIndices, ddof: u64) -> Self::Output;
+}
+
 pub trait DaftCompareAggable {
     type Output;
     fn min(&self) -> Self::Output;
@@ -1,26 +1,83 @@
-use common_error::DaftResult;
-use num_traits::Pow;
+use common_error::{DaftError, DaftResult};
+use num_traits::{NumCast, ToPrimitive, Zero};
 
-use crate::datatypes::{Float32Array, Float64Array};
+use crate::{
+    array::DataArray,
+    datatypes::{DaftIntegerType, DaftNumericType, Float32Array, Float64Array},
+};
+
+/// Rounds `v` to `decimal` places with ties going to the nearest even digit.
+///
+/// Negative `decimal` rounds to the left of the decimal point, e.g. `1250.0` at `-2` gives `1200.0`.
+/// NaN and infinities are returned unchanged.
+fn round_half_even(v: f64, decimal: i32) -> f64 {
+    if !v.is_finite() {
+        return v;
+    }
+    if decimal == 0 {
+        v.round_ties_even()
+    } else if decimal > 0 {
+        let multiplier = 10f64.powi(decimal);
+        (v * multiplier).round_ties_even() / multiplier
+    } else {
+        // dividing by the exact power of ten avoids the representation error of e.g. `0.01`
+        let divisor = 10f64.powi(-decimal);
+        (v / divisor).round_ties_even() * divisor
+    }
+}
 
 impl Float32Array {
     pub fn round(&self, decimal: i32) -> DaftResult<Self> {
-        if decimal == 0 {
-            self.apply(|v| v.round())
-        } else {
-            let multiplier: f64 = 10.0.pow(decimal);
-            self.apply(|v| ((v as f64 * multiplier).round() / multiplier) as f32)
-        }
+        self.apply(|v| round_half_even(f64::from(v), decimal) as f32)
     }
 }
 
 impl Float64Array {
     pub fn round(&self, decimal: i32) -> DaftResult<Self> {
-        if decimal == 0 {
-            self.apply(|v| v.round())
-        } else {
-            let multiplier: f64 = 10.0.pow(decimal);
-            self.apply(|v| ((v * multiplier).round() / multiplier))
+        self.apply(|v| round_half_even(v, decimal))
+    }
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftIntegerType,
+    <T as DaftNumericType>::Native: Ord,
+{
+    /// Rounds to a multiple of `10^-decimal` with ties going to the even multiple.
+    ///
+    /// Integers are already exact for `decimal >= 0`, so this only changes values for negative `decimal`.
+    pub fn round(&self, decimal: i32) -> DaftResult<Self> {
+        if decimal >= 0 {
+            return Ok(self.clone());
         }
+        // anything past 10^38 rounds every representable integer to zero
+        let Some(divisor) = 10i128.checked_pow(decimal.unsigned_abs()) else {
+            return self.apply(|_| <T as DaftNumericType>::Native::zero());
+        };
+        let values = self
+            .as_arrow()
+            .iter()
+            .map(|v| {
+                v.map(|v| {
+                    let v = v.to_i128().expect("integer types fit in i128");
+                    let (q, r) = (v.div_euclid(divisor), v.rem_euclid(divisor));
+                    let q = match (2 * r).cmp(&divisor) {
+                        std::cmp::Ordering::Less => q,
+                        std::cmp::Ordering::Greater => q + 1,
+                        std::cmp::Ordering::Equal => q + q.rem_euclid(2),
+                    };
+                    <<T as DaftNumericType>::Native as NumCast>::from(q * divisor).ok_or_else(
+                        || {
+                            DaftError::ComputeError(format!(
+                                "Rounding {v} to {decimal} decimals overflows {}",
+                                self.data_type()
+                            ))
+                        },
+                    )
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Self::from_iter(self.field.clone(), values.into_iter()))
     }
 }
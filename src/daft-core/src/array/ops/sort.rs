@@ -2,7 +2,7 @@ use arrow2::{
     array::ord::{self, DynComparator},
     types::Index,
 };
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use super::{arrow2::sort::primitive::common::multi_column_idx_sort, as_arrow::AsArrow};
 #[cfg(feature = "python")]
@@ -17,11 +17,13 @@ use crate::{
         },
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, Decimal128Array,
         ExtensionArray, FixedSizeBinaryArray, Float32Array, Float64Array, IntervalArray, NullArray,
-        Utf8Array,
+        UInt64Array, Utf8Array,
+    },
+    kernels::search_sorted::{
+        build_compare_with_nan, build_compare_with_nulls, build_is_valid, cmp_float,
     },
-    kernels::search_sorted::{build_compare_with_nulls, cmp_float},
     series::Series,
-    utils::{ensure_nulls_first, ensure_nulls_first_arr},
+    utils::ensure_nulls_first_arr,
 };
 
 pub fn build_multi_array_compare(
@@ -58,6 +60,144 @@ pub fn build_multi_array_bicompare(
     Ok(combined_comparator)
 }
 
+/// Builds a row comparator over `series` that orders lexicographically, with each key column
+/// ordered by its own `descending` and `nulls_first` flag.
+fn build_multi_key_comparator(
+    series: &[&Series],
+    descending: &[bool],
+    nulls_first: &[bool],
+) -> DaftResult<DynComparator> {
+    let Some(first) = series.first() else {
+        return Err(DaftError::ValueError(
+            "sort requires at least one key".to_string(),
+        ));
+    };
+    if series.len() != descending.len() || series.len() != nulls_first.len() {
+        return Err(DaftError::ValueError(format!(
+            "sort keys, descending and nulls_first must have the same length, got {}, {} and {}",
+            series.len(),
+            descending.len(),
+            nulls_first.len()
+        )));
+    }
+    let len = first.len();
+    if let Some(s) = series.iter().find(|s| s.len() != len) {
+        return Err(DaftError::ValueError(format!(
+            "sort keys must all have the same length, got {len} for {} and {} for {}",
+            first.name(),
+            s.len(),
+            s.name()
+        )));
+    }
+
+    let comparators = series
+        .iter()
+        .zip(descending)
+        .zip(nulls_first)
+        .map(|((s, &descending), &nulls_first)| {
+            let array = s.to_arrow();
+            let cmp = build_compare_with_nan(array.as_ref(), array.as_ref())?;
+            let is_valid = build_is_valid(array.as_ref());
+            let comparator: DynComparator =
+                Box::new(move |i, j| match (is_valid(i), is_valid(j)) {
+                    (true, true) if descending => cmp(i, j).reverse(),
+                    (true, true) => cmp(i, j),
+                    (false, false) => std::cmp::Ordering::Equal,
+                    (false, true) if nulls_first => std::cmp::Ordering::Less,
+                    (false, true) => std::cmp::Ordering::Greater,
+                    (true, false) if nulls_first => std::cmp::Ordering::Greater,
+                    (true, false) => std::cmp::Ordering::Less,
+                });
+            Ok(comparator)
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+
+    Ok(Box::new(move |a, b| {
+        comparators
+            .iter()
+            .map(|cmp| cmp(a, b))
+            .find(|ord| ord.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    }))
+}
+
+/// Returns the stable permutation that sorts the rows of `series` lexicographically, with each key
+/// column sorted by its own `descending` and `nulls_first` flag.
+pub fn sort_multiple(
+    series: &[&Series],
+    descending: &[bool],
+    nulls_first: &[bool],
+) -> DaftResult<UInt64Array> {
+    let comparator = build_multi_key_comparator(series, descending, nulls_first)?;
+    let first = series[0];
+    let mut indices = (0..first.len() as u64).collect::<Vec<_>>();
+    indices.sort_by(|&a, &b| comparator(a as usize, b as usize));
+    Ok(UInt64Array::from((first.name(), indices)))
+}
+
+/// A row index ordered by a shared comparator, with ties broken by position so that the order
+/// is total and matches a stable sort.
+struct TopNEntry<'a> {
+    idx: usize,
+    comparator: &'a DynComparator,
+}
+
+impl Ord for TopNEntry<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.comparator)(self.idx, other.idx).then(self.idx.cmp(&other.idx))
+    }
+}
+
+impl PartialOrd for TopNEntry<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TopNEntry<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for TopNEntry<'_> {}
+
+/// Returns the first `n` indices of [`sort_multiple`]'s permutation without sorting every row.
+///
+/// Keeps a bounded max-heap of the `n` smallest rows seen so far, so this runs in
+/// O(rows · log n) rather than O(rows · log rows).
+pub fn top_n_multiple(
+    series: &[&Series],
+    descending: &[bool],
+    nulls_first: &[bool],
+    n: usize,
+) -> DaftResult<UInt64Array> {
+    let comparator = build_multi_key_comparator(series, descending, nulls_first)?;
+    let first = series[0];
+    let mut heap = std::collections::BinaryHeap::with_capacity(n.min(first.len()));
+    if n > 0 {
+        for idx in 0..first.len() {
+            let entry = TopNEntry {
+                idx,
+                comparator: &comparator,
+            };
+            if heap.len() < n {
+                heap.push(entry);
+            } else if let Some(mut largest) = heap.peek_mut()
+                && entry < *largest
+            {
+                *largest = entry;
+            }
+        }
+    }
+    let indices = heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|entry| entry.idx as u64)
+        .collect::<Vec<_>>();
+    Ok(UInt64Array::from((first.name(), indices)))
+}
+
 impl<T> DataArray<T>
 where
     T: DaftIntegerType,
@@ -68,8 +208,6 @@ where
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
-        ensure_nulls_first(descending, nulls_first)?;
-
         let arrow_array = self.as_arrow();
 
         let result =
@@ -77,7 +215,7 @@ where
                 I::Native,
                 T::Native,
                 _,
-            >(arrow_array, ord::total_cmp, descending);
+            >(arrow_array, ord::total_cmp, descending, nulls_first);
 
         Ok(DataArray::<I>::from((self.name(), Box::new(result))))
     }
@@ -164,7 +302,6 @@ impl Float32Array {
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
-        ensure_nulls_first(descending, nulls_first)?;
         let arrow_array = self.as_arrow();
 
         let result =
@@ -172,7 +309,7 @@ impl Float32Array {
                 I::Native,
                 f32,
                 _,
-            >(arrow_array, cmp_float::<f32>, descending);
+            >(arrow_array, cmp_float::<f32>, descending, nulls_first);
 
         Ok(DataArray::<I>::from((self.name(), Box::new(result))))
     }
@@ -260,7 +397,6 @@ impl Float64Array {
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
-        ensure_nulls_first(descending, nulls_first)?;
         let arrow_array = self.as_arrow();
 
         let result =
@@ -268,7 +404,7 @@ impl Float64Array {
                 I::Native,
                 f64,
                 _,
-            >(arrow_array, cmp_float::<f64>, descending);
+            >(arrow_array, cmp_float::<f64>, descending, nulls_first);
 
         Ok(DataArray::<I>::from((self.name(), Box::new(result))))
     }
@@ -356,7 +492,6 @@ impl Decimal128Array {
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
-        ensure_nulls_first(descending, nulls_first)?;
         let arrow_array = self.as_arrow();
 
         let result =
@@ -364,7 +499,7 @@ impl Decimal128Array {
                 I::Native,
                 i128,
                 _,
-            >(arrow_array, ord::total_cmp, descending);
+            >(arrow_array, ord::total_cmp, descending, nulls_first);
 
         Ok(DataArray::<I>::from((self.name(), Box::new(result))))
     }
@@ -815,3 +950,66 @@ impl FixedShapeTensorArray {
         todo!("impl sort for FixedShapeTensorArray")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::{sort_multiple, top_n_multiple};
+    use crate::{
+        datatypes::{DataType, Field, Int64Array, Utf8Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_sort_multiple_mixed_dtypes_and_directions() -> DaftResult<()> {
+        let ints = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), Some(2), Some(1), None, Some(2), Some(1)].into_iter(),
+        )
+        .into_series();
+        let strs = Utf8Array::from_iter(
+            "b",
+            vec![Some("x"), Some("y"), Some("z"), Some("w"), Some("y"), None].into_iter(),
+        )
+        .into_series();
+
+        // a ascending with nulls last, then b descending with nulls first. Rows 1 and 4 tie on
+        // both keys, so they keep their input order.
+        let indices = sort_multiple(&[&ints, &strs], &[false, true], &[false, true])?;
+        assert_eq!(indices.as_slice(), &[5, 2, 0, 1, 4, 3]);
+
+        // a descending with nulls last, then b ascending with nulls last.
+        let indices = sort_multiple(&[&ints, &strs], &[true, false], &[false, false])?;
+        assert_eq!(indices.as_slice(), &[1, 4, 0, 2, 5, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_multiple_rejects_mismatched_arguments() {
+        let ints = Int64Array::from(("a", vec![1, 2])).into_series();
+        let short = Int64Array::from(("b", vec![1])).into_series();
+        assert!(sort_multiple(&[], &[], &[]).is_err());
+        assert!(sort_multiple(&[&ints], &[true, false], &[true]).is_err());
+        assert!(sort_multiple(&[&ints, &short], &[true, true], &[true, true]).is_err());
+    }
+
+    #[test]
+    fn test_top_n_multiple_matches_sort_prefix() -> DaftResult<()> {
+        let ints = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(3), None, Some(1), Some(3), Some(2), Some(1), None].into_iter(),
+        )
+        .into_series();
+
+        for (descending, nulls_first) in [(false, false), (true, true), (true, false)] {
+            let sorted = sort_multiple(&[&ints], &[descending], &[nulls_first])?;
+            for n in 0..=ints.len() + 1 {
+                let top = top_n_multiple(&[&ints], &[descending], &[nulls_first], n)?;
+                let expected = &sorted.as_slice()[..n.min(ints.len())];
+                assert_eq!(top.as_slice(), expected, "n = {n}");
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,34 +1,234 @@
 use arrow2::array::PrimitiveArray;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use crate::{
     array::{
-        ops::{DaftStddevAggable, GroupIndices},
-        DataArray,
+        ops::{
+            DaftMergeVarianceAggable, DaftStddevAggable, DaftVarianceAggable,
+            DaftVarianceStateAggable, GroupIndices,
+        },
+        DataArray, StructArray,
     },
-    datatypes::Float64Type,
-    utils::stats,
+    datatypes::{DataType, Field, Float64Array, Float64Type, UInt64Array},
+    series::IntoSeries,
+    utils::stats::VarianceState,
 };
 
 impl DaftStddevAggable for DataArray<Float64Type> {
     type Output = DaftResult<Self>;
 
     fn stddev(&self) -> Self::Output {
-        let stats = stats::calculate_stats(self)?;
-        let values = self.into_iter().flatten().copied();
-        let stddev = stats::calculate_stddev(stats, values);
+        let stddev = self.variance(0)?.get(0).map(f64::sqrt);
         let field = self.field.clone();
         let data = PrimitiveArray::<f64>::from([stddev]).boxed();
         Self::new(field, data)
     }
 
     fn grouped_stddev(&self, groups: &GroupIndices) -> Self::Output {
-        let grouped_stddevs_iter = stats::grouped_stats(self, groups)?.map(|(stats, group)| {
-            let values = group.iter().filter_map(|&index| self.get(index as _));
-            stats::calculate_stddev(stats, values)
-        });
+        let grouped_variances = self.grouped_variance(groups, 0)?;
+        let grouped_stddevs_iter = grouped_variances
+            .into_iter()
+            .map(|variance| variance.map(|v| v.sqrt()));
         let field = self.field.clone();
         let data = PrimitiveArray::<f64>::from_iter(grouped_stddevs_iter).boxed();
         Self::new(field, data)
     }
 }
+
+impl DaftVarianceAggable for DataArray<Float64Type> {
+    type Output = DaftResult<Self>;
+
+    fn variance(&self, ddof: u64) -> Self::Output {
+        let variance = self
+            .into_iter()
+            .flatten()
+            .copied()
+            .collect::<VarianceState>()
+            .variance(ddof);
+        let field = self.field.clone();
+        let data = PrimitiveArray::<f64>::from([variance]).boxed();
+        Self::new(field, data)
+    }
+
+    fn grouped_variance(&self, groups: &GroupIndices, ddof: u64) -> Self::Output {
+        let grouped_variances_iter = groups.iter().map(|group| {
+            group
+                .iter()
+                .filter_map(|&index| self.get(index as _))
+                .collect::<VarianceState>()
+                .variance(ddof)
+        });
+        let field = self.field.clone();
+        let data = PrimitiveArray::<f64>::from_iter(grouped_variances_iter).boxed();
+        Self::new(field, data)
+    }
+}
+
+fn states_to_struct(name: &str, states: impl Iterator<Item = VarianceState>) -> StructArray {
+    let (counts, (means, m2s)): (Vec<_>, (Vec<_>, Vec<_>)) = states
+        .map(|state| (state.count, (state.mean, state.m2)))
+        .unzip();
+    StructArray::new(
+        Field::new(name, VarianceState::dtype()),
+        vec![
+            UInt64Array::from(("count", counts)).into_series(),
+            Float64Array::from(("mean", means)).into_series(),
+            Float64Array::from(("m2", m2s)).into_series(),
+        ],
+        None,
+    )
+}
+
+/// Reads back the states written by [`states_to_struct`], treating nulls as empty states.
+fn struct_to_states(array: &StructArray) -> DaftResult<Vec<VarianceState>> {
+    let [count, mean, m2] = array.children.as_slice() else {
+        return Err(DaftError::TypeError(format!(
+            "Expected variance states of type {} but received {}",
+            VarianceState::dtype(),
+            array.field.dtype
+        )));
+    };
+    let (count, mean, m2) = (count.u64()?, mean.f64()?, m2.f64()?);
+    Ok((0..array.len())
+        .map(|index| {
+            if array
+                .validity()
+                .is_some_and(|validity| !validity.get_bit(index))
+            {
+                return VarianceState::default();
+            }
+            match (count.get(index), mean.get(index), m2.get(index)) {
+                (Some(count), Some(mean), Some(m2)) => VarianceState { count, mean, m2 },
+                _ => VarianceState::default(),
+            }
+        })
+        .collect())
+}
+
+impl DaftVarianceStateAggable for DataArray<Float64Type> {
+    type Output = DaftResult<StructArray>;
+
+    fn variance_state(&self) -> Self::Output {
+        let state = self
+            .into_iter()
+            .flatten()
+            .copied()
+            .collect::<VarianceState>();
+        Ok(states_to_struct(&self.field.name, std::iter::once(state)))
+    }
+
+    fn grouped_variance_state(&self, groups: &GroupIndices) -> Self::Output {
+        let grouped_states_iter = groups.iter().map(|group| {
+            group
+                .iter()
+                .filter_map(|&index| self.get(index as _))
+                .collect::<VarianceState>()
+        });
+        Ok(states_to_struct(&self.field.name, grouped_states_iter))
+    }
+}
+
+impl DaftMergeVarianceAggable for StructArray {
+    type Output = DaftResult<Float64Array>;
+
+    fn merge_variance(&self, ddof: u64) -> Self::Output {
+        let variance = struct_to_states(self)?
+            .into_iter()
+            .fold(VarianceState::default(), VarianceState::merge)
+            .variance(ddof);
+        Ok(Float64Array::from_iter(
+            Field::new(&self.field.name, DataType::Float64),
+            std::iter::once(variance),
+        ))
+    }
+
+    fn grouped_merge_variance(&self, groups: &GroupIndices, ddof: u64) -> Self::Output {
+        let states = struct_to_states(self)?;
+        let grouped_variances_iter = groups.iter().map(|group| {
+            group
+                .iter()
+                .map(|&index| states[index as usize])
+                .fold(VarianceState::default(), VarianceState::merge)
+                .variance(ddof)
+        });
+        Ok(Float64Array::from_iter(
+            Field::new(&self.field.name, DataType::Float64),
+            grouped_variances_iter,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{
+            ops::{
+                DaftMergeVarianceAggable, DaftStddevAggable, DaftVarianceAggable,
+                DaftVarianceStateAggable,
+            },
+            StructArray,
+        },
+        datatypes::{DataType, Field, Float64Array},
+    };
+
+    fn values() -> Float64Array {
+        Float64Array::from_iter(
+            Field::new("x", DataType::Float64),
+            vec![Some(1.0), Some(2.0), None, Some(3.0), Some(4.0), Some(10.0)].into_iter(),
+        )
+    }
+
+    #[test]
+    fn test_variance() -> DaftResult<()> {
+        let values = values();
+        // mean = 4, squared deviations = 9 + 4 + 1 + 0 + 36 = 50
+        assert!((values.variance(0)?.get(0).unwrap() - 10.0).abs() < 1e-12);
+        assert!((values.variance(1)?.get(0).unwrap() - 12.5).abs() < 1e-12);
+        assert!((values.stddev()?.get(0).unwrap() - 10f64.sqrt()).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_grouped_variance() -> DaftResult<()> {
+        let values = values();
+        let groups = vec![vec![0, 1, 2], vec![3, 4, 5], vec![2]];
+
+        let population = values.grouped_variance(&groups, 0)?;
+        assert_eq!(population.get(0), Some(0.25));
+        assert!((population.get(1).unwrap() - 86.0 / 9.0).abs() < 1e-12);
+        assert_eq!(population.get(2), None);
+
+        let sample = values.grouped_variance(&groups, 1)?;
+        assert_eq!(sample.get(0), Some(0.5));
+        assert!((sample.get(1).unwrap() - 14.333_333_333_333_334).abs() < 1e-12);
+        assert_eq!(sample.get(2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_variance_states() -> DaftResult<()> {
+        // Values far from zero, where summing squares would cancel catastrophically.
+        let values = Float64Array::from_iter(
+            Field::new("x", DataType::Float64),
+            [4.0, 7.0, 13.0, 16.0, 10.0]
+                .into_iter()
+                .map(|v| Some(1e9 + v)),
+        );
+        let partials = StructArray::concat(&[
+            &values.slice(0, 2)?.variance_state()?,
+            &values.slice(2, 5)?.variance_state()?,
+        ])?;
+        assert_eq!(partials.len(), 2);
+        assert!((partials.merge_variance(0)?.get(0).unwrap() - 18.0).abs() < 1e-6);
+        assert!((partials.merge_variance(1)?.get(0).unwrap() - 22.5).abs() < 1e-6);
+
+        let groups = vec![vec![0, 1], vec![1], vec![]];
+        let grouped = partials.grouped_merge_variance(&groups, 1)?;
+        assert!((grouped.get(0).unwrap() - 22.5).abs() < 1e-6);
+        assert!((grouped.get(1).unwrap() - 9.0).abs() < 1e-6);
+        assert_eq!(grouped.get(2), None);
+        Ok(())
+    }
+}
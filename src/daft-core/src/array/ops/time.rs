@@ -61,7 +61,44 @@ fn process_interval(interval: &str, timeunit: TimeUnit) -> DaftResult<i64> {
     }
 }
 
+/// Formats each present value with `format_value`, surfacing invalid format strings (or specifiers
+/// that don't apply to the value, like `%H` on a date) as errors rather than panics.
+fn strftime_values<T, D: std::fmt::Display>(
+    name: &str,
+    values: impl Iterator<Item = Option<T>>,
+    format: &str,
+    format_value: impl Fn(T) -> D,
+) -> DaftResult<Utf8Array> {
+    use std::fmt::Write;
+
+    let formatted = values
+        .map(|val| {
+            val.map(|val| {
+                let mut out = String::new();
+                write!(out, "{}", format_value(val)).map_err(|_| {
+                    DaftError::ComputeError(format!(
+                        "Error in strftime: failed to format value with format {format}"
+                    ))
+                })?;
+                Ok(out)
+            })
+            .transpose()
+        })
+        .collect::<DaftResult<arrow2::array::Utf8Array<i64>>>()?;
+    Ok(Utf8Array::from((name, Box::new(formatted))))
+}
+
 impl DateArray {
+    /// Formats each date into a string using a chrono `format`.
+    pub fn strftime(&self, format: &str) -> DaftResult<Utf8Array> {
+        strftime_values(
+            self.name(),
+            self.physical.as_arrow().iter().map(|d| d.copied()),
+            format,
+            |days| arrow2::temporal_conversions::date32_to_date(days).format(format),
+        )
+    }
+
     pub fn day(&self) -> DaftResult<UInt32Array> {
         let input_array = self
             .physical
@@ -103,7 +140,161 @@ impl DateArray {
     }
 }
 
+/// A timestamp's timezone, either a fixed offset like `+05:00` or a named IANA zone.
+enum TimestampTz {
+    Fixed(chrono::FixedOffset),
+    Named(chrono_tz::Tz),
+}
+
+impl TimestampTz {
+    fn parse(tz: &str) -> DaftResult<Self> {
+        if let Ok(offset) = arrow2::temporal_conversions::parse_offset(tz) {
+            Ok(Self::Fixed(offset))
+        } else {
+            Self::parse_iana(tz)
+        }
+    }
+
+    fn parse_iana(tz: &str) -> DaftResult<Self> {
+        tz.parse::<chrono_tz::Tz>().map(Self::Named).map_err(|_| {
+            DaftError::ValueError(format!(
+                "Unknown timezone {tz}, expected an IANA timezone name"
+            ))
+        })
+    }
+
+    /// The local wall-clock time of the UTC instant `utc` in this timezone.
+    fn to_local(&self, utc: &chrono::NaiveDateTime) -> chrono::NaiveDateTime {
+        use chrono::TimeZone;
+        match self {
+            Self::Fixed(offset) => offset.from_utc_datetime(utc).naive_local(),
+            Self::Named(tz) => tz.from_utc_datetime(utc).naive_local(),
+        }
+    }
+
+    /// The UTC instant of the local wall-clock time `local` in this timezone, picking the earlier
+    /// instant for ambiguous times and `None` for times skipped by a DST transition.
+    fn to_utc(&self, local: &chrono::NaiveDateTime) -> Option<chrono::NaiveDateTime> {
+        use chrono::TimeZone;
+        match self {
+            Self::Fixed(offset) => offset
+                .from_local_datetime(local)
+                .earliest()
+                .map(|dt| dt.naive_utc()),
+            Self::Named(tz) => tz
+                .from_local_datetime(local)
+                .earliest()
+                .map(|dt| dt.naive_utc()),
+        }
+    }
+}
+
+fn naive_datetime_to_timestamp(dt: chrono::NaiveDateTime, timeunit: TimeUnit) -> DaftResult<i64> {
+    let dt = dt.and_utc();
+    match timeunit {
+        TimeUnit::Seconds => Ok(dt.timestamp()),
+        TimeUnit::Milliseconds => Ok(dt.timestamp_millis()),
+        TimeUnit::Microseconds => Ok(dt.timestamp_micros()),
+        TimeUnit::Nanoseconds => dt.timestamp_nanos_opt().ok_or_else(|| {
+            DaftError::ComputeError(format!("{dt} is out of range for nanosecond timestamps"))
+        }),
+    }
+}
+
 impl TimestampArray {
+    /// Formats each timestamp into a string using a chrono `format`, in the column's timezone if
+    /// it has one.
+    pub fn strftime(&self, format: &str) -> DaftResult<Utf8Array> {
+        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let tu = timeunit.to_arrow();
+        let values = self.physical.as_arrow().iter().map(|ts| ts.copied());
+        match tz {
+            Some(tz) => {
+                if let Ok(tz) = arrow2::temporal_conversions::parse_offset(tz) {
+                    strftime_values(self.name(), values, format, |ts| {
+                        arrow2::temporal_conversions::timestamp_to_datetime(ts, tu, &tz)
+                            .format(format)
+                    })
+                } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
+                    strftime_values(self.name(), values, format, |ts| {
+                        arrow2::temporal_conversions::timestamp_to_datetime(ts, tu, &tz)
+                            .format(format)
+                    })
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Cannot parse timezone in Timestamp datatype: {tz}"
+                    )))
+                }
+            }
+            None => strftime_values(self.name(), values, format, |ts| {
+                arrow2::temporal_conversions::timestamp_to_naive_datetime(ts, tu).format(format)
+            }),
+        }
+    }
+
+    /// Moves a timezone-aware timestamp into the IANA timezone `tz`, keeping the instant and so
+    /// changing its wall-clock representation.
+    pub fn convert_timezone(&self, tz: &str) -> DaftResult<Self> {
+        let DataType::Timestamp(timeunit, Some(_)) = self.data_type() else {
+            return Err(DaftError::TypeError(format!(
+                "convert_timezone requires a timezone-aware timestamp, got {}",
+                self.data_type()
+            )));
+        };
+        TimestampTz::parse_iana(tz)?;
+        Ok(Self::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, Some(tz.to_string())),
+            ),
+            self.physical.clone(),
+        ))
+    }
+
+    /// Attaches, replaces or (with `None`) removes the timezone, keeping the wall-clock time and so
+    /// shifting the instant. Wall-clock times that don't exist in the new timezone become errors.
+    pub fn replace_timezone(&self, tz: Option<&str>) -> DaftResult<Self> {
+        let DataType::Timestamp(timeunit, old_tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let old_tz = old_tz.as_deref().map(TimestampTz::parse).transpose()?;
+        let new_tz = tz.map(TimestampTz::parse_iana).transpose()?;
+        let tu = timeunit.to_arrow();
+
+        let physical = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|ts| {
+                ts.map(|&ts| {
+                    let utc = arrow2::temporal_conversions::timestamp_to_naive_datetime(ts, tu);
+                    let local = old_tz.as_ref().map_or(utc, |old_tz| old_tz.to_local(&utc));
+                    let shifted = match &new_tz {
+                        Some(new_tz) => new_tz.to_utc(&local).ok_or_else(|| {
+                            DaftError::ComputeError(format!(
+                                "Wall-clock time {local} does not exist in timezone {}",
+                                tz.unwrap_or_default()
+                            ))
+                        })?,
+                        None => local,
+                    };
+                    naive_datetime_to_timestamp(shifted, *timeunit)
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<arrow2::array::Int64Array>>()?;
+
+        Ok(Self::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, tz.map(str::to_string)),
+            ),
+            Int64Array::from((self.name(), Box::new(physical))),
+        ))
+    }
+
     pub fn date(&self) -> DaftResult<DateArray> {
         let physical = self.physical.as_arrow();
         let DataType::Timestamp(timeunit, tz) = self.data_type() else {
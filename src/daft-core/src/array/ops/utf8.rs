@@ -1,5 +1,6 @@
 use std::{
     borrow::Cow,
+    collections::HashMap,
     iter::{self, Repeat, Take},
     sync::Arc,
 };
@@ -147,6 +148,21 @@ fn split_array_on_regex<'a>(
     Ok(())
 }
 
+/// Compiles each distinct pattern once, reusing the compiled regex for repeated patterns.
+fn compile_regexes<'a>(
+    patterns: impl Iterator<Item = Option<&'a str>>,
+) -> impl Iterator<Item = Option<Result<regex::Regex, regex::Error>>> {
+    let mut cache: HashMap<&'a str, Result<regex::Regex, regex::Error>> = HashMap::new();
+    patterns.map(move |pat| {
+        pat.map(|pat| {
+            cache
+                .entry(pat)
+                .or_insert_with(|| regex::Regex::new(pat))
+                .clone()
+        })
+    })
+}
+
 fn regex_extract_first_match<'a>(
     arr_iter: impl Iterator<Item = Option<&'a str>>,
     regex_iter: impl Iterator<Item = Option<Result<regex::Regex, regex::Error>>>,
@@ -397,11 +413,29 @@ impl Utf8Array {
             };
         }
 
-        self.binary_broadcasted_compare(
-            pattern,
-            |data: &str, pat: &str| Ok(regex::Regex::new(pat)?.is_match(data)),
-            "match",
-        )
+        let (is_full_null, expected_size) = parse_inputs(self, &[pattern])
+            .map_err(|e| DaftError::ValueError(format!("Error in match: {e}")))?;
+        if is_full_null {
+            return Ok(BooleanArray::full_null(
+                self.name(),
+                &DataType::Boolean,
+                expected_size,
+            ));
+        }
+        if expected_size == 0 {
+            return Ok(BooleanArray::empty(self.name(), &DataType::Boolean));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let regex_iter = compile_regexes(pattern.as_arrow().iter());
+        let arrow_result = self_iter
+            .zip(regex_iter)
+            .map(|(val, re)| match (val, re) {
+                (Some(val), Some(re)) => Ok(Some(re?.is_match(val))),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<arrow2::array::BooleanArray>>()?;
+        Ok(BooleanArray::from((self.name(), arrow_result)))
     }
 
     pub fn split(&self, pattern: &Self, regex: bool) -> DaftResult<ListArray> {
@@ -442,10 +476,7 @@ impl Utf8Array {
                 )?;
             }
             (true, _) => {
-                let regex_iter = pattern
-                    .as_arrow()
-                    .iter()
-                    .map(|pat| pat.map(regex::Regex::new));
+                let regex_iter = compile_regexes(pattern.as_arrow().iter());
                 split_array_on_regex(
                     self_iter,
                     regex_iter,
@@ -502,10 +533,7 @@ impl Utf8Array {
                 regex_extract_first_match(self_iter, regex_iter, index, self.name())?
             }
             _ => {
-                let regex_iter = pattern
-                    .as_arrow()
-                    .iter()
-                    .map(|pat| pat.map(regex::Regex::new));
+                let regex_iter = compile_regexes(pattern.as_arrow().iter());
                 regex_extract_first_match(self_iter, regex_iter, index, self.name())?
             }
         };
@@ -538,10 +566,7 @@ impl Utf8Array {
                 regex_extract_all_matches(self_iter, regex_iter, index, expected_size, self.name())?
             }
             _ => {
-                let regex_iter = pattern
-                    .as_arrow()
-                    .iter()
-                    .map(|pat| pat.map(regex::Regex::new));
+                let regex_iter = compile_regexes(pattern.as_arrow().iter());
                 regex_extract_all_matches(self_iter, regex_iter, index, expected_size, self.name())?
             }
         };
@@ -569,10 +594,7 @@ impl Utf8Array {
                 regex_replace(self_iter, regex_iter, replacement_iter, self.name())?
             }
             (true, _) => {
-                let regex_iter = pattern
-                    .as_arrow()
-                    .iter()
-                    .map(|pat| pat.map(regex::Regex::new));
+                let regex_iter = compile_regexes(pattern.as_arrow().iter());
                 regex_replace(self_iter, regex_iter, replacement_iter, self.name())?
             }
             (false, _) => {
@@ -915,49 +937,80 @@ impl Utf8Array {
     }
 
     pub fn to_datetime(&self, format: &str, timezone: Option<&str>) -> DaftResult<TimestampArray> {
-        let len = self.len();
-        let self_iter = self.as_arrow().iter();
         let timeunit = daft_schema::time_unit::infer_timeunit_from_format_string(format);
+        self.strptime(format, timeunit, timezone, true)
+    }
 
-        let arrow_result = self_iter
-            .map(|val| match val {
-                Some(val) => {
-                    let timestamp = match timezone {
-                        Some(tz) => {
-                            let datetime = chrono::DateTime::parse_from_str(val, format).map_err(|e| {
-                                DaftError::ComputeError(format!(
-                                    "Error in to_datetime: failed to parse datetime {val} with format {format} : {e}"
-                                ))
-                            })?;
-                            let datetime_with_timezone = datetime.with_timezone(&tz.parse::<chrono_tz::Tz>().map_err(|e| {
-                                DaftError::ComputeError(format!(
-                                    "Error in to_datetime: failed to parse timezone {tz} : {e}"
-                                ))
-                            })?);
-                            match timeunit {
-                                TimeUnit::Seconds => datetime_with_timezone.timestamp(),
-                                TimeUnit::Milliseconds => datetime_with_timezone.timestamp_millis(),
-                                TimeUnit::Microseconds => datetime_with_timezone.timestamp_micros(),
-                                TimeUnit::Nanoseconds => datetime_with_timezone.timestamp_nanos_opt().ok_or_else(|| DaftError::ComputeError(format!("Error in to_datetime: failed to get nanoseconds for {val}")))?,
-                            }
+    /// Parses each string with the chrono `format` into a timestamp of the given `timeunit`.
+    ///
+    /// With a `timezone`, the format must contain an offset and values are converted into that
+    /// timezone. Unparseable values are an error when `raise_on_error` is set, and null otherwise.
+    pub fn strptime(
+        &self,
+        format: &str,
+        timeunit: TimeUnit,
+        timezone: Option<&str>,
+        raise_on_error: bool,
+    ) -> DaftResult<TimestampArray> {
+        let len = self.len();
+        let tz = timezone
+            .map(|tz| {
+                tz.parse::<chrono_tz::Tz>().map_err(|e| {
+                    DaftError::ComputeError(format!(
+                        "Error in to_datetime: failed to parse timezone {tz} : {e}"
+                    ))
+                })
+            })
+            .transpose()?;
+
+        let parse = |val: &str| -> DaftResult<i64> {
+            let parse_err = |e: chrono::ParseError| {
+                DaftError::ComputeError(format!(
+                    "Error in to_datetime: failed to parse datetime {val} with format {format} : {e}"
+                ))
+            };
+            let nanos_err = || {
+                DaftError::ComputeError(format!(
+                    "Error in to_datetime: failed to get nanoseconds for {val}"
+                ))
+            };
+            match tz {
+                Some(tz) => {
+                    let datetime = chrono::DateTime::parse_from_str(val, format)
+                        .map_err(parse_err)?
+                        .with_timezone(&tz);
+                    Ok(match timeunit {
+                        TimeUnit::Seconds => datetime.timestamp(),
+                        TimeUnit::Milliseconds => datetime.timestamp_millis(),
+                        TimeUnit::Microseconds => datetime.timestamp_micros(),
+                        TimeUnit::Nanoseconds => {
+                            datetime.timestamp_nanos_opt().ok_or_else(nanos_err)?
                         }
-                        None => {
-                            let naive_datetime = chrono::NaiveDateTime::parse_from_str(val, format).map_err(|e| {
-                                DaftError::ComputeError(format!(
-                                    "Error in to_datetime: failed to parse datetime {val} with format {format} : {e}"
-                                ))
-                            })?;
-                            match timeunit {
-                                TimeUnit::Seconds => naive_datetime.and_utc().timestamp(),
-                                TimeUnit::Milliseconds => naive_datetime.and_utc().timestamp_millis(),
-                                TimeUnit::Microseconds => naive_datetime.and_utc().timestamp_micros(),
-                                TimeUnit::Nanoseconds => naive_datetime.and_utc().timestamp_nanos_opt().ok_or_else(|| DaftError::ComputeError(format!("Error in to_datetime: failed to get nanoseconds for {val}")))?,
-                            }
+                    })
+                }
+                None => {
+                    let datetime = chrono::NaiveDateTime::parse_from_str(val, format)
+                        .map_err(parse_err)?
+                        .and_utc();
+                    Ok(match timeunit {
+                        TimeUnit::Seconds => datetime.timestamp(),
+                        TimeUnit::Milliseconds => datetime.timestamp_millis(),
+                        TimeUnit::Microseconds => datetime.timestamp_micros(),
+                        TimeUnit::Nanoseconds => {
+                            datetime.timestamp_nanos_opt().ok_or_else(nanos_err)?
                         }
-                    };
-                    Ok(Some(timestamp))
+                    })
                 }
-                _ => Ok(None),
+            }
+        };
+
+        let arrow_result = self
+            .as_arrow()
+            .iter()
+            .map(|val| match val.map(&parse) {
+                Some(Ok(timestamp)) => Ok(Some(timestamp)),
+                Some(Err(e)) if raise_on_error => Err(e),
+                Some(Err(_)) | None => Ok(None),
             })
             .collect::<DaftResult<arrow2::array::Int64Array>>()?;
 
@@ -1475,4 +1528,228 @@ mod tests {
         assert!(result.as_arrow().value(2));
         Ok(())
     }
+
+    fn utf8_array(name: &str, values: Vec<Option<&str>>) -> Utf8Array {
+        Utf8Array::from((
+            name,
+            Box::new(arrow2::array::Utf8Array::<i64>::from(values)),
+        ))
+    }
+
+    fn split_values(list: &ListArray, idx: usize) -> Option<Vec<String>> {
+        list.get(idx).map(|s| {
+            s.utf8()
+                .unwrap()
+                .as_arrow()
+                .values_iter()
+                .map(str::to_string)
+                .collect()
+        })
+    }
+
+    #[test]
+    fn check_split_broadcast_delimiter() -> DaftResult<()> {
+        let data = utf8_array("data", vec![Some("a,b,c"), Some(""), None, Some("abc")]);
+        let delimiter = utf8_array("delimiter", vec![Some(",")]);
+
+        let result = data.split(&delimiter, false)?;
+        assert_eq!(result.name(), "data");
+        assert_eq!(
+            result.data_type(),
+            &DataType::List(Box::new(DataType::Utf8))
+        );
+        assert_eq!(
+            split_values(&result, 0),
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+        // an empty string splits into a single empty string
+        assert_eq!(split_values(&result, 1), Some(vec![String::new()]));
+        assert_eq!(split_values(&result, 2), None);
+        assert_eq!(split_values(&result, 3), Some(vec!["abc".to_string()]));
+        Ok(())
+    }
+
+    #[test]
+    fn check_split_elementwise_delimiter() -> DaftResult<()> {
+        let data = utf8_array("data", vec![Some("a,b"), Some("a|b"), Some("a b")]);
+        let delimiter = utf8_array("delimiter", vec![Some(","), Some("|"), None]);
+
+        let result = data.split(&delimiter, false)?;
+        assert_eq!(
+            split_values(&result, 0),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(
+            split_values(&result, 1),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(split_values(&result, 2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn check_split_then_join_roundtrip() -> DaftResult<()> {
+        let data = utf8_array(
+            "data",
+            vec![
+                Some("a-b-c"),
+                Some(""),
+                None,
+                Some("--"),
+                Some("no delimiter"),
+            ],
+        );
+        let delimiter = utf8_array("delimiter", vec![Some("-")]);
+
+        let joined = data.split(&delimiter, false)?.join(&delimiter)?;
+        assert_eq!(joined.as_arrow(), data.as_arrow());
+        Ok(())
+    }
+
+    #[test]
+    fn check_match_regex() -> DaftResult<()> {
+        let data = utf8_array("data", vec![Some("abc123"), Some("xyz"), None]);
+
+        let pattern = utf8_array("pattern", vec![Some(r"\d+")]);
+        let result = data.match_(&pattern)?;
+        assert_eq!(result.name(), "data");
+        assert_eq!(result.get(0), Some(true));
+        assert_eq!(result.get(1), Some(false));
+        assert_eq!(result.get(2), None);
+
+        // anchored patterns give full matches
+        let pattern = utf8_array(
+            "pattern",
+            vec![Some(r"^[a-z]+$"), Some(r"^[a-z]+$"), Some("a")],
+        );
+        let result = data.match_(&pattern)?;
+        assert_eq!(result.get(0), Some(false));
+        assert_eq!(result.get(1), Some(true));
+        assert_eq!(result.get(2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn check_extract_capture_groups() -> DaftResult<()> {
+        let data = utf8_array("data", vec![Some("key=value"), Some("no match"), None]);
+        let pattern = utf8_array("pattern", vec![Some(r"(\w+)=(\w+)")]);
+
+        let full = data.extract(&pattern, 0)?;
+        assert_eq!(full.get(0), Some("key=value"));
+        assert_eq!(full.get(1), None);
+        assert_eq!(full.get(2), None);
+
+        let key = data.extract(&pattern, 1)?;
+        assert_eq!(key.get(0), Some("key"));
+        let value = data.extract(&pattern, 2)?;
+        assert_eq!(value.get(0), Some("value"));
+        // group indices past the last group are null
+        let missing = data.extract(&pattern, 3)?;
+        assert_eq!(missing.get(0), None);
+        Ok(())
+    }
+
+    #[test]
+    fn check_invalid_regex_errors() {
+        let data = utf8_array("data", vec![Some("abc"), Some("def")]);
+        let broadcast = utf8_array("pattern", vec![Some("(unclosed")]);
+        let elementwise = utf8_array("pattern", vec![Some("a"), Some("[")]);
+
+        for pattern in [&broadcast, &elementwise] {
+            assert!(matches!(
+                data.match_(pattern),
+                Err(DaftError::RegexError(_))
+            ));
+            assert!(matches!(
+                data.extract(pattern, 0),
+                Err(DaftError::RegexError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn check_replace_literal() -> DaftResult<()> {
+        let data = utf8_array("data", vec![Some("a.b.c"), Some("abc"), None]);
+        let pattern = utf8_array("pattern", vec![Some(".")]);
+        let replacement = utf8_array("replacement", vec![Some("-")]);
+
+        // `.` is matched literally, and every occurrence is replaced
+        let result = data.replace(&pattern, &replacement, false)?;
+        assert_eq!(result.name(), "data");
+        assert_eq!(result.get(0), Some("a-b-c"));
+        assert_eq!(result.get(1), Some("abc"));
+        assert_eq!(result.get(2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn check_replace_regex_group_references() -> DaftResult<()> {
+        let data = utf8_array("data", vec![Some("2024-01-31"), Some("not a date"), None]);
+        let pattern = utf8_array("pattern", vec![Some(r"(\d{4})-(\d{2})-(\d{2})")]);
+        let replacement = utf8_array("replacement", vec![Some("$3/$2/$1")]);
+
+        let result = data.replace(&pattern, &replacement, true)?;
+        assert_eq!(result.get(0), Some("31/01/2024"));
+        assert_eq!(result.get(1), Some("not a date"));
+        assert_eq!(result.get(2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn check_replace_broadcast() -> DaftResult<()> {
+        // a single input row broadcast against per-row patterns and replacements
+        let data = utf8_array("data", vec![Some("aaa")]);
+        let pattern = utf8_array("pattern", vec![Some("a"), Some("a+"), None]);
+        let replacement = utf8_array("replacement", vec![Some("b"), Some("c"), Some("d")]);
+
+        let result = data.replace(&pattern, &replacement, true)?;
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.get(0), Some("bbb"));
+        assert_eq!(result.get(1), Some("c"));
+        assert_eq!(result.get(2), None);
+
+        let null_replacement = utf8_array("replacement", vec![None]);
+        let result = data.replace(&pattern, &null_replacement, false)?;
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.as_arrow().null_count(), 3);
+
+        let invalid = utf8_array("pattern", vec![Some("(")]);
+        assert!(matches!(
+            data.replace(&invalid, &replacement, true),
+            Err(DaftError::RegexError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn check_strptime_nulls_or_errors_on_malformed_rows() -> DaftResult<()> {
+        let data = Utf8Array::from_iter(
+            "data",
+            vec![Some("2024-01-02 03:04:05"), Some("not a datetime"), None].into_iter(),
+        );
+        let format = "%Y-%m-%d %H:%M:%S";
+
+        let result = data.strptime(format, TimeUnit::Milliseconds, None, false)?;
+        assert_eq!(
+            result.data_type(),
+            &DataType::Timestamp(TimeUnit::Milliseconds, None)
+        );
+        assert_eq!(
+            result
+                .physical
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1_704_164_645_000), None, None]
+        );
+
+        assert!(data
+            .strptime(format, TimeUnit::Milliseconds, None, true)
+            .is_err());
+        assert!(data
+            .strptime(format, TimeUnit::Seconds, Some("Not/AZone"), false)
+            .is_err());
+        Ok(())
+    }
 }
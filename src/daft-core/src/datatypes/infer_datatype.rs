@@ -61,6 +61,31 @@ impl<'a> InferDataType<'a> {
         }
     }
 
+    pub fn bitwise_op(&self, other: &Self) -> DaftResult<DataType> {
+        // Whether a bitwise op (bitwise_and, bitwise_or, bitwise_xor) is supported between the two types.
+        let left = self.0;
+        let other = other.0;
+        match (left, other) {
+            (s, o) if s.is_integer() && o.is_integer() => {
+                let dtype = try_numeric_supertype(s, o)?;
+                if dtype.is_integer() {
+                    Ok(dtype)
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Cannot perform bitwise op on types: {}, {}",
+                        left, other
+                    )))
+                }
+            }
+            (s, o) if (s.is_integer() && o.is_null()) => Ok(s.clone()),
+            (s, o) if (s.is_null() && o.is_integer()) => Ok(o.clone()),
+            _ => Err(DaftError::TypeError(format!(
+                "Bitwise ops require integer operands, got: {}, {}",
+                left, other
+            ))),
+        }
+    }
+
     pub fn comparison_op(
         &self,
         other: &Self,
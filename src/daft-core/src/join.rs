@@ -17,6 +17,12 @@ pub enum JoinType {
     Outer,
     Anti,
     Semi,
+    /// Rows of the right side that have no match on the left side.
+    RightAnti,
+    /// Rows of the right side that have at least one match on the left side.
+    RightSemi,
+    Cross,
+    AsOf,
 }
 
 #[cfg(feature = "python")]
@@ -39,13 +45,17 @@ impl_bincode_py_state_serialization!(JoinType);
 
 impl JoinType {
     pub fn iterator() -> std::slice::Iter<'static, Self> {
-        static JOIN_TYPES: [JoinType; 6] = [
+        static JOIN_TYPES: [JoinType; 10] = [
             JoinType::Inner,
             JoinType::Left,
             JoinType::Right,
             JoinType::Outer,
             JoinType::Anti,
             JoinType::Semi,
+            JoinType::RightAnti,
+            JoinType::RightSemi,
+            JoinType::Cross,
+            JoinType::AsOf,
         ];
         JOIN_TYPES.iter()
     }
@@ -60,8 +70,12 @@ impl FromStr for JoinType {
             "left" => Ok(Self::Left),
             "right" => Ok(Self::Right),
             "outer" => Ok(Self::Outer),
-            "anti" => Ok(Self::Anti),
-            "semi" => Ok(Self::Semi),
+            "anti" | "left_anti" => Ok(Self::Anti),
+            "semi" | "left_semi" => Ok(Self::Semi),
+            "right_anti" => Ok(Self::RightAnti),
+            "right_semi" => Ok(Self::RightSemi),
+            "cross" => Ok(Self::Cross),
+            "asof" => Ok(Self::AsOf),
             _ => Err(DaftError::TypeError(format!(
                 "Join type {} is not supported; only the following types are supported: {:?}",
                 join_type,
@@ -71,6 +85,33 @@ impl FromStr for JoinType {
     }
 }
 
+/// Which right row an asof join matches to each left row.
+#[derive(Clone, Copy, Debug, Display, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub enum AsofDirection {
+    /// The last right row whose key is less than or equal to the left key.
+    #[default]
+    Backward,
+    /// The first right row whose key is greater than or equal to the left key.
+    Forward,
+    /// Whichever of the backward and forward matches is closer, preferring backward on ties.
+    Nearest,
+}
+
+impl FromStr for AsofDirection {
+    type Err = DaftError;
+
+    fn from_str(direction: &str) -> DaftResult<Self> {
+        match direction {
+            "backward" => Ok(Self::Backward),
+            "forward" => Ok(Self::Forward),
+            "nearest" => Ok(Self::Nearest),
+            _ => Err(DaftError::ValueError(format!(
+                "Asof direction {direction} is not supported; expected one of: backward, forward, nearest"
+            ))),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
 pub enum JoinStrategy {
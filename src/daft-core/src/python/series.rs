@@ -5,7 +5,7 @@ use std::{
 
 use common_arrow_ffi as ffi;
 use daft_hash::{HashFunctionKind, MurBuildHasher, Sha1Hasher};
-use daft_schema::python::PyDataType;
+use daft_schema::python::{PyDataType, PyTimeUnit};
 use pyo3::{
     exceptions::PyValueError,
     prelude::*,
@@ -266,6 +266,10 @@ impl PySeries {
         Ok(self.series.exp()?.into())
     }
 
+    pub fn pow(&self, exponent: &Self) -> PyResult<Self> {
+        Ok(self.series.pow(&exponent.series)?.into())
+    }
+
     pub fn take(&self, idx: &Self) -> PyResult<Self> {
         Ok(self.series.take(&idx.series)?.into())
     }
@@ -307,7 +311,27 @@ impl PySeries {
         Ok(self.series.argsort(descending, nulls_first)?.into())
     }
 
-    pub fn hash(&self, seed: Option<Self>) -> PyResult<Self> {
+    pub fn rank(&self, descending: bool, method: &str) -> PyResult<Self> {
+        let method = method.parse::<series::RankMethod>()?;
+        Ok(self.series.rank(descending, method)?.into())
+    }
+
+    #[pyo3(signature = (ignore_nulls=false))]
+    pub fn n_unique(&self, ignore_nulls: bool) -> PyResult<usize> {
+        Ok(self.series.n_unique(ignore_nulls)?)
+    }
+
+    pub fn is_unique(&self) -> PyResult<Self> {
+        Ok(self.series.is_unique()?.into())
+    }
+
+    pub fn mode(&self) -> PyResult<Self> {
+        Ok(self.series.mode()?.into())
+    }
+
+    #[pyo3(signature = (seed=None, hash_function="default"))]
+    pub fn hash(&self, seed: Option<Self>, hash_function: &str) -> PyResult<Self> {
+        let algorithm: series::HashAlgorithm = hash_function.parse()?;
         let seed_series;
         let mut seed_array = None;
         if let Some(s) = seed {
@@ -320,7 +344,7 @@ impl PySeries {
             seed_series = s.series;
             seed_array = Some(seed_series.u64()?);
         }
-        Ok(self.series.hash(seed_array)?.into_series().into())
+        Ok(self.series.hash_with(algorithm, seed_array)?.into())
     }
 
     pub fn minhash(
@@ -394,6 +418,18 @@ impl PySeries {
         Ok((self.series).mean(None)?.into())
     }
 
+    pub fn cumsum(&self) -> PyResult<Self> {
+        Ok(self.series.cumsum()?.into())
+    }
+
+    pub fn cummax(&self) -> PyResult<Self> {
+        Ok(self.series.cummax()?.into())
+    }
+
+    pub fn cummin(&self) -> PyResult<Self> {
+        Ok(self.series.cummin()?.into())
+    }
+
     pub fn min(&self) -> PyResult<Self> {
         Ok((self.series).min(None)?.into())
     }
@@ -418,6 +454,23 @@ impl PySeries {
         Ok(self.series.cast(&dtype.into())?.into())
     }
 
+    pub fn cast_strict(&self, dtype: PyDataType) -> PyResult<Self> {
+        Ok(self.series.cast_strict(&dtype.into())?.into())
+    }
+
+    #[pyo3(signature = (dtype, decimal_sep=".", thousands_sep=None))]
+    pub fn cast_string_to_numeric(
+        &self,
+        dtype: PyDataType,
+        decimal_sep: char,
+        thousands_sep: Option<char>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .series
+            .cast_string_to_numeric(&dtype.into(), decimal_sep, thousands_sep)?
+            .into())
+    }
+
     #[staticmethod]
     pub fn concat(series: Vec<Self>) -> PyResult<Self> {
         let series: Vec<_> = series.iter().map(|s| &s.series).collect();
@@ -568,6 +621,20 @@ impl PySeries {
         Ok(self.series.utf8_to_datetime(format, timezone)?.into())
     }
 
+    #[pyo3(signature = (format, timeunit, timezone=None, raise_on_error=false))]
+    pub fn utf8_strptime(
+        &self,
+        format: &str,
+        timeunit: PyTimeUnit,
+        timezone: Option<&str>,
+        raise_on_error: bool,
+    ) -> PyResult<Self> {
+        Ok(self
+            .series
+            .strptime(format, timeunit.timeunit, timezone, raise_on_error)?
+            .into())
+    }
+
     pub fn utf8_normalize(
         &self,
         remove_punct: bool,
@@ -633,6 +700,19 @@ impl PySeries {
         Ok(self.series.dt_second()?.into())
     }
 
+    pub fn dt_convert_timezone(&self, tz: &str) -> PyResult<Self> {
+        Ok(self.series.convert_timezone(tz)?.into())
+    }
+
+    #[pyo3(signature = (tz=None))]
+    pub fn dt_replace_timezone(&self, tz: Option<&str>) -> PyResult<Self> {
+        Ok(self.series.replace_timezone(tz)?.into())
+    }
+
+    pub fn dt_strftime(&self, format: &str) -> PyResult<Self> {
+        Ok(self.series.strftime(format)?.into())
+    }
+
     pub fn dt_time(&self) -> PyResult<Self> {
         Ok(self.series.dt_time()?.into())
     }
@@ -722,10 +802,22 @@ impl PySeries {
         Ok(self.series.not_null()?.into())
     }
 
+    pub fn null_count(&self) -> usize {
+        self.series.null_count()
+    }
+
     pub fn fill_null(&self, fill_value: &Self) -> PyResult<Self> {
         Ok(self.series.fill_null(&fill_value.series)?.into())
     }
 
+    #[pyo3(signature = (lower=None, upper=None))]
+    pub fn clip(&self, lower: Option<&Self>, upper: Option<&Self>) -> PyResult<Self> {
+        Ok(self
+            .series
+            .clip(lower.map(|s| &s.series), upper.map(|s| &s.series))?
+            .into())
+    }
+
     pub fn _debug_bincode_serialize(&self, py: Python) -> PyResult<PyObject> {
         let values = bincode::serialize(&self.series).unwrap();
         Ok(PyBytes::new_bound(py, &values).into())
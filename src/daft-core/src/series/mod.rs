@@ -10,7 +10,7 @@ pub use array_impl::IntoSeries;
 use common_display::table_display::{make_comfy_table, StrValue};
 use common_error::DaftResult;
 use derive_more::Display;
-pub use ops::cast_series_to_supertype;
+pub use ops::{cast_series_to_supertype, hash::HashAlgorithm, rank::RankMethod};
 
 pub(crate) use self::series_like::SeriesLike;
 use crate::{
@@ -24,3 +24,51 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        datatypes::{BooleanArray, DataType, Field, Int32Array, UInt16Array, Utf8Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_abs_unsigned_is_unchanged() -> DaftResult<()> {
+        let s = UInt16Array::from(("a", vec![0, 7, u16::MAX])).into_series();
+        let result = s.abs()?;
+        assert_eq!(result.data_type(), &DataType::UInt16);
+        assert_eq!(result.u16()?.as_slice(), &[0, 7, u16::MAX]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_abs_signed_flips_negatives() -> DaftResult<()> {
+        let s = Int32Array::from_iter(
+            Field::new("a", DataType::Int32),
+            vec![Some(-3), None, Some(0), Some(5)].into_iter(),
+        )
+        .into_series();
+        let result = s.abs()?;
+        assert_eq!(result.data_type(), &DataType::Int32);
+        assert_eq!(
+            result
+                .i32()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(3), None, Some(0), Some(5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_abs_rejects_non_numeric() {
+        let utf8 = Utf8Array::from(("a", ["-1"].as_slice())).into_series();
+        assert!(matches!(utf8.abs(), Err(DaftError::TypeError(_))));
+
+        let bool = BooleanArray::from(("b", [true].as_slice())).into_series();
+        assert!(matches!(bool.abs(), Err(DaftError::TypeError(_))));
+    }
+}
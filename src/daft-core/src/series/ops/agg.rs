@@ -4,8 +4,9 @@ use common_error::{DaftError, DaftResult};
 use crate::{
     array::{
         ops::{
-            DaftApproxSketchAggable, DaftHllMergeAggable, DaftMeanAggable, DaftStddevAggable,
-            DaftSumAggable, GroupIndices,
+            DaftApproxSketchAggable, DaftHllMergeAggable, DaftMeanAggable,
+            DaftMergeVarianceAggable, DaftStddevAggable, DaftSumAggable, DaftVarianceAggable,
+            DaftVarianceStateAggable, GroupIndices,
         },
         ListArray,
     },
@@ -185,6 +186,60 @@ impl Series {
         }
     }
 
+    pub fn variance(&self, groups: Option<&GroupIndices>, ddof: u64) -> DaftResult<Self> {
+        let target_type = try_stddev_aggregation_supertype(self.data_type())?;
+        match target_type {
+            DataType::Float64 => {
+                let casted = self.cast(&DataType::Float64)?;
+                let casted = casted.f64()?;
+                let series = groups
+                    .map_or_else(
+                        || casted.variance(ddof),
+                        |groups| casted.grouped_variance(groups, ddof),
+                    )?
+                    .into_series();
+                Ok(series)
+            }
+            _ => Err(DaftError::not_implemented(format!(
+                "Variance not implemented for {target_type}, source type: {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    /// Partial `(count, mean, M2)` states of the variance, to be combined with [`Self::merge_variance`].
+    pub fn variance_state(&self, groups: Option<&GroupIndices>) -> DaftResult<Self> {
+        let target_type = try_stddev_aggregation_supertype(self.data_type())?;
+        match target_type {
+            DataType::Float64 => {
+                let casted = self.cast(&DataType::Float64)?;
+                let casted = casted.f64()?;
+                let series = groups
+                    .map_or_else(
+                        || casted.variance_state(),
+                        |groups| casted.grouped_variance_state(groups),
+                    )?
+                    .into_series();
+                Ok(series)
+            }
+            _ => Err(DaftError::not_implemented(format!(
+                "Variance not implemented for {target_type}, source type: {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn merge_variance(&self, groups: Option<&GroupIndices>, ddof: u64) -> DaftResult<Self> {
+        let states = self.struct_()?;
+        let series = groups
+            .map_or_else(
+                || states.merge_variance(ddof),
+                |groups| states.grouped_merge_variance(groups, ddof),
+            )?
+            .into_series();
+        Ok(series)
+    }
+
     pub fn min(&self, groups: Option<&GroupIndices>) -> DaftResult<Self> {
         self.inner.min(groups)
     }
@@ -194,6 +249,39 @@ impl Series {
     }
 
     pub fn any_value(&self, groups: Option<&GroupIndices>, ignore_nulls: bool) -> DaftResult<Self> {
+        self.first(groups, ignore_nulls)
+    }
+
+    /// The first value of each group, skipping nulls if `ignore_nulls` is set.
+    pub fn first(&self, groups: Option<&GroupIndices>, ignore_nulls: bool) -> DaftResult<Self> {
+        self.take_one_per_group(groups, ignore_nulls, false)
+    }
+
+    /// The last value of each group, skipping nulls if `ignore_nulls` is set.
+    pub fn last(&self, groups: Option<&GroupIndices>, ignore_nulls: bool) -> DaftResult<Self> {
+        self.take_one_per_group(groups, ignore_nulls, true)
+    }
+
+    fn take_one_per_group(
+        &self,
+        groups: Option<&GroupIndices>,
+        ignore_nulls: bool,
+        from_end: bool,
+    ) -> DaftResult<Self> {
+        fn pick<I: DoubleEndedIterator<Item = u64>>(
+            mut iter: I,
+            from_end: bool,
+            is_valid: impl Fn(u64) -> bool,
+        ) -> Option<u64> {
+            if from_end {
+                iter.rfind(|i| is_valid(*i))
+            } else {
+                iter.find(|i| is_valid(*i))
+            }
+        }
+
+        let validity = if ignore_nulls { self.validity() } else { None };
+        let is_valid = |i: u64| validity.map_or(true, |validity| validity.get_bit(i as usize));
         let indices = match groups {
             Some(groups) => {
                 if self.data_type().is_null() {
@@ -201,23 +289,19 @@ impl Series {
                         arrow2::datatypes::DataType::UInt64,
                         groups.len(),
                     ))
-                } else if ignore_nulls && let Some(validity) = self.validity() {
-                    Box::new(PrimitiveArray::from_trusted_len_iter(groups.iter().map(
-                        |g| g.iter().find(|i| validity.get_bit(**i as usize)).copied(),
-                    )))
                 } else {
                     Box::new(PrimitiveArray::from_trusted_len_iter(
-                        groups.iter().map(|g| g.first().copied()),
+                        groups
+                            .iter()
+                            .map(|g| pick(g.iter().copied(), from_end, is_valid)),
                     ))
                 }
             }
             None => {
-                let idx = if self.data_type().is_null() || self.is_empty() {
+                let idx = if self.data_type().is_null() {
                     None
-                } else if ignore_nulls && let Some(validity) = self.validity() {
-                    validity.iter().position(|v| v).map(|i| i as u64)
                 } else {
-                    Some(0)
+                    pick(0..self.len() as u64, from_end, is_valid)
                 };
 
                 Box::new(PrimitiveArray::from([idx]))
@@ -272,3 +356,53 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field, Int64Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn values() -> Series {
+        Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![None, Some(1), Some(2), None, Some(3), None].into_iter(),
+        )
+        .into_series()
+    }
+
+    fn to_vec(s: &Series) -> DaftResult<Vec<Option<i64>>> {
+        Ok(s.i64()?.into_iter().map(|v| v.copied()).collect())
+    }
+
+    #[test]
+    fn test_grouped_first_and_last() -> DaftResult<()> {
+        let groups = vec![vec![0, 1, 2], vec![3, 4, 5], vec![5]];
+
+        let first = values().first(Some(&groups), false)?;
+        assert_eq!(to_vec(&first)?, vec![None, None, None]);
+        let first = values().first(Some(&groups), true)?;
+        assert_eq!(to_vec(&first)?, vec![Some(1), Some(3), None]);
+
+        let last = values().last(Some(&groups), false)?;
+        assert_eq!(to_vec(&last)?, vec![Some(2), None, None]);
+        let last = values().last(Some(&groups), true)?;
+        assert_eq!(to_vec(&last)?, vec![Some(2), Some(3), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ungrouped_first_and_last() -> DaftResult<()> {
+        assert_eq!(to_vec(&values().first(None, false)?)?, vec![None]);
+        assert_eq!(to_vec(&values().first(None, true)?)?, vec![Some(1)]);
+        assert_eq!(to_vec(&values().last(None, false)?)?, vec![None]);
+        assert_eq!(to_vec(&values().last(None, true)?)?, vec![Some(3)]);
+
+        let empty = Int64Array::empty("a", &DataType::Int64).into_series();
+        assert_eq!(to_vec(&empty.last(None, true)?)?, vec![None]);
+        Ok(())
+    }
+}
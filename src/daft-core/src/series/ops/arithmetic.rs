@@ -321,6 +321,11 @@ impl Rem for &Series {
         match &output_type {
             #[cfg(feature = "python")]
             DataType::Python => run_python_binary_operator_fn(lhs, rhs, "mod"),
+            output_type if output_type.is_integer() => {
+                with_match_integer_daft_types!(output_type, |$T| {
+                    Ok(cast_downcast_op!(lhs, rhs, output_type, <$T as DaftDataType>::ArrayType, floor_rem)?.into_series())
+                })
+            }
             output_type if output_type.is_numeric() => {
                 with_match_numeric_daft_types!(output_type, |$T| {
                     Ok(cast_downcast_op!(lhs, rhs, output_type, <$T as DaftDataType>::ArrayType, rem)?.into_series())
@@ -344,7 +349,7 @@ impl Series {
             DataType::Python => run_python_binary_operator_fn(lhs, rhs, "floordiv"),
             output_type if output_type.is_integer() => {
                 with_match_integer_daft_types!(output_type, |$T| {
-                    Ok(cast_downcast_op!(lhs, rhs, output_type, <$T as DaftDataType>::ArrayType, div)?.into_series())
+                    Ok(cast_downcast_op!(lhs, rhs, output_type, <$T as DaftDataType>::ArrayType, floor_div)?.into_series())
                 })
             }
             output_type if output_type.is_numeric() => {
@@ -510,6 +515,36 @@ mod tests {
         Ok(())
     }
     #[test]
+    fn floor_div_int_negative_operands() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![7, -7, 7, -7, 6, -6]));
+        let b = Int64Array::from(("b", vec![3, 3, -3, -3, -3, 3]));
+        let c = a.into_series().floor_div(&b.into_series())?;
+        assert_eq!(c.i64()?.as_slice(), &[2, -3, -3, 2, -2, -2]);
+        Ok(())
+    }
+    #[test]
+    fn rem_int_negative_operands() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![7, -7, 7, -7, 6, -6]));
+        let b = Int32Array::from(("b", vec![3, 3, -3, -3, -3, 3]));
+        let c = (a.into_series() % b.into_series())?;
+        assert_eq!(*c.data_type(), DataType::Int64);
+        assert_eq!(c.i64()?.as_slice(), &[1, 2, -2, -1, 0, 0]);
+        Ok(())
+    }
+    #[test]
+    fn rem_int_negative_broadcast_divisor() -> DaftResult<()> {
+        let a = Int32Array::from(("a", vec![-7, -1, 0, 5]));
+        let b = Int32Array::from(("b", vec![3]));
+        let c = (a.into_series() % b.into_series())?;
+        assert_eq!(c.i32()?.as_slice(), &[2, 2, 0, 2]);
+
+        let a = Int32Array::from(("a", vec![-7]));
+        let b = Int32Array::from(("b", vec![3, -3, 4]));
+        let c = a.into_series().floor_div(&b.into_series())?;
+        assert_eq!(c.i32()?.as_slice(), &[-3, 2, -2]);
+        Ok(())
+    }
+    #[test]
     fn add_int_and_int_full_null() -> DaftResult<()> {
         let a = Int64Array::from(("a", vec![1, 2, 3]));
         let b = Int64Array::full_null("b", &DataType::Int64, 3);
@@ -0,0 +1,67 @@
+use common_error::DaftResult;
+
+use crate::{
+    array::ops::DaftLogical,
+    datatypes::InferDataType,
+    series::{utils::cast::cast_downcast_op, IntoSeries, Series},
+    with_match_integer_daft_types,
+};
+
+macro_rules! impl_bitwise_op {
+    ($fname:ident, $op:ident) => {
+        pub fn $fname(&self, rhs: &Self) -> DaftResult<Self> {
+            let output_type = InferDataType::from(self.data_type())
+                .bitwise_op(&InferDataType::from(rhs.data_type()))?;
+            with_match_integer_daft_types!(output_type, |$T| {
+                Ok(cast_downcast_op!(
+                    self,
+                    rhs,
+                    &output_type,
+                    <$T as DaftDataType>::ArrayType,
+                    $op
+                )?
+                .into_series())
+            })
+        }
+    };
+}
+
+impl Series {
+    impl_bitwise_op!(bitwise_and, and);
+    impl_bitwise_op!(bitwise_or, or);
+    impl_bitwise_op!(bitwise_xor, xor);
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Float64Array, Int32Array, Int64Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_bitwise_ops_on_integers() -> DaftResult<()> {
+        let lhs = Int32Array::from(("a", vec![0b1100, 0b1010, 0b1111])).into_series();
+        let rhs = Int64Array::from(("b", vec![0b1010, 0b0110, 0b0000])).into_series();
+
+        let and = lhs.bitwise_and(&rhs)?;
+        assert_eq!(and.data_type(), &DataType::Int64);
+        assert_eq!(and.i64()?.as_slice(), &[0b1000, 0b0010, 0b0000]);
+
+        let or = lhs.bitwise_or(&rhs)?;
+        assert_eq!(or.i64()?.as_slice(), &[0b1110, 0b1110, 0b1111]);
+
+        let xor = lhs.bitwise_xor(&rhs)?;
+        assert_eq!(xor.i64()?.as_slice(), &[0b0110, 0b1100, 0b1111]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bitwise_ops_reject_non_integers() {
+        let lhs = Float64Array::from(("a", vec![1.0])).into_series();
+        let rhs = Int32Array::from(("b", vec![1])).into_series();
+        assert!(lhs.bitwise_and(&rhs).is_err());
+    }
+}
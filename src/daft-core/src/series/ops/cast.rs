@@ -1,9 +1,178 @@
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
-use crate::{datatypes::DataType, series::Series};
+use crate::{
+    array::ops::{as_arrow::AsArrow, DaftCompare},
+    datatypes::{DataType, Utf8Array},
+    series::{IntoSeries, Series},
+};
 
 impl Series {
     pub fn cast(&self, datatype: &DataType) -> DaftResult<Self> {
         self.inner.cast(datatype)
     }
+
+    /// Casts to `datatype` like [`Series::cast`], but returns an error instead of wrapping around
+    /// when an integer value does not fit in the target integer type.
+    pub fn cast_strict(&self, datatype: &DataType) -> DaftResult<Self> {
+        let casted = self.cast(datatype)?;
+        if !(self.data_type().is_integer() && datatype.is_integer()) {
+            return Ok(casted);
+        }
+        // Wrapping changes a value by a multiple of the target type's range, so the original and the
+        // casted values stay distinguishable when both are compared as floats.
+        let lossless = casted
+            .cast(&DataType::Float64)?
+            .equal(&self.cast(&DataType::Float64)?)?;
+        if let Some(idx) = lossless
+            .as_arrow()
+            .iter()
+            .position(|is_equal| is_equal == Some(false))
+        {
+            return Err(DaftError::ValueError(format!(
+                "Can not strictly cast Series {} from {} to {}: value {} at index {idx} does not fit in the target type",
+                self.name(),
+                self.data_type(),
+                datatype,
+                self.inner.str_value(idx)?,
+            )));
+        }
+        Ok(casted)
+    }
+
+    /// Parses a Utf8 series into the numeric `target` type, for numbers written with locale specific
+    /// separators such as `"1.234,56"`.
+    ///
+    /// Every `thousands_sep` is removed and `decimal_sep` is replaced with `.` before parsing, and
+    /// surrounding whitespace is ignored. Values that still do not parse become null.
+    pub fn cast_string_to_numeric(
+        &self,
+        target: &DataType,
+        decimal_sep: char,
+        thousands_sep: Option<char>,
+    ) -> DaftResult<Self> {
+        if !target.is_numeric() {
+            return Err(DaftError::TypeError(format!(
+                "cast_string_to_numeric expects a numeric target type, got {target}"
+            )));
+        }
+        if thousands_sep == Some(decimal_sep) {
+            return Err(DaftError::ValueError(format!(
+                "cast_string_to_numeric expects different decimal and thousands separators, got {decimal_sep:?} for both"
+            )));
+        }
+        let normalized = self.utf8()?.as_arrow().iter().map(|v| {
+            v.map(|v| {
+                v.trim()
+                    .chars()
+                    .filter(|c| Some(*c) != thousands_sep)
+                    .map(|c| if c == decimal_sep { '.' } else { c })
+                    .collect::<String>()
+            })
+        });
+        Utf8Array::from_iter(self.name(), normalized)
+            .into_series()
+            .cast(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        datatypes::{DataType, Field, Float64Array, Int64Array, Utf8Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_cast_strict_in_range() -> DaftResult<()> {
+        let s = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(-128), None, Some(127)].into_iter(),
+        )
+        .into_series();
+        let casted = s.cast_strict(&DataType::Int8)?;
+        assert_eq!(casted.data_type(), &DataType::Int8);
+        assert_eq!(
+            casted.i8()?.into_iter().collect::<Vec<_>>(),
+            vec![Some(&-128), None, Some(&127)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_strict_out_of_range_errors() -> DaftResult<()> {
+        let s = Int64Array::from(("a", vec![1, i64::from(i32::MAX) + 1])).into_series();
+        // The lenient cast wraps around.
+        assert_eq!(s.cast(&DataType::Int32)?.i32()?.as_slice(), &[1, i32::MIN]);
+
+        let err = s.cast_strict(&DataType::Int32).unwrap_err();
+        assert!(matches!(err, DaftError::ValueError(_)));
+        assert!(err.to_string().contains("2147483648"));
+
+        let negative = Int64Array::from(("a", vec![-1])).into_series();
+        assert!(negative.cast_strict(&DataType::UInt64).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_strict_non_integer_matches_cast() -> DaftResult<()> {
+        let s = Float64Array::from(("a", vec![1.5, 2.0])).into_series();
+        assert_eq!(s.cast_strict(&DataType::Utf8)?, s.cast(&DataType::Utf8)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_string_to_numeric_with_comma_decimal() -> DaftResult<()> {
+        let s = Utf8Array::from_iter(
+            "a",
+            vec![
+                Some("1.234,56"),
+                Some(" -7,5 "),
+                Some("12"),
+                Some("abc"),
+                None,
+            ]
+            .into_iter(),
+        )
+        .into_series();
+        let casted = s.cast_string_to_numeric(&DataType::Float64, ',', Some('.'))?;
+        assert_eq!(casted.name(), "a");
+        assert_eq!(casted.data_type(), &DataType::Float64);
+        assert_eq!(
+            casted
+                .f64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1234.56), Some(-7.5), Some(12.0), None, None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_string_to_numeric_integers_and_bad_args() -> DaftResult<()> {
+        let s = Utf8Array::from(("a", ["1 000 000", "2,5"].as_slice())).into_series();
+        let casted = s.cast_string_to_numeric(&DataType::Int64, ',', Some(' '))?;
+        assert_eq!(
+            casted
+                .i64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1_000_000), None]
+        );
+
+        assert!(s
+            .cast_string_to_numeric(&DataType::Utf8, ',', None)
+            .is_err());
+        assert!(s
+            .cast_string_to_numeric(&DataType::Float64, ',', Some(','))
+            .is_err());
+        let not_string = Int64Array::from(("a", vec![1])).into_series();
+        assert!(not_string
+            .cast_string_to_numeric(&DataType::Float64, '.', None)
+            .is_err());
+        Ok(())
+    }
 }
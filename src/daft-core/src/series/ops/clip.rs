@@ -0,0 +1,142 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::ops::DaftCompare,
+    datatypes::{BooleanArray, DataType},
+    series::{IntoSeries, Series},
+    utils::supertype::try_get_supertype,
+};
+
+impl Series {
+    /// Clamps each element into `[lower, upper]`.
+    ///
+    /// Either bound may be omitted, and each bound is either broadcast (length 1) or applied
+    /// element-wise. Null elements stay null, and a null bound leaves its element unbounded on that
+    /// side. The output has the supertype of the input and its bounds.
+    pub fn clip(&self, lower: Option<&Self>, upper: Option<&Self>) -> DaftResult<Self> {
+        let bounds = lower.into_iter().chain(upper);
+        let mut output_type = self.data_type().clone();
+        for series in std::iter::once(self).chain(bounds.clone()) {
+            if !series.data_type().is_numeric() && series.data_type() != &DataType::Null {
+                return Err(DaftError::TypeError(format!(
+                    "clip expects numeric inputs, but received {}",
+                    series.data_type()
+                )));
+            }
+        }
+        for bound in bounds {
+            if bound.len() != 1 && bound.len() != self.len() {
+                return Err(DaftError::ValueError(format!(
+                    "clip expects bounds of length 1 or {}, but received length {}",
+                    self.len(),
+                    bound.len()
+                )));
+            }
+            output_type = try_get_supertype(&output_type, bound.data_type())?;
+        }
+
+        let no_match = BooleanArray::from(("", [false].as_slice())).into_series();
+        let mut clipped = self.cast(&output_type)?;
+        if let Some(lower) = lower {
+            let lower = lower.cast(&output_type)?;
+            let below = clipped.lt(&lower)?.into_series().fill_null(&no_match)?;
+            clipped = lower.if_else(&clipped, &below)?;
+        }
+        if let Some(upper) = upper {
+            let upper = upper.cast(&output_type)?;
+            let above = clipped.gt(&upper)?.into_series().fill_null(&no_match)?;
+            clipped = upper.if_else(&clipped, &above)?;
+        }
+        Ok(clipped.rename(self.name()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field, Float64Array, Int32Array, Int64Array, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn input() -> Series {
+        Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(-5), Some(0), None, Some(5), Some(10)].into_iter(),
+        )
+        .into_series()
+    }
+
+    fn values(series: &Series) -> DaftResult<Vec<Option<i64>>> {
+        Ok(series.i64()?.into_iter().map(|v| v.copied()).collect())
+    }
+
+    #[test]
+    fn test_clip_lower_only() -> DaftResult<()> {
+        let lower = Int64Array::from(("lower", vec![0])).into_series();
+        let clipped = input().clip(Some(&lower), None)?;
+        assert_eq!(clipped.name(), "a");
+        assert_eq!(
+            values(&clipped)?,
+            vec![Some(0), Some(0), None, Some(5), Some(10)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_upper_only() -> DaftResult<()> {
+        let upper = Int64Array::from(("upper", vec![4])).into_series();
+        let clipped = input().clip(None, Some(&upper))?;
+        assert_eq!(
+            values(&clipped)?,
+            vec![Some(-5), Some(0), None, Some(4), Some(4)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_both() -> DaftResult<()> {
+        let lower = Int32Array::from(("lower", vec![-1])).into_series();
+        let upper = Int32Array::from(("upper", vec![6])).into_series();
+        let clipped = input().clip(Some(&lower), Some(&upper))?;
+        assert_eq!(clipped.data_type(), &DataType::Int64);
+        assert_eq!(
+            values(&clipped)?,
+            vec![Some(-1), Some(0), None, Some(5), Some(6)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_elementwise_bounds() -> DaftResult<()> {
+        let lower = Int64Array::from_iter(
+            Field::new("lower", DataType::Int64),
+            vec![Some(-10), Some(1), Some(0), None, Some(20)].into_iter(),
+        )
+        .into_series();
+        let upper = Int64Array::from(("upper", vec![-6, 2, 0, 3, 30])).into_series();
+        let clipped = input().clip(Some(&lower), Some(&upper))?;
+        assert_eq!(
+            values(&clipped)?,
+            vec![Some(-6), Some(1), None, Some(3), Some(20)]
+        );
+
+        let too_short = Int64Array::from(("lower", vec![0, 1])).into_series();
+        assert!(input().clip(Some(&too_short), None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_promotes_to_supertype() -> DaftResult<()> {
+        let lower = Float64Array::from(("lower", vec![0.5])).into_series();
+        let clipped = input().clip(Some(&lower), None)?;
+        assert_eq!(clipped.data_type(), &DataType::Float64);
+        assert_eq!(clipped.f64()?.get(0), Some(0.5));
+        assert_eq!(clipped.f64()?.get(3), Some(5.0));
+
+        let not_numeric = Utf8Array::from(("lower", ["a"].as_slice())).into_series();
+        assert!(input().clip(Some(&not_numeric), None).is_err());
+        Ok(())
+    }
+}
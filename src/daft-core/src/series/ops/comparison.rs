@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_schema::prelude::DataType;
 
 #[cfg(feature = "python")]
@@ -66,3 +66,98 @@ impl DaftCompare<&Self> for Series {
     impl_compare_method!(gt, gt);
     impl_compare_method!(gte, ge);
 }
+
+impl Series {
+    /// Null-safe equality (`<=>`): like [`DaftCompare::equal`], except that two nulls are equal and a
+    /// null is never equal to a value, so the result never contains nulls.
+    ///
+    /// Either side may be broadcast from length 1.
+    pub fn eq_null_safe(&self, rhs: &Self) -> DaftResult<BooleanArray> {
+        let len = match (self.len(), rhs.len()) {
+            (l, r) if l == r => l,
+            (1, r) => r,
+            (l, 1) => l,
+            (l, r) => {
+                return Err(DaftError::ValueError(format!(
+                    "trying to compare different length series: {}: {l} vs {}: {r}",
+                    self.name(),
+                    rhs.name()
+                )))
+            }
+        };
+        let broadcast = |s: &Self| -> DaftResult<Cow<Self>> {
+            if s.len() == len {
+                Ok(Cow::Borrowed(s))
+            } else {
+                Ok(Cow::Owned(s.broadcast(len)?))
+            }
+        };
+        let (lhs, rhs) = (broadcast(self)?, broadcast(rhs)?);
+
+        let equal = lhs.equal(&rhs)?;
+        let lhs_null = lhs.is_null()?;
+        let rhs_null = rhs.is_null()?;
+        let (lhs_null, rhs_null) = (lhs_null.bool()?, rhs_null.bool()?);
+        let values = (0..len)
+            .map(|i| match (lhs_null.get(i), rhs_null.get(i)) {
+                (Some(true), Some(true)) => true,
+                (Some(false), Some(false)) => equal.get(i).unwrap_or(false),
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+        Ok(BooleanArray::from((self.name(), values.as_slice())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::full::FullNull,
+        datatypes::{DataType, Field, Int64Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn values(series: &[Option<i64>]) -> Series {
+        Int64Array::from_iter(Field::new("a", DataType::Int64), series.iter().copied())
+            .into_series()
+    }
+
+    #[test]
+    fn test_eq_null_safe_null_and_value_combinations() -> DaftResult<()> {
+        let lhs = values(&[Some(1), None, Some(1), None, Some(1)]);
+        let rhs = values(&[Some(1), Some(1), None, None, Some(2)]);
+        let result = lhs.eq_null_safe(&rhs)?;
+        assert_eq!(result.name(), "a");
+        assert_eq!(result.validity(), None);
+        assert_eq!(
+            result.into_iter().collect::<Vec<_>>(),
+            vec![
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(false)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_null_safe_broadcasts() -> DaftResult<()> {
+        let lhs = values(&[Some(1), None, Some(2)]);
+        let null = Int64Array::full_null("b", &DataType::Int64, 1).into_series();
+        assert_eq!(
+            lhs.eq_null_safe(&null)?.into_iter().collect::<Vec<_>>(),
+            vec![Some(false), Some(true), Some(false)]
+        );
+        let one = values(&[Some(1)]);
+        assert_eq!(
+            one.eq_null_safe(&lhs)?.into_iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(false), Some(false)]
+        );
+        assert!(lhs.eq_null_safe(&values(&[Some(1), Some(2)])).is_err());
+        Ok(())
+    }
+}
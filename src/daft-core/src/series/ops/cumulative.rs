@@ -0,0 +1,162 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    datatypes::DataType,
+    series::{IntoSeries, Series},
+    with_match_numeric_daft_types,
+};
+
+impl Series {
+    /// Running sum in row order; nulls stay null and are skipped by the accumulator.
+    pub fn cumsum(&self) -> DaftResult<Self> {
+        check_numeric("cumsum", self.data_type())?;
+        let casted = match self.data_type() {
+            // intX -> int64 and uintX -> uint64, in line with `sum`
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                self.cast(&DataType::Int64)?
+            }
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                self.cast(&DataType::UInt64)?
+            }
+            _ => self.clone(),
+        };
+        with_match_numeric_daft_types!(casted.data_type(), |$T| {
+            Ok(casted.downcast::<<$T as DaftDataType>::ArrayType>()?.cumsum()?.into_series())
+        })
+    }
+
+    /// Running maximum in row order; nulls stay null and are skipped by the accumulator.
+    pub fn cummax(&self) -> DaftResult<Self> {
+        check_numeric("cummax", self.data_type())?;
+        with_match_numeric_daft_types!(self.data_type(), |$T| {
+            Ok(self.downcast::<<$T as DaftDataType>::ArrayType>()?.cummax()?.into_series())
+        })
+    }
+
+    /// Running minimum in row order; nulls stay null and are skipped by the accumulator.
+    pub fn cummin(&self) -> DaftResult<Self> {
+        check_numeric("cummin", self.data_type())?;
+        with_match_numeric_daft_types!(self.data_type(), |$T| {
+            Ok(self.downcast::<<$T as DaftDataType>::ArrayType>()?.cummin()?.into_series())
+        })
+    }
+}
+
+fn check_numeric(op: &str, dtype: &DataType) -> DaftResult<()> {
+    match dtype {
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64 => Ok(()),
+        dt => Err(DaftError::TypeError(format!(
+            "{op} not implemented for {dt}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        datatypes::{
+            DataType, Field, Float64Array, Int32Array, Int64Array, Int8Array, UInt64Array,
+            Utf8Array,
+        },
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_cumulative_ops_with_interior_nulls() -> DaftResult<()> {
+        let s = Int32Array::from_iter(
+            Field::new("a", DataType::Int32),
+            vec![Some(3), None, Some(1), Some(4), None, Some(2)].into_iter(),
+        )
+        .into_series();
+
+        let cumsum = s.cumsum()?;
+        assert_eq!(cumsum.data_type(), &DataType::Int64);
+        assert_eq!(
+            cumsum
+                .i64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(3), None, Some(4), Some(8), None, Some(10)]
+        );
+
+        let cummax = s.cummax()?;
+        assert_eq!(cummax.data_type(), &DataType::Int32);
+        assert_eq!(
+            cummax
+                .i32()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(3), None, Some(3), Some(4), None, Some(4)]
+        );
+
+        let cummin = s.cummin()?;
+        assert_eq!(
+            cummin
+                .i32()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(3), None, Some(1), Some(1), None, Some(1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cumulative_ops_leading_nulls_and_floats() -> DaftResult<()> {
+        let s = Float64Array::from_iter(
+            Field::new("a", DataType::Float64),
+            vec![None, Some(1.5), Some(-2.0)].into_iter(),
+        )
+        .into_series();
+        let cumsum = s.cumsum()?;
+        assert_eq!(cumsum.data_type(), &DataType::Float64);
+        assert_eq!(
+            cumsum
+                .f64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![None, Some(1.5), Some(-0.5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cumsum_widens_and_reports_overflow() -> DaftResult<()> {
+        // Small integers are widened, so they can't overflow their own dtype.
+        let s = Int8Array::from(("a", vec![100i8, 100])).into_series();
+        assert_eq!(s.cumsum()?.i64()?.as_slice(), &[100, 200]);
+
+        let s = Int64Array::from(("a", vec![i64::MAX - 1, 1, 1])).into_series();
+        assert!(matches!(s.cumsum(), Err(DaftError::ComputeError(_))));
+
+        let s = UInt64Array::from(("a", vec![u64::MAX, 1])).into_series();
+        assert!(matches!(s.cumsum(), Err(DaftError::ComputeError(_))));
+
+        // Running maxima and minima never overflow.
+        let s = Int64Array::from(("a", vec![i64::MAX, i64::MIN])).into_series();
+        assert_eq!(s.cummax()?.i64()?.as_slice(), &[i64::MAX, i64::MAX]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cumulative_ops_reject_non_numeric() {
+        let s = Utf8Array::from(("a", ["x", "y"].as_slice())).into_series();
+        assert!(s.cumsum().is_err());
+        assert!(s.cummax().is_err());
+        assert!(s.cummin().is_err());
+    }
+}
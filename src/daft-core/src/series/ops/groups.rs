@@ -2,7 +2,8 @@ use common_error::DaftResult;
 
 use crate::{
     array::ops::{GroupIndicesPair, IntoGroups},
-    series::Series,
+    datatypes::{BooleanArray, UInt64Array},
+    series::{IntoSeries, Series},
     with_match_hashable_daft_types,
 };
 
@@ -15,3 +16,143 @@ impl IntoGroups for Series {
         })
     }
 }
+
+impl Series {
+    /// Returns the exact number of distinct values in this series.
+    ///
+    /// Nulls count as a single distinct value unless `ignore_nulls` is set.
+    pub fn n_unique(&self, ignore_nulls: bool) -> DaftResult<usize> {
+        if self.is_empty() {
+            return Ok(0);
+        }
+        let (_, groups) = self.make_groups()?;
+        let has_null_group = ignore_nulls && self.null_count() > 0;
+        Ok(groups.len() - usize::from(has_null_group))
+    }
+
+    /// Returns a boolean mask marking the rows whose value appears exactly once in this series,
+    /// treating all nulls as the same value.
+    pub fn is_unique(&self) -> DaftResult<Self> {
+        let mut mask = vec![false; self.len()];
+        if !self.is_empty() {
+            let (_, groups) = self.make_groups()?;
+            for group in groups {
+                if let [idx] = group.as_slice() {
+                    mask[*idx as usize] = true;
+                }
+            }
+        }
+        Ok(BooleanArray::from((self.name(), mask.as_slice())).into_series())
+    }
+
+    /// Returns the most frequently occurring value(s) of this series.
+    ///
+    /// Ties are all returned, sorted in ascending order, so the first element is the smallest of
+    /// the most frequent values. Nulls are not counted unless every value is null, in which case
+    /// the result is a single null.
+    pub fn mode(&self) -> DaftResult<Self> {
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+        let (sample_indices, groups) = self.make_groups()?;
+        let counts = sample_indices
+            .into_iter()
+            .zip(groups.iter().map(Vec::len))
+            .filter(|(idx, _)| self.is_valid(*idx as usize))
+            .collect::<Vec<_>>();
+        let Some(max_count) = counts.iter().map(|(_, count)| *count).max() else {
+            return Ok(Self::full_null(self.name(), self.data_type(), 1));
+        };
+        let modes = counts
+            .into_iter()
+            .filter(|(_, count)| *count == max_count)
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+        self.take(&UInt64Array::from(("idx", modes)).into_series())?
+            .sort(false, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field, Int64Array, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn values() -> Series {
+        Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(3), Some(1), None, Some(3), Some(2), None].into_iter(),
+        )
+        .into_series()
+    }
+
+    fn mask(series: &Series) -> DaftResult<Vec<bool>> {
+        Ok(series.bool()?.into_iter().map(|v| v.unwrap()).collect())
+    }
+
+    #[test]
+    fn test_n_unique() -> DaftResult<()> {
+        let s = values();
+        assert_eq!(s.n_unique(false)?, 4);
+        assert_eq!(s.n_unique(true)?, 3);
+
+        let strings = Utf8Array::from(("s", ["x", "y", "x"].as_slice())).into_series();
+        assert_eq!(strings.n_unique(false)?, 2);
+        assert_eq!(strings.n_unique(true)?, 2);
+
+        let empty = Series::empty("e", &DataType::Int64);
+        assert_eq!(empty.n_unique(false)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_unique() -> DaftResult<()> {
+        let s = values();
+        let unique = s.is_unique()?;
+        assert_eq!(unique.name(), "a");
+        assert_eq!(mask(&unique)?, vec![false, true, false, false, true, false]);
+
+        let single_null = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), None, Some(1)].into_iter(),
+        )
+        .into_series();
+        assert_eq!(mask(&single_null.is_unique()?)?, vec![false, true, false]);
+        Ok(())
+    }
+
+    fn ints(values: Vec<Option<i64>>) -> Series {
+        Int64Array::from_iter(Field::new("a", DataType::Int64), values.into_iter()).into_series()
+    }
+
+    fn to_vec(series: &Series) -> DaftResult<Vec<Option<i64>>> {
+        Ok(series.i64()?.into_iter().map(|v| v.copied()).collect())
+    }
+
+    #[test]
+    fn test_mode() -> DaftResult<()> {
+        // Nulls are not counted, even when they are the most frequent value.
+        let clear = ints(vec![Some(3), None, Some(1), Some(3), None, None]);
+        let mode = clear.mode()?;
+        assert_eq!(mode.name(), "a");
+        assert_eq!(to_vec(&mode)?, vec![Some(3)]);
+
+        let tie = ints(vec![Some(5), Some(2), Some(5), Some(2), Some(9), None]);
+        assert_eq!(to_vec(&tie.mode()?)?, vec![Some(2), Some(5)]);
+
+        let all_null = ints(vec![None, None]);
+        let mode = all_null.mode()?;
+        assert_eq!(mode.data_type(), &DataType::Int64);
+        assert_eq!(to_vec(&mode)?, vec![None]);
+
+        let null_typed = Series::full_null("n", &DataType::Null, 3);
+        assert_eq!(null_typed.mode()?.len(), 1);
+
+        assert!(ints(vec![]).mode()?.is_empty());
+        Ok(())
+    }
+}
@@ -1,12 +1,48 @@
+use std::str::FromStr;
+
 use arrow2::bitmap::Bitmap;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
+use derive_more::Display;
 
 use crate::{
-    datatypes::{DataType, Int32Array, UInt64Array},
-    series::Series,
+    datatypes::{DataType, Field, Int32Array, UInt32Array, UInt64Array},
+    series::{IntoSeries, Series},
     with_match_hashable_daft_types,
 };
 
+/// Hash function used by [`Series::hash_with`].
+///
+/// | XxHash3_64 - Daft's own hash, as used by [`Series::hash`]. Supports every hashable type and
+/// |              hashes nulls to a value.
+/// | Murmur3_32 - 32 bit murmur3 (x86, seed 0) over the bytes Iceberg's bucket transform hashes,
+/// |              returned as `UInt32`.
+/// | XxHash64   - XXH64 over the same bytes as `Murmur3_32`, returned as `UInt64`.
+///
+/// Both external functions propagate nulls and only support integer, string, binary, decimal and
+/// temporal types.
+#[derive(Clone, Copy, Debug, Display, Default, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    #[default]
+    XxHash3_64,
+    Murmur3_32,
+    XxHash64,
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = DaftError;
+
+    fn from_str(hash_function: &str) -> DaftResult<Self> {
+        match hash_function {
+            "default" | "xxhash3_64" => Ok(Self::XxHash3_64),
+            "murmur3_32" => Ok(Self::Murmur3_32),
+            "xxhash64" => Ok(Self::XxHash64),
+            _ => Err(DaftError::ValueError(format!(
+                "Hash function {hash_function} is not supported; expected one of \"default\", \"murmur3_32\" or \"xxhash64\""
+            ))),
+        }
+    }
+}
+
 impl Series {
     pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
         let s = self.as_physical()?;
@@ -26,6 +62,70 @@ impl Series {
         hash.with_validity(validity)
     }
 
+    /// Hashes each value with `algorithm`, optionally seeded per row.
+    ///
+    /// Returns `UInt32` for [`HashAlgorithm::Murmur3_32`], which does not take a seed, and `UInt64`
+    /// otherwise.
+    pub fn hash_with(
+        &self,
+        algorithm: HashAlgorithm,
+        seed: Option<&UInt64Array>,
+    ) -> DaftResult<Self> {
+        if let Some(seed) = seed
+            && seed.len() != self.len()
+        {
+            return Err(DaftError::ValueError(format!(
+                "seed length does not match array length: {} vs {}",
+                seed.len(),
+                self.len()
+            )));
+        }
+        match algorithm {
+            HashAlgorithm::XxHash3_64 => Ok(self.hash(seed)?.into_series()),
+            HashAlgorithm::Murmur3_32 => {
+                if seed.is_some() {
+                    return Err(DaftError::ValueError(
+                        "murmur3_32 does not support a seed".to_string(),
+                    ));
+                }
+                let hashes = self.murmur3_32()?;
+                let unsigned = hashes
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.map(|v| u32::from_ne_bytes(v.to_ne_bytes())));
+                Ok(
+                    UInt32Array::from_iter(Field::new(self.name(), DataType::UInt32), unsigned)
+                        .into_series(),
+                )
+            }
+            HashAlgorithm::XxHash64 => Ok(self.xxhash64(seed)?.into_series()),
+        }
+    }
+
+    pub fn xxhash64(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        use crate::datatypes::DataType::*;
+        match self.data_type() {
+            Int8 => self.i8()?.xxhash64(seed),
+            Int16 => self.i16()?.xxhash64(seed),
+            Int32 => self.i32()?.xxhash64(seed),
+            Int64 => self.i64()?.xxhash64(seed),
+            UInt8 => self.u8()?.xxhash64(seed),
+            UInt16 => self.u16()?.xxhash64(seed),
+            UInt32 => self.u32()?.xxhash64(seed),
+            UInt64 => self.u64()?.xxhash64(seed),
+            Utf8 => self.utf8()?.xxhash64(seed),
+            Binary => self.binary()?.xxhash64(seed),
+            FixedSizeBinary(_) => self.fixed_size_binary()?.xxhash64(seed),
+            Date => self.date()?.xxhash64(seed),
+            Time(..) => self.time()?.xxhash64(seed),
+            Timestamp(..) => self.timestamp()?.xxhash64(seed),
+            Decimal128(..) => self.decimal128()?.xxhash64(seed),
+            v => Err(DaftError::TypeError(format!(
+                "xxhash64 not implemented for datatype: {v}"
+            ))),
+        }
+    }
+
     pub fn murmur3_32(&self) -> DaftResult<Int32Array> {
         use crate::datatypes::DataType::*;
         match self.data_type() {
@@ -48,3 +148,97 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::HashAlgorithm;
+    use crate::{
+        datatypes::{
+            DataType, Field, Float64Array, Int32Array, Int64Array, UInt64Array, Utf8Array,
+        },
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_murmur3_32_matches_iceberg_fixtures() -> DaftResult<()> {
+        // fixtures from the Iceberg spec's bucket transform appendix
+        let ints = Int32Array::from(("a", vec![34])).into_series();
+        let longs = Int64Array::from(("a", vec![34])).into_series();
+        let strings = Utf8Array::from(("a", ["iceberg"].as_slice())).into_series();
+        for (series, expected) in [
+            (ints, 2017239379),
+            (longs, 2017239379),
+            (strings, 1210000089),
+        ] {
+            let hashed = series.hash_with(HashAlgorithm::Murmur3_32, None)?;
+            assert_eq!(hashed.data_type(), &DataType::UInt32);
+            assert_eq!(hashed.u32()?.get(0), Some(expected));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_xxhash64_matches_reference_fixtures() -> DaftResult<()> {
+        // reference XXH64 values for the empty string, "a", "iceberg" and the i64 34
+        let strings = Utf8Array::from_iter(
+            "a",
+            vec![Some(""), Some("a"), Some("iceberg"), None].into_iter(),
+        )
+        .into_series();
+        let hashed = strings.hash_with(HashAlgorithm::XxHash64, None)?;
+        assert_eq!(hashed.data_type(), &DataType::UInt64);
+        assert_eq!(
+            hashed
+                .u64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![
+                Some(0xEF46_DB37_51D8_E999),
+                Some(0xD24E_C4F1_A98C_6E5B),
+                Some(10_407_765_374_454_221_717),
+                None
+            ]
+        );
+
+        let ints = Int32Array::from(("a", vec![34])).into_series();
+        let hashed = ints.hash_with(HashAlgorithm::XxHash64, None)?;
+        assert_eq!(hashed.u64()?.get(0), Some(15_319_147_710_452_729_493));
+
+        let seed = UInt64Array::from(("seed", vec![42])).into_series();
+        let hashed = Utf8Array::from(("a", ["iceberg"].as_slice()))
+            .into_series()
+            .hash_with(HashAlgorithm::XxHash64, Some(seed.u64()?))?;
+        assert_eq!(hashed.u64()?.get(0), Some(12_430_058_073_231_558_722));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_default_matches_hash() -> DaftResult<()> {
+        let s = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), None, Some(3)].into_iter(),
+        )
+        .into_series();
+        let hashed = s.hash_with("default".parse()?, None)?;
+        assert_eq!(hashed.u64()?.as_slice(), s.hash(None)?.as_slice());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_rejects_bad_input() {
+        assert!("md5".parse::<HashAlgorithm>().is_err());
+        let floats = Float64Array::from(("a", vec![1.0])).into_series();
+        assert!(floats.hash_with(HashAlgorithm::XxHash64, None).is_err());
+        let seed = UInt64Array::from(("seed", vec![1, 2]));
+        let ints = Int64Array::from(("a", vec![1])).into_series();
+        assert!(ints
+            .hash_with(HashAlgorithm::Murmur3_32, Some(&seed))
+            .is_err());
+        assert!(ints
+            .hash_with(HashAlgorithm::XxHash64, Some(&seed))
+            .is_err());
+    }
+}
@@ -1,7 +1,8 @@
+use arrow2::offset::OffsetsBuffer;
 use common_error::{DaftError, DaftResult};
 
 use crate::{
-    datatypes::{DataType, UInt64Array, Utf8Array},
+    datatypes::{DataType, Field, Int64Array, UInt64Array, Utf8Array},
     prelude::CountMode,
     series::{IntoSeries, Series},
 };
@@ -23,10 +24,19 @@ impl Series {
         Ok(series)
     }
 
+    /// Explodes each list into one row per element, and each map into one `{key, value}` struct row
+    /// per entry. Null and empty rows explode to a single null row.
     pub fn explode(&self) -> DaftResult<Self> {
         match self.data_type() {
             DataType::List(_) => self.list()?.explode(),
             DataType::FixedSizeList(..) => self.fixed_size_list()?.explode(),
+            DataType::Map { key, value } => {
+                let entry_type = DataType::Struct(vec![
+                    Field::new("key", key.as_ref().clone()),
+                    Field::new("value", value.as_ref().clone()),
+                ]);
+                self.map()?.physical.explode()?.cast(&entry_type)
+            }
             dt => Err(DaftError::TypeError(format!(
                 "explode not implemented for {}",
                 dt
@@ -34,11 +44,28 @@ impl Series {
         }
     }
 
+    /// Borrows the raw offsets and flattened child of a List series without copying.
+    ///
+    /// Row `i` spans `values[offsets[i]..offsets[i + 1]]`, so the offsets have `len + 1` entries.
+    /// Offsets of a sliced series need not start at 0, and null rows may still span values.
+    pub fn list_offsets_and_values(&self) -> DaftResult<(&OffsetsBuffer<i64>, &Self)> {
+        match self.data_type() {
+            DataType::List(_) => {
+                let list = self.list()?;
+                Ok((list.offsets(), &list.flat_child))
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "list offsets not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
     pub fn list_count(&self, mode: CountMode) -> DaftResult<UInt64Array> {
         match self.data_type() {
             DataType::List(_) => self.list()?.count(mode),
             DataType::FixedSizeList(..) => self.fixed_size_list()?.count(mode),
-            DataType::Embedding(..) | DataType::FixedShapeImage(..) => {
+            DataType::Embedding(..) | DataType::FixedShapeImage(..) | DataType::Map { .. } => {
                 self.as_physical()?.list_count(mode)
             }
             DataType::Image(..) => {
@@ -105,9 +132,36 @@ impl Series {
         }
     }
 
-    pub fn list_chunk(&self, size: usize) -> DaftResult<Self> {
+    /// The zero-based index of the first element in each list that equals `value`, or null if there
+    /// is none. A length 1 `value` is compared against every list.
+    pub fn list_index_of(&self, value: &Self) -> DaftResult<Int64Array> {
+        match self.data_type() {
+            DataType::List(child_dtype) => self.list()?.index_of(&value.cast(child_dtype)?),
+            DataType::FixedSizeList(child_dtype, _) => self
+                .fixed_size_list()?
+                .to_list()
+                .index_of(&value.cast(child_dtype)?),
+            dt => Err(DaftError::TypeError(format!(
+                "list index_of not implemented for {dt}"
+            ))),
+        }
+    }
+
+    /// Splits each list into chunks of `size` elements. A final chunk with fewer than `size` elements
+    /// is kept if `keep_remainder` is set, giving a list of lists, and dropped otherwise, giving a list
+    /// of fixed size lists.
+    pub fn list_chunk(&self, size: usize, keep_remainder: bool) -> DaftResult<Self> {
+        if size == 0 {
+            return Err(DaftError::ValueError(
+                "list chunk size must be greater than 0".to_string(),
+            ));
+        }
         match self.data_type() {
+            DataType::List(_) if keep_remainder => self.list()?.get_chunks_with_remainder(size),
             DataType::List(_) => self.list()?.get_chunks(size),
+            DataType::FixedSizeList(..) if keep_remainder => {
+                self.fixed_size_list()?.get_chunks_with_remainder(size)
+            }
             DataType::FixedSizeList(..) => self.fixed_size_list()?.get_chunks(size),
             dt => Err(DaftError::TypeError(format!(
                 "list chunk not implemented for {dt}"
@@ -176,3 +230,206 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow2::offset::OffsetsBuffer;
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{ops::as_arrow::AsArrow, ListArray, StructArray},
+        datatypes::{logical::MapArray, DataType, Field, Int64Array, Utf8Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_explode_map() -> DaftResult<()> {
+        let entry_type = DataType::Struct(vec![
+            Field::new("key", DataType::Utf8),
+            Field::new("value", DataType::Int64),
+        ]);
+        let entries = StructArray::new(
+            Field::new("entries", entry_type.clone()),
+            vec![
+                Utf8Array::from(("key", ["a", "b", "c"].as_slice())).into_series(),
+                Int64Array::from(("value", vec![1, 2, 3])).into_series(),
+            ],
+            None,
+        )
+        .into_series();
+        let physical = ListArray::new(
+            Field::new("m", DataType::List(Box::new(entry_type.clone()))),
+            entries,
+            OffsetsBuffer::try_from(vec![0, 2, 2, 2, 3])?,
+            Some(arrow2::bitmap::Bitmap::from([true, true, false, true])),
+        );
+        let map = MapArray::new(
+            Field::new(
+                "m",
+                DataType::Map {
+                    key: Box::new(DataType::Utf8),
+                    value: Box::new(DataType::Int64),
+                },
+            ),
+            physical,
+        )
+        .into_series();
+
+        // one row per entry, with the empty and null maps each producing a single null row
+        let exploded = map.explode()?;
+        assert_eq!(exploded.name(), "m");
+        assert_eq!(exploded.data_type(), &entry_type);
+        assert_eq!(exploded.len(), 5);
+        let exploded = exploded.struct_()?;
+        assert_eq!(
+            exploded.children[0]
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some("a"), Some("b"), None, None, Some("c")]
+        );
+        assert_eq!(
+            exploded.children[1]
+                .i64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(2), None, None, Some(3)]
+        );
+        assert_eq!(
+            (0..5).map(|i| exploded.is_valid(i)).collect::<Vec<_>>(),
+            vec![true, true, false, false, true]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_chunk_keeps_remainder() -> DaftResult<()> {
+        let flat_child = Int64Array::from(("a", (0..7).collect::<Vec<i64>>())).into_series();
+        let list = ListArray::new(
+            Field::new("a", DataType::List(Box::new(DataType::Int64))),
+            flat_child,
+            OffsetsBuffer::try_from(vec![0, 4, 4, 7])?,
+            None,
+        )
+        .into_series();
+
+        let chunked = list.list_chunk(2, true)?;
+        assert_eq!(
+            chunked.data_type(),
+            &DataType::List(Box::new(DataType::List(Box::new(DataType::Int64))))
+        );
+        let chunked = chunked.list()?;
+        assert_eq!(chunked.offsets().as_slice(), &[0, 2, 2, 4]);
+        assert_eq!(
+            chunked.flat_child.list()?.offsets().as_slice(),
+            &[0, 2, 4, 6, 7]
+        );
+
+        assert!(list.list_chunk(0, true).is_err());
+        assert!(list.list_chunk(0, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_offsets_and_values() -> DaftResult<()> {
+        let flat_child = Int64Array::from(("a", (0..7).collect::<Vec<i64>>())).into_series();
+        let list = ListArray::new(
+            Field::new("a", DataType::List(Box::new(DataType::Int64))),
+            flat_child,
+            OffsetsBuffer::try_from(vec![0, 4, 4, 7])?,
+            None,
+        )
+        .into_series();
+
+        let (offsets, values) = list.list_offsets_and_values()?;
+        assert_eq!(offsets.len(), list.len() + 1);
+        let total: i64 = offsets.lengths().map(|l| l as i64).sum();
+        assert_eq!(total, values.len() as i64);
+        assert_eq!(
+            values.i64()?.as_slice(),
+            (0..7).collect::<Vec<i64>>().as_slice()
+        );
+
+        let sliced = list.slice(1, 3)?;
+        let (offsets, _) = sliced.list_offsets_and_values()?;
+        assert_eq!(offsets.len(), sliced.len() + 1);
+        assert_eq!(offsets.as_slice(), &[4, 4, 7]);
+
+        let empty = ListArray::new(
+            Field::new("a", DataType::List(Box::new(DataType::Int64))),
+            Int64Array::from(("a", Vec::<i64>::new())).into_series(),
+            OffsetsBuffer::try_from(vec![0])?,
+            None,
+        )
+        .into_series();
+        assert_eq!(empty.list_offsets_and_values()?.0.len(), 1);
+
+        let not_list = Int64Array::from(("a", vec![1])).into_series();
+        assert!(not_list.list_offsets_and_values().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_index_of() -> DaftResult<()> {
+        // [[1, 2, 3, 2], [], None, [4, None, 5]]
+        let flat_child = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), Some(2), Some(3), Some(2), Some(4), None, Some(5)].into_iter(),
+        )
+        .into_series();
+        let list = ListArray::new(
+            Field::new("a", DataType::List(Box::new(DataType::Int64))),
+            flat_child,
+            OffsetsBuffer::try_from(vec![0, 4, 4, 4, 7])?,
+            Some(arrow2::bitmap::Bitmap::from([true, true, false, true])),
+        )
+        .into_series();
+
+        let value = Int64Array::from(("value", vec![2])).into_series();
+        let result = list.list_index_of(&value)?;
+        assert_eq!(result.name(), "a");
+        assert_eq!(
+            (0..result.len()).map(|i| result.get(i)).collect::<Vec<_>>(),
+            vec![Some(1), None, None, None]
+        );
+
+        // Null values never match, not even null elements.
+        let value = Int64Array::from_iter(
+            Field::new("value", DataType::Int64),
+            vec![Some(3), Some(3), Some(1), None].into_iter(),
+        )
+        .into_series();
+        let result = list.list_index_of(&value)?;
+        assert_eq!(
+            (0..result.len()).map(|i| result.get(i)).collect::<Vec<_>>(),
+            vec![Some(2), None, None, None]
+        );
+
+        let value = Int64Array::from(("value", vec![1, 2])).into_series();
+        assert!(list.list_index_of(&value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_index_of_casts_value_to_child_type() -> DaftResult<()> {
+        let flat_child = Utf8Array::from(("a", ["x", "y", "z"].as_slice())).into_series();
+        let list = ListArray::new(
+            Field::new("a", DataType::List(Box::new(DataType::Utf8))),
+            flat_child,
+            OffsetsBuffer::try_from(vec![0, 3])?,
+            None,
+        )
+        .into_series();
+
+        let value = Utf8Array::from(("value", ["z"].as_slice())).into_series();
+        let result = list.list_index_of(&value)?;
+        assert_eq!(result.get(0), Some(2));
+
+        let value = Int64Array::from(("value", vec![1])).into_series();
+        let result = list.list_index_of(&value)?;
+        assert_eq!(result.get(0), None);
+        Ok(())
+    }
+}
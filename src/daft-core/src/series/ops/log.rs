@@ -95,3 +95,37 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Float64Array, Int64Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_log_of_negative_is_nan() -> DaftResult<()> {
+        let s = Float64Array::from(("a", vec![-1.0, 0.0, std::f64::consts::E])).into_series();
+        let ln = s.ln()?;
+        let values = ln.f64()?.as_slice();
+        assert!(values[0].is_nan());
+        assert_eq!(values[1], f64::NEG_INFINITY);
+        assert_eq!(values[2], 1.0);
+        assert!(s.log(2.0)?.f64()?.as_slice()[0].is_nan());
+        Ok(())
+    }
+
+    #[test]
+    fn test_log_integer_input() -> DaftResult<()> {
+        let s = Int64Array::from(("a", vec![1, 8, 64])).into_series();
+        let log = s.log(2.0)?;
+        assert_eq!(log.data_type(), &DataType::Float64);
+        let values = log.f64()?.as_slice();
+        assert_eq!(values[0], 0.0);
+        assert!((values[1] - 3.0).abs() < 1e-12);
+        assert!((values[2] - 6.0).abs() < 1e-12);
+        Ok(())
+    }
+}
@@ -7,12 +7,15 @@ pub mod abs;
 pub mod agg;
 pub mod arithmetic;
 pub mod between;
+pub mod bitwise;
 pub mod broadcast;
 pub mod cast;
 pub mod cbrt;
 pub mod ceil;
+pub mod clip;
 pub mod comparison;
 pub mod concat;
+pub mod cumulative;
 pub mod downcast;
 mod exp;
 pub mod filter;
@@ -31,6 +34,9 @@ pub mod minhash;
 pub mod not;
 pub mod null;
 pub mod partitioning;
+pub mod pow;
+pub mod qcut;
+pub mod rank;
 pub mod repeat;
 pub mod round;
 pub mod search_sorted;
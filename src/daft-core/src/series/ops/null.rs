@@ -1,8 +1,20 @@
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
-use crate::series::Series;
+use crate::{datatypes::DataType, series::Series};
 
 impl Series {
+    /// Number of null slots. Floating point NaNs are valid values and are not counted.
+    #[must_use]
+    pub fn null_count(&self) -> usize {
+        match self.data_type() {
+            // Null arrays have no validity bitmap but every slot is null
+            DataType::Null => self.len(),
+            _ => self
+                .validity()
+                .map_or(0, arrow2::bitmap::Bitmap::unset_bits),
+        }
+    }
+
     pub fn is_null(&self) -> DaftResult<Self> {
         self.inner.is_null()
     }
@@ -11,8 +23,119 @@ impl Series {
         self.inner.not_null()
     }
 
+    /// Replaces null slots with the corresponding value of `fill_value`, which is either broadcast
+    /// (length 1) or taken element-wise (same length as `self`).
+    ///
+    /// The output has the supertype of both inputs. Floating point NaNs are valid values and are
+    /// not filled; use [`Series::fill_nan`] for those.
     pub fn fill_null(&self, fill_value: &Self) -> DaftResult<Self> {
+        if fill_value.len() != 1 && fill_value.len() != self.len() {
+            return Err(DaftError::ValueError(format!(
+                "fill_null expects a fill value of length 1 or {}, but received length {}",
+                self.len(),
+                fill_value.len()
+            )));
+        }
         let predicate = self.not_null()?;
         self.if_else(fill_value, &predicate)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataArray, DataType, Field, Float64Array, Int32Array, Int64Array},
+        series::{IntoSeries, Series},
+    };
+
+    #[test]
+    fn test_null_masks_distinguish_nan_from_null() -> DaftResult<()> {
+        let s: Float64Array = DataArray::from_iter(
+            Field::new("a", DataType::Float64),
+            vec![Some(1.0), None, Some(f64::NAN), None].into_iter(),
+        );
+        let s = s.into_series();
+
+        assert_eq!(s.null_count(), 2);
+        let is_null = s.is_null()?;
+        assert_eq!(is_null.data_type(), &DataType::Boolean);
+        assert_eq!(
+            is_null.bool()?.into_iter().collect::<Vec<_>>(),
+            vec![Some(false), Some(true), Some(false), Some(true)]
+        );
+        assert_eq!(
+            s.not_null()?.bool()?.into_iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(false), Some(true), Some(false)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_masks_without_nulls() -> DaftResult<()> {
+        let s = Int64Array::from(("a", vec![1, 2, 3])).into_series();
+        assert_eq!(s.null_count(), 0);
+        assert_eq!(
+            s.is_null()?.bool()?.into_iter().collect::<Vec<_>>(),
+            vec![Some(false); 3]
+        );
+
+        let nulls = Series::full_null("n", &DataType::Null, 2);
+        assert_eq!(nulls.null_count(), 2);
+        assert_eq!(
+            nulls.is_null()?.bool()?.into_iter().collect::<Vec<_>>(),
+            vec![Some(true); 2]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_null_broadcast() -> DaftResult<()> {
+        let s = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), None, Some(3), None].into_iter(),
+        )
+        .into_series();
+        let fill = Int64Array::from(("fill", vec![0])).into_series();
+
+        let filled = s.fill_null(&fill)?;
+        assert_eq!(filled.name(), "a");
+        assert_eq!(filled.data_type(), &DataType::Int64);
+        assert_eq!(filled.i64()?.as_slice(), &[1, 0, 3, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_null_elementwise() -> DaftResult<()> {
+        let s = Int32Array::from_iter(
+            Field::new("a", DataType::Int32),
+            vec![None, Some(2), None].into_iter(),
+        )
+        .into_series();
+        let fill = Int64Array::from(("fill", vec![10, 20, 30])).into_series();
+
+        let filled = s.fill_null(&fill)?;
+        assert_eq!(filled.data_type(), &DataType::Int64);
+        assert_eq!(filled.i64()?.as_slice(), &[10, 2, 30]);
+
+        let bad_len = Int64Array::from(("fill", vec![10, 20])).into_series();
+        assert!(s.fill_null(&bad_len).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_null_keeps_nan() -> DaftResult<()> {
+        let s: Float64Array = DataArray::from_iter(
+            Field::new("a", DataType::Float64),
+            vec![Some(f64::NAN), None].into_iter(),
+        );
+        let fill = Float64Array::from(("fill", vec![0.0])).into_series();
+
+        let filled = s.into_series().fill_null(&fill)?;
+        let filled = filled.f64()?;
+        assert!(filled.get(0).unwrap().is_nan());
+        assert_eq!(filled.get(1), Some(0.0));
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    datatypes::DataType,
+    series::{array_impl::IntoSeries, Series},
+};
+
+impl Series {
+    /// Raises each element to the power of `exponent`, returning Float64.
+    ///
+    /// `exponent` is either broadcast (length 1) or applied element-wise. Results outside the real
+    /// domain, such as a fractional power of a negative base, are NaN.
+    pub fn pow(&self, exponent: &Self) -> DaftResult<Self> {
+        match (self.data_type(), exponent.data_type()) {
+            (lhs_dt, rhs_dt) if lhs_dt.is_numeric() && rhs_dt.is_numeric() => {
+                let base = self.cast(&DataType::Float64)?;
+                let exponent = exponent.cast(&DataType::Float64)?;
+                Ok(base
+                    .f64()?
+                    .binary_apply(exponent.f64()?, f64::powf)?
+                    .into_series())
+            }
+            (lhs_dt, rhs_dt) => Err(DaftError::TypeError(format!(
+                "Expected inputs to pow to be numeric, got {} and {}",
+                lhs_dt, rhs_dt
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field, Float64Array, Int32Array, Int64Array, Utf8Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_pow_broadcasts_exponent() -> DaftResult<()> {
+        let base = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(2), None, Some(-3), Some(4)].into_iter(),
+        )
+        .into_series();
+        let exponent = Int32Array::from(("e", vec![2])).into_series();
+        let result = base.pow(&exponent)?;
+        assert_eq!(result.name(), "a");
+        assert_eq!(result.data_type(), &DataType::Float64);
+        assert_eq!(
+            result
+                .f64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(4.0), None, Some(9.0), Some(16.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pow_elementwise_and_domain() -> DaftResult<()> {
+        let base = Float64Array::from(("a", vec![2.0, -8.0, 9.0])).into_series();
+        let exponent = Float64Array::from(("e", vec![-1.0, 0.5, 0.5])).into_series();
+        let result = base.pow(&exponent)?;
+        let values = result.f64()?.as_slice();
+        assert_eq!(values[0], 0.5);
+        assert!(values[1].is_nan());
+        assert_eq!(values[2], 3.0);
+
+        let too_short = Float64Array::from(("e", vec![1.0, 2.0])).into_series();
+        assert!(base.pow(&too_short).is_err());
+        let not_numeric = Utf8Array::from(("e", ["x"].as_slice())).into_series();
+        assert!(base.pow(&not_numeric).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    datatypes::{DataType, Field, UInt64Array, Utf8Array},
+    series::{IntoSeries, Series},
+};
+
+/// Returns the `n_bins - 1` inner edges that split `sorted` into `n_bins` equal-count bins,
+/// linearly interpolating between neighbouring values.
+fn quantile_edges(sorted: &[f64], n_bins: usize) -> Vec<f64> {
+    let last = (sorted.len() - 1) as f64;
+    (1..n_bins)
+        .map(|k| {
+            let pos = last * k as f64 / n_bins as f64;
+            let lo = pos.floor() as usize;
+            let hi = pos.ceil() as usize;
+            sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+        })
+        .collect()
+}
+
+impl Series {
+    /// Assigns each value to one of `n_bins` quantile-based bins, so that each bin holds roughly
+    /// the same number of values.
+    ///
+    /// Returns a UInt64 column of bin indices, or a Utf8 column if `labels` (one per bin) are given.
+    /// A value equal to a bin edge goes to the upper bin, and nulls and NaNs map to null.
+    ///
+    /// When there are fewer distinct values than bins, some edges coincide; the empty bins between
+    /// them are merged and the remaining non-empty bins are numbered contiguously from 0, so fewer
+    /// than `n_bins` indices are used. A merged bin takes the label of the last bin it spans.
+    pub fn qcut(&self, n_bins: usize, labels: Option<Vec<String>>) -> DaftResult<Self> {
+        if n_bins == 0 {
+            return Err(DaftError::ValueError(
+                "qcut requires at least one bin".to_string(),
+            ));
+        }
+        if let Some(labels) = &labels
+            && labels.len() != n_bins
+        {
+            return Err(DaftError::ValueError(format!(
+                "qcut expected {n_bins} labels, got {}",
+                labels.len()
+            )));
+        }
+        if !self.data_type().is_numeric() {
+            return Err(DaftError::TypeError(format!(
+                "qcut requires a numeric series, got {}",
+                self.data_type()
+            )));
+        }
+
+        let values = self.cast(&DataType::Float64)?;
+        let values = values
+            .f64()?
+            .into_iter()
+            .map(|v| v.copied().filter(|v| !v.is_nan()))
+            .collect::<Vec<_>>();
+        let mut sorted = values.iter().flatten().copied().collect::<Vec<_>>();
+        sorted.sort_by(f64::total_cmp);
+
+        let edges = if sorted.is_empty() {
+            vec![]
+        } else {
+            quantile_edges(&sorted, n_bins)
+        };
+        let mut distinct_edges = edges.clone();
+        distinct_edges.dedup();
+
+        // Number of edges less than or equal to `v`, i.e. the bin `v` falls into.
+        let bin_of = |edges: &[f64], v: f64| edges.partition_point(|edge| *edge <= v);
+
+        match labels {
+            Some(labels) => {
+                let binned = values
+                    .iter()
+                    .map(|v| v.map(|v| labels[bin_of(&edges, v)].as_str()));
+                Ok(Utf8Array::from_iter(self.name(), binned).into_series())
+            }
+            None => {
+                let bins = values
+                    .iter()
+                    .map(|v| v.map(|v| bin_of(&distinct_edges, v)))
+                    .collect::<Vec<_>>();
+                // Renumber the non-empty bins so that merged bins don't leave gaps in the indices.
+                let mut non_empty = vec![false; distinct_edges.len() + 1];
+                for &bin in bins.iter().flatten() {
+                    non_empty[bin] = true;
+                }
+                let renumbered = non_empty
+                    .iter()
+                    .scan(0u64, |next, &non_empty| {
+                        let index = *next;
+                        *next += u64::from(non_empty);
+                        Some(index)
+                    })
+                    .collect::<Vec<_>>();
+                let binned = bins.iter().map(|bin| bin.map(|bin| renumbered[bin]));
+                Ok(
+                    UInt64Array::from_iter(Field::new(self.name(), DataType::UInt64), binned)
+                        .into_series(),
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, Field, Float64Array, Int64Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn bins(series: &Series) -> DaftResult<Vec<Option<u64>>> {
+        Ok(series.u64()?.into_iter().map(|v| v.copied()).collect())
+    }
+
+    #[test]
+    fn test_qcut_uniform_bins_are_balanced() -> DaftResult<()> {
+        let s = Int64Array::from(("a", (0..100).rev().collect::<Vec<_>>())).into_series();
+        let binned = s.qcut(4, None)?;
+        assert_eq!(binned.name(), "a");
+        assert_eq!(binned.data_type(), &DataType::UInt64);
+
+        let mut counts = [0; 4];
+        for bin in bins(&binned)? {
+            counts[bin.unwrap() as usize] += 1;
+        }
+        assert_eq!(counts, [25, 25, 25, 25]);
+
+        let s = Float64Array::from((
+            "a",
+            (0..1000).map(|i| f64::from(i).sqrt()).collect::<Vec<_>>(),
+        ))
+        .into_series();
+        let mut counts = [0; 3];
+        for bin in bins(&s.qcut(3, None)?)? {
+            counts[bin.unwrap() as usize] += 1;
+        }
+        assert!(counts.iter().all(|c| (332..=335).contains(c)), "{counts:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_qcut_edges_nulls_and_labels() -> DaftResult<()> {
+        // The single edge is 3, which goes to the upper bin.
+        let s = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), Some(2), Some(3), None, Some(4), Some(5)].into_iter(),
+        )
+        .into_series();
+        assert_eq!(
+            bins(&s.qcut(2, None)?)?,
+            vec![Some(0), Some(0), Some(1), None, Some(1), Some(1)]
+        );
+
+        let labeled = s.qcut(2, Some(vec!["low".to_string(), "high".to_string()]))?;
+        assert_eq!(labeled.data_type(), &DataType::Utf8);
+        assert_eq!(
+            labeled.utf8()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![
+                Some("low"),
+                Some("low"),
+                Some("high"),
+                None,
+                Some("high"),
+                Some("high")
+            ]
+        );
+
+        assert!(s.qcut(0, None).is_err());
+        assert!(s.qcut(3, Some(vec!["x".to_string()])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_qcut_merges_empty_bins() -> DaftResult<()> {
+        // The edges are 1, 1 and 1.25, so the two bins starting at 1 are merged and the bin below 1
+        // is empty, leaving two contiguously numbered bins.
+        let s = Int64Array::from(("a", vec![1, 1, 1, 1, 1, 1, 2, 3])).into_series();
+        assert_eq!(
+            bins(&s.qcut(4, None)?)?,
+            vec![
+                Some(0),
+                Some(0),
+                Some(0),
+                Some(0),
+                Some(0),
+                Some(0),
+                Some(1),
+                Some(1)
+            ]
+        );
+
+        let labels = ["a", "b", "c", "d"].map(str::to_string).to_vec();
+        let labeled = s.qcut(4, Some(labels))?;
+        assert_eq!(
+            labeled.utf8()?.as_arrow().values_iter().collect::<Vec<_>>(),
+            vec!["c", "c", "c", "c", "c", "c", "d", "d"]
+        );
+
+        let all_null = Series::full_null("a", &DataType::Int64, 2);
+        assert_eq!(bins(&all_null.qcut(3, None)?)?, vec![None, None]);
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use std::str::FromStr;
+
+use common_error::{DaftError, DaftResult};
+use derive_more::Display;
+
+use crate::{
+    datatypes::{Float64Array, UInt64Array},
+    series::{IntoSeries, Series},
+    utils::dyn_compare::build_dyn_compare,
+};
+
+/// How [`Series::rank`] assigns ranks to tied values.
+///
+/// | Dense   - Ties share a rank, and the next distinct value gets the following rank.
+/// | Min     - Ties share the lowest rank of the group.
+/// | Max     - Ties share the highest rank of the group.
+/// | Average - Ties share the mean rank of the group.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash)]
+pub enum RankMethod {
+    Dense,
+    Min,
+    Max,
+    Average,
+}
+
+impl FromStr for RankMethod {
+    type Err = DaftError;
+
+    fn from_str(method: &str) -> DaftResult<Self> {
+        match method {
+            "dense" => Ok(Self::Dense),
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            "average" => Ok(Self::Average),
+            _ => Err(DaftError::ValueError(format!(
+                "Rank method {method} is not supported; expected one of \"dense\", \"min\", \"max\" or \"average\""
+            ))),
+        }
+    }
+}
+
+impl Series {
+    /// Ranks each value, starting from 1, in ascending or descending order. Nulls rank last
+    /// regardless of direction and are tied with each other.
+    ///
+    /// Returns `UInt64` ranks, or `Float64` ranks for [`RankMethod::Average`].
+    pub fn rank(&self, descending: bool, method: RankMethod) -> DaftResult<Self> {
+        let indices = self.argsort(descending, descending)?;
+        // Descending sorts put nulls first, so move them to the end, keeping both parts in order.
+        let (mut order, nulls): (Vec<usize>, Vec<usize>) = indices
+            .u64()?
+            .as_slice()
+            .iter()
+            .map(|&i| i as usize)
+            .partition(|&i| self.is_valid(i));
+        order.extend(nulls);
+
+        let array = self.as_physical()?.to_arrow();
+        let compare = build_dyn_compare(self.data_type(), self.data_type(), true, true)?;
+
+        let mut ranks = vec![0f64; self.len()];
+        let mut start = 0;
+        let mut dense_rank = 0;
+        while start < order.len() {
+            let mut end = start + 1;
+            while end < order.len()
+                && compare(array.as_ref(), array.as_ref(), order[start], order[end]).is_eq()
+            {
+                end += 1;
+            }
+            dense_rank += 1;
+            // Tied values occupy the 1-based ranks start + 1 ..= end.
+            let rank = match method {
+                RankMethod::Dense => dense_rank as f64,
+                RankMethod::Min => (start + 1) as f64,
+                RankMethod::Max => end as f64,
+                RankMethod::Average => (start + 1 + end) as f64 / 2.,
+            };
+            for &i in &order[start..end] {
+                ranks[i] = rank;
+            }
+            start = end;
+        }
+
+        match method {
+            RankMethod::Average => Ok(Float64Array::from((self.name(), ranks)).into_series()),
+            _ => {
+                let ranks = ranks.into_iter().map(|r| r as u64).collect::<Vec<_>>();
+                Ok(UInt64Array::from((self.name(), ranks)).into_series())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::RankMethod;
+    use crate::{
+        datatypes::{DataType, Field, Int64Array, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn values() -> Series {
+        Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(3), Some(1), None, Some(3), Some(2), Some(3)].into_iter(),
+        )
+        .into_series()
+    }
+
+    fn u64_ranks(s: &Series) -> DaftResult<Vec<u64>> {
+        Ok(s.u64()?.as_slice().to_vec())
+    }
+
+    #[test]
+    fn test_rank_dense() -> DaftResult<()> {
+        let ranks = values().rank(false, RankMethod::Dense)?;
+        assert_eq!(ranks.data_type(), &DataType::UInt64);
+        assert_eq!(ranks.name(), "a");
+        assert_eq!(u64_ranks(&ranks)?, vec![3, 1, 4, 3, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rank_min() -> DaftResult<()> {
+        let ranks = values().rank(false, RankMethod::Min)?;
+        assert_eq!(u64_ranks(&ranks)?, vec![3, 1, 6, 3, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rank_max() -> DaftResult<()> {
+        let ranks = values().rank(false, RankMethod::Max)?;
+        assert_eq!(u64_ranks(&ranks)?, vec![5, 1, 6, 5, 2, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rank_average() -> DaftResult<()> {
+        let ranks = values().rank(false, RankMethod::Average)?;
+        assert_eq!(ranks.data_type(), &DataType::Float64);
+        assert_eq!(ranks.f64()?.as_slice(), &[4., 1., 6., 4., 2., 4.]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rank_descending_keeps_nulls_last() -> DaftResult<()> {
+        let ranks = values().rank(true, RankMethod::Min)?;
+        assert_eq!(u64_ranks(&ranks)?, vec![1, 5, 6, 1, 4, 1]);
+
+        let strings = Utf8Array::from_iter("s", vec![None, Some("b"), None, Some("a")].into_iter())
+            .into_series();
+        let ranks = strings.rank(true, RankMethod::Dense)?;
+        assert_eq!(u64_ranks(&ranks)?, vec![3, 1, 3, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rank_method_from_str() {
+        assert_eq!(
+            "average".parse::<RankMethod>().unwrap(),
+            RankMethod::Average
+        );
+        assert!("first".parse::<RankMethod>().is_err());
+    }
+}
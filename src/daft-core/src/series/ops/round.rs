@@ -3,9 +3,13 @@ use common_error::{DaftError, DaftResult};
 use crate::{
     datatypes::DataType,
     series::{array_impl::IntoSeries, Series},
+    with_match_integer_daft_types,
 };
 
 impl Series {
+    /// Rounds to `decimal` places with ties going to the nearest even digit (`2.5 -> 2`, `3.5 -> 4`).
+    ///
+    /// A negative `decimal` rounds to the left of the decimal point. Nulls and NaN pass through.
     pub fn round(&self, decimal: i32) -> DaftResult<Self> {
         match self.data_type() {
             DataType::Int8
@@ -15,7 +19,11 @@ impl Series {
             | DataType::UInt8
             | DataType::UInt16
             | DataType::UInt32
-            | DataType::UInt64 => Ok(self.clone()),
+            | DataType::UInt64 => {
+                with_match_integer_daft_types!(self.data_type(), |$T| {
+                    Ok(self.downcast::<<$T as DaftDataType>::ArrayType>()?.round(decimal)?.into_series())
+                })
+            }
             DataType::Float32 => Ok(self.f32().unwrap().round(decimal)?.into_series()),
             DataType::Float64 => Ok(self.f64().unwrap().round(decimal)?.into_series()),
             dt => Err(DaftError::TypeError(format!(
@@ -25,3 +33,63 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field, Float32Array, Float64Array, Int64Array, Int8Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_round_ties_to_even() -> DaftResult<()> {
+        let s = Float64Array::from(("a", vec![0.5, 1.5, 2.5, 3.5, -2.5, -3.5])).into_series();
+        let rounded = s.round(0)?;
+        assert_eq!(rounded.f64()?.as_slice(), &[0.0, 2.0, 2.0, 4.0, -2.0, -4.0]);
+
+        let s = Float32Array::from(("a", vec![0.125f32, 0.375])).into_series();
+        assert_eq!(s.round(2)?.f32()?.as_slice(), &[0.12, 0.38]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_negative_decimals() -> DaftResult<()> {
+        let s = Float64Array::from(("a", vec![1250.0, 1350.0, 1234.5, -1251.0])).into_series();
+        assert_eq!(
+            s.round(-2)?.f64()?.as_slice(),
+            &[1200.0, 1400.0, 1200.0, -1300.0]
+        );
+
+        let s = Int64Array::from(("a", vec![1250, 1350, 1234, -1251, -1250])).into_series();
+        let rounded = s.round(-2)?;
+        assert_eq!(rounded.data_type(), &DataType::Int64);
+        assert_eq!(rounded.i64()?.as_slice(), &[1200, 1400, 1200, -1300, -1200]);
+        assert_eq!(s.round(-40)?.i64()?.as_slice(), &[0, 0, 0, 0, 0]);
+
+        let s = Int8Array::from(("a", vec![127i8])).into_series();
+        assert!(s.round(-1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_passes_through_nulls_and_nan() -> DaftResult<()> {
+        let s = Float64Array::from_iter(
+            Field::new("a", DataType::Float64),
+            vec![None, Some(f64::NAN), Some(f64::INFINITY), Some(2.5)].into_iter(),
+        )
+        .into_series();
+        let rounded = s.round(-1)?;
+        let values = rounded
+            .f64()?
+            .into_iter()
+            .map(|v| v.copied())
+            .collect::<Vec<_>>();
+        assert_eq!(values[0], None);
+        assert!(values[1].unwrap().is_nan());
+        assert_eq!(values[2], Some(f64::INFINITY));
+        assert_eq!(values[3], Some(0.0));
+        Ok(())
+    }
+}
@@ -21,3 +21,54 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::Int64Array,
+        series::{IntoSeries, Series},
+    };
+
+    fn assert_percentile(sketch: &Series, percentile: f64, expected: f64) -> DaftResult<()> {
+        let estimate = sketch.sketch_percentile(&[percentile], false)?;
+        let estimate = estimate.f64()?.get(0).unwrap();
+        assert!(
+            (estimate - expected).abs() <= expected * 0.02,
+            "p{percentile}: expected ~{expected}, got {estimate}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sketch_percentile_uniform() -> DaftResult<()> {
+        let values = Int64Array::from(("a", (1..=10_000).collect::<Vec<_>>())).into_series();
+        let sketch = values.approx_sketch(None)?;
+
+        assert_percentile(&sketch, 0.5, 5_000.0)?;
+        assert_percentile(&sketch, 0.9, 9_000.0)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_sketch_percentile_after_merge() -> DaftResult<()> {
+        let evens = (1..=10_000).filter(|v| v % 2 == 0).collect::<Vec<i64>>();
+        let odds = (1..=10_000).filter(|v| v % 2 == 1).collect::<Vec<i64>>();
+        let partial_evens = Int64Array::from(("a", evens))
+            .into_series()
+            .approx_sketch(None)?;
+        let partial_odds = Int64Array::from(("a", odds))
+            .into_series()
+            .approx_sketch(None)?;
+
+        let partials = Series::concat(&[&partial_evens, &partial_odds])?;
+        assert_eq!(partials.len(), 2);
+        let merged = partials.merge_sketch(None)?;
+        assert_eq!(merged.len(), 1);
+
+        assert_percentile(&merged, 0.5, 5_000.0)?;
+        assert_percentile(&merged, 0.9, 9_000.0)?;
+        Ok(())
+    }
+}
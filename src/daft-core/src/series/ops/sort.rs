@@ -2,13 +2,12 @@ use common_error::{DaftError, DaftResult};
 
 use crate::{
     series::{array_impl::IntoSeries, Series},
-    utils::{ensure_nulls_first, ensure_nulls_first_arr},
+    utils::ensure_nulls_first_arr,
     with_match_comparable_daft_types,
 };
 
 impl Series {
     pub fn argsort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
-        ensure_nulls_first(descending, nulls_first)?;
         let series = self.as_physical()?;
         with_match_comparable_daft_types!(series.data_type(), |$T| {
             let downcasted = series.downcast::<<$T as DaftDataType>::ArrayType>()?;
@@ -49,3 +48,37 @@ impl Series {
         self.inner.sort(descending, nulls_first)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field, Int64Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn test_argsort_nulls_first_independent_of_descending() -> DaftResult<()> {
+        let s = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(2), None, Some(1), Some(3)].into_iter(),
+        )
+        .into_series();
+
+        for (descending, nulls_first, expected) in [
+            (false, false, vec![2, 0, 3, 1]),
+            (false, true, vec![1, 2, 0, 3]),
+            (true, false, vec![3, 0, 2, 1]),
+            (true, true, vec![1, 3, 0, 2]),
+        ] {
+            let indices = s.argsort(descending, nulls_first)?;
+            assert_eq!(
+                indices.u64()?.as_slice(),
+                expected.as_slice(),
+                "descending: {descending}, nulls_first: {nulls_first}"
+            );
+        }
+        Ok(())
+    }
+}
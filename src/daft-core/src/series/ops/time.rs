@@ -6,6 +6,40 @@ use crate::{
 };
 
 impl Series {
+    /// Formats a date or timestamp column into strings using a chrono `format`.
+    pub fn strftime(&self, format: &str) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.strftime(format)?.into_series()),
+            DataType::Timestamp(..) => Ok(self.timestamp()?.strftime(format)?.into_series()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run strftime() operation on date or timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    /// Moves a timezone-aware timestamp column into the IANA timezone `tz`, keeping each instant.
+    pub fn convert_timezone(&self, tz: &str) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => Ok(self.timestamp()?.convert_timezone(tz)?.into_series()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run convert_timezone() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    /// Attaches, replaces or removes the timezone of a timestamp column, keeping each wall-clock time.
+    pub fn replace_timezone(&self, tz: Option<&str>) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => Ok(self.timestamp()?.replace_timezone(tz)?.into_series()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run replace_timezone() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
     pub fn dt_date(&self) -> DaftResult<Self> {
         match self.data_type() {
             DataType::Date => Ok(self.clone()),
@@ -201,3 +235,134 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, TimeUnit, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn to_strings(series: &Series) -> DaftResult<Vec<Option<String>>> {
+        Ok(series
+            .utf8()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.map(str::to_string))
+            .collect())
+    }
+
+    #[test]
+    fn test_strftime_round_trips_strptime() -> DaftResult<()> {
+        let format = "%Y-%m-%d %H:%M:%S";
+        let strings =
+            Utf8Array::from_iter("ts", vec![Some("2024-01-02 03:04:05"), None].into_iter())
+                .into_series();
+
+        let parsed = strings.strptime(format, TimeUnit::Microseconds, None, true)?;
+        assert_eq!(
+            to_strings(&parsed.strftime(format)?)?,
+            to_strings(&strings)?
+        );
+        assert_eq!(
+            to_strings(&parsed.dt_date()?.strftime("%d/%m/%Y")?)?,
+            vec![Some("02/01/2024".to_string()), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_strftime_uses_column_timezone() -> DaftResult<()> {
+        let strings =
+            Utf8Array::from_iter("ts", vec![Some("1970-01-01 00:00:00 +0000")].into_iter())
+                .into_series();
+        let parsed = strings.strptime(
+            "%Y-%m-%d %H:%M:%S %z",
+            TimeUnit::Seconds,
+            Some("America/New_York"),
+            true,
+        )?;
+        assert_eq!(
+            to_strings(&parsed.strftime("%Y-%m-%d %H:%M %Z")?)?,
+            vec![Some("1969-12-31 19:00 EST".to_string())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_strftime_rejects_inapplicable_format() -> DaftResult<()> {
+        let strings =
+            Utf8Array::from_iter("d", vec![Some("2024-01-02 00:00:00")].into_iter()).into_series();
+        let dates = strings
+            .strptime("%Y-%m-%d %H:%M:%S", TimeUnit::Seconds, None, true)?
+            .dt_date()?;
+        assert!(dates.strftime("%H").is_err());
+        assert!(strings.strftime("%Y").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_timezone_applies_dst_offset() -> DaftResult<()> {
+        let strings = Utf8Array::from_iter(
+            "ts",
+            vec![
+                Some("2024-01-15 12:00:00 +0000"),
+                Some("2024-07-15 12:00:00 +0000"),
+                None,
+            ]
+            .into_iter(),
+        )
+        .into_series();
+        let utc = strings.strptime("%Y-%m-%d %H:%M:%S %z", TimeUnit::Seconds, Some("UTC"), true)?;
+
+        let new_york = utc.convert_timezone("America/New_York")?;
+        assert_eq!(
+            new_york.data_type(),
+            &DataType::Timestamp(TimeUnit::Seconds, Some("America/New_York".to_string()))
+        );
+        // The instants are unchanged, only the wall-clock representation moves.
+        assert_eq!(
+            new_york.timestamp()?.physical.as_arrow(),
+            utc.timestamp()?.physical.as_arrow()
+        );
+        assert_eq!(
+            to_strings(&new_york.strftime("%H:%M %z")?)?,
+            vec![
+                Some("07:00 -0500".to_string()),
+                Some("08:00 -0400".to_string()),
+                None
+            ]
+        );
+
+        assert!(utc.convert_timezone("Not/AZone").is_err());
+        assert!(utc.replace_timezone(None)?.convert_timezone("UTC").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_timezone_keeps_wall_clock() -> DaftResult<()> {
+        let strings =
+            Utf8Array::from_iter("ts", vec![Some("2024-07-15 12:00:00")].into_iter()).into_series();
+        let naive = strings.strptime("%Y-%m-%d %H:%M:%S", TimeUnit::Seconds, None, true)?;
+
+        let new_york = naive.replace_timezone(Some("America/New_York"))?;
+        assert_eq!(
+            to_strings(&new_york.strftime("%Y-%m-%d %H:%M:%S %z")?)?,
+            vec![Some("2024-07-15 12:00:00 -0400".to_string())]
+        );
+        let tokyo = new_york.replace_timezone(Some("Asia/Tokyo"))?;
+        assert_eq!(
+            to_strings(&tokyo.strftime("%Y-%m-%d %H:%M:%S %z")?)?,
+            vec![Some("2024-07-15 12:00:00 +0900".to_string())]
+        );
+        let stripped = tokyo.replace_timezone(None)?;
+        assert_eq!(
+            stripped.timestamp()?.physical.as_arrow(),
+            naive.timestamp()?.physical.as_arrow()
+        );
+        Ok(())
+    }
+}
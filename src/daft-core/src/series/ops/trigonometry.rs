@@ -62,3 +62,91 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::trigonometry::TrigonometricFunction,
+        datatypes::{DataType, Float64Array, Int64Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn values(series: &Series) -> DaftResult<Vec<f64>> {
+        Ok(series.f64()?.as_slice().to_vec())
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-12, "expected {e}, got {a}");
+        }
+    }
+
+    #[test]
+    fn test_trigonometry_known_values() -> DaftResult<()> {
+        let s = Int64Array::from(("a", vec![0])).into_series();
+        let sin = s.trigonometry(&TrigonometricFunction::Sin)?;
+        assert_eq!(sin.data_type(), &DataType::Float64);
+        assert_close(&values(&sin)?, &[0.0]);
+        assert_close(
+            &values(&s.trigonometry(&TrigonometricFunction::Cos)?)?,
+            &[1.0],
+        );
+        assert_close(
+            &values(&s.trigonometry(&TrigonometricFunction::Tan)?)?,
+            &[0.0],
+        );
+
+        let s = Float64Array::from(("a", vec![1.0])).into_series();
+        assert_close(
+            &values(&s.trigonometry(&TrigonometricFunction::ArcSin)?)?,
+            &[FRAC_PI_2],
+        );
+        assert_close(
+            &values(&s.trigonometry(&TrigonometricFunction::ArcCos)?)?,
+            &[0.0],
+        );
+        assert_close(
+            &values(&s.trigonometry(&TrigonometricFunction::ArcTan)?)?,
+            &[FRAC_PI_4],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_inverse_trigonometry_out_of_domain_is_nan() -> DaftResult<()> {
+        let s = Float64Array::from(("a", vec![-2.0, 1.5])).into_series();
+        for func in [TrigonometricFunction::ArcSin, TrigonometricFunction::ArcCos] {
+            assert!(values(&s.trigonometry(&func)?)?.iter().all(|v| v.is_nan()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_atan2_quadrants() -> DaftResult<()> {
+        let y = Float64Array::from(("y", vec![1.0, 1.0, -1.0, -1.0, 0.0, 1.0])).into_series();
+        let x = Float64Array::from(("x", vec![1.0, -1.0, -1.0, 1.0, -1.0, 0.0])).into_series();
+        assert_close(
+            &values(&y.atan2(&x)?)?,
+            &[
+                FRAC_PI_4,
+                3.0 * FRAC_PI_4,
+                -3.0 * FRAC_PI_4,
+                -FRAC_PI_4,
+                PI,
+                FRAC_PI_2,
+            ],
+        );
+
+        let y = Int64Array::from(("y", vec![0])).into_series();
+        let x = Int64Array::from(("x", vec![1, -1])).into_series();
+        let result = y.atan2(&x)?;
+        assert_eq!(result.data_type(), &DataType::Float64);
+        assert_close(&values(&result)?, &[0.0, PI]);
+        Ok(())
+    }
+}
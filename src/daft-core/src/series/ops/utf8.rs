@@ -255,6 +255,20 @@ impl Series {
         self.with_utf8_array(|arr| Ok(arr.to_datetime(format, timezone)?.into_series()))
     }
 
+    pub fn strptime(
+        &self,
+        format: &str,
+        timeunit: TimeUnit,
+        timezone: Option<&str>,
+        raise_on_error: bool,
+    ) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| {
+            Ok(arr
+                .strptime(format, timeunit, timezone, raise_on_error)?
+                .into_series())
+        })
+    }
+
     pub fn utf8_normalize(&self, opts: Utf8NormalizeOptions) -> DaftResult<Self> {
         self.with_utf8_array(|arr| Ok(arr.normalize(opts)?.into_series()))
     }
@@ -273,4 +287,128 @@ impl Series {
             })
         })
     }
+
+    /// Concatenates the Utf8 `inputs` row-wise, joining the values with `separator`, with length-1
+    /// inputs broadcast to the others.
+    ///
+    /// A null in any input makes the row null, unless `skip_nulls` is set, in which case null values
+    /// are left out and only rows where every input is null are null.
+    pub fn concat_str(inputs: &[&Self], separator: &str, skip_nulls: bool) -> DaftResult<Self> {
+        let Some(first) = inputs.first() else {
+            return Err(DaftError::ValueError(
+                "concat_str needs at least 1 input".to_string(),
+            ));
+        };
+        let len = inputs.iter().map(|s| s.len()).max().unwrap_or(0);
+        let arrays = inputs
+            .iter()
+            .map(|s| {
+                if s.len() != len && s.len() != 1 {
+                    return Err(DaftError::ValueError(format!(
+                        "concat_str inputs must have the same length or length 1, but {} has length {} and expected {len}",
+                        s.name(),
+                        s.len()
+                    )));
+                }
+                match s.data_type() {
+                    DataType::Utf8 => Ok(Some(s.utf8()?)),
+                    DataType::Null => Ok(None),
+                    dt => Err(DaftError::TypeError(format!(
+                        "concat_str expects Utf8 inputs, but {} is {dt}",
+                        s.name()
+                    ))),
+                }
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let values = (0..len)
+            .map(|i| {
+                let mut out: Option<String> = None;
+                for arr in &arrays {
+                    let value = arr.and_then(|arr| arr.get(if arr.len() == 1 { 0 } else { i }));
+                    match (value, out.as_mut()) {
+                        (Some(value), Some(out)) => {
+                            out.push_str(separator);
+                            out.push_str(value);
+                        }
+                        (Some(value), None) => out = Some(value.to_string()),
+                        (None, _) if skip_nulls => {}
+                        (None, _) => return None,
+                    }
+                }
+                out
+            })
+            .collect::<Vec<_>>();
+        Ok(Utf8Array::from_iter(first.name(), values.into_iter()).into_series())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, Int64Array, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn utf8(name: &str, values: &[Option<&str>]) -> Series {
+        Utf8Array::from_iter(name, values.iter().copied()).into_series()
+    }
+
+    fn values(series: &Series) -> DaftResult<Vec<Option<String>>> {
+        Ok(series
+            .utf8()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.map(str::to_string))
+            .collect())
+    }
+
+    #[test]
+    fn test_concat_str_propagates_nulls() -> DaftResult<()> {
+        let a = utf8("a", &[Some("x"), None, Some("z")]);
+        let b = utf8("b", &[Some("1"), Some("2"), None]);
+        let result = Series::concat_str(&[&a, &b], "-", false)?;
+        assert_eq!(result.name(), "a");
+        assert_eq!(result.data_type(), &DataType::Utf8);
+        assert_eq!(values(&result)?, vec![Some("x-1".to_string()), None, None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_str_skips_nulls() -> DaftResult<()> {
+        let a = utf8("a", &[Some("x"), None, Some("z"), None]);
+        let b = utf8("b", &[Some("1"), Some("2"), None, None]);
+        let result = Series::concat_str(&[&a, &b], ", ", true)?;
+        assert_eq!(
+            values(&result)?,
+            vec![
+                Some("x, 1".to_string()),
+                Some("2".to_string()),
+                Some("z".to_string()),
+                None
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_str_broadcasts_literal_column() -> DaftResult<()> {
+        let a = utf8("a", &[Some("x"), Some("y")]);
+        let sep = utf8("literal", &[Some("/")]);
+        let b = utf8("b", &[Some("1"), Some("2")]);
+        let result = Series::concat_str(&[&a, &sep, &b], "", false)?;
+        assert_eq!(
+            values(&result)?,
+            vec![Some("x/1".to_string()), Some("y/2".to_string())]
+        );
+
+        let short = utf8("c", &[Some("1"), Some("2"), Some("3")]);
+        assert!(Series::concat_str(&[&a, &short], "", false).is_err());
+        let ints = Int64Array::from(("i", vec![1, 2])).into_series();
+        assert!(Series::concat_str(&[&a, &ints], "", false).is_err());
+        Ok(())
+    }
 }
@@ -6,6 +6,7 @@ use crate::{
         prelude::{Float64Array, UInt64Array},
     },
     count_mode::CountMode,
+    datatypes::{DataType, Field},
 };
 
 #[derive(Clone, Copy, Default, Debug)]
@@ -74,9 +75,98 @@ pub fn calculate_mean(sum: f64, count: u64) -> Option<f64> {
     }
 }
 
-pub fn calculate_stddev(stats: Stats, values: impl Iterator<Item = f64>) -> Option<f64> {
-    stats.mean.map(|mean| {
-        let sum_of_squares = values.map(|value| (value - mean).powi(2)).sum::<f64>();
-        (sum_of_squares / stats.count).sqrt()
-    })
+/// Running `(count, mean, M2)` state of Welford's single pass variance algorithm, where `M2` is the
+/// sum of squared deviations from the mean.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct VarianceState {
+    pub count: u64,
+    pub mean: f64,
+    pub m2: f64,
+}
+
+impl VarianceState {
+    /// Struct dtype of a column of states, as exchanged between the stages of a distributed variance.
+    #[must_use]
+    pub fn dtype() -> DataType {
+        DataType::Struct(vec![
+            Field::new("count", DataType::UInt64),
+            Field::new("mean", DataType::Float64),
+            Field::new("m2", DataType::Float64),
+        ])
+    }
+
+    pub fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Combines the states of two disjoint sets of values, using Chan et al.'s parallel formula.
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        if self.count == 0 {
+            return other;
+        }
+        if other.count == 0 {
+            return self;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let mean = self.mean + delta * (other.count as f64 / count as f64);
+        let m2 = self.m2
+            + other.m2
+            + delta * delta * (self.count as f64 * other.count as f64 / count as f64);
+        Self { count, mean, m2 }
+    }
+
+    /// Variance with `ddof` delta degrees of freedom, or `None` if there are not more than `ddof` values.
+    pub fn variance(&self, ddof: u64) -> Option<f64> {
+        (self.count > ddof).then(|| self.m2 / (self.count - ddof) as f64)
+    }
+}
+
+impl FromIterator<f64> for VarianceState {
+    fn from_iter<T: IntoIterator<Item = f64>>(iter: T) -> Self {
+        let mut state = Self::default();
+        for value in iter {
+            state.update(value);
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VarianceState;
+
+    #[test]
+    fn test_variance_state() {
+        let state = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .into_iter()
+            .collect::<VarianceState>();
+        assert_eq!(state.count, 8);
+        assert!((state.mean - 5.0).abs() < 1e-12);
+        assert!((state.variance(0).unwrap() - 4.0).abs() < 1e-12);
+        assert!((state.variance(1).unwrap() - 32.0 / 7.0).abs() < 1e-12);
+        assert_eq!(VarianceState::default().variance(0), None);
+        assert_eq!(
+            [1.0].into_iter().collect::<VarianceState>().variance(1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_variance_state_merge() {
+        let values = [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0, 1e9 + 10.0];
+        let full = values.into_iter().collect::<VarianceState>();
+        let left = values[..2].iter().copied().collect::<VarianceState>();
+        let right = values[2..].iter().copied().collect::<VarianceState>();
+        let merged = left.merge(right);
+
+        assert_eq!(merged.count, full.count);
+        assert!((merged.variance(0).unwrap() - 18.0).abs() < 1e-6);
+        assert!((merged.variance(1).unwrap() - 22.5).abs() < 1e-6);
+        assert_eq!(VarianceState::default().merge(full), full);
+    }
 }
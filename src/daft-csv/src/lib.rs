@@ -17,7 +17,9 @@ pub mod read;
 mod schema;
 
 pub use metadata::read_csv_schema_bulk;
-pub use options::{char_to_byte, CsvConvertOptions, CsvParseOptions, CsvReadOptions};
+pub use options::{
+    char_to_byte, CsvConvertOptions, CsvInferencePolicy, CsvParseOptions, CsvReadOptions,
+};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 pub use read::{read_csv, read_csv_bulk, stream_csv};
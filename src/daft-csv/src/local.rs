@@ -27,7 +27,7 @@ use snafu::ResultExt;
 
 use crate::{
     metadata::read_csv_schema_single,
-    read::{fields_to_projection_indices, tables_concat},
+    read::{fields_to_projection_indices, rename_fields, resolve_include_columns, tables_concat},
     ArrowSnafu, CsvConvertOptions, CsvParseOptions, CsvReadOptions, JoinSnafu,
 };
 
@@ -140,6 +140,9 @@ use pool::{CsvBuffer, CsvBufferPool, FileSlab, FileSlabPool, SLABSIZE};
 // Default size for CSV buffers.
 const DEFAULT_CSV_BUFFER_SIZE: usize = SLABSIZE; // 4MiB. Like SLABSIZE, this can be tuned.
 
+// The number of consecutive reads that fill a CSV buffer before we grow it.
+const FULL_READS_BEFORE_GROWING: usize = 2;
+
 /// Reads a single local CSV file in a non-streaming fashion.
 pub async fn read_csv_local(
     uri: &str,
@@ -236,8 +239,17 @@ pub async fn stream_csv_local(
         get_schema_and_estimators(uri, &convert_options, &parse_options, io_client, io_stats)
             .await?;
     let num_fields = schema.fields.len();
-    let projection_indices =
-        fields_to_projection_indices(&schema.fields, &convert_options.clone().include_columns);
+    let include_columns = resolve_include_columns(
+        &schema.fields,
+        include_columns,
+        convert_options.ignore_missing_columns,
+    )?;
+    let read_include_columns = resolve_include_columns(
+        &schema.fields,
+        convert_options.include_columns.clone(),
+        convert_options.ignore_missing_columns,
+    )?;
+    let projection_indices = fields_to_projection_indices(&schema.fields, &read_include_columns);
     let fields_subset = projection_indices
         .iter()
         .map(|i| schema.fields.get(*i).unwrap().into())
@@ -294,6 +306,7 @@ async fn get_schema_and_estimators(
     let (inferred_schema, read_stats) = read_csv_schema_single(
         uri,
         parse_options.clone(),
+        convert_options.inference_policy,
         // Read at most 1 MiB to estimate stats.
         Some(1024 * 1024),
         io_client.clone(),
@@ -307,16 +320,8 @@ async fn get_schema_and_estimators(
         inferred_schema.to_arrow()?
     };
     // Rename fields, if necessary.
-    if let Some(column_names) = convert_options.column_names.clone() {
-        schema = schema
-            .fields
-            .into_iter()
-            .zip(column_names.iter())
-            .map(|(field, name)| {
-                Field::new(name, field.data_type, field.is_nullable).with_metadata(field.metadata)
-            })
-            .collect::<Vec<_>>()
-            .into();
+    if let Some(column_names) = &convert_options.column_names {
+        schema = rename_fields(schema, column_names)?;
     }
     Ok((
         schema,
@@ -783,7 +788,9 @@ impl CsvValidator {
                 CsvState::QuotedField;
         }
 
-        // Escape transitions.
+        // Escape transitions. An escape char only has meaning inside a quoted field, and it escapes
+        // exactly one byte, so whatever follows (a quote, a delimiter, a newline, or another escape
+        // char) is taken literally and we stay in the quoted field.
         self.transition_table[CsvState::Escape as usize] = [CsvState::QuotedField; 256];
     }
 
@@ -877,10 +884,22 @@ where
 {
     let mut tables = vec![];
     let mut local_limit = limit;
+    let mut consecutive_full_reads = 0;
     loop {
         let (rows_read, has_more) =
             local_read_rows(&mut reader, csv_buffer.buffer.as_mut_slice(), local_limit)
                 .context(ArrowSnafu {})?;
+        // If the chunk keeps overflowing the buffer, our row size estimate was too large. Grow the
+        // buffer so that later iterations (and later chunks reusing it from the pool) need fewer retries.
+        let filled_buffer = has_more && rows_read == csv_buffer.buffer.len();
+        if filled_buffer {
+            consecutive_full_reads += 1;
+            if consecutive_full_reads >= FULL_READS_BEFORE_GROWING && csv_buffer.grow() {
+                consecutive_full_reads = 0;
+            }
+        } else {
+            consecutive_full_reads = 0;
+        }
         let chunk = projection_indices
             .par_iter()
             .enumerate()
@@ -926,3 +945,114 @@ where
     }
     Ok(tables)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow2::{
+        datatypes::{DataType, Field},
+        io::csv::read::ReaderBuilder,
+    };
+    use common_error::DaftResult;
+    use daft_core::prelude::Schema;
+    use daft_table::Table;
+
+    use super::{parse_csv_chunk, CsvBufferPool, CsvValidator, DOUBLE_QUOTE};
+
+    fn validate(validator: &mut CsvValidator, record: &[u8]) -> Option<bool> {
+        validator.validate_record(&mut record.iter())
+    }
+
+    fn backslash_validator(num_fields: usize) -> CsvValidator {
+        CsvValidator::new(num_fields, DOUBLE_QUOTE, b',', Some(b'\\'), true)
+    }
+
+    #[test]
+    fn test_backslash_escaped_quotes() {
+        let mut validator = backslash_validator(2);
+        assert_eq!(validate(&mut validator, b"\"a\\\"b\",c\n"), Some(true));
+        assert_eq!(validate(&mut validator, b"\"\\\"\",c\n"), Some(true));
+        // An escaped quote does not close the field, so the delimiter and newline stay inside it.
+        assert_eq!(validate(&mut validator, b"\"a\\\",c\n"), None);
+        assert_eq!(validate(&mut validator, b"\"a\\\"b\",c,d\n"), Some(false));
+    }
+
+    #[test]
+    fn test_backslash_escaped_delimiters() {
+        let mut validator = backslash_validator(2);
+        assert_eq!(validate(&mut validator, b"\"a\\,b\",c\n"), Some(true));
+        assert_eq!(validate(&mut validator, b"\"a\\\\\",c\n"), Some(true));
+        assert_eq!(validate(&mut validator, b"\"a\\\nb\",c\n"), Some(true));
+        // Escapes are only interpreted inside quoted fields.
+        assert_eq!(validate(&mut validator, b"a\\,b,c\n"), Some(false));
+        assert_eq!(
+            validate(&mut backslash_validator(3), b"a\\,b,c\n"),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_double_quote_escapes() {
+        let mut validator = CsvValidator::new(2, DOUBLE_QUOTE, b',', Some(DOUBLE_QUOTE), true);
+        assert_eq!(validate(&mut validator, b"\"a\"\"b\",c\n"), Some(true));
+        assert_eq!(validate(&mut validator, b"\"a\"\",\"\",c\n"), Some(true));
+
+        let mut validator = CsvValidator::new(2, DOUBLE_QUOTE, b',', Some(DOUBLE_QUOTE), false);
+        assert_eq!(validate(&mut validator, b"\"a\"\"b\",c\n"), Some(false));
+    }
+
+    #[test]
+    fn test_parse_csv_chunk_grows_buffer_for_rows_larger_than_estimate() -> DaftResult<()> {
+        let num_rows = 40;
+        let wide_value = "x".repeat(1000);
+        let csv = (0..num_rows)
+            .map(|i| format!("{i},{wide_value}\n"))
+            .collect::<String>();
+
+        let fields = vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let read_daft_fields = Arc::new(
+            fields
+                .iter()
+                .map(|f| Arc::new(daft_core::datatypes::Field::from(f)))
+                .collect::<Vec<_>>(),
+        );
+        let read_schema = Arc::new(Schema::new(
+            read_daft_fields
+                .iter()
+                .map(|f| f.as_ref().clone())
+                .collect(),
+        )?);
+
+        // Estimate 4 byte records and 2 records per buffer, far below the real row size and count.
+        let buffer_pool = Arc::new(CsvBufferPool::new(4, 2, 2));
+        let mut buffer = buffer_pool.get_buffer();
+        let reader = ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(csv.as_bytes());
+        let tables = parse_csv_chunk(
+            reader,
+            Arc::new(vec![0, 1]),
+            fields,
+            read_daft_fields,
+            read_schema,
+            &mut buffer,
+            None,
+            None,
+            None,
+        )?;
+
+        assert_eq!(tables.iter().map(Table::len).sum::<usize>(), num_rows);
+        assert!(tables.len() < num_rows / 2);
+        let grown_len = buffer.buffer.len();
+        assert!(grown_len > 2);
+
+        // The enlarged buffer goes back to the pool for later chunks.
+        drop(buffer);
+        assert_eq!(buffer_pool.get_buffer().buffer.len(), grown_len);
+        Ok(())
+    }
+}
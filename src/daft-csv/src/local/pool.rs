@@ -9,6 +9,9 @@ use parking_lot::{Mutex, RwLock};
 // The default size of a slab used for reading CSV files in chunks. Currently set to 4 MiB. This can be tuned.
 pub const SLABSIZE: usize = 4 * 1024 * 1024;
 
+// How many times larger than the pool's initial row count a CsvSlab may grow. This can be tuned.
+const MAX_CSV_SLAB_GROWTH: usize = 16;
+
 #[derive(Clone, Debug, Default)]
 pub struct CsvSlab(Vec<read::ByteRecord>);
 
@@ -83,6 +86,28 @@ impl CsvBufferPool {
     }
 }
 
+impl CsvBuffer {
+    /// Doubles the number of records this buffer can hold, up to `MAX_CSV_SLAB_GROWTH` times the
+    /// pool's initial row count. Returns whether the buffer grew. Since buffers are returned to the
+    /// pool on drop, the enlarged buffer is reused by later chunks.
+    pub fn grow(&mut self) -> bool {
+        let Some(pool) = self.pool.upgrade() else {
+            return false;
+        };
+        let max_rows = pool.num_rows.max(1) * MAX_CSV_SLAB_GROWTH;
+        let num_rows = self.buffer.len();
+        if num_rows >= max_rows {
+            return false;
+        }
+        let new_num_rows = (num_rows * 2).clamp(1, max_rows);
+        self.buffer.resize(
+            new_num_rows,
+            read::ByteRecord::with_capacity(pool.record_size, pool.num_fields),
+        );
+        true
+    }
+}
+
 impl Drop for CsvBuffer {
     fn drop(&mut self) {
         if let Some(pool) = self.pool.upgrade() {
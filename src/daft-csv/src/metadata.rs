@@ -17,7 +17,7 @@ use tokio::{
 };
 use tokio_util::io::StreamReader;
 
-use crate::{schema::merge_schema, CsvParseOptions};
+use crate::{schema::merge_schema, CsvInferencePolicy, CsvParseOptions};
 
 const DEFAULT_COLUMN_PREFIX: &str = "column_";
 
@@ -64,6 +64,7 @@ pub fn read_csv_schema(
         read_csv_schema_single(
             uri,
             parse_options.unwrap_or_default(),
+            CsvInferencePolicy::default(),
             // Default to 1 MiB.
             max_bytes.or(Some(1024 * 1024)),
             io_client,
@@ -93,6 +94,7 @@ pub async fn read_csv_schema_bulk(
                     read_csv_schema_single(
                         &owned_string,
                         owned_parse_options.unwrap_or_default(),
+                        CsvInferencePolicy::default(),
                         max_bytes,
                         owned_client,
                         owned_io_stats,
@@ -112,6 +114,7 @@ pub async fn read_csv_schema_bulk(
 pub(crate) async fn read_csv_schema_single(
     uri: &str,
     parse_options: CsvParseOptions,
+    inference_policy: CsvInferencePolicy,
     max_bytes: Option<usize>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
@@ -126,6 +129,7 @@ pub(crate) async fn read_csv_schema_single(
                 BufReader::new(File::open(file.path).await?),
                 compression_codec,
                 parse_options,
+                inference_policy,
                 max_bytes,
             )
             .await
@@ -135,6 +139,7 @@ pub(crate) async fn read_csv_schema_single(
                 StreamReader::new(stream),
                 compression_codec,
                 parse_options,
+                inference_policy,
                 // Truncate max_bytes to size if both are set.
                 max_bytes.map(|m| size.map_or(m, |s| m.min(s))),
             )
@@ -148,6 +153,7 @@ async fn read_csv_schema_from_compressed_reader<R>(
     reader: R,
     compression_codec: Option<CompressionCodec>,
     parse_options: CsvParseOptions,
+    inference_policy: CsvInferencePolicy,
     max_bytes: Option<usize>,
 ) -> DaftResult<(Schema, CsvReadStats)>
 where
@@ -158,11 +164,20 @@ where
             read_csv_schema_from_uncompressed_reader(
                 compression.to_decoder(reader),
                 parse_options,
+                inference_policy,
+                max_bytes,
+            )
+            .await
+        }
+        None => {
+            read_csv_schema_from_uncompressed_reader(
+                reader,
+                parse_options,
+                inference_policy,
                 max_bytes,
             )
             .await
         }
-        None => read_csv_schema_from_uncompressed_reader(reader, parse_options, max_bytes).await,
     }
 }
 
@@ -170,13 +185,19 @@ where
 async fn read_csv_schema_from_uncompressed_reader<R>(
     reader: R,
     parse_options: CsvParseOptions,
+    inference_policy: CsvInferencePolicy,
     max_bytes: Option<usize>,
 ) -> DaftResult<(Schema, CsvReadStats)>
 where
     R: AsyncRead + Unpin + Send,
 {
-    let (schema, read_stats) =
-        read_csv_arrow_schema_from_uncompressed_reader(reader, parse_options, max_bytes).await?;
+    let (schema, read_stats) = read_csv_arrow_schema_from_uncompressed_reader(
+        reader,
+        parse_options,
+        inference_policy,
+        max_bytes,
+    )
+    .await?;
     Ok((Schema::try_from(&schema)?, read_stats))
 }
 
@@ -184,6 +205,7 @@ where
 async fn read_csv_arrow_schema_from_uncompressed_reader<R>(
     reader: R,
     parse_options: CsvParseOptions,
+    inference_policy: CsvInferencePolicy,
     max_bytes: Option<usize>,
 ) -> DaftResult<(arrow2::datatypes::Schema, CsvReadStats)>
 where
@@ -199,16 +221,47 @@ where
         .buffer_capacity(max_bytes.unwrap_or(1 << 20).min(1 << 20))
         .flexible(parse_options.allow_variable_columns)
         .create_reader(reader.compat());
-    let (fields, read_stats) =
-        infer_schema(&mut reader, None, max_bytes, parse_options.has_header).await?;
+    let (fields, read_stats) = infer_schema(
+        &mut reader,
+        None,
+        max_bytes,
+        parse_options.has_header,
+        inference_policy,
+    )
+    .await?;
     Ok((fields.into(), read_stats))
 }
 
+/// Infers the type of a single CSV value under `policy`.
+fn infer_with_policy(bytes: &[u8], policy: CsvInferencePolicy) -> arrow2::datatypes::DataType {
+    use arrow2::datatypes::DataType;
+
+    match policy {
+        CsvInferencePolicy::NumericPreferring => infer(bytes),
+        CsvInferencePolicy::StringPreserving => match infer(bytes) {
+            DataType::Int64 if has_leading_zero(bytes) => DataType::Utf8,
+            dtype => dtype,
+        },
+        CsvInferencePolicy::AllStrings if bytes.is_empty() => DataType::Null,
+        CsvInferencePolicy::AllStrings => DataType::Utf8,
+    }
+}
+
+/// Whether an integer literal has a leading zero that would be lost by parsing it, e.g. "007" or "-01".
+fn has_leading_zero(bytes: &[u8]) -> bool {
+    let digits = match bytes.first() {
+        Some(b'+' | b'-') => &bytes[1..],
+        _ => bytes,
+    };
+    digits.len() > 1 && digits[0] == b'0'
+}
+
 async fn infer_schema<R>(
     reader: &mut AsyncReader<R>,
     max_rows: Option<usize>,
     max_bytes: Option<usize>,
     has_header: bool,
+    inference_policy: CsvInferencePolicy,
 ) -> arrow2::error::Result<(Vec<arrow2::datatypes::Field>, CsvReadStats)>
 where
     R: futures::AsyncRead + Unpin + Send,
@@ -256,7 +309,7 @@ where
         m2 += delta * delta2;
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
-                column.insert(infer(string));
+                column.insert(infer_with_policy(string, inference_policy));
             }
         }
     }
@@ -275,7 +328,7 @@ where
         m2 += delta * delta2;
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
-                column.insert(infer(string));
+                column.insert(infer_with_policy(string, inference_policy));
             }
         }
     }
@@ -296,8 +349,8 @@ mod tests {
     use daft_io::{IOClient, IOConfig};
     use rstest::rstest;
 
-    use super::read_csv_schema;
-    use crate::CsvParseOptions;
+    use super::{infer_with_policy, read_csv_schema};
+    use crate::{CsvInferencePolicy, CsvParseOptions};
 
     #[rstest]
     fn test_csv_schema_local(
@@ -650,4 +703,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_infer_with_policy() {
+        use arrow2::datatypes::DataType as ArrowDataType;
+
+        let numeric = CsvInferencePolicy::NumericPreferring;
+        assert_eq!(infer_with_policy(b"02134", numeric), ArrowDataType::Int64);
+
+        let strings = CsvInferencePolicy::StringPreserving;
+        assert_eq!(infer_with_policy(b"02134", strings), ArrowDataType::Utf8);
+        assert_eq!(infer_with_policy(b"-07", strings), ArrowDataType::Utf8);
+        assert_eq!(infer_with_policy(b"0", strings), ArrowDataType::Int64);
+        assert_eq!(infer_with_policy(b"10001", strings), ArrowDataType::Int64);
+        assert_eq!(infer_with_policy(b"0.5", strings), ArrowDataType::Float64);
+
+        let all_strings = CsvInferencePolicy::AllStrings;
+        assert_eq!(infer_with_policy(b"1.5", all_strings), ArrowDataType::Utf8);
+        assert_eq!(infer_with_policy(b"", all_strings), ArrowDataType::Null);
+    }
 }
@@ -9,6 +9,19 @@ use {
     pyo3::{pyclass, pyclass::CompareOp, pymethods, PyObject, PyResult, Python},
 };
 
+/// How column types are chosen when inferring a schema from CSV data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CsvInferencePolicy {
+    /// Infer the narrowest numeric, boolean or temporal type that fits every value.
+    #[default]
+    NumericPreferring,
+    /// Like `NumericPreferring`, but integers written with leading zeros (e.g. zip codes such as
+    /// "02134") make the column Utf8, so their textual form is preserved.
+    StringPreserving,
+    /// Infer every non-empty column as Utf8.
+    AllStrings,
+}
+
 /// Options for converting CSV data to Daft data.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
@@ -18,6 +31,10 @@ pub struct CsvConvertOptions {
     pub column_names: Option<Vec<String>>,
     pub schema: Option<SchemaRef>,
     pub predicate: Option<ExprRef>,
+    /// Whether names in `include_columns` that are not in the file are dropped instead of erroring.
+    pub ignore_missing_columns: bool,
+    /// How column types are inferred when no `schema` is provided.
+    pub inference_policy: CsvInferencePolicy,
 }
 
 impl CsvConvertOptions {
@@ -35,6 +52,8 @@ impl CsvConvertOptions {
             column_names,
             schema,
             predicate,
+            ignore_missing_columns: false,
+            inference_policy: CsvInferencePolicy::default(),
         }
     }
 
@@ -46,6 +65,8 @@ impl CsvConvertOptions {
             column_names: self.column_names,
             schema: self.schema,
             predicate: self.predicate,
+            ignore_missing_columns: self.ignore_missing_columns,
+            inference_policy: self.inference_policy,
         }
     }
 
@@ -57,6 +78,8 @@ impl CsvConvertOptions {
             column_names: self.column_names,
             schema: self.schema,
             predicate: self.predicate,
+            ignore_missing_columns: self.ignore_missing_columns,
+            inference_policy: self.inference_policy,
         }
     }
 
@@ -68,6 +91,8 @@ impl CsvConvertOptions {
             column_names,
             schema: self.schema,
             predicate: self.predicate,
+            ignore_missing_columns: self.ignore_missing_columns,
+            inference_policy: self.inference_policy,
         }
     }
 
@@ -79,6 +104,34 @@ impl CsvConvertOptions {
             column_names: self.column_names,
             schema,
             predicate: self.predicate,
+            ignore_missing_columns: self.ignore_missing_columns,
+            inference_policy: self.inference_policy,
+        }
+    }
+
+    #[must_use]
+    pub fn with_ignore_missing_columns(self, ignore_missing_columns: bool) -> Self {
+        Self {
+            limit: self.limit,
+            include_columns: self.include_columns,
+            column_names: self.column_names,
+            schema: self.schema,
+            predicate: self.predicate,
+            ignore_missing_columns,
+            inference_policy: self.inference_policy,
+        }
+    }
+
+    #[must_use]
+    pub fn with_inference_policy(self, inference_policy: CsvInferencePolicy) -> Self {
+        Self {
+            limit: self.limit,
+            include_columns: self.include_columns,
+            column_names: self.column_names,
+            schema: self.schema,
+            predicate: self.predicate,
+            ignore_missing_columns: self.ignore_missing_columns,
+            inference_policy,
         }
     }
 }
@@ -101,8 +154,9 @@ impl CsvConvertOptions {
     /// * `column_names` - The names for the CSV columns.
     /// * `schema` - The names and dtypes for the CSV columns.
     /// * `predicate` - Expression to filter rows applied before the limit
+    /// * `ignore_missing_columns` - Drop `include_columns` that are not in the file instead of erroring.
     #[new]
-    #[pyo3(signature = (limit=None, include_columns=None, column_names=None, schema=None, predicate=None))]
+    #[pyo3(signature = (limit=None, include_columns=None, column_names=None, schema=None, predicate=None, ignore_missing_columns=false))]
     #[must_use]
     pub fn new(
         limit: Option<usize>,
@@ -110,6 +164,7 @@ impl CsvConvertOptions {
         column_names: Option<Vec<String>>,
         schema: Option<PySchema>,
         predicate: Option<PyExpr>,
+        ignore_missing_columns: bool,
     ) -> Self {
         Self::new_internal(
             limit,
@@ -118,6 +173,7 @@ impl CsvConvertOptions {
             schema.map(std::convert::Into::into),
             predicate.map(|p| p.expr),
         )
+        .with_ignore_missing_columns(ignore_missing_columns)
     }
 
     #[getter]
@@ -140,6 +196,11 @@ impl CsvConvertOptions {
         Ok(self.schema.as_ref().map(|s| s.clone().into()))
     }
 
+    #[getter]
+    pub fn get_ignore_missing_columns(&self) -> PyResult<bool> {
+        Ok(self.ignore_missing_columns)
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
         match op {
             CompareOp::Eq => self == other,
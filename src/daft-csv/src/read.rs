@@ -257,6 +257,9 @@ async fn read_csv_single_into_table(
     let include_columns = convert_options
         .as_ref()
         .and_then(|opts| opts.include_columns.clone());
+    let ignore_missing_columns = convert_options
+        .as_ref()
+        .is_some_and(|opts| opts.ignore_missing_columns);
 
     let convert_options_with_predicate_columns = match (convert_options, &predicate) {
         (None, _) => None,
@@ -285,6 +288,8 @@ async fn read_csv_single_into_table(
         io_stats,
     )
     .await?;
+    let include_columns =
+        resolve_include_columns(&fields, include_columns, ignore_missing_columns)?;
     // Default max chunks in flight is set to 2x the number of cores, which should ensure pipelining of reading chunks
     // with the parsing of chunks on the rayon threadpool.
     let max_chunks_in_flight = max_chunks_in_flight.unwrap_or_else(|| {
@@ -383,6 +388,9 @@ pub async fn stream_csv_single(
     let include_columns = convert_options
         .as_ref()
         .and_then(|opts| opts.include_columns.clone());
+    let ignore_missing_columns = convert_options
+        .as_ref()
+        .is_some_and(|opts| opts.ignore_missing_columns);
 
     let convert_options_with_predicate_columns = match (convert_options, &predicate) {
         (None, _) => None,
@@ -402,7 +410,7 @@ pub async fn stream_csv_single(
         }
     };
 
-    let (chunk_stream, _fields) = read_csv_single_into_stream(
+    let (chunk_stream, fields) = read_csv_single_into_stream(
         uri,
         convert_options_with_predicate_columns.unwrap_or_default(),
         parse_options.unwrap_or_default(),
@@ -411,6 +419,8 @@ pub async fn stream_csv_single(
         io_stats,
     )
     .await?;
+    let include_columns =
+        resolve_include_columns(&fields, include_columns, ignore_missing_columns)?;
     // Default max chunks in flight is set to 2x the number of cores, which should ensure pipelining of reading chunks
     // with the parsing of chunks on the rayon threadpool.
     let max_chunks_in_flight = max_chunks_in_flight.unwrap_or_else(|| {
@@ -471,6 +481,7 @@ async fn read_csv_single_into_stream(
             let (schema, read_stats) = read_csv_schema_single(
                 uri,
                 parse_options.clone(),
+                convert_options.inference_policy,
                 // Read at most 1 MiB when doing schema inference.
                 Some(1024 * 1024),
                 io_client.clone(),
@@ -485,15 +496,7 @@ async fn read_csv_single_into_stream(
         };
     // Rename fields, if necessary.
     if let Some(column_names) = convert_options.column_names {
-        schema = schema
-            .fields
-            .into_iter()
-            .zip(column_names.iter())
-            .map(|(field, name)| {
-                Field::new(name, field.data_type, field.is_nullable).with_metadata(field.metadata)
-            })
-            .collect::<Vec<_>>()
-            .into();
+        schema = rename_fields(schema, &column_names)?;
     }
     let (reader, buffer_size, chunk_size): (Box<dyn AsyncBufRead + Unpin + Send>, usize, usize) =
         match io_client
@@ -548,8 +551,12 @@ async fn read_csv_single_into_stream(
         estimated_mean_row_size,
         estimated_std_row_size,
     );
-    let projection_indices =
-        fields_to_projection_indices(&schema.fields, &convert_options.include_columns);
+    let include_columns = resolve_include_columns(
+        &schema.fields,
+        convert_options.include_columns,
+        convert_options.ignore_missing_columns,
+    )?;
+    let projection_indices = fields_to_projection_indices(&schema.fields, &include_columns);
 
     let fields = schema.fields;
     let stream = parse_into_column_array_chunk_stream(
@@ -677,6 +684,62 @@ fn parse_into_column_array_chunk_stream(
     }))
 }
 
+/// Applies user-provided `column_names` to the fields of a CSV schema, in order. There must be
+/// exactly one name per column, since silently dropping or leaving columns unnamed would misalign
+/// the data with its names.
+pub fn rename_fields(
+    schema: arrow2::datatypes::Schema,
+    column_names: &[String],
+) -> DaftResult<arrow2::datatypes::Schema> {
+    if schema.fields.len() != column_names.len() {
+        return Err(DaftError::ValueError(format!(
+            "Got {} column names for a CSV file with {} columns: {:?}",
+            column_names.len(),
+            schema.fields.len(),
+            column_names
+        )));
+    }
+    Ok(schema
+        .fields
+        .into_iter()
+        .zip(column_names.iter())
+        .map(|(field, name)| {
+            Field::new(name, field.data_type, field.is_nullable).with_metadata(field.metadata)
+        })
+        .collect::<Vec<_>>()
+        .into())
+}
+
+/// Checks the requested `include_columns` against the file's fields. Names that are not in the file
+/// are an error listing the available columns, unless `ignore_missing` is set, in which case they
+/// are dropped.
+pub fn resolve_include_columns(
+    fields: &[arrow2::datatypes::Field],
+    include_columns: Option<Vec<String>>,
+    ignore_missing: bool,
+) -> DaftResult<Option<Vec<String>>> {
+    let Some(include_columns) = include_columns else {
+        return Ok(None);
+    };
+    let is_present = |c: &String| fields.iter().any(|f| &f.name == c);
+    if ignore_missing {
+        return Ok(Some(
+            include_columns.into_iter().filter(is_present).collect(),
+        ));
+    }
+    if let Some(missing) = include_columns.iter().find(|c| !is_present(c)) {
+        let available = fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(DaftError::FieldNotFound(format!(
+            "Column \"{missing}\" not found in CSV file, available columns: [{available}]"
+        )));
+    }
+    Ok(Some(include_columns))
+}
+
 pub fn fields_to_projection_indices(
     fields: &[arrow2::datatypes::Field],
     include_columns: &Option<Vec<String>>,
@@ -717,7 +780,9 @@ mod tests {
     use rstest::rstest;
 
     use super::read_csv;
-    use crate::{char_to_byte, CsvConvertOptions, CsvParseOptions, CsvReadOptions};
+    use crate::{
+        char_to_byte, CsvConvertOptions, CsvInferencePolicy, CsvParseOptions, CsvReadOptions,
+    };
 
     #[allow(clippy::too_many_arguments)]
     fn check_equal_local_arrow2(
@@ -878,6 +943,7 @@ mod tests {
             None,
         )?;
         assert_eq!(table.len(), 20);
+        assert_eq!(table.get_column("sepal.length")?.f64()?.get(0), Some(5.1));
         assert_eq!(
             table.schema,
             Schema::new(vec![
@@ -1249,6 +1315,220 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_csv_read_local_projection_missing_column() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let err = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_include_columns(Some(vec![
+                "petal.length".to_string(),
+                "petal.height".to_string(),
+            ]))),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )
+        .unwrap_err();
+        match err {
+            DaftError::FieldNotFound(msg) => {
+                assert!(msg.contains("\"petal.height\""));
+                assert!(
+                    msg.contains("sepal.length, sepal.width, petal.length, petal.width, variety")
+                );
+            }
+            err => panic!("expected FieldNotFound, got {err}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_projection_ignore_missing_column() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_include_columns(Some(vec![
+                        "petal.length".to_string(),
+                        "petal.height".to_string(),
+                        "petal.width".to_string(),
+                    ]))
+                    .with_ignore_missing_columns(true),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+            ])?
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_no_headers_default_names() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_no_headers.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_has_header(false)),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        // The first record is data, not a header.
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("column_1", DataType::Float64),
+                Field::new("column_2", DataType::Float64),
+                Field::new("column_3", DataType::Float64),
+                Field::new("column_4", DataType::Float64),
+                Field::new("column_5", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        assert_eq!(table.get_column("column_1")?.f64()?.get(0), Some(5.1));
+        assert_eq!(table.get_column("column_5")?.utf8()?.get(0), Some("Setosa"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_no_headers_column_names_mismatch() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_no_headers.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let err = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_column_names(Some(vec![
+                "sepal.length".to_string(),
+                "sepal.width".to_string(),
+            ]))),
+            Some(CsvParseOptions::default().with_has_header(false)),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )
+        .unwrap_err();
+        assert!(matches!(err, DaftError::ValueError(_)), "{err}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_inference_policy() -> DaftResult<()> {
+        let file = format!("{}/test/zip_codes.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let read_with_policy = |policy| {
+            read_csv(
+                file.as_ref(),
+                Some(CsvConvertOptions::default().with_inference_policy(policy)),
+                None,
+                None,
+                io_client.clone(),
+                None,
+                true,
+                None,
+            )
+        };
+
+        // Numbers are preferred by default, dropping the leading zeros of the zip codes.
+        let table = read_with_policy(CsvInferencePolicy::default())?;
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("zip", DataType::Int64),
+                Field::new("count", DataType::Int64),
+                Field::new("city", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        assert_eq!(table.get_column("zip")?.i64()?.get(0), Some(2134));
+
+        let table = read_with_policy(CsvInferencePolicy::StringPreserving)?;
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("zip", DataType::Utf8),
+                Field::new("count", DataType::Int64),
+                Field::new("city", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        let zips = table.get_column("zip")?.utf8()?;
+        assert_eq!(
+            (0..zips.len()).map(|i| zips.get(i)).collect::<Vec<_>>(),
+            vec![Some("02134"), Some("10001"), Some("07030"), Some("94105")]
+        );
+        assert_eq!(table.get_column("count")?.i64()?.get(2), None);
+
+        let table = read_with_policy(CsvInferencePolicy::AllStrings)?;
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("zip", DataType::Utf8),
+                Field::new("count", DataType::Utf8),
+                Field::new("city", DataType::Utf8),
+            ])?
+            .into(),
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_csv_read_local_no_headers_and_projection() -> DaftResult<()> {
         let file = format!(
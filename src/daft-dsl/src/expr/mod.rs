@@ -207,6 +207,15 @@ pub enum AggExpr {
     #[display("stddev({_0})")]
     Stddev(ExprRef),
 
+    #[display("variance({_0}, ddof={_1})")]
+    Variance(ExprRef, u64),
+
+    #[display("variance_state({_0})")]
+    VarianceState(ExprRef),
+
+    #[display("merge_variance({_0}, ddof={_1})")]
+    MergeVariance(ExprRef, u64),
+
     #[display("min({_0})")]
     Min(ExprRef),
 
@@ -216,6 +225,12 @@ pub enum AggExpr {
     #[display("any_value({_0}, ignore_nulls={_1})")]
     AnyValue(ExprRef, bool),
 
+    #[display("first({_0}, ignore_nulls={_1})")]
+    First(ExprRef, bool),
+
+    #[display("last({_0}, ignore_nulls={_1})")]
+    Last(ExprRef, bool),
+
     #[display("list({_0})")]
     List(ExprRef),
 
@@ -254,9 +269,14 @@ impl AggExpr {
             | Self::MergeSketch(expr, _)
             | Self::Mean(expr)
             | Self::Stddev(expr)
+            | Self::Variance(expr, _)
+            | Self::VarianceState(expr)
+            | Self::MergeVariance(expr, _)
             | Self::Min(expr)
             | Self::Max(expr)
             | Self::AnyValue(expr, _)
+            | Self::First(expr, _)
+            | Self::Last(expr, _)
             | Self::List(expr)
             | Self::Concat(expr) => expr.name(),
             Self::MapGroups { func: _, inputs } => inputs.first().unwrap().name(),
@@ -308,6 +328,18 @@ impl AggExpr {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_stddev()"))
             }
+            Self::Variance(expr, ddof) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_variance(ddof={ddof})"))
+            }
+            Self::VarianceState(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_variance_state()"))
+            }
+            Self::MergeVariance(expr, ddof) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_merge_variance(ddof={ddof})"))
+            }
             Self::Min(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_min()"))
@@ -322,6 +354,18 @@ impl AggExpr {
                     "{child_id}.local_any_value(ignore_nulls={ignore_nulls})"
                 ))
             }
+            Self::First(expr, ignore_nulls) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!(
+                    "{child_id}.local_first(ignore_nulls={ignore_nulls})"
+                ))
+            }
+            Self::Last(expr, ignore_nulls) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!(
+                    "{child_id}.local_last(ignore_nulls={ignore_nulls})"
+                ))
+            }
             Self::List(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_list()"))
@@ -344,9 +388,14 @@ impl AggExpr {
             | Self::MergeSketch(expr, _)
             | Self::Mean(expr)
             | Self::Stddev(expr)
+            | Self::Variance(expr, _)
+            | Self::VarianceState(expr)
+            | Self::MergeVariance(expr, _)
             | Self::Min(expr)
             | Self::Max(expr)
             | Self::AnyValue(expr, _)
+            | Self::First(expr, _)
+            | Self::Last(expr, _)
             | Self::List(expr)
             | Self::Concat(expr) => vec![expr.clone()],
             Self::MapGroups { func: _, inputs } => inputs.clone(),
@@ -365,9 +414,14 @@ impl AggExpr {
             Self::Sum(_) => Self::Sum(first_child()),
             Self::Mean(_) => Self::Mean(first_child()),
             Self::Stddev(_) => Self::Stddev(first_child()),
+            Self::Variance(_, ddof) => Self::Variance(first_child(), *ddof),
+            Self::VarianceState(_) => Self::VarianceState(first_child()),
+            Self::MergeVariance(_, ddof) => Self::MergeVariance(first_child(), *ddof),
             Self::Min(_) => Self::Min(first_child()),
             Self::Max(_) => Self::Max(first_child()),
             Self::AnyValue(_, ignore_nulls) => Self::AnyValue(first_child(), *ignore_nulls),
+            Self::First(_, ignore_nulls) => Self::First(first_child(), *ignore_nulls),
+            Self::Last(_, ignore_nulls) => Self::Last(first_child(), *ignore_nulls),
             Self::List(_) => Self::List(first_child()),
             Self::Concat(_) => Self::Concat(first_child()),
             Self::MapGroups { func, inputs: _ } => Self::MapGroups {
@@ -470,15 +524,37 @@ impl AggExpr {
                     try_mean_aggregation_supertype(&field.dtype)?,
                 ))
             }
-            Self::Stddev(expr) => {
+            Self::Stddev(expr) | Self::Variance(expr, _) => {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(
                     field.name.as_str(),
                     try_stddev_aggregation_supertype(&field.dtype)?,
                 ))
             }
+            Self::VarianceState(expr) => {
+                let field = expr.to_field(schema)?;
+                try_stddev_aggregation_supertype(&field.dtype)?;
+                Ok(Field::new(
+                    field.name.as_str(),
+                    daft_core::utils::stats::VarianceState::dtype(),
+                ))
+            }
+            Self::MergeVariance(expr, _) => {
+                let field = expr.to_field(schema)?;
+                if field.dtype != daft_core::utils::stats::VarianceState::dtype() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to merge_variance() to be variance states but received dtype {} for column \"{}\"",
+                        field.dtype, field.name,
+                    )));
+                }
+                Ok(Field::new(field.name.as_str(), DataType::Float64))
+            }
 
-            Self::Min(expr) | Self::Max(expr) | Self::AnyValue(expr, _) => {
+            Self::Min(expr)
+            | Self::Max(expr)
+            | Self::AnyValue(expr, _)
+            | Self::First(expr, _)
+            | Self::Last(expr, _) => {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(field.name.as_str(), field.dtype))
             }
@@ -583,6 +659,12 @@ impl Expr {
         Self::Agg(AggExpr::Stddev(self)).into()
     }
 
+    /// Variance with `ddof` delta degrees of freedom: 0 for the population variance, 1 for the
+    /// sample variance.
+    pub fn variance(self: ExprRef, ddof: u64) -> ExprRef {
+        Self::Agg(AggExpr::Variance(self, ddof)).into()
+    }
+
     pub fn min(self: ExprRef) -> ExprRef {
         Self::Agg(AggExpr::Min(self)).into()
     }
@@ -595,6 +677,16 @@ impl Expr {
         Self::Agg(AggExpr::AnyValue(self, ignore_nulls)).into()
     }
 
+    /// The first value in each group, in the order rows are aggregated, skipping nulls if `ignore_nulls` is set.
+    pub fn first(self: ExprRef, ignore_nulls: bool) -> ExprRef {
+        Self::Agg(AggExpr::First(self, ignore_nulls)).into()
+    }
+
+    /// The last value in each group, in the order rows are aggregated, skipping nulls if `ignore_nulls` is set.
+    pub fn last(self: ExprRef, ignore_nulls: bool) -> ExprRef {
+        Self::Agg(AggExpr::Last(self, ignore_nulls)).into()
+    }
+
     pub fn agg_list(self: ExprRef) -> ExprRef {
         Self::Agg(AggExpr::List(self)).into()
     }
@@ -632,6 +724,11 @@ impl Expr {
         binary_op(Operator::Eq, self, other)
     }
 
+    /// Null-safe equality (`<=>`), where two nulls are equal and a null never equals a value.
+    pub fn eq_null_safe(self: ExprRef, other: ExprRef) -> ExprRef {
+        binary_op(Operator::EqNullSafe, self, other)
+    }
+
     pub fn not_eq(self: ExprRef, other: ExprRef) -> ExprRef {
         binary_op(Operator::NotEq, self, other)
     }
@@ -644,6 +741,18 @@ impl Expr {
         binary_op(Operator::Or, self, other)
     }
 
+    pub fn bitwise_and(self: ExprRef, other: ExprRef) -> ExprRef {
+        binary_op(Operator::BitwiseAnd, self, other)
+    }
+
+    pub fn bitwise_or(self: ExprRef, other: ExprRef) -> ExprRef {
+        binary_op(Operator::BitwiseOr, self, other)
+    }
+
+    pub fn bitwise_xor(self: ExprRef, other: ExprRef) -> ExprRef {
+        binary_op(Operator::BitwiseXor, self, other)
+    }
+
     pub fn lt(self: ExprRef, other: ExprRef) -> ExprRef {
         binary_op(Operator::Lt, self, other)
     }
@@ -959,10 +1068,18 @@ impl Expr {
                         Ok(Field::new(left_field.name.as_str(), result_type))
                     }
 
+                    // Bitwise operations
+                    Operator::BitwiseAnd | Operator::BitwiseOr | Operator::BitwiseXor => {
+                        let result_type = InferDataType::from(&left_field.dtype)
+                            .bitwise_op(&InferDataType::from(&right_field.dtype))?;
+                        Ok(Field::new(left_field.name.as_str(), result_type))
+                    }
+
                     // Comparison operations
                     Operator::Lt
                     | Operator::Gt
                     | Operator::Eq
+                    | Operator::EqNullSafe
                     | Operator::NotEq
                     | Operator::LtEq
                     | Operator::GtEq => {
@@ -1128,6 +1245,9 @@ impl Expr {
                         Operator::Or => "OR",
                         Operator::ShiftLeft => "<<",
                         Operator::ShiftRight => ">>",
+                        Operator::BitwiseAnd => "&",
+                        Operator::BitwiseOr => "|",
+                        Operator::BitwiseXor => "^",
                         _ => {
                             return Err(io::Error::new(
                                 io::ErrorKind::Other,
@@ -1191,6 +1311,8 @@ impl Expr {
 pub enum Operator {
     #[display("==")]
     Eq,
+    #[display("<=>")]
+    EqNullSafe,
     #[display("!=")]
     NotEq,
     #[display("<")]
@@ -1219,6 +1341,12 @@ pub enum Operator {
     Or,
     #[display("^")]
     Xor,
+    #[display("bitwise_and")]
+    BitwiseAnd,
+    #[display("bitwise_or")]
+    BitwiseOr,
+    #[display("bitwise_xor")]
+    BitwiseXor,
     #[display("<<")]
     ShiftLeft,
     #[display(">>")]
@@ -1231,6 +1359,7 @@ impl Operator {
         matches!(
             self,
             Self::Eq
+                | Self::EqNullSafe
                 | Self::NotEq
                 | Self::Lt
                 | Self::LtEq
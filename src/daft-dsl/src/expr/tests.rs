@@ -81,3 +81,49 @@ fn check_arithmetic_type_with_columns() -> DaftResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn check_bitwise_type() -> DaftResult<()> {
+    let schema = Schema::new(vec![
+        Field::new("flags", DataType::UInt8),
+        Field::new("mask", DataType::Int32),
+        Field::new("ratio", DataType::Float64),
+        Field::new("is_set", DataType::Boolean),
+    ])?;
+
+    let z = col("flags").bitwise_and(col("mask"));
+    assert_eq!(z.get_type(&schema)?, DataType::Int32);
+
+    let z = col("flags").bitwise_or(lit(0x0F));
+    assert_eq!(z.get_type(&schema)?, DataType::Int32);
+
+    let z = col("flags").bitwise_xor(crate::null_lit());
+    assert_eq!(z.get_type(&schema)?, DataType::UInt8);
+
+    assert!(col("ratio")
+        .bitwise_and(col("mask"))
+        .get_type(&schema)
+        .is_err());
+    assert!(col("is_set")
+        .bitwise_or(col("is_set"))
+        .get_type(&schema)
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn check_bitwise_to_sql() {
+    assert_eq!(
+        col("flags").bitwise_and(col("mask")).to_sql().as_deref(),
+        Some("flags & mask")
+    );
+    assert_eq!(
+        col("flags").bitwise_or(lit(1)).to_sql().as_deref(),
+        Some("flags | 1")
+    );
+    assert_eq!(
+        col("flags").bitwise_xor(col("mask")).to_sql().as_deref(),
+        Some("flags ^ mask")
+    );
+}
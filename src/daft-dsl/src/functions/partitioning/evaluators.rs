@@ -74,9 +74,11 @@ impl FunctionEvaluator for IcebergBucketEvaluator {
                 Ok(field) => match field.dtype {
                     DataType::Decimal128(_, _)
                     | DataType::Date
+                    | DataType::Time(..)
                     | DataType::Timestamp(..)
                     | DataType::Utf8
-                    | DataType::Binary => Ok(Field::new(
+                    | DataType::Binary
+                    | DataType::FixedSizeBinary(_) => Ok(Field::new(
                         format!("{}_bucket", field.name),
                         DataType::Int32,
                     )),
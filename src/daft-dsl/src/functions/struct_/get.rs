@@ -73,3 +73,68 @@ impl FunctionEvaluator for GetEvaluator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+    use daft_core::prelude::*;
+
+    use super::{super::StructExpr, GetEvaluator};
+    use crate::{
+        col,
+        functions::{struct_::get, FunctionEvaluator, FunctionExpr},
+    };
+
+    fn struct_field() -> Field {
+        Field::new(
+            "s",
+            DataType::Struct(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+            ]),
+        )
+    }
+
+    #[test]
+    fn test_struct_get_to_field() -> DaftResult<()> {
+        let schema = Schema::new(vec![struct_field(), Field::new("x", DataType::Int64)])?;
+
+        let field = get(col("s"), "b").to_field(&schema)?;
+        assert_eq!(field, Field::new("b", DataType::Utf8));
+
+        assert!(matches!(
+            get(col("s"), "missing").to_field(&schema),
+            Err(DaftError::FieldNotFound(_))
+        ));
+        assert!(matches!(
+            get(col("x"), "a").to_field(&schema),
+            Err(DaftError::TypeError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_get_evaluate() -> DaftResult<()> {
+        let input = StructArray::new(
+            struct_field(),
+            vec![
+                Int64Array::from(("a", vec![1, 2])).into_series(),
+                Utf8Array::from(("b", ["x", "y"].as_slice())).into_series(),
+            ],
+            None,
+        )
+        .into_series();
+
+        let get_field = |name: &str| {
+            GetEvaluator {}.evaluate(
+                &[input.clone()],
+                &FunctionExpr::Struct(StructExpr::Get(name.to_string())),
+            )
+        };
+        let result = get_field("a")?;
+        assert_eq!(result.name(), "a");
+        assert_eq!(result.i64()?.as_slice(), &[1, 2]);
+        assert!(get_field("missing").is_err());
+        Ok(())
+    }
+}
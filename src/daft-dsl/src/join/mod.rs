@@ -47,6 +47,8 @@ pub fn infer_join_schema(
 
     if matches!(how, JoinType::Anti | JoinType::Semi) {
         Ok(left_schema.clone())
+    } else if matches!(how, JoinType::RightAnti | JoinType::RightSemi) {
+        Ok(right_schema.clone())
     } else {
         let common_join_keys: IndexSet<_> = get_common_join_keys(left_on, right_on)
             .map(|k| k.to_string())
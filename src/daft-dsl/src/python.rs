@@ -358,6 +358,10 @@ impl PyExpr {
         Ok(self.expr.clone().stddev().into())
     }
 
+    pub fn variance(&self, ddof: u64) -> PyResult<Self> {
+        Ok(self.expr.clone().variance(ddof).into())
+    }
+
     pub fn min(&self) -> PyResult<Self> {
         Ok(self.expr.clone().min().into())
     }
@@ -370,6 +374,14 @@ impl PyExpr {
         Ok(self.expr.clone().any_value(ignore_nulls).into())
     }
 
+    pub fn first(&self, ignore_nulls: bool) -> PyResult<Self> {
+        Ok(self.expr.clone().first(ignore_nulls).into())
+    }
+
+    pub fn last(&self, ignore_nulls: bool) -> PyResult<Self> {
+        Ok(self.expr.clone().last(ignore_nulls).into())
+    }
+
     pub fn agg_list(&self) -> PyResult<Self> {
         Ok(self.expr.clone().agg_list().into())
     }
@@ -0,0 +1,131 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::*, utils::supertype::try_get_supertype};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Coalesce {}
+
+#[typetag::serde]
+impl ScalarUDF for Coalesce {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "coalesce"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let fields = inputs
+            .iter()
+            .map(|e| e.to_field(schema))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let Some((first, rest)) = fields.split_first() else {
+            return Err(DaftError::ValueError(
+                "Cannot call coalesce with no inputs".to_string(),
+            ));
+        };
+        let dtype = rest.iter().try_fold(first.dtype.clone(), |dtype, field| {
+            try_get_supertype(&dtype, &field.dtype)
+        })?;
+        Ok(Field::new(first.name.clone(), dtype))
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let Some((first, rest)) = inputs.split_first() else {
+            return Err(DaftError::ValueError(
+                "Cannot call coalesce with no inputs".to_string(),
+            ));
+        };
+        let dtype = rest
+            .iter()
+            .try_fold(first.data_type().clone(), |dtype, s| {
+                try_get_supertype(&dtype, s.data_type())
+            })?;
+        let len = inputs.iter().map(Series::len).max().unwrap_or(0);
+        let broadcast = |s: &Series| -> DaftResult<Series> {
+            let s = s.cast(&dtype)?;
+            if s.len() == len {
+                Ok(s)
+            } else {
+                s.broadcast(len)
+            }
+        };
+
+        let mut result = broadcast(first)?;
+        for s in rest {
+            if !result.validity().is_some_and(|v| v.unset_bits() > 0) {
+                break;
+            }
+            result = result.fill_null(&broadcast(s)?)?;
+        }
+        Ok(result.rename(first.name()))
+    }
+}
+
+/// Returns the first non-null value of `inputs` for each row.
+#[must_use]
+pub fn coalesce(inputs: Vec<ExprRef>) -> ExprRef {
+    ScalarFunction::new(Coalesce {}, inputs).into()
+}
+
+#[cfg(feature = "python")]
+pub mod python {
+    use daft_dsl::python::PyExpr;
+    use pyo3::{pyfunction, PyResult};
+
+    #[pyfunction]
+    pub fn coalesce(inputs: Vec<PyExpr>) -> PyResult<PyExpr> {
+        let inputs = inputs.into_iter().map(std::convert::Into::into).collect();
+        Ok(super::coalesce(inputs).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::functions::ScalarUDF;
+
+    use super::Coalesce;
+
+    #[test]
+    fn test_coalesce_all_null_row_takes_last_input() -> DaftResult<()> {
+        let a = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), None, None].into_iter(),
+        )
+        .into_series();
+        let b = Int64Array::from_iter(
+            Field::new("b", DataType::Int64),
+            vec![Some(10), Some(20), None].into_iter(),
+        )
+        .into_series();
+        let zero = Int64Array::from(("literal", vec![0])).into_series();
+
+        let result = Coalesce {}.evaluate(&[a, b, zero])?;
+        assert_eq!(result.name(), "a");
+        assert_eq!(result.i64()?.as_slice(), &[1, 20, 0]);
+        assert!(result.is_valid(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce_promotes_to_supertype() -> DaftResult<()> {
+        let a = Int32Array::from_iter(
+            Field::new("a", DataType::Int32),
+            vec![None, Some(2)].into_iter(),
+        )
+        .into_series();
+        let b = Float64Array::from(("b", vec![1.5, 3.5])).into_series();
+
+        let result = Coalesce {}.evaluate(&[a, b])?;
+        assert_eq!(result.data_type(), &DataType::Float64);
+        assert_eq!(result.f64()?.as_slice(), &[1.5, 2.0]);
+        Ok(())
+    }
+}
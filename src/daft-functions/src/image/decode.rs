@@ -15,11 +15,16 @@ use serde::{Deserialize, Serialize};
 /// image_decode(input, on_error='null')
 /// image_decode(input, on_error='null', mode='RGB')
 /// image_decode(input, mode='RGB', on_error='null')
+/// image_decode(input, shape=(224, 224, 3), on_error='null')
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct ImageDecode {
     pub mode: Option<ImageMode>,
     pub raise_on_error: bool,
+    /// Expected `(height, width, channels)` of every image. When set, the output is a
+    /// fixed-shape image column and images of any other shape are handled per `raise_on_error`.
+    #[serde(default)]
+    pub shape: Option<(u32, u32, u16)>,
 }
 
 impl Default for ImageDecode {
@@ -27,6 +32,7 @@ impl Default for ImageDecode {
         Self {
             mode: None,
             raise_on_error: true,
+            shape: None,
         }
     }
 }
@@ -50,7 +56,15 @@ impl ScalarUDF for ImageDecode {
                         "ImageDecode can only decode BinaryArrays, got {field}"
                     )));
                 }
-                Ok(Field::new(field.name, DataType::Image(self.mode)))
+                let dtype = match self.shape {
+                    Some((height, width, num_channels)) => {
+                        let mode =
+                            daft_image::series::fixed_shape_decode_mode(self.mode, num_channels)?;
+                        DataType::FixedShapeImage(mode, height, width)
+                    }
+                    None => DataType::Image(self.mode),
+                };
+                Ok(Field::new(field.name, dtype))
             }
             _ => Err(DaftError::SchemaMismatch(format!(
                 "Expected 1 input arg, got {}",
@@ -62,7 +76,17 @@ impl ScalarUDF for ImageDecode {
     fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
         let raise_error_on_failure = self.raise_on_error;
         match inputs {
-            [input] => daft_image::series::decode(input, raise_error_on_failure, self.mode),
+            [input] => match self.shape {
+                Some((height, width, num_channels)) => daft_image::series::decode_fixed_shape(
+                    input,
+                    raise_error_on_failure,
+                    self.mode,
+                    height,
+                    width,
+                    num_channels,
+                ),
+                None => daft_image::series::decode(input, raise_error_on_failure, self.mode),
+            },
             _ => Err(DaftError::ValueError(format!(
                 "Expected 1 input arg, got {}",
                 inputs.len()
@@ -93,6 +117,7 @@ pub fn py_decode(
     let image_decode = ImageDecode {
         mode,
         raise_on_error: raise_on_error.unwrap_or(true),
+        shape: None,
     };
 
     Ok(decode(expr.into(), Some(image_decode)).into())
@@ -9,11 +9,38 @@ use serde::{Deserialize, Serialize};
 /// Container for the keyword arguments for `image_encode`
 /// ex:
 /// ```text
-/// image_encode(input, image_format='png')
+/// image_encode(input, image_format='jpeg', quality=90)
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct ImageEncode {
     pub image_format: ImageFormat,
+    /// Encoding quality (1-100) for lossy formats. Only JPEG supports it.
+    #[serde(default)]
+    pub quality: Option<u8>,
+}
+
+impl ImageEncode {
+    /// Builds the encode arguments, checking that `quality` is in range and paired with a lossy format.
+    pub fn try_new(image_format: ImageFormat, quality: Option<i64>) -> DaftResult<Self> {
+        let quality = match quality {
+            None => None,
+            Some(q) if !(1..=100).contains(&q) => {
+                return Err(DaftError::ValueError(format!(
+                    "image_encode quality must be between 1 and 100, got {q}"
+                )))
+            }
+            Some(_) if image_format != ImageFormat::JPEG => {
+                return Err(DaftError::ValueError(format!(
+                    "image_encode quality is only supported for JPEG, got format {image_format}"
+                )))
+            }
+            Some(q) => Some(q as u8),
+        };
+        Ok(Self {
+            image_format,
+            quality,
+        })
+    }
 }
 
 #[typetag::serde]
@@ -47,7 +74,7 @@ impl ScalarUDF for ImageEncode {
 
     fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
         match inputs {
-            [input] => daft_image::series::encode(input, self.image_format),
+            [input] => daft_image::series::encode(input, self.image_format, self.quality),
             _ => Err(DaftError::ValueError(format!(
                 "Expected 1 input arg, got {}",
                 inputs.len()
@@ -70,6 +97,9 @@ use {
 #[pyfunction]
 #[pyo3(name = "image_encode")]
 pub fn py_encode(expr: PyExpr, image_format: ImageFormat) -> PyResult<PyExpr> {
-    let image_encode = ImageEncode { image_format };
+    let image_encode = ImageEncode {
+        image_format,
+        quality: None,
+    };
     Ok(encode(expr.into(), image_encode).into())
 }
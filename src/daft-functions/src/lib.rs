@@ -1,4 +1,5 @@
 #![feature(async_closure)]
+pub mod coalesce;
 pub mod count_matches;
 pub mod distance;
 pub mod float;
@@ -21,6 +22,7 @@ use snafu::Snafu;
 #[cfg(feature = "python")]
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     // keep in sorted order
+    parent.add_function(wrap_pyfunction_bound!(coalesce::python::coalesce, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(
         count_matches::python::utf8_count_matches,
         parent
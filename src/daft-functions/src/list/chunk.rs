@@ -12,6 +12,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct ListChunk {
     pub size: usize,
+    /// Whether to keep a final chunk that is shorter than `size` rather than dropping its elements.
+    #[serde(default)]
+    pub keep_remainder: bool,
 }
 
 #[typetag::serde]
@@ -27,11 +30,19 @@ impl ScalarUDF for ListChunk {
     fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
         match inputs {
             [input] => {
+                if self.size == 0 {
+                    return Err(DaftError::ValueError(
+                        "list chunk size must be greater than 0".to_string(),
+                    ));
+                }
                 let input_field = input.to_field(schema)?;
-                Ok(input_field
-                    .to_exploded_field()?
-                    .to_fixed_size_list_field(self.size)?
-                    .to_list_field()?)
+                let exploded_field = input_field.to_exploded_field()?;
+                let chunk_field = if self.keep_remainder {
+                    exploded_field.to_list_field()?
+                } else {
+                    exploded_field.to_fixed_size_list_field(self.size)?
+                };
+                chunk_field.to_list_field()
             }
             _ => Err(DaftError::SchemaMismatch(format!(
                 "Expected 1 input args, got {}",
@@ -42,7 +53,7 @@ impl ScalarUDF for ListChunk {
 
     fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
         match inputs {
-            [input] => input.list_chunk(self.size),
+            [input] => input.list_chunk(self.size, self.keep_remainder),
             _ => Err(DaftError::ValueError(format!(
                 "Expected 1 input args, got {}",
                 inputs.len()
@@ -53,7 +64,27 @@ impl ScalarUDF for ListChunk {
 
 #[must_use]
 pub fn list_chunk(expr: ExprRef, size: usize) -> ExprRef {
-    ScalarFunction::new(ListChunk { size }, vec![expr]).into()
+    ScalarFunction::new(
+        ListChunk {
+            size,
+            keep_remainder: false,
+        },
+        vec![expr],
+    )
+    .into()
+}
+
+/// Like [`list_chunk`], but keeps a final chunk that is shorter than `size`.
+#[must_use]
+pub fn list_chunk_with_remainder(expr: ExprRef, size: usize) -> ExprRef {
+    ScalarFunction::new(
+        ListChunk {
+            size,
+            keep_remainder: true,
+        },
+        vec![expr],
+    )
+    .into()
 }
 
 #[cfg(feature = "python")]
@@ -64,7 +95,11 @@ use {
 
 #[cfg(feature = "python")]
 #[pyfunction]
-#[pyo3(name = "list_chunk")]
-pub fn py_list_chunk(expr: PyExpr, size: usize) -> PyResult<PyExpr> {
-    Ok(list_chunk(expr.into(), size).into())
+#[pyo3(name = "list_chunk", signature = (expr, size, keep_remainder=false))]
+pub fn py_list_chunk(expr: PyExpr, size: usize, keep_remainder: bool) -> PyResult<PyExpr> {
+    if keep_remainder {
+        Ok(list_chunk_with_remainder(expr.into(), size).into())
+    } else {
+        Ok(list_chunk(expr.into(), size).into())
+    }
 }
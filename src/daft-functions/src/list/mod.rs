@@ -2,6 +2,7 @@ mod chunk;
 mod count;
 mod explode;
 mod get;
+mod index_of;
 mod join;
 mod max;
 mod mean;
@@ -11,10 +12,13 @@ mod sort;
 mod sum;
 mod value_counts;
 
-pub use chunk::{list_chunk as chunk, ListChunk};
+pub use chunk::{
+    list_chunk as chunk, list_chunk_with_remainder as chunk_with_remainder, ListChunk,
+};
 pub use count::{list_count as count, ListCount};
 pub use explode::{explode, Explode};
 pub use get::{list_get as get, ListGet};
+pub use index_of::{list_index_of as index_of, ListIndexOf};
 pub use join::{list_join as join, ListJoin};
 pub use max::{list_max as max, ListMax};
 pub use mean::{list_mean as mean, ListMean};
@@ -31,6 +35,7 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction_bound!(chunk::py_list_chunk, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(count::py_list_count, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(get::py_list_get, parent)?)?;
+    parent.add_function(wrap_pyfunction_bound!(index_of::py_list_index_of, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(join::py_list_join, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(
         value_counts::py_list_value_counts,
@@ -0,0 +1,92 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::Series,
+    utils::supertype::try_get_supertype,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    null_lit, ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Clip {}
+
+#[typetag::serde]
+impl ScalarUDF for Clip {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "clip"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input, lower, upper] => {
+                let input_field = input.to_field(schema)?;
+                let mut dtype = input_field.dtype.clone();
+                for field in [
+                    &input_field,
+                    &lower.to_field(schema)?,
+                    &upper.to_field(schema)?,
+                ] {
+                    if !field.dtype.is_numeric() && field.dtype != DataType::Null {
+                        return Err(DaftError::TypeError(format!(
+                            "Expected inputs to clip to be numeric, got {}",
+                            field.dtype
+                        )));
+                    }
+                    dtype = try_get_supertype(&dtype, &field.dtype)?;
+                }
+                Ok(Field::new(input_field.name, dtype))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input, lower, upper] => {
+                let bound = |s: &Series| (s.data_type() != &DataType::Null).then_some(s);
+                input.clip(bound(lower), bound(upper))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Clamps `input` into `[lower, upper]`, where a missing bound leaves that side unbounded.
+#[must_use]
+pub fn clip(input: ExprRef, lower: Option<ExprRef>, upper: Option<ExprRef>) -> ExprRef {
+    ScalarFunction::new(
+        Clip {},
+        vec![
+            input,
+            lower.unwrap_or_else(null_lit),
+            upper.unwrap_or_else(null_lit),
+        ],
+    )
+    .into()
+}
+
+#[cfg(feature = "python")]
+use {
+    daft_dsl::python::PyExpr,
+    pyo3::{pyfunction, PyResult},
+};
+
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "clip")]
+pub fn py_clip(expr: PyExpr, lower: Option<PyExpr>, upper: Option<PyExpr>) -> PyResult<PyExpr> {
+    Ok(clip(expr.into(), lower.map(Into::into), upper.map(Into::into)).into())
+}
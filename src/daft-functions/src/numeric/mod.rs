@@ -1,9 +1,11 @@
 pub mod abs;
 pub mod cbrt;
 pub mod ceil;
+pub mod clip;
 pub mod exp;
 pub mod floor;
 pub mod log;
+pub mod pow;
 pub mod round;
 pub mod sign;
 pub mod sqrt;
@@ -23,12 +25,14 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction_bound!(abs::py_abs, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(cbrt::py_cbrt, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(ceil::py_ceil, parent)?)?;
+    parent.add_function(wrap_pyfunction_bound!(clip::py_clip, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(exp::py_exp, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(floor::py_floor, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(log::py_log2, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(log::py_log10, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(log::py_log, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(log::py_ln, parent)?)?;
+    parent.add_function(wrap_pyfunction_bound!(pow::py_pow, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(round::py_round, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(sign::py_sign, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(sqrt::py_sqrt, parent)?)?;
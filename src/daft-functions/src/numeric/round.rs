@@ -49,12 +49,5 @@ use super::{evaluate_single_numeric, to_field_single_numeric};
 #[pyfunction]
 #[pyo3(name = "round")]
 pub fn py_round(expr: PyExpr, decimal: i32) -> PyResult<PyExpr> {
-    use pyo3::exceptions::PyValueError;
-
-    if decimal < 0 {
-        return Err(PyValueError::new_err(format!(
-            "decimal can not be negative: {decimal}"
-        )));
-    }
     Ok(round(expr.into(), decimal).into())
 }
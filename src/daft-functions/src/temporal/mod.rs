@@ -1,3 +1,4 @@
+pub mod strftime;
 pub mod truncate;
 use common_error::{DaftError, DaftResult};
 use daft_core::{
@@ -25,6 +26,7 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction_bound!(py_dt_second, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(py_dt_time, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(py_dt_year, parent)?)?;
+    parent.add_function(wrap_pyfunction_bound!(strftime::py_dt_strftime, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(truncate::py_dt_truncate, parent)?)?;
     Ok(())
 }
@@ -166,7 +168,7 @@ pub fn py_dt_time(expr: PyExpr) -> PyResult<PyExpr> {
 mod test {
     use std::sync::Arc;
 
-    use super::truncate::Truncate;
+    use super::{strftime::Strftime, truncate::Truncate};
 
     #[test]
     fn test_fn_name() {
@@ -181,6 +183,12 @@ mod test {
             (Arc::new(Second), "second"),
             (Arc::new(Time), "time"),
             (Arc::new(Year), "year"),
+            (
+                Arc::new(Strftime {
+                    format: String::new(),
+                }),
+                "strftime",
+            ),
             (
                 Arc::new(Truncate {
                     interval: String::new(),
@@ -0,0 +1,76 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Strftime {
+    pub(super) format: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Strftime {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "strftime"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => match input.to_field(schema) {
+                Ok(field) if matches!(field.dtype, DataType::Date | DataType::Timestamp(..)) => {
+                    Ok(Field::new(field.name, DataType::Utf8))
+                }
+                Ok(field) => Err(DaftError::TypeError(format!(
+                    "Expected input to strftime to be date or timestamp, got {}",
+                    field.dtype
+                ))),
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.strftime(&self.format),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn dt_strftime<S: Into<String>>(input: ExprRef, format: S) -> ExprRef {
+    ScalarFunction::new(
+        Strftime {
+            format: format.into(),
+        },
+        vec![input],
+    )
+    .into()
+}
+
+#[cfg(feature = "python")]
+use {
+    daft_dsl::python::PyExpr,
+    pyo3::{pyfunction, PyResult},
+};
+
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "dt_strftime")]
+pub fn py_dt_strftime(expr: PyExpr, format: &str) -> PyResult<PyExpr> {
+    Ok(dt_strftime(expr.into(), format).into())
+}
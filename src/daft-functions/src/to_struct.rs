@@ -6,11 +6,23 @@ use daft_dsl::{
 };
 use serde::{Deserialize, Serialize};
 
-fn series_to_struct(inputs: &[Series]) -> Series {
+/// Packs the inputs into a struct, broadcasting length-1 inputs to the length of the others.
+fn series_to_struct(inputs: &[Series]) -> DaftResult<Series> {
+    let len = inputs.iter().map(Series::len).max().unwrap_or(0);
+    let inputs = inputs
+        .iter()
+        .map(|s| match s.len() {
+            l if l == len => Ok(s.clone()),
+            1 => s.broadcast(len),
+            l => Err(DaftError::ValueError(format!(
+                "Cannot call to_struct with inputs of different lengths: {} has length {l}, expected {len}",
+                s.name()
+            ))),
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
     let child_fields: Vec<Field> = inputs.iter().map(|s| s.field().clone()).collect();
     let field = Field::new("struct", DataType::Struct(child_fields));
-    let inputs = inputs.to_vec();
-    StructArray::new(field, inputs, None).into_series()
+    Ok(StructArray::new(field, inputs, None).into_series())
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -32,7 +44,7 @@ impl ScalarUDF for ToStructFunction {
                 "Cannot call to_struct with no inputs".to_string(),
             ));
         }
-        Ok(series_to_struct(inputs))
+        series_to_struct(inputs)
     }
 
     fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
@@ -66,3 +78,69 @@ pub mod python {
         Ok(expr.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{
+        col,
+        functions::{
+            struct_::{get, StructExpr},
+            FunctionEvaluator, FunctionExpr, ScalarUDF,
+        },
+    };
+
+    use super::{series_to_struct, to_struct, ToStructFunction};
+
+    #[test]
+    fn test_to_struct_round_trips_through_get() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])?;
+        let packed = to_struct(vec![col("a"), col("b").alias("c")]);
+        let struct_type = DataType::Struct(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+        ]);
+        assert_eq!(packed.to_field(&schema)?.dtype, struct_type);
+        assert_eq!(
+            get(packed, "c").to_field(&schema)?,
+            Field::new("c", DataType::Utf8)
+        );
+
+        let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
+        let c = Utf8Array::from(("c", ["x", "y", "z"].as_slice())).into_series();
+        let result = ToStructFunction {}.evaluate(&[a, c])?;
+        assert_eq!(result.data_type(), &struct_type);
+
+        let get_c = FunctionExpr::Struct(StructExpr::Get("c".to_string()));
+        let c = get_c.evaluate(&[result], &get_c)?;
+        assert_eq!(
+            c.utf8()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("x"), Some("y"), Some("z")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_struct_broadcasts_and_checks_lengths() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
+        let b = Utf8Array::from(("b", ["x"].as_slice())).into_series();
+        let packed = series_to_struct(&[a.clone(), b])?;
+        assert_eq!(packed.len(), 3);
+        assert_eq!(
+            packed.struct_()?.children[1]
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some("x"), Some("x"), Some("x")]
+        );
+
+        let short = Int64Array::from(("c", vec![1, 2])).into_series();
+        assert!(series_to_struct(&[a, short]).is_err());
+        Ok(())
+    }
+}
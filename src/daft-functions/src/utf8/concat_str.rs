@@ -0,0 +1,105 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Utf8ConcatStr {
+    pub separator: String,
+    pub skip_nulls: bool,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Utf8ConcatStr {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "concat_str"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let Some(first) = inputs.first() else {
+            return Err(DaftError::SchemaMismatch(
+                "Expected at least 1 input arg, got 0".to_string(),
+            ));
+        };
+        for input in inputs {
+            let field = input.to_field(schema)?;
+            if !matches!(field.dtype, DataType::Utf8 | DataType::Null) {
+                return Err(DaftError::TypeError(format!(
+                    "Expects inputs to concat_str to be utf8, but received {field}",
+                )));
+            }
+        }
+        Ok(Field::new(first.name(), DataType::Utf8))
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let inputs = inputs.iter().collect::<Vec<_>>();
+        Series::concat_str(&inputs, &self.separator, self.skip_nulls)
+    }
+}
+
+#[must_use]
+pub fn utf8_concat_str(inputs: Vec<ExprRef>, separator: &str, skip_nulls: bool) -> ExprRef {
+    ScalarFunction::new(
+        Utf8ConcatStr {
+            separator: separator.to_string(),
+            skip_nulls,
+        },
+        inputs,
+    )
+    .into()
+}
+
+#[cfg(feature = "python")]
+use {
+    daft_dsl::python::PyExpr,
+    pyo3::{pyfunction, PyResult},
+};
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "utf8_concat_str", signature = (inputs, separator, skip_nulls=false))]
+pub fn py_utf8_concat_str(
+    inputs: Vec<PyExpr>,
+    separator: &str,
+    skip_nulls: bool,
+) -> PyResult<PyExpr> {
+    let inputs = inputs.into_iter().map(Into::into).collect();
+    Ok(utf8_concat_str(inputs, separator, skip_nulls).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+
+    use super::utf8_concat_str;
+
+    #[test]
+    fn test_concat_str_to_field() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8),
+            Field::new("b", DataType::Utf8),
+            Field::new("c", DataType::Int64),
+        ])?;
+        let expr = utf8_concat_str(vec![col("a"), lit("-"), col("b")], "", false);
+        assert_eq!(expr.to_field(&schema)?, Field::new("a", DataType::Utf8));
+
+        let bad = utf8_concat_str(vec![col("a"), col("c")], ",", true);
+        assert!(bad.to_field(&schema).is_err());
+        assert!(utf8_concat_str(vec![], ",", true)
+            .to_field(&schema)
+            .is_err());
+        Ok(())
+    }
+}
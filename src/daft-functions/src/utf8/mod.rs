@@ -1,4 +1,5 @@
 mod capitalize;
+mod concat_str;
 mod contains;
 mod endswith;
 mod extract;
@@ -22,12 +23,14 @@ mod rpad;
 mod rstrip;
 mod split;
 mod startswith;
+mod strptime;
 mod substr;
 mod to_date;
 mod to_datetime;
 mod upper;
 
 pub use capitalize::{utf8_capitalize as capitalize, Utf8Capitalize};
+pub use concat_str::{utf8_concat_str as concat_str, Utf8ConcatStr};
 pub use contains::{utf8_contains as contains, Utf8Contains};
 pub use endswith::{utf8_endswith as endswith, Utf8Endswith};
 pub use extract::{utf8_extract as extract, Utf8Extract};
@@ -53,6 +56,7 @@ pub use rpad::{utf8_rpad as rpad, Utf8Rpad};
 pub use rstrip::{utf8_rstrip as rstrip, Utf8Rstrip};
 pub use split::{utf8_split as split, Utf8Split};
 pub use startswith::{utf8_startswith as startswith, Utf8Startswith};
+pub use strptime::{utf8_strptime as strptime, Utf8Strptime};
 pub use substr::{utf8_substr as substr, Utf8Substr};
 pub use to_date::{utf8_to_date as to_date, Utf8ToDate};
 pub use to_datetime::{utf8_to_datetime as to_datetime, Utf8ToDatetime};
@@ -64,6 +68,10 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
         capitalize::py_utf8_capitalize,
         parent
     )?)?;
+    parent.add_function(wrap_pyfunction_bound!(
+        concat_str::py_utf8_concat_str,
+        parent
+    )?)?;
     parent.add_function(wrap_pyfunction_bound!(contains::py_utf8_contains, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(endswith::py_utf8_endswith, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(extract::py_utf8_extract, parent)?)?;
@@ -99,6 +107,7 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
         startswith::py_utf8_startswith,
         parent
     )?)?;
+    parent.add_function(wrap_pyfunction_bound!(strptime::py_utf8_strptime, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(substr::py_utf8_substr, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(to_date::py_utf8_to_date, parent)?)?;
     parent.add_function(wrap_pyfunction_bound!(
@@ -0,0 +1,112 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::TimeUnit,
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Utf8Strptime {
+    pub format: String,
+    pub timeunit: TimeUnit,
+    pub timezone: Option<String>,
+    pub raise_on_error: bool,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Utf8Strptime {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "strptime"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => match data.to_field(schema) {
+                Ok(data_field) => match &data_field.dtype {
+                    DataType::Utf8 => Ok(Field::new(
+                        data_field.name,
+                        DataType::Timestamp(self.timeunit, self.timezone.clone()),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects inputs to strptime to be utf8, but received {data_field}",
+                    ))),
+                },
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => data.strptime(
+                &self.format,
+                self.timeunit,
+                self.timezone.as_deref(),
+                self.raise_on_error,
+            ),
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn utf8_strptime<S: Into<String>>(
+    input: ExprRef,
+    format: S,
+    timeunit: TimeUnit,
+    timezone: Option<S>,
+    raise_on_error: bool,
+) -> ExprRef {
+    ScalarFunction::new(
+        Utf8Strptime {
+            format: format.into(),
+            timeunit,
+            timezone: timezone.map(|s| s.into()),
+            raise_on_error,
+        },
+        vec![input],
+    )
+    .into()
+}
+
+#[cfg(feature = "python")]
+use {
+    daft_core::python::PyTimeUnit,
+    daft_dsl::python::PyExpr,
+    pyo3::{pyfunction, PyResult},
+};
+
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "utf8_strptime")]
+pub fn py_utf8_strptime(
+    expr: PyExpr,
+    format: &str,
+    timeunit: PyTimeUnit,
+    timezone: Option<&str>,
+    raise_on_error: bool,
+) -> PyResult<PyExpr> {
+    Ok(utf8_strptime::<&str>(
+        expr.into(),
+        format,
+        timeunit.timeunit,
+        timezone,
+        raise_on_error,
+    )
+    .into())
+}
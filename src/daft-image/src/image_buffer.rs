@@ -6,7 +6,7 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{array::image_array::BBox, datatypes::prelude::*};
-use image::{ColorType, DynamicImage, ImageBuffer, Luma, LumaA, Rgb, Rgba};
+use image::{ColorType, DynamicImage, ImageBuffer, ImageEncoder, Luma, LumaA, Rgb, Rgba};
 
 #[allow(clippy::upper_case_acronyms, dead_code)]
 #[derive(Debug)]
@@ -116,6 +116,40 @@ impl<'a> DaftImageBuffer<'a> {
     where
         W: Write + Seek,
     {
+        self.encode_with_quality(image_format, None, writer)
+    }
+
+    /// Encodes the image into `image_format`, using `quality` (1-100) for lossy formats.
+    ///
+    /// Only JPEG supports a quality setting; WEBP encoding is lossless.
+    pub fn encode_with_quality<W>(
+        &self,
+        image_format: ImageFormat,
+        quality: Option<u8>,
+        writer: &mut W,
+    ) -> DaftResult<()>
+    where
+        W: Write + Seek,
+    {
+        if let Some(quality) = quality {
+            if image_format != ImageFormat::JPEG {
+                return Err(DaftError::ValueError(format!(
+                    "Encoding quality is only supported for JPEG, but got format {image_format}"
+                )));
+            }
+            return image::codecs::jpeg::JpegEncoder::new_with_quality(writer, quality)
+                .write_image(
+                    self.as_u8_slice(),
+                    self.width(),
+                    self.height(),
+                    self.color().into(),
+                )
+                .map_err(|e| {
+                    DaftError::ValueError(format!(
+                        "Encoding image into file format {image_format} failed: {e}"
+                    ))
+                });
+        }
         image::write_buffer_with_format(
             writer,
             self.as_u8_slice(),
@@ -308,5 +342,6 @@ fn convert_img_fmt(fmt: ImageFormat) -> image::ImageFormat {
         ImageFormat::TIFF => image::ImageFormat::Tiff,
         ImageFormat::GIF => image::ImageFormat::Gif,
         ImageFormat::BMP => image::ImageFormat::Bmp,
+        ImageFormat::WEBP => image::ImageFormat::WebP,
     }
 }
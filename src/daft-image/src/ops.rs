@@ -22,7 +22,7 @@ pub trait AsImageObj {
 }
 
 pub trait ImageOps {
-    fn encode(&self, image_format: ImageFormat) -> DaftResult<BinaryArray>;
+    fn encode(&self, image_format: ImageFormat, quality: Option<u8>) -> DaftResult<BinaryArray>;
     fn resize(&self, w: u32, h: u32) -> DaftResult<Self>
     where
         Self: Sized;
@@ -153,8 +153,8 @@ pub(crate) fn fixed_image_array_from_img_buffers(
 }
 
 impl ImageOps for ImageArray {
-    fn encode(&self, image_format: ImageFormat) -> DaftResult<BinaryArray> {
-        encode_images(self, image_format)
+    fn encode(&self, image_format: ImageFormat, quality: Option<u8>) -> DaftResult<BinaryArray> {
+        encode_images(self, image_format, quality)
     }
 
     fn resize(&self, w: u32, h: u32) -> DaftResult<Self> {
@@ -197,8 +197,8 @@ impl ImageOps for ImageArray {
 }
 
 impl ImageOps for FixedShapeImageArray {
-    fn encode(&self, image_format: ImageFormat) -> DaftResult<BinaryArray> {
-        encode_images(self, image_format)
+    fn encode(&self, image_format: ImageFormat, quality: Option<u8>) -> DaftResult<BinaryArray> {
+        encode_images(self, image_format, quality)
     }
 
     fn resize(&self, w: u32, h: u32) -> DaftResult<Self>
@@ -342,6 +342,7 @@ impl AsImageObj for FixedShapeImageArray {
 fn encode_images<Arr: AsImageObj>(
     images: &Arr,
     image_format: ImageFormat,
+    quality: Option<u8>,
 ) -> DaftResult<BinaryArray> {
     let arrow_array = if image_format == ImageFormat::TIFF {
         // NOTE: A single writer/buffer can't be used for TIFF files because the encoder will overwrite the
@@ -354,7 +355,7 @@ fn encode_images<Arr: AsImageObj>(
                     let buf = Vec::new();
                     let mut writer: CountingWriter<std::io::BufWriter<_>> =
                         std::io::BufWriter::new(std::io::Cursor::new(buf)).into();
-                    img.encode(image_format, &mut writer)?;
+                    img.encode_with_quality(image_format, quality, &mut writer)?;
                     // NOTE: BufWriter::into_inner() will flush the buffer.
                     Ok(writer
                         .into_inner()
@@ -380,7 +381,7 @@ fn encode_images<Arr: AsImageObj>(
         ImageBufferIter::new(images)
             .map(|img| {
                 if let Some(img) = img {
-                    img.encode(image_format, &mut writer)?;
+                    img.encode_with_quality(image_format, quality, &mut writer)?;
                     offsets.push(writer.count() as i64);
                     validity.push(true);
                 } else {
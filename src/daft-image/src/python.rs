@@ -16,7 +16,7 @@ pub fn decode(
 
 #[pyfunction]
 pub fn encode(s: &PySeries, image_format: ImageFormat) -> PyResult<PySeries> {
-    let s = crate::series::encode(&s.series, image_format)?;
+    let s = crate::series::encode(&s.series, image_format, None)?;
     Ok(s.into())
 }
 
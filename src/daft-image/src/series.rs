@@ -2,7 +2,7 @@ use common_error::{DaftError, DaftResult};
 use daft_core::prelude::*;
 
 use crate::{
-    ops::{image_array_from_img_buffers, ImageOps},
+    ops::{fixed_image_array_from_img_buffers, image_array_from_img_buffers, ImageOps},
     DaftImageBuffer,
 };
 fn image_decode_impl(
@@ -60,6 +60,124 @@ fn image_decode_impl(
     }
 }
 
+/// Resolves the mode of the images produced by [`decode_fixed_shape`]: `mode` if given, otherwise
+/// the uint8 mode with `num_channels` channels.
+pub fn fixed_shape_decode_mode(
+    mode: Option<ImageMode>,
+    num_channels: u16,
+) -> DaftResult<ImageMode> {
+    let target_mode = match mode {
+        Some(mode) if mode.num_channels() != num_channels => {
+            return Err(DaftError::ValueError(format!(
+                "Image mode {mode} has {} channels, but the expected shape has {num_channels}",
+                mode.num_channels()
+            )))
+        }
+        Some(mode) => mode,
+        None => ImageMode::try_from_num_channels(num_channels, &DataType::UInt8)?,
+    };
+    if target_mode.get_dtype() != DataType::UInt8 {
+        return Err(DaftError::ValueError(format!(
+            "Decoding into fixed-shape images is only supported for uint8 modes, but got {target_mode}"
+        )));
+    }
+    Ok(target_mode)
+}
+
+fn image_decode_fixed_shape_impl(
+    ba: &BinaryArray,
+    raise_error_on_failure: bool,
+    mode: Option<ImageMode>,
+    height: u32,
+    width: u32,
+    num_channels: u16,
+) -> DaftResult<FixedShapeImageArray> {
+    let target_mode = fixed_shape_decode_mode(mode, num_channels)?;
+    let arrow_array = ba
+        .data()
+        .as_any()
+        .downcast_ref::<arrow2::array::BinaryArray<i64>>()
+        .unwrap();
+    let expected_shape = (height, width, num_channels);
+    let mut img_bufs = Vec::<Option<DaftImageBuffer>>::with_capacity(arrow_array.len());
+    for (index, row) in arrow_array.iter().enumerate() {
+        let decoded = row
+            .map(|bytes| {
+                let mut img_buf = DaftImageBuffer::decode(bytes)?;
+                if let Some(mode) = mode {
+                    img_buf = img_buf.into_mode(mode);
+                }
+                let shape = (
+                    img_buf.height(),
+                    img_buf.width(),
+                    img_buf.mode().num_channels(),
+                );
+                // Comparing modes rather than channel counts also rejects non-uint8 images.
+                if shape == expected_shape && img_buf.mode() == target_mode {
+                    Ok(img_buf)
+                } else {
+                    Err(DaftError::ValueError(format!(
+                        "Expected image of shape (height, width, channels) {expected_shape:?} and mode {target_mode}, but got {shape:?} and mode {}",
+                        img_buf.mode()
+                    )))
+                }
+            })
+            .transpose();
+        let img_buf = match decoded {
+            Ok(val) => val,
+            Err(err) => {
+                if raise_error_on_failure {
+                    return Err(err);
+                }
+                log::warn!(
+                    "Error occurred during image decoding at index: {index} {err} (falling back to Null)"
+                );
+                None
+            }
+        };
+        img_bufs.push(img_buf);
+    }
+    fixed_image_array_from_img_buffers(ba.name(), img_bufs.as_slice(), &target_mode, height, width)
+}
+
+/// Decodes a series of binary data into a fixed-shape image array, checking that every image
+/// has the expected `(height, width, num_channels)` shape.
+///
+/// # Arguments
+/// * `s` - Input Series containing binary image data
+/// * `raise_error_on_failure` - If true, raises errors on decode failures and shape mismatches,
+///   otherwise those rows become null
+/// * `mode` - Optional target ImageMode, applied before the shape check
+/// * `height` - Expected height of every image
+/// * `width` - Expected width of every image
+/// * `num_channels` - Expected number of channels of every image
+///
+/// # Returns
+/// A DaftResult containing a Series of fixed-shape images
+pub fn decode_fixed_shape(
+    s: &Series,
+    raise_error_on_failure: bool,
+    mode: Option<ImageMode>,
+    height: u32,
+    width: u32,
+    num_channels: u16,
+) -> DaftResult<Series> {
+    match s.data_type() {
+        DataType::Binary => image_decode_fixed_shape_impl(
+            s.binary()?,
+            raise_error_on_failure,
+            mode,
+            height,
+            width,
+            num_channels,
+        )
+        .map(|arr| arr.into_series()),
+        dtype => Err(DaftError::ValueError(format!(
+            "Decoding in-memory data into images is only supported for binary arrays, but got {dtype}"
+        ))),
+    }
+}
+
 /// Decodes a series of binary data into image arrays.
 ///
 /// # Arguments
@@ -92,18 +210,19 @@ pub fn decode(
 /// # Arguments
 /// * `s` - The input Series containing image data
 /// * `image_format` - The desired output format for the encoded images
+/// * `quality` - Optional quality (1-100) for lossy formats; only JPEG supports it
 ///
 /// # Returns
 /// A DaftResult containing a new Series of encoded binary data
-pub fn encode(s: &Series, image_format: ImageFormat) -> DaftResult<Series> {
+pub fn encode(s: &Series, image_format: ImageFormat, quality: Option<u8>) -> DaftResult<Series> {
     match s.data_type() {
         DataType::Image(..) => Ok(s
             .downcast::<ImageArray>()?
-            .encode(image_format)?
+            .encode(image_format, quality)?
             .into_series()),
         DataType::FixedShapeImage(..) => Ok(s
             .downcast::<FixedShapeImageArray>()?
-            .encode(image_format)?
+            .encode(image_format, quality)?
             .into_series()),
         dtype => Err(DaftError::ValueError(format!(
             "Encoding images into bytes is only supported for image arrays, but got {dtype}"
@@ -573,6 +573,13 @@ impl ObjectSource for AzureBlobSource {
         todo!("PUTs to Azure blob store are not yet supported! Please file an issue.");
     }
 
+    async fn delete(&self, _uri: &str) -> super::Result<()> {
+        Err(super::Error::NotImplementedOperation {
+            store: super::SourceType::AzureBlob,
+            op: "DELETE".to_string(),
+        })
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         let parsed_uri = parse_azure_uri(uri)?;
         let (container, key) = parsed_uri
@@ -522,6 +522,13 @@ impl ObjectSource for GCSSource {
         todo!("PUTS to GCS are not yet supported! Please file an issue.");
     }
 
+    async fn delete(&self, _uri: &str) -> super::Result<()> {
+        Err(super::Error::NotImplementedOperation {
+            store: super::SourceType::GCS,
+            op: "DELETE".to_string(),
+        })
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         self.client.get_size(uri, io_stats).await
     }
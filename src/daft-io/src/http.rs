@@ -239,6 +239,13 @@ impl ObjectSource for HttpSource {
         todo!("PUTs to HTTP URLs are not yet supported! Please file an issue.");
     }
 
+    async fn delete(&self, _uri: &str) -> super::Result<()> {
+        Err(super::Error::NotImplementedOperation {
+            store: super::SourceType::Http,
+            op: "DELETE".to_string(),
+        })
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         let request = self.client.head(uri);
         let response = request
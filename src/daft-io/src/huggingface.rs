@@ -334,6 +334,13 @@ impl ObjectSource for HFSource {
         todo!("PUTs to HTTP URLs are not yet supported! Please file an issue.");
     }
 
+    async fn delete(&self, _uri: &str) -> super::Result<()> {
+        Err(super::Error::NotImplementedOperation {
+            store: super::SourceType::HF,
+            op: "DELETE".to_string(),
+        })
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         let path_parts = uri.parse::<HFPathParts>()?;
         let uri = &path_parts.get_file_uri();
@@ -122,6 +122,9 @@ pub enum Error {
     #[snafu(display("Source not yet implemented: {}", store))]
     NotImplementedSource { store: String },
 
+    #[snafu(display("{op} is not yet supported for store: {store}. Please file an issue."))]
+    NotImplementedOperation { store: SourceType, op: String },
+
     #[snafu(display("Unhandled Error for path: {}\nDetails:\n{}", path, msg))]
     Unhandled { path: String, msg: String },
 
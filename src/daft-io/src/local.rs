@@ -9,7 +9,7 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use common_error::DaftError;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
-use snafu::{ResultExt, Snafu};
+use snafu::{IntoError, ResultExt, Snafu};
 use tokio::io::{AsyncReadExt, AsyncSeekExt};
 
 use super::{
@@ -61,6 +61,12 @@ enum Error {
         source: std::io::Error,
     },
 
+    #[snafu(display("Unable to delete file {}: {}", path, source))]
+    UnableToDeleteFile {
+        path: String,
+        source: std::io::Error,
+    },
+
     #[snafu(display("Unable to fetch file metadata for file {}: {}", path, source))]
     UnableToFetchFileMetadata {
         path: String,
@@ -177,6 +183,18 @@ impl ObjectSource for LocalSource {
         }
     }
 
+    async fn delete(&self, uri: &str) -> super::Result<()> {
+        const LOCAL_PROTOCOL: &str = "file://";
+        let Some(stripped_uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: uri.into() }.into());
+        };
+        match std::fs::remove_file(stripped_uri) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(UnableToDeleteFileSnafu { path: uri }.into_error(err).into()),
+        }
+    }
+
     async fn get_size(&self, uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         const LOCAL_PROTOCOL: &str = "file://";
         let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
@@ -502,4 +520,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_local_put_delete_roundtrip() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = format!(
+            "file://{}/data.bin",
+            dir.path().to_string_lossy().replace('\\', "/")
+        );
+        let client = LocalSource::get_client().await?;
+
+        client
+            .put(&file_path, bytes::Bytes::from_static(b"daft"), None)
+            .await?;
+        assert_eq!(client.get_size(&file_path, None).await?, 4);
+
+        client.delete(&file_path).await?;
+        assert!(matches!(
+            client.get_size(&file_path, None).await,
+            Err(crate::Error::NotFound { .. })
+        ));
+
+        // Deleting a file that no longer exists is a no-op.
+        client.delete(&file_path).await?;
+
+        Ok(())
+    }
 }
@@ -189,6 +189,9 @@ pub trait ObjectSource: Sync + Send {
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<()>;
 
+    /// Deletes the object at `uri`. Deleting an object that does not exist is a no-op.
+    async fn delete(&self, uri: &str) -> super::Result<()>;
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
     async fn glob(
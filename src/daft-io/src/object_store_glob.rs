@@ -5,7 +5,7 @@ use futures::stream::{BoxStream, StreamExt};
 use globset::{GlobBuilder, GlobMatcher};
 use itertools::Itertools;
 use lazy_static::lazy_static;
-use tokio::sync::mpsc::Sender;
+use tokio::sync::{mpsc::Sender, Semaphore};
 
 use crate::{
     object_io::{FileMetadata, FileType, ObjectSource},
@@ -33,6 +33,9 @@ const MARKER_FILES: [&str; 3] = ["_metadata", "_common_metadata", "_success"];
 // NOTE: We use the following prefixes to filter out Spark/Databricks marker files
 const MARKER_PREFIXES: [&str; 2] = ["_started", "_committed"];
 
+/// Default maximum number of directory listings that a single [`glob`] call will have in-flight at once
+pub const DEFAULT_MAX_CONCURRENT_LISTINGS: usize = 64;
+
 #[derive(Clone)]
 pub struct GlobState {
     // Current path in dirtree and glob_fragments
@@ -50,6 +53,9 @@ pub struct GlobState {
     pub full_glob_matcher: Arc<GlobMatcher>,
     pub fanout_limit: Option<usize>,
     pub page_size: Option<i32>,
+
+    // Bounds the number of concurrent listings across all dispatched visits
+    pub listing_semaphore: Arc<Semaphore>,
 }
 
 impl GlobState {
@@ -390,6 +396,38 @@ pub async fn glob(
     limit: Option<usize>,
     io_stats: Option<IOStatsRef>,
 ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
+    glob_with_max_concurrent_listings(
+        source,
+        glob,
+        fanout_limit,
+        page_size,
+        limit,
+        io_stats,
+        DEFAULT_MAX_CONCURRENT_LISTINGS,
+    )
+    .await
+}
+
+/// Same as [`glob`], but allows for configuring the maximum number of directory listings that may be in-flight at once.
+///
+/// Each directory discovered during globbing is visited by its own task, but a task must first acquire one of
+/// `max_concurrent_listings` permits before listing, so deep/wide directory trees do not overwhelm the ObjectSource.
+/// Results are still streamed back as soon as each listing produces them.
+pub async fn glob_with_max_concurrent_listings(
+    source: Arc<dyn ObjectSource>,
+    glob: &str,
+    fanout_limit: Option<usize>,
+    page_size: Option<i32>,
+    limit: Option<usize>,
+    io_stats: Option<IOStatsRef>,
+    max_concurrent_listings: usize,
+) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
+    if max_concurrent_listings == 0 {
+        return Err(super::Error::InvalidArgument {
+            msg: "max_concurrent_listings for glob must be greater than 0".to_string(),
+        });
+    }
+
     // If no special characters, we fall back to ls behavior
     let full_fragment = GlobFragment::new(glob);
     if !full_fragment.has_special_character() {
@@ -464,6 +502,14 @@ pub async fn glob(
         io_stats: Option<IOStatsRef>,
     ) {
         tokio::spawn(async move {
+            // Hold a permit for the lifetime of this visit; sub-directories are dispatched as new tasks which
+            // wait on their own permits, so this never blocks on its children.
+            let _permit = state
+                .listing_semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("Glob listing semaphore should never be closed");
             log::debug!(
                 target: "glob",
                 "Visiting '{}' with glob_fragments: {:?}",
@@ -678,6 +724,7 @@ pub async fn glob(
             current_fanout: 1,
             fanout_limit,
             page_size,
+            listing_semaphore: Arc::new(Semaphore::new(max_concurrent_listings)),
         },
         io_stats,
     );
@@ -700,7 +747,151 @@ pub async fn glob(
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        ops::Range,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use futures::TryStreamExt;
+
     use super::*;
+    use crate::{
+        object_io::{GetResult, LSResult},
+        FileFormat,
+    };
+
+    const TREE_DEPTH: usize = 4;
+    const TREE_BRANCHING: usize = 3;
+
+    /// Synthetic directory tree where every directory holds `TREE_BRANCHING` sub-directories (down to `TREE_DEPTH`)
+    /// and a single `data.txt` file. Tracks the number of concurrent listings it observes.
+    #[derive(Default)]
+    struct SyntheticTreeSource {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    impl SyntheticTreeSource {
+        fn depth(path: &str) -> usize {
+            path.trim_start_matches("mem://root")
+                .split(GLOB_DELIMITER)
+                .filter(|s| !s.is_empty())
+                .count()
+        }
+    }
+
+    #[async_trait]
+    impl ObjectSource for SyntheticTreeSource {
+        async fn get(
+            &self,
+            _uri: &str,
+            _range: Option<Range<usize>>,
+            _io_stats: Option<IOStatsRef>,
+        ) -> crate::Result<GetResult> {
+            unimplemented!()
+        }
+
+        async fn put(
+            &self,
+            _uri: &str,
+            _data: bytes::Bytes,
+            _io_stats: Option<IOStatsRef>,
+        ) -> crate::Result<()> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _uri: &str) -> crate::Result<()> {
+            unimplemented!()
+        }
+
+        async fn get_size(
+            &self,
+            _uri: &str,
+            _io_stats: Option<IOStatsRef>,
+        ) -> crate::Result<usize> {
+            unimplemented!()
+        }
+
+        async fn glob(
+            self: Arc<Self>,
+            _glob_path: &str,
+            _fanout_limit: Option<usize>,
+            _page_size: Option<i32>,
+            _limit: Option<usize>,
+            _io_stats: Option<IOStatsRef>,
+            _file_format: Option<FileFormat>,
+        ) -> crate::Result<BoxStream<'static, crate::Result<FileMetadata>>> {
+            unimplemented!()
+        }
+
+        async fn ls(
+            &self,
+            path: &str,
+            _posix: bool,
+            _continuation_token: Option<&str>,
+            _page_size: Option<i32>,
+            _io_stats: Option<IOStatsRef>,
+        ) -> crate::Result<LSResult> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+
+            let dir = format!("{}{GLOB_DELIMITER}", path.trim_end_matches(GLOB_DELIMITER));
+            let mut files = vec![FileMetadata {
+                filepath: format!("{dir}data.txt"),
+                size: Some(1),
+                filetype: FileType::File,
+            }];
+            if Self::depth(&dir) < TREE_DEPTH {
+                files.extend((0..TREE_BRANCHING).map(|i| FileMetadata {
+                    filepath: format!("{dir}d{i}{GLOB_DELIMITER}"),
+                    size: None,
+                    filetype: FileType::Directory,
+                }));
+            }
+
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(LSResult {
+                files,
+                continuation_token: None,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_glob_bounds_concurrent_listings() -> crate::Result<()> {
+        const MAX_CONCURRENT_LISTINGS: usize = 4;
+
+        let source = Arc::new(SyntheticTreeSource::default());
+        let results = glob_with_max_concurrent_listings(
+            source.clone(),
+            "mem://root/**",
+            None,
+            None,
+            None,
+            None,
+            MAX_CONCURRENT_LISTINGS,
+        )
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        let num_dirs: usize = (0..=TREE_DEPTH).map(|d| TREE_BRANCHING.pow(d as u32)).sum();
+        let paths = results
+            .into_iter()
+            .map(|fm| fm.filepath)
+            .collect::<HashSet<_>>();
+        assert_eq!(paths.len(), num_dirs);
+        assert!(paths.contains("mem://root/data.txt"));
+        assert!(paths.contains("mem://root/d2/d0/d1/d2/data.txt"));
+
+        let max_in_flight = source.max_in_flight.load(Ordering::SeqCst);
+        assert!(max_in_flight > 1);
+        assert!(max_in_flight <= MAX_CONCURRENT_LISTINGS);
+        Ok(())
+    }
 
     #[test]
     fn test_verify_glob() {
@@ -11,7 +11,9 @@ use aws_credential_types::{
     provider::error::CredentialsError,
 };
 use aws_sdk_s3::{
-    self as s3, error::ProvideErrorMetadata, operation::put_object::PutObjectError,
+    self as s3,
+    error::ProvideErrorMetadata,
+    operation::{delete_object::DeleteObjectError, put_object::PutObjectError},
     primitives::ByteStreamError,
 };
 use aws_sig_auth::signer::SigningRequirements;
@@ -69,6 +71,16 @@ enum Error {
         source: SdkError<PutObjectError, Response>,
     },
 
+    #[snafu(display(
+        "Unable to delete file {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToDeleteFile {
+        path: String,
+        source: SdkError<DeleteObjectError, Response>,
+    },
+
     #[snafu(display("Unable to head {}: {}", path, s3::error::DisplayErrorContext(source)))]
     UnableToHeadFile {
         path: String,
@@ -118,6 +130,9 @@ enum Error {
 
     #[snafu(display("Uploads cannot be anonymous. Please disable anonymous S3 access."))]
     UploadsCannotBeAnonymous {},
+
+    #[snafu(display("Deletes cannot be anonymous. Please disable anonymous S3 access."))]
+    DeletesCannotBeAnonymous {},
 }
 
 /// List of AWS error codes that are due to throttling
@@ -1000,6 +1015,47 @@ impl S3LikeSource {
             }
         }
     }
+
+    async fn _delete_impl(
+        &self,
+        _permit: SemaphorePermit<'_>,
+        uri: &str,
+        region: &Region,
+    ) -> super::Result<()> {
+        log::debug!("S3 delete at {uri}, in region: {region}");
+        let (_scheme, bucket, key) = parse_url(uri)?;
+
+        if key.is_empty() {
+            Err(Error::NotAFile { path: uri.into() }.into())
+        } else {
+            log::debug!("S3 delete parsed uri: {uri} into Bucket: {bucket}, Key: {key}");
+            let request = self
+                .get_s3_client(region)
+                .await?
+                .delete_object()
+                .bucket(bucket)
+                .key(key);
+
+            let request = if self.s3_config.requester_pays {
+                request.request_payer(s3::types::RequestPayer::Requester)
+            } else {
+                request
+            };
+
+            let response = if self.anonymous {
+                return Err(Error::DeletesCannotBeAnonymous {}.into());
+            } else {
+                request.send().await
+            };
+
+            // S3 responds with a success to deletes of keys that do not exist, so there is no
+            // NotFound case to handle here.
+            match response {
+                Ok(_) => Ok(()),
+                Err(err) => Err(UnableToDeleteFileSnafu { path: uri }.into_error(err).into()),
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -1063,6 +1119,15 @@ impl ObjectSource for S3LikeSource {
         Ok(())
     }
 
+    async fn delete(&self, uri: &str) -> super::Result<()> {
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        self._delete_impl(permit, uri, &self.default_region).await
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         let permit = self
             .connection_pool_sema
@@ -1276,4 +1341,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_anonymous_delete_is_rejected() -> Result<()> {
+        let config = S3Config {
+            anonymous: true,
+            ..Default::default()
+        };
+        let client = S3LikeSource::get_client(&config).await?;
+
+        let err = client
+            .delete("s3://daft-public-data/test_fixtures/does-not-exist")
+            .await
+            .unwrap_err();
+        let crate::Error::Generic { source, .. } = &err else {
+            panic!("Expected a generic S3 error, got: {err}");
+        };
+        assert!(matches!(
+            source.downcast_ref::<super::Error>(),
+            Some(super::Error::DeletesCannotBeAnonymous {})
+        ));
+
+        Ok(())
+    }
 }
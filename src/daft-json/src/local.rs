@@ -2,8 +2,9 @@ use std::{borrow::Cow, collections::HashSet, num::NonZeroUsize, sync::Arc};
 
 use common_error::DaftResult;
 use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
-use daft_dsl::Expr;
+use daft_dsl::{optimization::get_required_columns, Expr};
 use daft_table::Table;
+use futures::{Stream, StreamExt, TryStreamExt};
 use indexmap::IndexMap;
 use num_traits::Pow;
 use rayon::{prelude::*, ThreadPoolBuilder};
@@ -15,13 +16,16 @@ use crate::{
     deserializer::Value,
     inference::{column_types_map_to_fields, infer_records_schema},
     read::tables_concat,
-    ArrowSnafu, JsonConvertOptions, JsonParseOptions, JsonReadOptions, RayonThreadPoolSnafu,
-    StdIOSnafu,
+    ArrowSnafu, JoinSnafu, JsonConvertOptions, JsonParseOptions, JsonReadOptions, OneShotRecvSnafu,
+    RayonThreadPoolSnafu, StdIOSnafu,
 };
 
 const NEWLINE: u8 = b'\n';
 const CLOSING_BRACKET: u8 = b'}';
 
+// Default size of the adjusted chunks that `stream_json_local` parses in parallel.
+const DEFAULT_CHUNK_SIZE_BYTES: usize = 4 * 1024 * 1024; // 4MiB
+
 pub fn read_json_local(
     uri: &str,
     convert_options: Option<JsonConvertOptions>,
@@ -46,6 +50,163 @@ pub fn read_json_local(
     reader.finish()
 }
 
+/// Reads a single local newline-delimited JSON file in a streaming fashion.
+///
+/// The memory-mapped file is split into adjusted chunks, i.e. contiguous byte ranges that start right
+/// after a record terminator and end on one (see `daft_csv::local` for a longer description of the approach).
+/// Unlike CSV, every `\n` in a JSONL file is a record terminator, since JSON strings cannot contain raw
+/// newlines, so each chunk boundary is simply the first newline found after the nominal chunk size.
+/// Chunks are then deserialized into Tables on the rayon threadpool, with up to `max_chunks_in_flight`
+/// chunks being parsed at any given time.
+pub fn stream_json_local(
+    uri: &str,
+    convert_options: Option<JsonConvertOptions>,
+    parse_options: Option<JsonParseOptions>,
+    read_options: Option<JsonReadOptions>,
+    max_chunks_in_flight: Option<usize>,
+) -> DaftResult<impl Stream<Item = DaftResult<Table>> + Send> {
+    let uri = uri.trim_start_matches("file://");
+    let file = std::fs::File::open(uri)?;
+    // SAFETY: mmapping is inherently unsafe.
+    // We are trusting that the file is not modified or accessed by other systems while we are reading it.
+    let mmap = Arc::new(unsafe { memmap2::Mmap::map(&file) }.context(StdIOSnafu)?);
+
+    // Process the JSON convert options.
+    let predicate = convert_options
+        .as_ref()
+        .and_then(|opts| opts.predicate.clone());
+    let limit = convert_options.as_ref().and_then(|opts| opts.limit);
+    let include_columns = convert_options
+        .as_ref()
+        .and_then(|opts| opts.include_columns.clone());
+    let convert_options = match (convert_options, &predicate) {
+        (None, _) => None,
+        (co, None) => co,
+        (Some(mut co), Some(predicate)) => {
+            if let Some(ref mut include_columns) = co.include_columns {
+                let required_columns_for_predicate = get_required_columns(predicate);
+                for rc in required_columns_for_predicate {
+                    if include_columns.iter().all(|c| c.as_str() != rc.as_str()) {
+                        include_columns.push(rc);
+                    }
+                }
+            }
+            Some(co)
+        }
+    }
+    .unwrap_or_default();
+
+    let schema = match convert_options.schema {
+        Some(schema) => schema,
+        None => {
+            let sample_size = parse_options.and_then(|options| options.sample_size);
+            Arc::new(Schema::try_from(&infer_schema(&mmap, sample_size, None)?)?)
+        }
+    };
+    let read_schema = match convert_options.include_columns {
+        Some(projected_columns) => Arc::new(schema.project(&projected_columns)?),
+        None => schema,
+    };
+
+    let chunk_size = read_options
+        .as_ref()
+        .and_then(|opts| opts.chunk_size)
+        .unwrap_or(DEFAULT_CHUNK_SIZE_BYTES)
+        .max(1);
+    let n_threads: usize = std::thread::available_parallelism()
+        .unwrap_or(NonZeroUsize::new(2).unwrap())
+        .into();
+
+    let chunk_iterator = JsonlChunkIterator::new(mmap.clone(), chunk_size);
+    let stream = futures::stream::iter(chunk_iterator)
+        .map(move |(start, end)| {
+            let mmap = mmap.clone();
+            let read_schema = read_schema.clone();
+            let include_columns = include_columns.clone();
+            let predicate = predicate.clone();
+            tokio::spawn(async move {
+                let (tx, rx) = tokio::sync::oneshot::channel();
+                rayon::spawn(move || {
+                    let chunk = &mmap[start..end];
+                    // Every record in a chunk is terminated by a newline, except possibly the last one in the file.
+                    let num_records = memchr::memchr_iter(NEWLINE, chunk).count() + 1;
+                    let table =
+                        parse_json_chunk(chunk, &read_schema, predicate.as_ref(), num_records)
+                            .and_then(|table| match (&predicate, &include_columns) {
+                                (Some(_), Some(include_columns)) => {
+                                    table.get_columns(include_columns.as_slice())
+                                }
+                                _ => Ok(table),
+                            });
+                    // We throw away the error because we might close the oneshot channel in the case where
+                    // a limit is applied and we early-terminate.
+                    let _ = tx.send(table);
+                });
+                rx.await
+            })
+        })
+        .buffered(max_chunks_in_flight.unwrap_or(n_threads))
+        .map(|v| -> DaftResult<Table> { v.context(JoinSnafu {})?.context(OneShotRecvSnafu {})? });
+
+    // Apply limit.
+    let mut remaining_rows = limit.map(|limit| limit as i64);
+    let limited = stream.try_take_while(move |table| {
+        match remaining_rows {
+            // Limit has been met, early-terminate.
+            Some(rows_left) if rows_left <= 0 => futures::future::ready(Ok(false)),
+            // Limit has not yet been met, update remaining limit slack and continue.
+            Some(rows_left) => {
+                remaining_rows = Some(rows_left - table.len() as i64);
+                futures::future::ready(Ok(true))
+            }
+            // No limit, never early-terminate.
+            None => futures::future::ready(Ok(true)),
+        }
+    });
+
+    Ok(limited)
+}
+
+/// An iterator of `(start, end)` byte ranges over a newline-delimited JSON file, where each range
+/// is an adjusted chunk that contains only complete JSON records.
+struct JsonlChunkIterator {
+    bytes: Arc<memmap2::Mmap>,
+    chunk_size: usize,
+    curr_pos: usize,
+}
+
+impl JsonlChunkIterator {
+    fn new(bytes: Arc<memmap2::Mmap>, chunk_size: usize) -> Self {
+        Self {
+            bytes,
+            chunk_size,
+            curr_pos: 0,
+        }
+    }
+}
+
+impl Iterator for JsonlChunkIterator {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let total_len = self.bytes.len();
+        if self.curr_pos >= total_len {
+            return None;
+        }
+        let start = self.curr_pos;
+        let search_pos = start + self.chunk_size;
+        // Extend the chunk up to and including the first record terminator at or after `search_pos`.
+        let end = if search_pos >= total_len {
+            total_len
+        } else {
+            memchr::memchr(NEWLINE, &self.bytes[search_pos..])
+                .map_or(total_len, |pos| search_pos + pos + 1)
+        };
+        self.curr_pos = end;
+        Some((start, end))
+    }
+}
+
 struct JsonReader<'a> {
     bytes: &'a [u8],
     schema: SchemaRef,
@@ -146,7 +307,7 @@ impl<'a> JsonReader<'a> {
                 .into_par_iter()
                 .map(|(start, stop)| {
                     let chunk = &bytes[start..stop];
-                    self.parse_json_chunk(chunk, chunk_size)
+                    parse_json_chunk(chunk, &self.schema, self.predicate.as_ref(), chunk_size)
                 })
                 .collect::<DaftResult<Vec<Table>>>()
         })?;
@@ -162,82 +323,6 @@ impl<'a> JsonReader<'a> {
         Ok(tbl)
     }
 
-    fn parse_json_chunk(&self, bytes: &[u8], chunk_size: usize) -> DaftResult<Table> {
-        let mut scratch = vec![];
-        let scratch = &mut scratch;
-
-        let daft_fields = self.schema.fields.values().map(|f| Arc::new(f.clone()));
-
-        let arrow_schema = self.schema.to_arrow()?;
-
-        // The `RawValue` is a pointer to the original JSON string and does not perform any deserialization.
-        // This is a trick to use the line-based deserializer from serde_json to iterate over the lines
-        // This is more accurate than using a `Lines` iterator.
-        // Ideally, we would instead use a line-based deserializer from simd_json, but that is not available.
-        let iter =
-            serde_json::Deserializer::from_slice(bytes).into_iter::<&serde_json::value::RawValue>();
-
-        let mut columns = arrow_schema
-            .fields
-            .iter()
-            .map(|f| {
-                (
-                    Cow::Owned(f.name.to_string()),
-                    allocate_array(f, chunk_size),
-                )
-            })
-            .collect::<IndexMap<_, _>>();
-
-        let mut num_rows = 0;
-        for record in iter {
-            let value = record.map_err(|e| super::Error::JsonDeserializationError {
-                string: e.to_string(),
-            })?;
-            let v = parse_raw_value(value, scratch)?;
-
-            match v {
-                Value::Object(record) => {
-                    for (s, inner) in &mut columns {
-                        match record.get(s) {
-                            Some(value) => {
-                                deserialize_into(inner, &[value]);
-                            }
-                            None => {
-                                Err(super::Error::JsonDeserializationError {
-                                    string: "Field not found in schema".to_string(),
-                                })?;
-                            }
-                        };
-                    }
-                }
-                _ => {
-                    return Err(super::Error::JsonDeserializationError {
-                        string: "Expected JSON object".to_string(),
-                    }
-                    .into());
-                }
-            }
-
-            num_rows += 1;
-        }
-        let columns = columns
-            .into_values()
-            .zip(daft_fields)
-            .map(|(mut ma, fld)| {
-                let arr = ma.as_box();
-                Series::try_from_field_and_arrow_array(fld, cast_array_for_daft_if_needed(arr))
-            })
-            .collect::<DaftResult<Vec<_>>>()?;
-
-        let tbl = Table::new_unchecked(self.schema.clone(), columns, num_rows);
-
-        if let Some(pred) = &self.predicate {
-            tbl.filter(&[pred.clone()])
-        } else {
-            Ok(tbl)
-        }
-    }
-
     /// Get the start and end positions of the chunks of the file
     fn get_file_chunks(
         &self,
@@ -276,6 +361,88 @@ impl<'a> JsonReader<'a> {
     }
 }
 
+/// Deserializes a chunk of newline-delimited JSON records into a Table with the provided `schema`.
+fn parse_json_chunk(
+    bytes: &[u8],
+    schema: &SchemaRef,
+    predicate: Option<&Arc<Expr>>,
+    chunk_size: usize,
+) -> DaftResult<Table> {
+    let mut scratch = vec![];
+    let scratch = &mut scratch;
+
+    let daft_fields = schema.fields.values().map(|f| Arc::new(f.clone()));
+
+    let arrow_schema = schema.to_arrow()?;
+
+    // The `RawValue` is a pointer to the original JSON string and does not perform any deserialization.
+    // This is a trick to use the line-based deserializer from serde_json to iterate over the lines
+    // This is more accurate than using a `Lines` iterator.
+    // Ideally, we would instead use a line-based deserializer from simd_json, but that is not available.
+    let iter =
+        serde_json::Deserializer::from_slice(bytes).into_iter::<&serde_json::value::RawValue>();
+
+    let mut columns = arrow_schema
+        .fields
+        .iter()
+        .map(|f| {
+            (
+                Cow::Owned(f.name.to_string()),
+                allocate_array(f, chunk_size),
+            )
+        })
+        .collect::<IndexMap<_, _>>();
+
+    let mut num_rows = 0;
+    for record in iter {
+        let value = record.map_err(|e| super::Error::JsonDeserializationError {
+            string: e.to_string(),
+        })?;
+        let v = parse_raw_value(value, scratch)?;
+
+        match v {
+            Value::Object(record) => {
+                for (s, inner) in &mut columns {
+                    match record.get(s) {
+                        Some(value) => {
+                            deserialize_into(inner, &[value]);
+                        }
+                        None => {
+                            Err(super::Error::JsonDeserializationError {
+                                string: "Field not found in schema".to_string(),
+                            })?;
+                        }
+                    };
+                }
+            }
+            _ => {
+                return Err(super::Error::JsonDeserializationError {
+                    string: "Expected JSON object".to_string(),
+                }
+                .into());
+            }
+        }
+
+        num_rows += 1;
+    }
+    let columns = columns
+        .into_values()
+        .zip(daft_fields)
+        .map(|(mut ma, fld)| {
+            let arr = ma.as_box();
+            Series::try_from_field_and_arrow_array(fld, cast_array_for_daft_if_needed(arr))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+
+    let tbl = Table::new_unchecked(schema.clone(), columns, num_rows);
+
+    if let Some(pred) = predicate {
+        tbl.filter(&[pred.clone()])
+    } else {
+        Ok(tbl)
+    }
+}
+
 // TODO: there should be much more shared code between this and the async version
 fn infer_schema(
     bytes: &[u8],
@@ -477,4 +644,58 @@ mod tests {
         let reader = JsonReader::try_new(json.as_bytes(), None, None, None, None).unwrap();
         let _result = reader.finish();
     }
+
+    #[tokio::test]
+    async fn test_stream_json_local() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.jsonl", env!("CARGO_MANIFEST_DIR"));
+
+        // Use a small chunk size so that the file is split into many adjusted chunks.
+        let stream = stream_json_local(
+            file.as_ref(),
+            None,
+            None,
+            Some(JsonReadOptions::default().with_chunk_size(Some(256))),
+            Some(2),
+        )?;
+        let tables = stream.try_collect::<Vec<_>>().await?;
+        assert!(tables.len() > 1);
+
+        let table = tables_concat(tables)?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("sepalLength", DataType::Float64),
+                Field::new("sepalWidth", DataType::Float64),
+                Field::new("petalLength", DataType::Float64),
+                Field::new("petalWidth", DataType::Float64),
+                Field::new("species", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        let species = table.get_column("species")?;
+        assert_eq!(species.utf8()?.get(0), Some("setosa"));
+        assert_eq!(species.utf8()?.get(19), Some("setosa"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream_json_local_with_limit() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.jsonl", env!("CARGO_MANIFEST_DIR"));
+
+        let stream = stream_json_local(
+            file.as_ref(),
+            Some(JsonConvertOptions::default().with_limit(Some(5))),
+            None,
+            Some(JsonReadOptions::default().with_chunk_size(Some(256))),
+            Some(2),
+        )?;
+        let table = tables_concat(stream.try_collect::<Vec<_>>().await?)?;
+        // The stream stops at the first chunk that satisfies the limit, so not every chunk is read.
+        assert!(table.len() >= 5);
+        assert!(table.len() < 20);
+
+        Ok(())
+    }
 }
@@ -21,7 +21,9 @@ use tokio::{
 use tokio_util::io::StreamReader;
 
 use crate::{
-    decoding::deserialize_records, local::read_json_local, schema::read_json_schema_single,
+    decoding::deserialize_records,
+    local::{read_json_local, stream_json_local},
+    schema::read_json_schema_single,
     ArrowSnafu, ChunkSnafu, JsonConvertOptions, JsonParseOptions, JsonReadOptions,
 };
 
@@ -296,6 +298,19 @@ pub async fn stream_json(
     io_stats: Option<IOStatsRef>,
     max_chunks_in_flight: Option<usize>,
 ) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+    let (source_type, fixed_uri) = parse_url(&uri)?;
+    let is_compressed = CompressionCodec::from_uri(&uri).is_some();
+    if matches!(source_type, SourceType::File) && !is_compressed {
+        let stream = stream_json_local(
+            fixed_uri.as_ref(),
+            convert_options,
+            parse_options,
+            read_options,
+            max_chunks_in_flight,
+        )?;
+        return Ok(Box::pin(stream));
+    }
+
     let predicate = convert_options
         .as_ref()
         .and_then(|opts| opts.predicate.clone());
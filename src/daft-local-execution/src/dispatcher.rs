@@ -192,7 +192,8 @@ impl PartitionedDispatcher {
     ) -> DaftResult<()> {
         for receiver in input_receivers {
             while let Some(morsel) = receiver.recv().await {
-                let partitions = morsel.partition_by_hash(&partition_by, worker_senders.len())?;
+                let partitions =
+                    morsel.partition_by_hash(&partition_by, worker_senders.len(), None)?;
                 for (partition, worker_sender) in partitions.into_iter().zip(worker_senders.iter())
                 {
                     if worker_sender.send(Arc::new(partition)).await.is_err() {
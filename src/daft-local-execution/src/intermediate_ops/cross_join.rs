@@ -0,0 +1,208 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use common_error::{DaftError, DaftResult};
+use common_runtime::RuntimeRef;
+use daft_core::prelude::SchemaRef;
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+use tracing::instrument;
+
+use super::intermediate_op::{
+    IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
+    IntermediateOperatorResult,
+};
+use crate::sinks::cross_join_collect::CrossJoinStateBridgeRef;
+
+enum CrossJoinState {
+    Collecting(CrossJoinStateBridgeRef),
+    Joining(Arc<Vec<Table>>),
+}
+
+impl CrossJoinState {
+    async fn get_or_await_right_tables(&mut self) -> Arc<Vec<Table>> {
+        match self {
+            Self::Collecting(bridge) => {
+                let tables = bridge.get_tables().await;
+                *self = Self::Joining(tables.clone());
+                tables
+            }
+            Self::Joining(tables) => tables.clone(),
+        }
+    }
+}
+
+impl IntermediateOpState for CrossJoinState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Streams the left side of a cross join, pairing each morsel with every collected right table.
+pub struct CrossJoinOperator {
+    output_schema: SchemaRef,
+    max_rows: usize,
+    /// Rows emitted so far across all workers, which `max_rows` caps.
+    emitted_rows: Arc<AtomicUsize>,
+    state_bridge: CrossJoinStateBridgeRef,
+}
+
+impl CrossJoinOperator {
+    pub(crate) fn new(
+        output_schema: &SchemaRef,
+        max_rows: usize,
+        state_bridge: CrossJoinStateBridgeRef,
+    ) -> Self {
+        Self {
+            output_schema: output_schema.clone(),
+            max_rows,
+            emitted_rows: Arc::new(AtomicUsize::new(0)),
+            state_bridge,
+        }
+    }
+
+    fn cross_join(
+        input: &Arc<MicroPartition>,
+        right_tables: &[Table],
+        output_schema: &SchemaRef,
+        max_rows: usize,
+        emitted_rows: &AtomicUsize,
+    ) -> DaftResult<Arc<MicroPartition>> {
+        // Reserve this morsel's output rows against the cap on the total output, before producing any of them.
+        let right_rows = right_tables.iter().map(Table::len).sum::<usize>();
+        let num_rows = input.len().checked_mul(right_rows);
+        emitted_rows
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |emitted| {
+                num_rows
+                    .and_then(|num_rows| emitted.checked_add(num_rows))
+                    .filter(|total| *total <= max_rows)
+            })
+            .map_err(|emitted| {
+                DaftError::ValueError(format!(
+                    "Cross join of {} rows with {right_rows} rows after {emitted} output rows exceeds the maximum of {max_rows} output rows",
+                    input.len()
+                ))
+            })?;
+
+        let input_tables = input.get_tables()?;
+        let tables = input_tables
+            .iter()
+            .flat_map(|left| {
+                right_tables
+                    .iter()
+                    .map(move |right| left.cross_join_with_max_rows(right, max_rows))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            output_schema.clone(),
+            Arc::new(tables),
+            None,
+        )))
+    }
+}
+
+impl IntermediateOperator for CrossJoinOperator {
+    #[instrument(skip_all, name = "CrossJoinOperator::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn IntermediateOpState>,
+        runtime_ref: &RuntimeRef,
+    ) -> IntermediateOpExecuteResult {
+        if input.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(self.output_schema.clone())));
+            return Ok((
+                state,
+                IntermediateOperatorResult::NeedMoreInput(Some(empty)),
+            ))
+            .into();
+        }
+
+        let output_schema = self.output_schema.clone();
+        let max_rows = self.max_rows;
+        let emitted_rows = self.emitted_rows.clone();
+        runtime_ref
+            .spawn(async move {
+                let cross_join_state = state
+                    .as_any_mut()
+                    .downcast_mut::<CrossJoinState>()
+                    .expect("CrossJoinState should be used with CrossJoinOperator");
+                let right_tables = cross_join_state.get_or_await_right_tables().await;
+                let res = Self::cross_join(
+                    &input,
+                    &right_tables,
+                    &output_schema,
+                    max_rows,
+                    &emitted_rows,
+                );
+                Ok((state, IntermediateOperatorResult::NeedMoreInput(Some(res?))))
+            })
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "CrossJoinOperator"
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
+        Ok(Box::new(CrossJoinState::Collecting(
+            self.state_bridge.clone(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::AtomicUsize, Arc};
+
+    use common_error::{DaftError, DaftResult};
+    use daft_core::prelude::*;
+    use daft_micropartition::MicroPartition;
+    use daft_table::Table;
+
+    use super::CrossJoinOperator;
+
+    #[test]
+    fn test_max_rows_caps_total_output_across_morsels() -> DaftResult<()> {
+        let right = Table::from_nonempty_columns(vec![
+            Int64Array::from(("r", vec![1, 2, 3])).into_series()
+        ])?;
+        let left =
+            Table::from_nonempty_columns(vec![Int64Array::from(("l", vec![1, 2])).into_series()])?;
+        let morsel = Arc::new(MicroPartition::new_loaded(
+            left.schema.clone(),
+            Arc::new(vec![left]),
+            None,
+        ));
+        let output_schema: SchemaRef = Schema::new(vec![
+            Field::new("l", DataType::Int64),
+            Field::new("r", DataType::Int64),
+        ])?
+        .into();
+
+        // Each morsel yields 6 rows, well under the cap, but the third one would bring the total to 18.
+        let emitted_rows = AtomicUsize::new(0);
+        let right_tables = [right];
+        for _ in 0..2 {
+            let out = CrossJoinOperator::cross_join(
+                &morsel,
+                &right_tables,
+                &output_schema,
+                15,
+                &emitted_rows,
+            )?;
+            assert_eq!(out.len(), 6);
+        }
+        let res = CrossJoinOperator::cross_join(
+            &morsel,
+            &right_tables,
+            &output_schema,
+            15,
+            &emitted_rows,
+        );
+        assert!(matches!(res, Err(DaftError::ValueError(_))));
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 pub mod actor_pool_project;
 pub mod aggregate;
 pub mod anti_semi_hash_join_probe;
+pub mod cross_join;
 pub mod explode;
 pub mod filter;
 pub mod inner_hash_join_probe;
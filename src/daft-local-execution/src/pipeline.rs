@@ -11,9 +11,9 @@ use daft_core::{
 };
 use daft_dsl::{col, join::get_common_join_keys, Expr};
 use daft_local_plan::{
-    ActorPoolProject, Concat, EmptyScan, Explode, Filter, HashAggregate, HashJoin, InMemoryScan,
-    Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalWrite, Pivot, Project, Sample,
-    Sort, UnGroupedAggregate, Unpivot,
+    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate, HashJoin,
+    InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalWrite, Pivot,
+    Project, Sample, Sort, UnGroupedAggregate, Unpivot,
 };
 use daft_logical_plan::JoinType;
 use daft_micropartition::MicroPartition;
@@ -27,15 +27,16 @@ use crate::{
     channel::Receiver,
     intermediate_ops::{
         actor_pool_project::ActorPoolProjectOperator, aggregate::AggregateOperator,
-        anti_semi_hash_join_probe::AntiSemiProbeOperator, explode::ExplodeOperator,
-        filter::FilterOperator, inner_hash_join_probe::InnerHashJoinProbeOperator,
-        intermediate_op::IntermediateNode, project::ProjectOperator, sample::SampleOperator,
-        unpivot::UnpivotOperator,
+        anti_semi_hash_join_probe::AntiSemiProbeOperator, cross_join::CrossJoinOperator,
+        explode::ExplodeOperator, filter::FilterOperator,
+        inner_hash_join_probe::InnerHashJoinProbeOperator, intermediate_op::IntermediateNode,
+        project::ProjectOperator, sample::SampleOperator, unpivot::UnpivotOperator,
     },
     sinks::{
         aggregate::AggregateSink,
         blocking_sink::BlockingSinkNode,
         concat::ConcatSink,
+        cross_join_collect::{CrossJoinCollectSink, CrossJoinStateBridge},
         hash_join_build::{HashJoinBuildSink, ProbeStateBridge},
         limit::LimitSink,
         monotonically_increasing_id::MonotonicallyIncreasingIdSink,
@@ -293,9 +294,15 @@ pub fn physical_plan_to_pipeline(
             sort_by,
             descending,
             nulls_first,
+            limit,
             ..
         }) => {
-            let sort_sink = SortSink::new(sort_by.clone(), descending.clone(), nulls_first.clone());
+            let sort_sink = SortSink::new(
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                *limit,
+            );
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             BlockingSinkNode::new(Arc::new(sort_sink), child_node).boxed()
         }
@@ -326,12 +333,16 @@ pub fn physical_plan_to_pipeline(
             // Determine the build and probe sides based on the join type
             // Currently it is a naive determination, in the future we should leverage the cardinality of the tables
             // to determine the build and probe sides
+            // Right joins build on the preserved right side, whose unmatched rows are emitted once probing is done.
             let build_on_left = match join_type {
                 JoinType::Inner => true,
-                JoinType::Right => true,
+                JoinType::Right => false,
                 JoinType::Outer => true,
                 JoinType::Left => false,
                 JoinType::Anti | JoinType::Semi => false,
+                JoinType::RightAnti | JoinType::RightSemi => false,
+                JoinType::Cross => unreachable!("Cross joins are planned as CrossJoin"),
+                JoinType::AsOf => unreachable!("Asof joins cannot be planned"),
             };
             let (build_on, probe_on, build_child, probe_child) = match build_on_left {
                 true => (left_on, right_on, left, right),
@@ -382,6 +393,7 @@ pub fn physical_plan_to_pipeline(
                     casted_build_on,
                     null_equals_null.clone(),
                     join_type,
+                    cfg.hash_join_spill_threshold_bytes,
                     probe_state_bridge.clone(),
                 )?;
                 let build_child_node = physical_plan_to_pipeline(build_child, psets, cfg)?;
@@ -414,27 +426,48 @@ pub fn physical_plan_to_pipeline(
                         vec![build_node, probe_child_node],
                     )
                     .boxed()),
-                    JoinType::Left | JoinType::Right | JoinType::Outer => {
-                        Ok(StreamingSinkNode::new(
-                            Arc::new(OuterHashJoinProbeSink::new(
-                                casted_probe_on,
-                                left_schema,
-                                right_schema,
-                                *join_type,
-                                common_join_keys,
-                                schema,
-                                probe_state_bridge,
-                            )),
-                            vec![build_node, probe_child_node],
-                        )
-                        .boxed())
-                    }
+                    JoinType::Cross => unreachable!("Cross joins are planned as CrossJoin"),
+                    JoinType::AsOf => unreachable!("Asof joins cannot be planned"),
+                    JoinType::Left
+                    | JoinType::Right
+                    | JoinType::Outer
+                    | JoinType::RightAnti
+                    | JoinType::RightSemi => Ok(StreamingSinkNode::new(
+                        Arc::new(OuterHashJoinProbeSink::new(
+                            casted_probe_on,
+                            left_schema,
+                            right_schema,
+                            *join_type,
+                            build_on_left,
+                            common_join_keys,
+                            schema,
+                            probe_state_bridge,
+                        )),
+                        vec![build_node, probe_child_node],
+                    )
+                    .boxed()),
                 }
             }()
             .with_context(|_| PipelineCreationSnafu {
                 plan_name: physical_plan.name(),
             })?
         }
+        LocalPhysicalPlan::CrossJoin(CrossJoin {
+            left,
+            right,
+            schema,
+        }) => {
+            // Collect the right side in full, then stream the left side against it.
+            let state_bridge = CrossJoinStateBridge::new();
+            let collect_sink = CrossJoinCollectSink::new(state_bridge.clone());
+            let right_node = physical_plan_to_pipeline(right, psets, cfg)?;
+            let collect_node = BlockingSinkNode::new(Arc::new(collect_sink), right_node).boxed();
+
+            let left_node = physical_plan_to_pipeline(left, psets, cfg)?;
+            let cross_join_op =
+                CrossJoinOperator::new(schema, cfg.cross_join_max_rows, state_bridge);
+            IntermediateNode::new(Arc::new(cross_join_op), vec![collect_node, left_node]).boxed()
+        }
         LocalPhysicalPlan::PhysicalWrite(PhysicalWrite {
             input,
             file_info,
@@ -0,0 +1,117 @@
+use std::sync::{Arc, OnceLock};
+
+use common_error::DaftResult;
+use common_runtime::RuntimeRef;
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+
+/// CrossJoinStateBridge passes the collected right side of a cross join to the operator that
+/// streams the left side, in the same way the ProbeStateBridge does for hash joins.
+pub(crate) type CrossJoinStateBridgeRef = Arc<CrossJoinStateBridge>;
+pub(crate) struct CrossJoinStateBridge {
+    inner: OnceLock<Arc<Vec<Table>>>,
+    notify: tokio::sync::Notify,
+}
+
+impl CrossJoinStateBridge {
+    pub(crate) fn new() -> Arc<Self> {
+        Arc::new(Self {
+            inner: OnceLock::new(),
+            notify: tokio::sync::Notify::new(),
+        })
+    }
+
+    pub(crate) fn set_tables(&self, tables: Arc<Vec<Table>>) {
+        assert!(
+            !self.inner.set(tables).is_err(),
+            "CrossJoinStateBridge should be set only once"
+        );
+        self.notify.notify_waiters();
+    }
+
+    pub(crate) async fn get_tables(&self) -> Arc<Vec<Table>> {
+        loop {
+            if let Some(tables) = self.inner.get() {
+                return tables.clone();
+            }
+            self.notify.notified().await;
+        }
+    }
+}
+
+struct CrossJoinCollectState(Vec<Table>);
+
+impl BlockingSinkState for CrossJoinCollectState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+pub struct CrossJoinCollectSink {
+    state_bridge: CrossJoinStateBridgeRef,
+}
+
+impl CrossJoinCollectSink {
+    pub(crate) fn new(state_bridge: CrossJoinStateBridgeRef) -> Self {
+        Self { state_bridge }
+    }
+}
+
+impl BlockingSink for CrossJoinCollectSink {
+    fn name(&self) -> &'static str {
+        "CrossJoinCollectSink"
+    }
+
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        runtime: &RuntimeRef,
+    ) -> BlockingSinkSinkResult {
+        runtime
+            .spawn(async move {
+                let collect_state = state
+                    .as_any_mut()
+                    .downcast_mut::<CrossJoinCollectState>()
+                    .expect("CrossJoinCollectSink should have CrossJoinCollectState");
+                collect_state.0.extend(
+                    input
+                        .get_tables()?
+                        .iter()
+                        .filter(|t| !t.is_empty())
+                        .cloned(),
+                );
+                Ok(BlockingSinkStatus::NeedMoreInput(state))
+            })
+            .into()
+    }
+
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        _runtime: &RuntimeRef,
+    ) -> BlockingSinkFinalizeResult {
+        assert_eq!(states.len(), 1);
+        let mut state = states.into_iter().next().unwrap();
+        let collect_state = state
+            .as_any_mut()
+            .downcast_mut::<CrossJoinCollectState>()
+            .expect("State type mismatch");
+        self.state_bridge
+            .set_tables(Arc::new(std::mem::take(&mut collect_state.0)));
+        Ok(None).into()
+    }
+
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(CrossJoinCollectState(Vec::new())))
+    }
+}
@@ -6,7 +6,7 @@ use daft_core::prelude::SchemaRef;
 use daft_dsl::ExprRef;
 use daft_logical_plan::JoinType;
 use daft_micropartition::MicroPartition;
-use daft_table::{make_probeable_builder, ProbeState, ProbeableBuilder, Table};
+use daft_table::{make_probeable_builder, ProbeState, ProbeTableBuilder, ProbeableBuilder, Table};
 
 use super::blocking_sink::{
     BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
@@ -64,14 +64,21 @@ impl ProbeTableState {
         projection: Vec<ExprRef>,
         nulls_equal_aware: Option<&Vec<bool>>,
         join_type: &JoinType,
+        spill_threshold_bytes: Option<usize>,
     ) -> DaftResult<Self> {
         let track_indices = !matches!(join_type, JoinType::Anti | JoinType::Semi);
-        Ok(Self::Building {
-            probe_table_builder: Some(make_probeable_builder(
+        let probe_table_builder = match spill_threshold_bytes {
+            Some(max_bytes) => ProbeTableBuilder::new_with_spill(
                 key_schema.clone(),
                 nulls_equal_aware,
                 track_indices,
-            )?),
+                max_bytes,
+                std::env::temp_dir(),
+            )?,
+            None => make_probeable_builder(key_schema.clone(), nulls_equal_aware, track_indices)?,
+        };
+        Ok(Self::Building {
+            probe_table_builder: Some(probe_table_builder),
             projection,
             tables: Vec::new(),
         })
@@ -126,6 +133,7 @@ pub struct HashJoinBuildSink {
     projection: Vec<ExprRef>,
     nulls_equal_aware: Option<Vec<bool>>,
     join_type: JoinType,
+    spill_threshold_bytes: Option<usize>,
     probe_state_bridge: ProbeStateBridgeRef,
 }
 
@@ -135,6 +143,7 @@ impl HashJoinBuildSink {
         projection: Vec<ExprRef>,
         nulls_equal_aware: Option<Vec<bool>>,
         join_type: &JoinType,
+        spill_threshold_bytes: Option<usize>,
         probe_state_bridge: ProbeStateBridgeRef,
     ) -> DaftResult<Self> {
         Ok(Self {
@@ -142,6 +151,7 @@ impl HashJoinBuildSink {
             projection,
             nulls_equal_aware,
             join_type: *join_type,
+            spill_threshold_bytes,
             probe_state_bridge,
         })
     }
@@ -197,6 +207,54 @@ impl BlockingSink for HashJoinBuildSink {
             self.projection.clone(),
             self.nulls_equal_aware.as_ref(),
             &self.join_type,
+            self.spill_threshold_bytes,
         )?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+    use daft_logical_plan::JoinType;
+    use daft_micropartition::MicroPartition;
+    use daft_table::Table;
+
+    use super::ProbeTableState;
+
+    #[test]
+    fn test_build_spills_past_threshold() -> DaftResult<()> {
+        let key_schema: SchemaRef = Schema::new(vec![Field::new("key", DataType::Int64)])?.into();
+        let mut state = ProbeTableState::new(
+            &key_schema,
+            vec![col("key")],
+            None,
+            &JoinType::Inner,
+            Some(1),
+        )?;
+        for keys in [vec![1, 2, 3], vec![4, 5]] {
+            let table =
+                Table::from_nonempty_columns(vec![Int64Array::from(("key", keys)).into_series()])?;
+            let input =
+                MicroPartition::new_loaded(table.schema.clone(), Arc::new(vec![table]), None);
+            state.add_tables(&Arc::new(input))?;
+        }
+
+        let probe_state = state.finalize();
+        let probe = Table::from_nonempty_columns(vec![
+            Int64Array::from(("key", vec![5, 6, 1])).into_series()
+        ])?;
+        let exists = probe_state
+            .get_probeable()
+            .probe_exists(&probe)?
+            .collect::<Vec<_>>();
+        assert_eq!(exists, vec![true, false, true]);
+        // A spilled build side is probed through its partitions, which do not report a group count.
+        assert_eq!(probe_state.get_probeable().num_groups(), None);
+        assert_eq!(probe_state.get_tables().len(), 2);
+        Ok(())
+    }
+}
@@ -111,3 +111,103 @@ impl StreamingSink for LimitSink {
         Arc::new(UnorderedDispatcher::new(None))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use async_trait::async_trait;
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_io::IOStatsRef;
+    use daft_micropartition::MicroPartition;
+    use daft_table::Table;
+    use futures::StreamExt;
+
+    use super::LimitSink;
+    use crate::{
+        pipeline::PipelineNode,
+        sinks::streaming_sink::StreamingSinkNode,
+        sources::source::{Source, SourceStream},
+        ExecutionRuntimeContext,
+    };
+
+    /// A source that yields single-row morsels forever, counting how many it has produced.
+    struct UnboundedSource {
+        schema: SchemaRef,
+        produced: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Source for UnboundedSource {
+        async fn get_data(
+            &self,
+            _maintain_order: bool,
+            _io_stats: IOStatsRef,
+        ) -> DaftResult<SourceStream<'static>> {
+            let schema = self.schema.clone();
+            let produced = self.produced.clone();
+            Ok(futures::stream::iter(0..)
+                .map(move |i: i64| -> DaftResult<Arc<MicroPartition>> {
+                    produced.fetch_add(1, Ordering::SeqCst);
+                    let table =
+                        Table::from_nonempty_columns(vec![
+                            Int64Array::from(("a", vec![i])).into_series()
+                        ])?;
+                    Ok(Arc::new(MicroPartition::new_loaded(
+                        schema.clone(),
+                        Arc::new(vec![table]),
+                        None,
+                    )))
+                })
+                .boxed())
+        }
+
+        fn name(&self) -> &'static str {
+            "UnboundedSource"
+        }
+
+        fn schema(&self) -> &SchemaRef {
+            &self.schema
+        }
+    }
+
+    #[test]
+    fn test_streaming_limit_stops_pulling_from_source() -> DaftResult<()> {
+        let limit = 3;
+        let produced = Arc::new(AtomicUsize::new(0));
+        let source: Arc<dyn Source> = Arc::new(UnboundedSource {
+            schema: Arc::new(Schema::new(vec![Field::new("a", DataType::Int64)])?),
+            produced: produced.clone(),
+        });
+        let pipeline =
+            StreamingSinkNode::new(Arc::new(LimitSink::new(limit)), vec![source.into()]).boxed();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let values = tokio::task::LocalSet::new().block_on(&runtime, async {
+            let mut runtime_handle = ExecutionRuntimeContext::new(1);
+            let receiver = pipeline.start(true, &mut runtime_handle)?;
+            let mut values = vec![];
+            while let Some(morsel) = receiver.recv().await {
+                for table in morsel.get_tables()?.iter() {
+                    values.extend_from_slice(table.get_column("a")?.i64()?.as_slice());
+                }
+            }
+            // Every node, including the unbounded source, must shut down once the limit is met.
+            while let Some(result) = runtime_handle.join_next().await {
+                result.expect("pipeline task should not panic")?;
+            }
+            DaftResult::Ok(values)
+        })?;
+
+        assert_eq!(values, vec![0, 1, 2]);
+        // Only the morsels already buffered in the channels between nodes may be pulled past the limit.
+        assert!(produced.load(Ordering::SeqCst) < limit + 8);
+        Ok(())
+    }
+}
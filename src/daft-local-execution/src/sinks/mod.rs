@@ -1,6 +1,7 @@
 pub mod aggregate;
 pub mod blocking_sink;
 pub mod concat;
+pub mod cross_join_collect;
 pub mod hash_join_build;
 pub mod limit;
 pub mod monotonically_increasing_id;
@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
+use common_error::DaftResult;
 use common_runtime::RuntimeRef;
 use daft_core::prelude::SchemaRef;
 use daft_micropartition::MicroPartition;
+use daft_table::Table;
 use tracing::instrument;
 
 use super::streaming_sink::{
@@ -32,6 +34,23 @@ impl StreamingSinkState for MonotonicallyIncreasingIdState {
     }
 }
 
+/// Assigns consecutive ids starting at `start` to the rows of `tables`, in order.
+///
+/// Local plans carry no clustering spec because the native executor always runs this operator over a single
+/// partition, so ids are a dense range and don't need the partition number packed into their upper bits. The
+/// multi-partition case only arises on the distributed runner, which uses
+/// [`Table::add_monotonically_increasing_id`].
+fn add_dense_ids(tables: &[Table], start: u64, column_name: &str) -> DaftResult<Vec<Table>> {
+    tables
+        .iter()
+        .scan(start, |offset, table| {
+            let table_with_id = table.add_dense_id(column_name, *offset);
+            *offset += table.len() as u64;
+            Some(table_with_id)
+        })
+        .collect()
+}
+
 struct MonotonicallyIncreasingIdParams {
     column_name: String,
     output_schema: SchemaRef,
@@ -62,23 +81,14 @@ impl StreamingSink for MonotonicallyIncreasingIdSink {
         let params = self.params.clone();
         runtime_ref
             .spawn(async move {
-                let mut id_offset = state
+                let id_offset = state
                     .as_any_mut()
                     .downcast_mut::<MonotonicallyIncreasingIdState>()
                     .expect("MonotonicallyIncreasingIdOperator should have MonotonicallyIncreasingIdState")
                     .fetch_and_increment_offset(input.len() as u64);
 
                 let tables = input.get_tables()?;
-                let mut results = Vec::with_capacity(tables.len());
-                for t in tables.iter() {
-                    let len = t.len() as u64;
-                    results.push(t.add_monotonically_increasing_id(
-                        0,
-                        id_offset,
-                        &params.column_name,
-                    )?);
-                    id_offset += len;
-                }
+                let results = add_dense_ids(&tables, id_offset, &params.column_name)?;
 
                 let out = MicroPartition::new_loaded(
                     params.output_schema.clone(),
@@ -124,3 +134,35 @@ impl StreamingSink for MonotonicallyIncreasingIdSink {
         Arc::new(UnorderedDispatcher::new(None))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_table::Table;
+
+    use super::add_dense_ids;
+
+    #[test]
+    fn test_dense_ids_span_tables() -> DaftResult<()> {
+        let tables = vec![
+            Table::from_nonempty_columns(vec![Int64Array::from(("a", vec![1, 2])).into_series()])?,
+            Table::from_nonempty_columns(vec![
+                Int64Array::from(("a", vec![3, 4, 5])).into_series()
+            ])?,
+        ];
+
+        let first = add_dense_ids(&tables, 0, "id")?;
+        let ids = first
+            .iter()
+            .map(|t| Ok(t.get_column("id")?.u64()?.as_slice().to_vec()))
+            .collect::<DaftResult<Vec<_>>>()?
+            .concat();
+        assert_eq!(ids, (0..5).collect::<Vec<u64>>());
+
+        // A subsequent morsel continues from the running offset.
+        let second = add_dense_ids(&tables[..1], 5, "id")?;
+        assert_eq!(second[0].get_column("id")?.u64()?.as_slice(), &[5, 6]);
+        Ok(())
+    }
+}
@@ -124,8 +124,59 @@ struct OuterHashJoinParams {
     common_join_keys: Vec<String>,
     left_non_join_columns: Vec<String>,
     right_non_join_columns: Vec<String>,
+    left_non_join_schema: SchemaRef,
     right_non_join_schema: SchemaRef,
     join_type: JoinType,
+    build_on_left: bool,
+}
+
+impl OuterHashJoinParams {
+    /// Whether build side rows are emitted once probing is done, which requires tracking matched build rows in a bitmap.
+    /// Outer joins and anti joins emit the unmatched build rows, while semi joins emit the matched ones.
+    fn emits_build_at_finalize(&self) -> bool {
+        match self.join_type {
+            JoinType::Outer => true,
+            JoinType::Left => self.build_on_left,
+            JoinType::Right => !self.build_on_left,
+            JoinType::RightAnti | JoinType::RightSemi => true,
+            _ => false,
+        }
+    }
+
+    /// Whether matched rows are emitted while probing. Anti and semi joins that build on the preserved side only
+    /// record matches, and emit build rows at finalize.
+    fn emits_matches(&self) -> bool {
+        !matches!(self.join_type, JoinType::RightAnti | JoinType::RightSemi)
+    }
+
+    /// Whether probe side rows without a match must be emitted with nulls for the build side.
+    fn emits_unmatched_probe(&self) -> bool {
+        match self.join_type {
+            JoinType::Outer => true,
+            JoinType::Left => !self.build_on_left,
+            JoinType::Right => self.build_on_left,
+            _ => false,
+        }
+    }
+}
+
+fn non_join_schema(schema: &SchemaRef, common_join_keys: &IndexSet<String>) -> SchemaRef {
+    let fields = schema
+        .fields
+        .values()
+        .filter(|f| !common_join_keys.contains(&f.name))
+        .cloned()
+        .collect();
+    Arc::new(Schema::new(fields).expect("schema should be valid"))
+}
+
+fn null_table(schema: &SchemaRef, len: usize) -> Table {
+    let columns = schema
+        .fields
+        .values()
+        .map(|field| Series::full_null(&field.name, &field.dtype, len))
+        .collect::<Vec<_>>();
+    Table::new_unchecked(schema.clone(), columns, len)
 }
 
 pub(crate) struct OuterHashJoinProbeSink {
@@ -135,29 +186,20 @@ pub(crate) struct OuterHashJoinProbeSink {
 }
 
 impl OuterHashJoinProbeSink {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         probe_on: Vec<ExprRef>,
         left_schema: &SchemaRef,
         right_schema: &SchemaRef,
         join_type: JoinType,
+        build_on_left: bool,
         common_join_keys: IndexSet<String>,
         output_schema: &SchemaRef,
         probe_state_bridge: ProbeStateBridgeRef,
     ) -> Self {
-        let left_non_join_columns = left_schema
-            .fields
-            .keys()
-            .filter(|c| !common_join_keys.contains(*c))
-            .cloned()
-            .collect();
-        let right_non_join_fields = right_schema
-            .fields
-            .values()
-            .filter(|f| !common_join_keys.contains(&f.name))
-            .cloned()
-            .collect();
-        let right_non_join_schema =
-            Arc::new(Schema::new(right_non_join_fields).expect("right schema should be valid"));
+        let left_non_join_schema = non_join_schema(left_schema, &common_join_keys);
+        let right_non_join_schema = non_join_schema(right_schema, &common_join_keys);
+        let left_non_join_columns = left_non_join_schema.fields.keys().cloned().collect();
         let right_non_join_columns = right_non_join_schema.fields.keys().cloned().collect();
         let common_join_keys = common_join_keys.into_iter().collect();
         Self {
@@ -166,27 +208,28 @@ impl OuterHashJoinProbeSink {
                 common_join_keys,
                 left_non_join_columns,
                 right_non_join_columns,
+                left_non_join_schema,
                 right_non_join_schema,
                 join_type,
+                build_on_left,
             }),
             output_schema: output_schema.clone(),
             probe_state_bridge,
         }
     }
 
-    fn probe_left_right(
+    fn probe(
         input: &Arc<MicroPartition>,
         probe_state: &ProbeState,
-        join_type: JoinType,
-        probe_on: &[ExprRef],
-        common_join_keys: &[String],
-        left_non_join_columns: &[String],
-        right_non_join_columns: &[String],
+        mut bitmap_builder: Option<&mut IndexBitmapBuilder>,
+        params: &OuterHashJoinParams,
     ) -> DaftResult<Arc<MicroPartition>> {
         let probe_table = probe_state.get_probeable().clone();
         let tables = probe_state.get_tables().clone();
+        let emits_unmatched_probe = params.emits_unmatched_probe();
 
         let _growables = info_span!("OuterHashJoinProbeSink::build_growables").entered();
+        // Need to set use_validity to true here because we add nulls to the build side
         let mut build_side_growable = GrowableTable::new(
             &tables.iter().collect::<Vec<_>>(),
             true,
@@ -201,23 +244,29 @@ impl OuterHashJoinProbeSink {
         {
             let _loop = info_span!("OuterHashJoinProbeSink::eval_and_probe").entered();
             for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
-                let join_keys = table.eval_expression_list(probe_on)?;
+                let join_keys = table.eval_expression_list(&params.probe_on)?;
                 let idx_mapper = probe_table.probe_indices(&join_keys)?;
+                if emits_unmatched_probe {
+                    // every probe row emits at least one output row
+                    build_side_growable.reserve(table.len());
+                    probe_side_growable.reserve(table.len());
+                }
 
                 for (probe_row_idx, inner_iter) in idx_mapper.make_iter().enumerate() {
                     if let Some(inner_iter) = inner_iter {
                         for (build_side_table_idx, build_row_idx) in inner_iter {
-                            build_side_growable.extend(
-                                build_side_table_idx as usize,
-                                build_row_idx as usize,
-                                1,
-                            );
-                            probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
+                            let build_side_table_idx = build_side_table_idx as usize;
+                            let build_row_idx = build_row_idx as usize;
+                            if let Some(bitmap_builder) = bitmap_builder.as_mut() {
+                                bitmap_builder.mark_used(build_side_table_idx, build_row_idx);
+                            }
+                            build_side_growable.extend_row(build_side_table_idx, build_row_idx);
+                            probe_side_growable.extend_row(probe_side_table_idx, probe_row_idx);
                         }
-                    } else {
+                    } else if emits_unmatched_probe {
                         // if there's no match, we should still emit the probe side and fill the build side with nulls
                         build_side_growable.add_nulls(1);
-                        probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
+                        probe_side_growable.extend_row(probe_side_table_idx, probe_row_idx);
                     }
                 }
             }
@@ -225,17 +274,19 @@ impl OuterHashJoinProbeSink {
         let build_side_table = build_side_growable.build()?;
         let probe_side_table = probe_side_growable.build()?;
 
-        let final_table = if join_type == JoinType::Left {
-            let join_table = probe_side_table.get_columns(common_join_keys)?;
-            let left = probe_side_table.get_columns(left_non_join_columns)?;
-            let right = build_side_table.get_columns(right_non_join_columns)?;
-            join_table.union(&left)?.union(&right)?
+        let join_table = probe_side_table.get_columns(&params.common_join_keys)?;
+        let (left, right) = if params.build_on_left {
+            (
+                build_side_table.get_columns(&params.left_non_join_columns)?,
+                probe_side_table.get_columns(&params.right_non_join_columns)?,
+            )
         } else {
-            let join_table = probe_side_table.get_columns(common_join_keys)?;
-            let left = build_side_table.get_columns(left_non_join_columns)?;
-            let right = probe_side_table.get_columns(right_non_join_columns)?;
-            join_table.union(&left)?.union(&right)?
+            (
+                probe_side_table.get_columns(&params.left_non_join_columns)?,
+                build_side_table.get_columns(&params.right_non_join_columns)?,
+            )
         };
+        let final_table = join_table.union(&left)?.union(&right)?;
         Ok(Arc::new(MicroPartition::new_loaded(
             final_table.schema.clone(),
             Arc::new(vec![final_table]),
@@ -243,73 +294,29 @@ impl OuterHashJoinProbeSink {
         )))
     }
 
-    fn probe_outer(
+    /// Records which build rows are matched by the input without emitting any rows.
+    fn mark_matches(
         input: &Arc<MicroPartition>,
         probe_state: &ProbeState,
         bitmap_builder: &mut IndexBitmapBuilder,
-        probe_on: &[ExprRef],
-        common_join_keys: &[String],
-        left_non_join_columns: &[String],
-        right_non_join_columns: &[String],
-    ) -> DaftResult<Arc<MicroPartition>> {
-        let probe_table = probe_state.get_probeable().clone();
-        let tables = probe_state.get_tables().clone();
-
-        let _growables = info_span!("OuterHashJoinProbeSink::build_growables").entered();
-        // Need to set use_validity to true here because we add nulls to the build side
-        let mut build_side_growable = GrowableTable::new(
-            &tables.iter().collect::<Vec<_>>(),
-            true,
-            tables.iter().map(|t| t.len()).sum(),
-        )?;
-
-        let input_tables = input.get_tables()?;
-        let mut probe_side_growable =
-            GrowableTable::new(&input_tables.iter().collect::<Vec<_>>(), false, input.len())?;
-
-        drop(_growables);
-        {
-            let _loop = info_span!("OuterHashJoinProbeSink::eval_and_probe").entered();
-            for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
-                let join_keys = table.eval_expression_list(probe_on)?;
-                let idx_mapper = probe_table.probe_indices(&join_keys)?;
-
-                for (probe_row_idx, inner_iter) in idx_mapper.make_iter().enumerate() {
-                    if let Some(inner_iter) = inner_iter {
-                        for (build_side_table_idx, build_row_idx) in inner_iter {
-                            let build_side_table_idx = build_side_table_idx as usize;
-                            let build_row_idx = build_row_idx as usize;
-                            bitmap_builder.mark_used(build_side_table_idx, build_row_idx);
-                            build_side_growable.extend(build_side_table_idx, build_row_idx, 1);
-                            probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
-                        }
-                    } else {
-                        // if there's no match, we should still emit the probe side and fill the build side with nulls
-                        build_side_growable.add_nulls(1);
-                        probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
-                    }
-                }
+        params: &OuterHashJoinParams,
+    ) -> DaftResult<()> {
+        let probe_table = probe_state.get_probeable();
+        for table in input.get_tables()?.iter() {
+            let join_keys = table.eval_expression_list(&params.probe_on)?;
+            let idx_mapper = probe_table.probe_indices(&join_keys)?;
+            for (build_side_table_idx, build_row_idx) in idx_mapper.make_iter().flatten().flatten()
+            {
+                bitmap_builder.mark_used(build_side_table_idx as usize, build_row_idx as usize);
             }
         }
-        let build_side_table = build_side_growable.build()?;
-        let probe_side_table = probe_side_growable.build()?;
-
-        let join_table = probe_side_table.get_columns(common_join_keys)?;
-        let left = build_side_table.get_columns(left_non_join_columns)?;
-        let right = probe_side_table.get_columns(right_non_join_columns)?;
-        let final_table = join_table.union(&left)?.union(&right)?;
-        Ok(Arc::new(MicroPartition::new_loaded(
-            final_table.schema.clone(),
-            Arc::new(vec![final_table]),
-            None,
-        )))
+        Ok(())
     }
 
-    async fn finalize_outer(
+    async fn finalize_build_side(
         mut states: Vec<Box<dyn StreamingSinkState>>,
-        common_join_keys: &[String],
-        left_non_join_columns: &[String],
-        right_non_join_schema: &SchemaRef,
+        params: &OuterHashJoinParams,
+        output_schema: SchemaRef,
     ) -> DaftResult<Option<Arc<MicroPartition>>> {
         let mut states_iter = states.iter_mut();
         let first_state = states_iter
@@ -354,23 +361,44 @@ impl OuterHashJoinProbeSink {
         }
         .expect("at least one bitmap should be present");
 
+        // The merged bitmap is set for the build rows that were never matched.
+        let merged_bitmap = if params.join_type == JoinType::RightSemi {
+            IndexBitmap {
+                bitmaps: merged_bitmap.bitmaps.iter().map(|b| !b).collect(),
+            }
+        } else {
+            merged_bitmap
+        };
         let leftovers = merged_bitmap
             .convert_to_boolean_arrays()
             .zip(tables.iter())
             .map(|(bitmap, table)| table.mask_filter(&bitmap.into_series()))
             .collect::<DaftResult<Vec<_>>>()?;
+        if leftovers.is_empty() {
+            return Ok(Some(Arc::new(MicroPartition::empty(Some(output_schema)))));
+        }
 
         let build_side_table = Table::concat(&leftovers)?;
+        if !params.emits_matches() {
+            // Anti and semi joins only output the build side.
+            return Ok(Some(Arc::new(MicroPartition::new_loaded(
+                output_schema,
+                Arc::new(vec![build_side_table]),
+                None,
+            ))));
+        }
 
-        let join_table = build_side_table.get_columns(common_join_keys)?;
-        let left = build_side_table.get_columns(left_non_join_columns)?;
-        let right = {
-            let columns = right_non_join_schema
-                .fields
-                .values()
-                .map(|field| Series::full_null(&field.name, &field.dtype, left.len()))
-                .collect::<Vec<_>>();
-            Table::new_unchecked(right_non_join_schema.clone(), columns, left.len())
+        let join_table = build_side_table.get_columns(&params.common_join_keys)?;
+        let (left, right) = if params.build_on_left {
+            (
+                build_side_table.get_columns(&params.left_non_join_columns)?,
+                null_table(&params.right_non_join_schema, build_side_table.len()),
+            )
+        } else {
+            (
+                null_table(&params.left_non_join_schema, build_side_table.len()),
+                build_side_table.get_columns(&params.right_non_join_columns)?,
+            )
         };
         let final_table = join_table.union(&left)?.union(&right)?;
         Ok(Some(Arc::new(MicroPartition::new_loaded(
@@ -403,36 +431,28 @@ impl StreamingSink for OuterHashJoinProbeSink {
                     .expect("OuterHashJoinProbeSink should have OuterHashJoinProbeState");
                 let probe_state = outer_join_state.get_or_build_probe_state().await;
                 let out = match params.join_type {
-                    JoinType::Left | JoinType::Right => Self::probe_left_right(
-                        &input,
-                        &probe_state,
-                        params.join_type,
-                        &params.probe_on,
-                        &params.common_join_keys,
-                        &params.left_non_join_columns,
-                        &params.right_non_join_columns,
-                    ),
-                    JoinType::Outer => {
+                    JoinType::Left | JoinType::Right | JoinType::Outer => {
+                        let bitmap_builder = if params.emits_build_at_finalize() {
+                            outer_join_state.get_or_build_bitmap().await.as_mut()
+                        } else {
+                            None
+                        };
+                        Some(Self::probe(&input, &probe_state, bitmap_builder, &params)?)
+                    }
+                    JoinType::RightAnti | JoinType::RightSemi => {
                         let bitmap_builder = outer_join_state
                             .get_or_build_bitmap()
                             .await
                             .as_mut()
                             .expect("bitmap should be set");
-                        Self::probe_outer(
-                            &input,
-                            &probe_state,
-                            bitmap_builder,
-                            &params.probe_on,
-                            &params.common_join_keys,
-                            &params.left_non_join_columns,
-                            &params.right_non_join_columns,
-                        )
+                        Self::mark_matches(&input, &probe_state, bitmap_builder, &params)?;
+                        None
                     }
                     _ => unreachable!(
-                        "Only Left, Right, and Outer joins are supported in OuterHashJoinProbeSink"
+                        "Only Left, Right, Outer, RightAnti, and RightSemi joins are supported in OuterHashJoinProbeSink"
                     ),
-                }?;
-                Ok((state, StreamingSinkOutput::NeedMoreInput(Some(out))))
+                };
+                Ok((state, StreamingSinkOutput::NeedMoreInput(out)))
             })
             .into()
     }
@@ -444,7 +464,7 @@ impl StreamingSink for OuterHashJoinProbeSink {
     fn make_state(&self) -> Box<dyn StreamingSinkState> {
         Box::new(OuterHashJoinState::Building(
             self.probe_state_bridge.clone(),
-            self.params.join_type == JoinType::Outer,
+            self.params.emits_build_at_finalize(),
         ))
     }
 
@@ -453,18 +473,13 @@ impl StreamingSink for OuterHashJoinProbeSink {
         states: Vec<Box<dyn StreamingSinkState>>,
         runtime_ref: &RuntimeRef,
     ) -> StreamingSinkFinalizeResult {
-        if self.params.join_type == JoinType::Outer {
+        if self.params.emits_build_at_finalize() {
             let params = self.params.clone();
+            let output_schema = self.output_schema.clone();
             runtime_ref
-                .spawn(async move {
-                    Self::finalize_outer(
-                        states,
-                        &params.common_join_keys,
-                        &params.left_non_join_columns,
-                        &params.right_non_join_schema,
-                    )
-                    .await
-                })
+                .spawn(
+                    async move { Self::finalize_build_side(states, &params, output_schema).await },
+                )
                 .into()
         } else {
             Ok(None).into()
@@ -487,3 +502,201 @@ impl StreamingSink for OuterHashJoinProbeSink {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+    use daft_logical_plan::JoinType;
+    use daft_micropartition::MicroPartition;
+    use daft_table::{make_probeable_builder, ProbeState, Table};
+    use indexmap::IndexSet;
+
+    use super::{OuterHashJoinProbeSink, OuterHashJoinState};
+    use crate::sinks::{hash_join_build::ProbeStateBridge, streaming_sink::StreamingSink};
+
+    fn make_table(key: Vec<i64>, name: &str, values: &[&str]) -> DaftResult<Table> {
+        Table::from_nonempty_columns(vec![
+            Int64Array::from(("key", key)).into_series(),
+            Utf8Array::from((name, values)).into_series(),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_right_join_with_right_build_side_emits_unmatched_rows() -> DaftResult<()> {
+        let left = make_table(vec![2, 4], "l", &["x", "y"])?;
+        let right = make_table(vec![1, 2, 3], "r", &["a", "b", "c"])?;
+        let output_schema: SchemaRef = Schema::new(vec![
+            Field::new("key", DataType::Int64),
+            Field::new("l", DataType::Utf8),
+            Field::new("r", DataType::Utf8),
+        ])?
+        .into();
+
+        // Build on the right side so that the preserved side of the right join is the build side.
+        let build_keys = right.get_columns(&["key"])?;
+        let mut builder = make_probeable_builder(build_keys.schema.clone(), None, true)?;
+        builder.add_table(&build_keys)?;
+        let bridge = ProbeStateBridge::new();
+        bridge.set_probe_state(Arc::new(ProbeState::new(
+            builder.build(),
+            Arc::new(vec![right.clone()]),
+        )));
+
+        let sink = OuterHashJoinProbeSink::new(
+            vec![col("key")],
+            &left.schema,
+            &right.schema,
+            JoinType::Right,
+            false,
+            IndexSet::from(["key".to_string()]),
+            &output_schema,
+            bridge,
+        );
+        let mut state = sink.make_state();
+        let join_state = state
+            .as_any_mut()
+            .downcast_mut::<OuterHashJoinState>()
+            .unwrap();
+        let probe_state = join_state.get_or_build_probe_state().await;
+        let input = Arc::new(MicroPartition::new_loaded(
+            left.schema.clone(),
+            Arc::new(vec![left.clone()]),
+            None,
+        ));
+        let matched = OuterHashJoinProbeSink::probe(
+            &input,
+            &probe_state,
+            join_state.get_or_build_bitmap().await.as_mut(),
+            &sink.params,
+        )?;
+        // The unmatched left row is dropped since the left side is not preserved.
+        let matched = Table::concat(matched.get_tables()?.as_slice())?;
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched.get_column("key")?.i64()?.get(0), Some(2));
+        assert_eq!(matched.get_column("r")?.utf8()?.get(0), Some("b"));
+
+        let unmatched = OuterHashJoinProbeSink::finalize_build_side(
+            vec![state],
+            &sink.params,
+            output_schema.clone(),
+        )
+        .await?
+        .expect("right join should emit unmatched build rows");
+        let unmatched = Table::concat(unmatched.get_tables()?.as_slice())?;
+        assert_eq!(unmatched.schema, output_schema);
+        assert_eq!(unmatched.get_column("key")?.i64()?.as_slice(), &[1, 3]);
+        assert_eq!(unmatched.get_column("r")?.utf8()?.get(1), Some("c"));
+        let l = unmatched.get_column("l")?;
+        assert!(!l.is_valid(0) && !l.is_valid(1));
+        Ok(())
+    }
+
+    async fn right_anti_semi_join(join_type: JoinType) -> DaftResult<Table> {
+        let left = make_table(vec![2, 4, 2], "l", &["x", "y", "z"])?;
+        let right = make_table(vec![1, 2, 3], "r", &["a", "b", "c"])?;
+
+        let build_keys = right.get_columns(&["key"])?;
+        let mut builder = make_probeable_builder(build_keys.schema.clone(), None, true)?;
+        builder.add_table(&build_keys)?;
+        let bridge = ProbeStateBridge::new();
+        bridge.set_probe_state(Arc::new(ProbeState::new(
+            builder.build(),
+            Arc::new(vec![right.clone()]),
+        )));
+
+        let sink = OuterHashJoinProbeSink::new(
+            vec![col("key")],
+            &left.schema,
+            &right.schema,
+            join_type,
+            false,
+            IndexSet::from(["key".to_string()]),
+            &right.schema,
+            bridge,
+        );
+        // Split the probe side across two states so that their bitmaps are merged at finalize.
+        let mut states = vec![];
+        for probe in [left.head(1)?, left.slice(1, 3)?] {
+            let mut state = sink.make_state();
+            let join_state = state
+                .as_any_mut()
+                .downcast_mut::<OuterHashJoinState>()
+                .unwrap();
+            let probe_state = join_state.get_or_build_probe_state().await;
+            let input = Arc::new(MicroPartition::new_loaded(
+                probe.schema.clone(),
+                Arc::new(vec![probe]),
+                None,
+            ));
+            OuterHashJoinProbeSink::mark_matches(
+                &input,
+                &probe_state,
+                join_state.get_or_build_bitmap().await.as_mut().unwrap(),
+                &sink.params,
+            )?;
+            states.push(state);
+        }
+
+        let output =
+            OuterHashJoinProbeSink::finalize_build_side(states, &sink.params, right.schema.clone())
+                .await?
+                .expect("right anti and semi joins should emit build rows");
+        let output = Table::concat(output.get_tables()?.as_slice())?;
+        assert_eq!(output.schema, right.schema);
+        Ok(output)
+    }
+
+    #[tokio::test]
+    async fn test_right_anti_join_emits_unmatched_build_rows() -> DaftResult<()> {
+        let output = right_anti_semi_join(JoinType::RightAnti).await?;
+        assert_eq!(output.get_column("key")?.i64()?.as_slice(), &[1, 3]);
+        assert_eq!(output.get_column("r")?.utf8()?.get(0), Some("a"));
+        assert_eq!(output.get_column("r")?.utf8()?.get(1), Some("c"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_right_semi_join_emits_matched_build_rows_once() -> DaftResult<()> {
+        let output = right_anti_semi_join(JoinType::RightSemi).await?;
+        assert_eq!(output.get_column("key")?.i64()?.as_slice(), &[2]);
+        assert_eq!(output.get_column("r")?.utf8()?.get(0), Some("b"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unmatched_sides_per_join_type() {
+        let schema: SchemaRef = Schema::new(vec![Field::new("key", DataType::Int64)])
+            .unwrap()
+            .into();
+        let make_sink = |join_type, build_on_left| {
+            OuterHashJoinProbeSink::new(
+                vec![col("key")],
+                &schema,
+                &schema,
+                join_type,
+                build_on_left,
+                IndexSet::from(["key".to_string()]),
+                &schema,
+                ProbeStateBridge::new(),
+            )
+        };
+        for (join_type, build_on_left, unmatched_build, unmatched_probe) in [
+            (JoinType::Left, false, false, true),
+            (JoinType::Left, true, true, false),
+            (JoinType::Right, true, false, true),
+            (JoinType::Right, false, true, false),
+            (JoinType::Outer, true, true, true),
+            (JoinType::Outer, false, true, true),
+            (JoinType::RightAnti, false, true, false),
+            (JoinType::RightSemi, false, true, false),
+        ] {
+            let sink = make_sink(join_type, build_on_left);
+            assert_eq!(sink.params.emits_build_at_finalize(), unmatched_build);
+            assert_eq!(sink.params.emits_unmatched_probe(), unmatched_probe);
+        }
+    }
+}
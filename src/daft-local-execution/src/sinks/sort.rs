@@ -47,18 +47,25 @@ struct SortParams {
     sort_by: Vec<ExprRef>,
     descending: Vec<bool>,
     nulls_first: Vec<bool>,
+    limit: Option<usize>,
 }
 pub struct SortSink {
     params: Arc<SortParams>,
 }
 
 impl SortSink {
-    pub fn new(sort_by: Vec<ExprRef>, descending: Vec<bool>, nulls_first: Vec<bool>) -> Self {
+    pub fn new(
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        limit: Option<usize>,
+    ) -> Self {
         Self {
             params: Arc::new(SortParams {
                 sort_by,
                 descending,
                 nulls_first,
+                limit,
             }),
         }
     }
@@ -72,6 +79,19 @@ impl BlockingSink for SortSink {
         mut state: Box<dyn BlockingSinkState>,
         _runtime_ref: &RuntimeRef,
     ) -> BlockingSinkSinkResult {
+        // With a limit, rows outside each input's own top rows can never make the final result.
+        let input = match self.params.limit {
+            Some(limit) => match input.top_n(
+                &self.params.sort_by,
+                &self.params.descending,
+                &self.params.nulls_first,
+                limit,
+            ) {
+                Ok(top) => Arc::new(top),
+                Err(e) => return Err(e).into(),
+            },
+            None => input,
+        };
         state
             .as_any_mut()
             .downcast_mut::<SortState>()
@@ -97,11 +117,17 @@ impl BlockingSink for SortSink {
                     state.finalize()
                 });
                 let concated = MicroPartition::concat(parts)?;
-                let sorted = Arc::new(concated.sort(
-                    &params.sort_by,
-                    &params.descending,
-                    &params.nulls_first,
-                )?);
+                let sorted = Arc::new(match params.limit {
+                    Some(limit) => concated.top_n(
+                        &params.sort_by,
+                        &params.descending,
+                        &params.nulls_first,
+                        limit,
+                    )?,
+                    None => {
+                        concated.sort(&params.sort_by, &params.descending, &params.nulls_first)?
+                    }
+                });
                 Ok(Some(sorted))
             })
             .into()
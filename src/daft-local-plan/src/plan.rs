@@ -33,6 +33,7 @@ pub enum LocalPhysicalPlan {
     Pivot(Pivot),
     Concat(Concat),
     HashJoin(HashJoin),
+    CrossJoin(CrossJoin),
     // SortMergeJoin(SortMergeJoin),
     // BroadcastJoin(BroadcastJoin),
     PhysicalWrite(PhysicalWrite),
@@ -227,6 +228,7 @@ impl LocalPhysicalPlan {
         sort_by: Vec<ExprRef>,
         descending: Vec<bool>,
         nulls_first: Vec<bool>,
+        limit: Option<usize>,
     ) -> LocalPhysicalPlanRef {
         let schema = input.schema().clone();
         Self::Sort(Sort {
@@ -234,6 +236,7 @@ impl LocalPhysicalPlan {
             sort_by,
             nulls_first,
             descending,
+            limit,
             schema,
             plan_stats: PlanStats {},
         })
@@ -293,6 +296,19 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn cross_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        schema: SchemaRef,
+    ) -> LocalPhysicalPlanRef {
+        Self::CrossJoin(CrossJoin {
+            left,
+            right,
+            schema,
+        })
+        .arced()
+    }
+
     pub(crate) fn concat(
         input: LocalPhysicalPlanRef,
         other: LocalPhysicalPlanRef,
@@ -371,6 +387,7 @@ impl LocalPhysicalPlan {
             | Self::Sort(Sort { schema, .. })
             | Self::Sample(Sample { schema, .. })
             | Self::HashJoin(HashJoin { schema, .. })
+            | Self::CrossJoin(CrossJoin { schema, .. })
             | Self::Explode(Explode { schema, .. })
             | Self::Unpivot(Unpivot { schema, .. })
             | Self::Concat(Concat { schema, .. }) => schema,
@@ -446,6 +463,8 @@ pub struct Sort {
     pub sort_by: Vec<ExprRef>,
     pub descending: Vec<bool>,
     pub nulls_first: Vec<bool>,
+    /// Set when a limit immediately follows this sort, so only the top `limit` rows are needed.
+    pub limit: Option<usize>,
     pub schema: SchemaRef,
     pub plan_stats: PlanStats,
 }
@@ -519,6 +538,13 @@ pub struct HashJoin {
     pub schema: SchemaRef,
 }
 
+#[derive(Debug)]
+pub struct CrossJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub schema: SchemaRef,
+}
+
 #[derive(Debug)]
 pub struct Concat {
     pub input: LocalPhysicalPlanRef,
@@ -33,7 +33,17 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             Ok(LocalPhysicalPlan::filter(input, filter.predicate.clone()))
         }
         LogicalPlan::Limit(limit) => {
-            let input = translate(&limit.input)?;
+            let input = match limit.input.as_ref() {
+                // A sort followed by a limit only needs to keep the top rows.
+                LogicalPlan::Sort(sort) => LocalPhysicalPlan::sort(
+                    translate(&sort.input)?,
+                    sort.sort_by.clone(),
+                    sort.descending.clone(),
+                    sort.nulls_first.clone(),
+                    usize::try_from(limit.limit).ok(),
+                ),
+                _ => translate(&limit.input)?,
+            };
             Ok(LocalPhysicalPlan::limit(input, limit.limit))
         }
         LogicalPlan::Project(project) => {
@@ -108,15 +118,21 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 sort.sort_by.clone(),
                 sort.descending.clone(),
                 sort.nulls_first.clone(),
+                None,
             ))
         }
         LogicalPlan::Join(join) => {
-            if join.left_on.is_empty()
-                && join.right_on.is_empty()
-                && join.join_type == JoinType::Inner
+            if join.join_type == JoinType::Cross
+                || (join.left_on.is_empty()
+                    && join.right_on.is_empty()
+                    && join.join_type == JoinType::Inner)
             {
-                return Err(DaftError::not_implemented(
-                    "Joins without join conditions (cross join) are not supported yet",
+                let left = translate(&join.left)?;
+                let right = translate(&join.right)?;
+                return Ok(LocalPhysicalPlan::cross_join(
+                    left,
+                    right,
+                    join.output_schema.clone(),
                 ));
             }
             if join.join_strategy.is_some_and(|x| x != JoinStrategy::Hash) {
@@ -213,3 +229,71 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
         _ => todo!("{} not yet implemented", plan.name()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_scan_info::{test::DummyScanOperator, ScanOperatorRef};
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+    use daft_logical_plan::LogicalPlanBuilder;
+
+    use super::translate;
+    use crate::LocalPhysicalPlan;
+
+    fn dummy_scan_node() -> DaftResult<LogicalPlanBuilder> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("x", DataType::Int64),
+            Field::new("y", DataType::Utf8),
+        ])?);
+        LogicalPlanBuilder::table_scan(
+            ScanOperatorRef(Arc::new(DummyScanOperator {
+                schema,
+                num_scan_tasks: 1,
+            })),
+            None,
+        )
+    }
+
+    /// `SELECT sum(x)` has no group-by keys and should be lowered to an ungrouped aggregate.
+    #[test]
+    fn test_translate_aggregate_without_groupby() -> DaftResult<()> {
+        let plan = dummy_scan_node()?
+            .aggregate(vec![col("x").sum()], vec![])?
+            .build();
+        let local_plan = translate(&plan)?;
+        assert!(matches!(
+            local_plan.as_ref(),
+            LocalPhysicalPlan::UnGroupedAggregate(..)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_translate_aggregate_with_groupby() -> DaftResult<()> {
+        let plan = dummy_scan_node()?
+            .aggregate(vec![col("x").sum()], vec![col("y")])?
+            .build();
+        let local_plan = translate(&plan)?;
+        assert!(matches!(
+            local_plan.as_ref(),
+            LocalPhysicalPlan::HashAggregate(..)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_translate_sample_propagates_seed() -> DaftResult<()> {
+        let plan = dummy_scan_node()?.sample(0.5, true, Some(42))?.build();
+        let local_plan = translate(&plan)?;
+        let LocalPhysicalPlan::Sample(sample) = local_plan.as_ref() else {
+            panic!("expected a Sample node, got: {}", local_plan.name());
+        };
+        assert_eq!(sample.fraction, 0.5);
+        assert!(sample.with_replacement);
+        assert_eq!(sample.seed, Some(42));
+        Ok(())
+    }
+}
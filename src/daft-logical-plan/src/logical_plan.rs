@@ -1,13 +1,15 @@
-use std::{num::NonZeroUsize, sync::Arc};
+use std::{collections::HashMap, num::NonZeroUsize, sync::Arc};
 
 use common_display::ascii::AsciiTreeDisplay;
-use common_error::DaftError;
+use common_error::{DaftError, DaftResult};
+use common_treenode::{Transformed, TreeNode};
 use daft_dsl::{optimization::get_required_columns, SubqueryPlan};
 use daft_schema::schema::SchemaRef;
 use indexmap::IndexSet;
 use snafu::Snafu;
 
 pub use crate::ops::*;
+use crate::source_info::{PlaceHolderInfo, SourceInfo};
 
 /// Logical plan for a Daft query.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -324,6 +326,42 @@ impl LogicalPlan {
         self.fmt_tree_indent_style(0, &mut s).unwrap();
         s
     }
+
+    /// Renumbers placeholder source ids in the order they are first visited, top down.
+    ///
+    /// Placeholder ids come from a process-wide counter, so otherwise identical plans built at
+    /// different times compare and hash differently. Normalized plans are suitable as cache keys
+    /// or in golden tests, but shouldn't be executed since the ids no longer match any
+    /// materialized results.
+    pub fn with_normalized_placeholder_ids(self: Arc<Self>) -> DaftResult<Arc<Self>> {
+        let mut ids = HashMap::new();
+        let normalized = self.transform_down(|node| {
+            let Self::Source(source) = node.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            let SourceInfo::PlaceHolder(info) = source.source_info.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            let next_id = ids.len();
+            let source_id = *ids.entry(info.source_id).or_insert(next_id);
+            if source_id == info.source_id {
+                return Ok(Transformed::no(node));
+            }
+            let source_info = SourceInfo::PlaceHolder(PlaceHolderInfo {
+                source_schema: info.source_schema.clone(),
+                clustering_spec: info.clustering_spec.clone(),
+                source_id,
+            });
+            Ok(Transformed::yes(
+                Self::Source(Source::new(
+                    source.output_schema.clone(),
+                    source_info.into(),
+                ))
+                .arced(),
+            ))
+        })?;
+        Ok(normalized.data)
+    }
 }
 
 impl SubqueryPlan for LogicalPlan {
@@ -401,3 +439,73 @@ impl_from_data_struct_for_logical_plan!(Join);
 impl_from_data_struct_for_logical_plan!(Sink);
 impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+        sync::Arc,
+    };
+
+    use common_error::DaftResult;
+    use daft_dsl::{col, lit};
+    use daft_schema::{dtype::DataType, field::Field, schema::Schema};
+
+    use crate::{
+        ops::Source,
+        source_info::{PlaceHolderInfo, SourceInfo},
+        ClusteringSpec, JoinType, LogicalPlan, LogicalPlanBuilder, LogicalPlanRef,
+    };
+
+    fn placeholder() -> LogicalPlanBuilder {
+        let schema = Arc::new(
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("text", DataType::Utf8),
+            ])
+            .unwrap(),
+        );
+        let info = PlaceHolderInfo::new(schema.clone(), Arc::new(ClusteringSpec::unknown()));
+        let plan =
+            LogicalPlan::Source(Source::new(schema, Arc::new(SourceInfo::PlaceHolder(info))))
+                .arced();
+        LogicalPlanBuilder::new(plan, None)
+    }
+
+    fn build_plan() -> DaftResult<LogicalPlanRef> {
+        let right = placeholder();
+        Ok(placeholder()
+            .filter(col("id").gt(lit(1)))?
+            .join(
+                right,
+                vec![col("id")],
+                vec![col("id")],
+                JoinType::Inner,
+                None,
+                None,
+                Some("right."),
+                false,
+            )?
+            .build())
+    }
+
+    fn hash_of(plan: &LogicalPlan) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        plan.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn identical_plans_hash_equal_after_normalizing_placeholder_ids() -> DaftResult<()> {
+        let first = build_plan()?;
+        let second = build_plan()?;
+        assert_ne!(first, second);
+
+        let first = first.with_normalized_placeholder_ids()?;
+        let second = second.with_normalized_placeholder_ids()?;
+        assert_eq!(first, second);
+        assert_eq!(hash_of(&first), hash_of(&second));
+        Ok(())
+    }
+}
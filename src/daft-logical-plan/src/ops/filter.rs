@@ -20,7 +20,8 @@ pub struct Filter {
 
 impl Filter {
     pub(crate) fn try_new(input: Arc<LogicalPlan>, predicate: ExprRef) -> Result<Self> {
-        let expr_resolver = ExprResolver::default();
+        // Stateful UDFs in the predicate are split out into ActorPoolProjects by the optimizer.
+        let expr_resolver = ExprResolver::builder().allow_stateful_udf(true).build();
 
         let (predicate, field) = expr_resolver
             .resolve_single(predicate, &input.schema())
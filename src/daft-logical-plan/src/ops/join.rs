@@ -115,10 +115,31 @@ impl Join {
             }
         }
 
-        if matches!(join_type, JoinType::Anti | JoinType::Semi) {
-            // The output schema is the same as the left input schema for anti and semi joins.
+        if join_type == JoinType::AsOf {
+            return Err(DaftError::not_implemented(
+                "Asof joins are not yet supported in logical plans",
+            ))
+            .context(CreationSnafu);
+        }
+
+        if join_type == JoinType::Cross && !(left_on.is_empty() && right_on.is_empty()) {
+            return Err(DaftError::ValueError(
+                "Cross joins do not take join keys".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
 
-            let output_schema = left.schema();
+        if matches!(
+            join_type,
+            JoinType::Anti | JoinType::Semi | JoinType::RightAnti | JoinType::RightSemi
+        ) {
+            // The output schema is the same as the preserved input schema for anti and semi joins.
+
+            let output_schema = if matches!(join_type, JoinType::RightAnti | JoinType::RightSemi) {
+                right.schema()
+            } else {
+                left.schema()
+            };
 
             Ok(Self {
                 left,
@@ -43,9 +43,14 @@ impl Source {
                 ));
                 res.extend(pushdowns.multiline_display());
             }
-            SourceInfo::InMemory(InMemoryInfo { num_partitions, .. }) => {
+            SourceInfo::InMemory(InMemoryInfo {
+                num_partitions,
+                num_rows,
+                ..
+            }) => {
                 res.push("Source:".to_string());
                 res.push(format!("Number of partitions = {}", num_partitions));
+                res.push(format!("Number of rows = {}", num_rows));
             }
             SourceInfo::PlaceHolder(PlaceHolderInfo {
                 source_id,
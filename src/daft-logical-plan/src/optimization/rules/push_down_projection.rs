@@ -570,26 +570,33 @@ impl PushDownProjection {
         // then explicitly create a projection to do so.
         // this is the case for semi and anti joins.
 
-        if matches!(join.join_type, JoinType::Anti | JoinType::Semi) {
+        // For right anti and semi joins, it is the left side that is pruned.
+        let pruned_side = match join.join_type {
+            JoinType::Anti | JoinType::Semi => Some(1),
+            JoinType::RightAnti | JoinType::RightSemi => Some(0),
+            _ => None,
+        };
+        if let Some(pruned_side) = pruned_side {
             let required_cols = plan.required_columns();
-            let right_required_cols = required_cols
-                .get(1)
+            let pruned_required_cols = required_cols
+                .get(pruned_side)
                 .expect("we expect 2 set of required columns for join");
-            let right_schema = join.right.schema();
+            let children = [join.left.clone(), join.right.clone()];
+            let pruned_schema = children[pruned_side].schema();
 
-            if right_required_cols.len() < right_schema.fields.len() {
+            if pruned_required_cols.len() < pruned_schema.fields.len() {
                 let new_subprojection: LogicalPlan = {
-                    let pushdown_column_exprs = right_required_cols
+                    let pushdown_column_exprs = pruned_required_cols
                         .iter()
                         .map(|s| col(s.as_str()))
                         .collect::<Vec<_>>();
 
-                    Project::try_new(join.right.clone(), pushdown_column_exprs)?.into()
+                    Project::try_new(children[pruned_side].clone(), pushdown_column_exprs)?.into()
                 };
 
-                let new_join = plan
-                    .with_new_children(&[(join.left).clone(), new_subprojection.into()])
-                    .arced();
+                let mut new_children = children;
+                new_children[pruned_side] = new_subprojection.into();
+                let new_join = plan.with_new_children(&new_children).arced();
 
                 Ok(self
                     .try_optimize_node(new_join.clone())?
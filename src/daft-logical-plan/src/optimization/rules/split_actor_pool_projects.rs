@@ -14,7 +14,7 @@ use itertools::Itertools;
 
 use super::OptimizerRule;
 use crate::{
-    ops::{ActorPoolProject, Project},
+    ops::{ActorPoolProject, Filter, Project},
     LogicalPlan,
 };
 
@@ -29,6 +29,8 @@ impl SplitActorPoolProjects {
 
 /// Implement SplitActorPoolProjects as an OptimizerRule
 /// * Splits PROJECT nodes into chains of (PROJECT -> ...ACTOR_POOL_PROJECTS -> PROJECT) ...
+/// * Rewrites FILTER nodes with StatefulUDFs in their predicate into (PROJECT -> FILTER -> PROJECT), where the first
+///   PROJECT materializes the predicate as a column and is then split as above
 /// * Resultant PROJECT nodes will never contain any StatefulUDF expressions
 /// * Each ACTOR_POOL_PROJECT node only contains a single StatefulUDF expression
 ///
@@ -123,6 +125,7 @@ impl OptimizerRule for SplitActorPoolProjects {
     fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
         plan.transform_down(|node| match node.as_ref() {
             LogicalPlan::Project(projection) => try_optimize_project(projection, node.clone()),
+            LogicalPlan::Filter(filter) => try_optimize_filter(filter, node.clone()),
             _ => Ok(Transformed::no(node)),
         })
     }
@@ -524,6 +527,52 @@ fn recursive_optimize_project(
     Ok(Transformed::yes(final_selection_project))
 }
 
+/// Name of the intermediate column that a FILTER's predicate is materialized into.
+const FILTER_PREDICATE_COLUMN_NAME: &str = "__SplitActorPoolProjectsFilterPredicate__";
+
+/// Rewrites a FILTER whose predicate contains StatefulUDFs as:
+///
+/// PROJECT(col(*), predicate) -> FILTER(col(predicate)) -> PROJECT(col(*))
+///
+/// The first PROJECT is visited next by the top-down traversal and split into ACTOR_POOL_PROJECTs as usual.
+fn try_optimize_filter(
+    filter: &Filter,
+    plan: Arc<LogicalPlan>,
+) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+    if !has_stateful_udf(&filter.predicate) {
+        return Ok(Transformed::no(plan));
+    }
+
+    let passthrough_columns = filter
+        .input
+        .schema()
+        .names()
+        .into_iter()
+        .map(|name| Expr::Column(name.into()).arced())
+        .collect::<Vec<_>>();
+
+    let predicate_project = LogicalPlan::Project(Project::try_new(
+        filter.input.clone(),
+        passthrough_columns
+            .iter()
+            .cloned()
+            .chain(iter::once(
+                filter.predicate.alias(FILTER_PREDICATE_COLUMN_NAME),
+            ))
+            .collect(),
+    )?)
+    .arced();
+    let new_filter = LogicalPlan::Filter(Filter::try_new(
+        predicate_project,
+        Expr::Column(FILTER_PREDICATE_COLUMN_NAME.into()).arced(),
+    )?)
+    .arced();
+    let final_selection_project =
+        LogicalPlan::Project(Project::try_new(new_filter, passthrough_columns)?).arced();
+
+    Ok(Transformed::yes(final_selection_project))
+}
+
 #[inline]
 fn has_stateful_udf(e: &ExprRef) -> bool {
     e.exists(|e| {
@@ -543,6 +592,7 @@ mod tests {
 
     use common_error::DaftResult;
     use common_resource_request::ResourceRequest;
+    use common_treenode::{TreeNode, TreeNodeRecursion};
     use daft_core::prelude::*;
     use daft_dsl::{
         col,
@@ -550,14 +600,17 @@ mod tests {
             python::{PythonUDF, StatefulPythonUDF, UDFRuntimeBinding},
             FunctionExpr,
         },
-        Expr, ExprRef,
+        lit, Expr, ExprRef,
     };
     use test_log::test;
 
-    use super::SplitActorPoolProjects;
+    use super::{has_stateful_udf, SplitActorPoolProjects};
     use crate::{
-        ops::{ActorPoolProject, Project},
-        optimization::{rules::PushDownProjection, test::assert_optimized_plan_with_rules_eq},
+        ops::{ActorPoolProject, Filter, Project},
+        optimization::{
+            rules::{OptimizerRule, PushDownProjection},
+            test::assert_optimized_plan_with_rules_eq,
+        },
         test::{dummy_scan_node, dummy_scan_operator},
         LogicalPlan,
     };
@@ -642,6 +695,76 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_actor_pool_project_takes_udf_concurrency_and_resources() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Utf8)]);
+        let scan_plan = dummy_scan_node(scan_op);
+        let project_plan = scan_plan
+            .with_columns(vec![create_stateful_udf(vec![col("a")]).alias("b")])?
+            .build();
+
+        let optimized = SplitActorPoolProjects {}.try_optimize(project_plan)?.data;
+        let LogicalPlan::Project(Project { input, .. }) = optimized.as_ref() else {
+            panic!(
+                "Expected a Project at the root, got:\n{}",
+                optimized.repr_ascii(false)
+            );
+        };
+        let LogicalPlan::ActorPoolProject(actor_pool_project) = input.as_ref() else {
+            panic!(
+                "Expected an ActorPoolProject, got:\n{}",
+                input.repr_ascii(false)
+            );
+        };
+        assert_eq!(actor_pool_project.concurrency(), 8);
+        assert_eq!(
+            actor_pool_project.resource_request(),
+            Some(create_resource_request())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_with_stateful_udf_predicate() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Utf8)]);
+        let scan_plan = dummy_scan_node(scan_op);
+        let filter_plan = scan_plan
+            .filter(create_stateful_udf(vec![col("a")]).eq(lit(1)))?
+            .build();
+
+        // Filter(foo(col("a")) == 1)
+        // -->
+        // Project(col("a"), foo(col("a")) == 1) --> Filter(col(predicate)) --> Project(col("a"))
+        // with the first Project further split into an ActorPoolProject for foo.
+        let optimized = SplitActorPoolProjects {}
+            .try_optimize(filter_plan.clone())?
+            .data;
+        assert_eq!(optimized.schema(), filter_plan.schema());
+
+        let mut num_actor_pool_projects = 0;
+        let mut filter_predicates = vec![];
+        optimized.apply(|node| {
+            match node.as_ref() {
+                LogicalPlan::ActorPoolProject(..) => num_actor_pool_projects += 1,
+                LogicalPlan::Project(Project { projection, .. }) => {
+                    assert!(!projection.iter().any(has_stateful_udf));
+                }
+                LogicalPlan::Filter(Filter { predicate, .. }) => {
+                    filter_predicates.push(predicate.clone());
+                }
+                _ => {}
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        assert_eq!(num_actor_pool_projects, 1);
+        assert_eq!(
+            filter_predicates,
+            vec![col("__SplitActorPoolProjectsFilterPredicate__")]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_multiple_with_column_parallel() -> DaftResult<()> {
         let scan_op = dummy_scan_operator(vec![
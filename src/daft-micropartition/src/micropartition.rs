@@ -94,7 +94,7 @@ pub struct MicroPartition {
 ///
 /// * `scan_task` - a batch of ScanTasks to materialize as Tables
 /// * `io_stats` - an optional IOStats object to record the IO operations performed
-fn materialize_scan_task(
+pub(crate) fn materialize_scan_task(
     scan_task: Arc<ScanTask>,
     io_stats: Option<IOStatsRef>,
 ) -> crate::Result<(Vec<Table>, SchemaRef)> {
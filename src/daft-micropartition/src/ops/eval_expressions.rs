@@ -1,7 +1,7 @@
 use std::{collections::HashSet, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
-use daft_core::prelude::Schema;
+use daft_core::prelude::{DataType, Field, Schema};
 use daft_dsl::ExprRef;
 use daft_io::IOStatsContext;
 use daft_stats::{ColumnRangeStatistics, TableStatistics};
@@ -55,14 +55,38 @@ impl MicroPartition {
     }
 
     pub fn explode(&self, exprs: &[ExprRef]) -> DaftResult<Self> {
+        self.explode_impl(exprs, None)
+    }
+
+    pub fn explode_with_index(&self, exprs: &[ExprRef], index_column: &str) -> DaftResult<Self> {
+        self.explode_impl(exprs, Some(index_column))
+    }
+
+    fn explode_impl(&self, exprs: &[ExprRef], index_column: Option<&str>) -> DaftResult<Self> {
         let io_stats = IOStatsContext::new("MicroPartition::explode");
 
         let tables = self.tables_or_read(io_stats)?;
         let evaluated_tables = tables
             .iter()
-            .map(|t| t.explode(exprs))
+            .map(|t| match index_column {
+                Some(index_column) => t.explode_with_index(exprs, index_column),
+                None => t.explode(exprs),
+            })
             .collect::<DaftResult<Vec<_>>>()?;
-        let expected_new_columns = infer_schema(exprs, &self.schema)?;
+        let mut expected_new_columns = infer_schema(exprs, &self.schema)?;
+        if let Some(index_column) = index_column {
+            if self.schema.fields.contains_key(index_column)
+                || expected_new_columns.fields.contains_key(index_column)
+            {
+                return Err(DaftError::ValueError(format!(
+                    "Explode index column {index_column} conflicts with an existing column"
+                )));
+            }
+            expected_new_columns.fields.insert(
+                index_column.to_string(),
+                Field::new(index_column, DataType::UInt64),
+            );
+        }
         let eval_stats = if let Some(stats) = &self.statistics {
             let mut new_stats = stats.columns.clone();
             for (name, _) in &expected_new_columns.fields {
@@ -1,10 +1,17 @@
+use std::sync::Arc;
+
 use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
 use daft_dsl::ExprRef;
 use daft_io::IOStatsContext;
+use daft_scan::{DataSource, ScanTask};
 use daft_stats::TruthValue;
 use snafu::ResultExt;
 
-use crate::{micropartition::MicroPartition, DaftCoreComputeSnafu};
+use crate::{
+    micropartition::{materialize_scan_task, MicroPartition, TableState},
+    DaftCoreComputeSnafu,
+};
 
 impl MicroPartition {
     pub fn filter(&self, predicate: &[ExprRef]) -> DaftResult<Self> {
@@ -12,12 +19,12 @@ impl MicroPartition {
         if predicate.is_empty() {
             return Ok(Self::empty(Some(self.schema.clone())));
         }
+        let folded_expr = predicate
+            .iter()
+            .cloned()
+            .reduce(daft_dsl::Expr::and)
+            .expect("should have at least 1 expr");
         if let Some(statistics) = &self.statistics {
-            let folded_expr = predicate
-                .iter()
-                .cloned()
-                .reduce(daft_dsl::Expr::and)
-                .expect("should have at least 1 expr");
             let eval_result = statistics.eval_expression(&folded_expr)?;
             let tv = eval_result.to_truth_value();
 
@@ -25,9 +32,38 @@ impl MicroPartition {
                 return Ok(Self::empty(Some(self.schema.clone())));
             }
         }
+
+        // An unloaded MicroPartition may span several sources, some of which the predicate can rule
+        // out on their own statistics. Only read the rest, leaving this MicroPartition unloaded.
+        let unloaded_scan_task = match &*self.state.lock().unwrap() {
+            TableState::Unloaded(scan_task) => Some(scan_task.clone()),
+            TableState::Loaded(_) => None,
+        };
+        let kept_sources = unloaded_scan_task
+            .as_deref()
+            .map(|scan_task| sources_passing_statistics(scan_task, &folded_expr, &self.schema))
+            .transpose()?
+            .flatten();
+
         // TODO figure out deferred IOStats
-        let tables = self
-            .tables_or_read(io_stats)?
+        let tables = match (unloaded_scan_task, kept_sources) {
+            (Some(scan_task), Some(sources)) if sources.len() < scan_task.sources.len() => {
+                if sources.is_empty() {
+                    return Ok(Self::empty(Some(self.schema.clone())));
+                }
+                let pruned_scan_task = Arc::new(ScanTask::new(
+                    sources,
+                    scan_task.file_format_config.clone(),
+                    scan_task.schema.clone(),
+                    scan_task.storage_config.clone(),
+                    scan_task.pushdowns.clone(),
+                    scan_task.generated_fields.clone(),
+                ));
+                Arc::new(materialize_scan_task(pruned_scan_task, Some(io_stats))?.0)
+            }
+            _ => self.tables_or_read(io_stats)?,
+        };
+        let tables = tables
             .iter()
             .map(|t| t.filter(predicate))
             .collect::<DaftResult<Vec<_>>>()
@@ -40,3 +76,122 @@ impl MicroPartition {
         ))
     }
 }
+
+/// Returns the sources of `scan_task` that may contain rows satisfying `predicate`, dropping those
+/// whose own statistics evaluate it to [`TruthValue::False`].
+///
+/// Returns `None` for tasks with a single source, which the MicroPartition's statistics already cover.
+fn sources_passing_statistics(
+    scan_task: &ScanTask,
+    predicate: &ExprRef,
+    schema: &SchemaRef,
+) -> DaftResult<Option<Vec<DataSource>>> {
+    if scan_task.sources.len() < 2 {
+        return Ok(None);
+    }
+    let fill_map = scan_task.partition_spec().map(|pspec| pspec.to_fill_map());
+    let mut sources = Vec::with_capacity(scan_task.sources.len());
+    for source in &scan_task.sources {
+        if let Some(statistics) = source.get_statistics() {
+            let statistics =
+                statistics.cast_to_schema_with_fill(schema.clone(), fill_map.as_ref())?;
+            if matches!(
+                statistics.eval_expression(predicate)?.to_truth_value(),
+                TruthValue::False
+            ) {
+                continue;
+            }
+        }
+        sources.push(source.clone());
+    }
+    Ok(Some(sources))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
+    use common_scan_info::Pushdowns;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+    use daft_scan::{
+        storage_config::{NativeStorageConfig, StorageConfig},
+        DataSource, ScanTask,
+    };
+    use daft_stats::{TableMetadata, TableStatistics};
+    use daft_table::Table;
+
+    use super::sources_passing_statistics;
+    use crate::micropartition::MicroPartition;
+
+    fn source(path: &str, min: i64, max: i64) -> DaftResult<DataSource> {
+        let bounds = Table::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![min, max])).into_series()
+        ])?;
+        Ok(DataSource::File {
+            path: path.to_string(),
+            chunk_spec: None,
+            size_bytes: None,
+            iceberg_delete_files: None,
+            metadata: Some(TableMetadata { length: 2 }),
+            partition_spec: None,
+            statistics: Some(TableStatistics::from_table(&bounds)),
+            parquet_metadata: None,
+        })
+    }
+
+    // The sources point at files that don't exist, so any attempt to read them fails the test.
+    fn scan_task() -> DaftResult<ScanTask> {
+        Ok(ScanTask::new(
+            vec![
+                source("out_of_range.parquet", 100, 200)?,
+                source("boundary.parquet", 0, 10)?,
+            ],
+            Arc::new(FileFormatConfig::Parquet(ParquetSourceConfig {
+                coerce_int96_timestamp_unit: TimeUnit::Seconds,
+                field_id_mapping: None,
+                row_groups: None,
+                chunk_size: None,
+            })),
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64)])?),
+            Arc::new(StorageConfig::Native(Arc::new(
+                NativeStorageConfig::new_internal(false, None),
+            ))),
+            Pushdowns::default(),
+            None,
+        ))
+    }
+
+    #[test]
+    fn test_sources_passing_statistics_skips_out_of_range_source() -> DaftResult<()> {
+        let scan_task = scan_task()?;
+        let schema = scan_task.materialized_schema();
+
+        // The boundary source's max equals the literal, so it may still hold matching rows.
+        let kept = sources_passing_statistics(&scan_task, &col("a").lt_eq(lit(10)), &schema)?
+            .expect("scan task has several sources");
+        assert_eq!(
+            kept.iter().map(DataSource::get_path).collect::<Vec<_>>(),
+            vec!["boundary.parquet"]
+        );
+
+        let kept = sources_passing_statistics(&scan_task, &col("a").gt(lit(-1)), &schema)?
+            .expect("scan task has several sources");
+        assert_eq!(kept.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_skips_reading_when_no_source_can_match() -> DaftResult<()> {
+        let scan_task = Arc::new(scan_task()?);
+        let statistics = scan_task.statistics.clone().unwrap();
+        let mp = MicroPartition::new_unloaded(scan_task, TableMetadata { length: 4 }, statistics);
+
+        // Within the union of both sources' ranges, but outside each individual range.
+        let filtered = mp.filter(&[col("a").gt(lit(50)), col("a").lt(lit(90))])?;
+        assert_eq!(filtered.len(), 0);
+        Ok(())
+    }
+}
@@ -24,8 +24,8 @@ impl MicroPartition {
     {
         let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on, how)?;
         match (how, self.len(), right.len()) {
-            (JoinType::Inner | JoinType::Left | JoinType::Semi, 0, _)
-            | (JoinType::Inner | JoinType::Right, _, 0)
+            (JoinType::Inner | JoinType::Left | JoinType::Semi | JoinType::Cross, 0, _)
+            | (JoinType::Inner | JoinType::Right | JoinType::RightSemi | JoinType::Cross, _, 0)
             | (JoinType::Outer, 0, 0) => {
                 return Ok(Self::empty(Some(join_schema)));
             }
@@ -100,12 +100,13 @@ impl MicroPartition {
         right: &Self,
         left_on: &[ExprRef],
         right_on: &[ExprRef],
+        descending: &[bool],
         is_sorted: bool,
     ) -> DaftResult<Self> {
         let io_stats = IOStatsContext::new("MicroPartition::sort_merge_join");
         let table_join =
             |lt: &Table, rt: &Table, lo: &[ExprRef], ro: &[ExprRef], _how: JoinType| {
-                Table::sort_merge_join(lt, rt, lo, ro, is_sorted)
+                Table::sort_merge_join(lt, rt, lo, ro, descending, is_sorted)
             };
 
         self.join(
@@ -39,6 +39,7 @@ impl MicroPartition {
         &self,
         exprs: &[ExprRef],
         num_partitions: usize,
+        seed: Option<u64>,
     ) -> DaftResult<Vec<Self>> {
         let io_stats = IOStatsContext::new("MicroPartition::partition_by_hash");
 
@@ -54,7 +55,7 @@ impl MicroPartition {
 
         let part_tables = tables
             .iter()
-            .map(|t| t.partition_by_hash(exprs, num_partitions))
+            .map(|t| t.partition_by_hash(exprs, num_partitions, seed))
             .collect::<DaftResult<Vec<_>>>()?;
         self.vec_part_tables_to_mps(part_tables)
     }
@@ -80,6 +81,36 @@ impl MicroPartition {
         self.vec_part_tables_to_mps(part_tables)
     }
 
+    pub fn partition_by_round_robin(
+        &self,
+        num_partitions: usize,
+        start: usize,
+    ) -> DaftResult<Vec<Self>> {
+        let io_stats = IOStatsContext::new("MicroPartition::partition_by_round_robin");
+
+        let tables = self.tables_or_read(io_stats)?;
+
+        if tables.is_empty() {
+            return Ok(
+                std::iter::repeat_with(|| Self::empty(Some(self.schema.clone())))
+                    .take(num_partitions)
+                    .collect(),
+            );
+        }
+
+        // continue the rotation across tables so the whole MicroPartition stays balanced
+        let mut offset = start;
+        let part_tables = tables
+            .iter()
+            .map(|t| {
+                let parts = t.partition_by_round_robin(num_partitions, offset)?;
+                offset += t.len();
+                Ok(parts)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        self.vec_part_tables_to_mps(part_tables)
+    }
+
     pub fn partition_by_range(
         &self,
         partition_keys: &[ExprRef],
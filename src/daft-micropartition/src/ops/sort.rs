@@ -32,6 +32,30 @@ impl MicroPartition {
         }
     }
 
+    pub fn top_n(
+        &self,
+        sort_keys: &[ExprRef],
+        descending: &[bool],
+        nulls_first: &[bool],
+        n: usize,
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::top_n");
+
+        let tables = self.concat_or_get(io_stats)?;
+        match tables.as_slice() {
+            [] => Ok(Self::empty(Some(self.schema.clone()))),
+            [single] => {
+                let top = single.top_n(sort_keys, descending, nulls_first, n)?;
+                Ok(Self::new_loaded(
+                    self.schema.clone(),
+                    Arc::new(vec![top]),
+                    self.statistics.clone(),
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
+
     pub fn argsort(
         &self,
         sort_keys: &[ExprRef],
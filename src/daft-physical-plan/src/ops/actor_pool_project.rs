@@ -21,6 +21,8 @@ pub struct ActorPoolProject {
     pub input: PhysicalPlanRef,
     pub projection: Vec<ExprRef>,
     pub clustering_spec: Arc<ClusteringSpec>,
+    /// Estimated ratio of output to input bytes per row, if the schemas were known at planning time.
+    pub row_size_ratio: Option<f64>,
 }
 
 impl ActorPoolProject {
@@ -55,9 +57,16 @@ impl ActorPoolProject {
             input,
             projection,
             clustering_spec,
+            row_size_ratio: None,
         })
     }
 
+    #[must_use]
+    pub(crate) fn with_row_size_ratio(mut self, row_size_ratio: Option<f64>) -> Self {
+        self.row_size_ratio = row_size_ratio;
+        self
+    }
+
     pub fn resource_request(&self) -> Option<ResourceRequest> {
         get_resource_request(self.projection.as_slice())
     }
@@ -2,7 +2,7 @@ use daft_dsl::{AggExpr, ExprRef};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::{impl_default_tree_display, PhysicalPlanRef};
+use crate::{impl_default_tree_display, ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Aggregate {
@@ -14,6 +14,8 @@ pub struct Aggregate {
 
     /// Grouping to apply.
     pub groupby: Vec<ExprRef>,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl Aggregate {
@@ -26,6 +28,7 @@ impl Aggregate {
             input,
             aggregations,
             groupby,
+            clustering_spec_cache: ClusteringSpecCache::default(),
         }
     }
 
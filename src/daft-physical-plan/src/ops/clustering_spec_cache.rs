@@ -0,0 +1,26 @@
+use std::sync::{Arc, OnceLock};
+
+use daft_logical_plan::partitioning::ClusteringSpec;
+
+/// Memoized clustering spec for ops that derive theirs from their inputs.
+///
+/// The cache is filled on the first call to [`crate::PhysicalPlan::clustering_spec`]. Ops are immutable once
+/// built and `with_new_children` rebuilds them with an empty cache, so a cached spec never goes stale.
+#[derive(Clone, Debug, Default)]
+pub struct ClusteringSpecCache(OnceLock<Arc<ClusteringSpec>>);
+
+impl ClusteringSpecCache {
+    pub(crate) fn get_or_init(
+        &self,
+        f: impl FnOnce() -> Arc<ClusteringSpec>,
+    ) -> Arc<ClusteringSpec> {
+        self.0.get_or_init(f).clone()
+    }
+}
+
+/// The cache is derived state, so it never affects equality of the ops that hold it.
+impl PartialEq for ClusteringSpecCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
@@ -1,17 +1,23 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{impl_default_tree_display, PhysicalPlanRef};
+use crate::{impl_default_tree_display, ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Concat {
     // Upstream node.
     pub input: PhysicalPlanRef,
     pub other: PhysicalPlanRef,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl Concat {
     pub(crate) fn new(input: PhysicalPlanRef, other: PhysicalPlanRef) -> Self {
-        Self { input, other }
+        Self {
+            input,
+            other,
+            clustering_spec_cache: ClusteringSpecCache::default(),
+        }
     }
 
     pub fn multiline_display(&self) -> Vec<String> {
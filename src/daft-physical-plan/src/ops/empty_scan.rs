@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use common_display::tree::TreeDisplay;
-use daft_logical_plan::partitioning::ClusteringSpec;
+use daft_logical_plan::partitioning::{ClusteringSpec, UnknownClusteringConfig};
 use daft_schema::schema::SchemaRef;
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +19,16 @@ impl EmptyScan {
         }
     }
 
+    /// An empty scan producing a single zero-row partition with `schema`, for operators that find
+    /// out at runtime that they have no input.
+    #[must_use]
+    pub fn from_schema(schema: SchemaRef) -> Self {
+        Self::new(
+            schema,
+            Arc::new(ClusteringSpec::Unknown(UnknownClusteringConfig::new(1))),
+        )
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         res.push("EmptyScan:".to_string());
@@ -47,3 +57,26 @@ impl TreeDisplay for EmptyScan {
         vec![]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+
+    use super::EmptyScan;
+
+    #[test]
+    fn test_from_schema_preserves_schema() -> DaftResult<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+            Field::new("c", DataType::Boolean),
+        ])?);
+        let scan = EmptyScan::from_schema(schema.clone());
+        assert_eq!(scan.schema, schema);
+        assert_eq!(scan.clustering_spec.num_partitions(), 1);
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use daft_dsl::ExprRef;
 use serde::{Deserialize, Serialize};
 
-use crate::PhysicalPlanRef;
+use crate::{ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Filter {
@@ -9,11 +9,17 @@ pub struct Filter {
     pub input: PhysicalPlanRef,
     // The Boolean expression to filter on.
     pub predicate: ExprRef,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl Filter {
     pub(crate) fn new(input: PhysicalPlanRef, predicate: ExprRef) -> Self {
-        Self { input, predicate }
+        Self {
+            input,
+            predicate,
+            clustering_spec_cache: ClusteringSpecCache::default(),
+        }
     }
 
     pub fn multiline_display(&self) -> Vec<String> {
@@ -4,7 +4,7 @@ use daft_dsl::ExprRef;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::PhysicalPlanRef;
+use crate::{ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HashJoin {
@@ -15,6 +15,11 @@ pub struct HashJoin {
     pub right_on: Vec<ExprRef>,
     pub null_equals_nulls: Option<Vec<bool>>,
     pub join_type: JoinType,
+    /// Estimated bytes per row of the join key columns that both sides share and that are merged
+    /// into a single column in the join output.
+    pub duplicated_key_bytes_per_row: usize,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl HashJoin {
@@ -33,9 +38,17 @@ impl HashJoin {
             right_on,
             null_equals_nulls,
             join_type,
+            duplicated_key_bytes_per_row: 0,
+            clustering_spec_cache: ClusteringSpecCache::default(),
         }
     }
 
+    #[must_use]
+    pub(crate) fn with_duplicated_key_bytes_per_row(mut self, bytes_per_row: usize) -> Self {
+        self.duplicated_key_bytes_per_row = bytes_per_row;
+        self
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         res.push(format!("HashJoin: Type = {}", self.join_type));
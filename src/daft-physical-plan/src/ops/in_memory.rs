@@ -30,6 +30,7 @@ impl InMemoryScan {
         res.push("InMemoryScan:".to_string());
         res.push(format!("Schema = {}", self.schema.short_string()));
         res.push(format!("Size bytes = {}", self.in_memory_info.size_bytes,));
+        res.push(format!("Num rows = {}", self.in_memory_info.num_rows));
         res.push(format!(
             "Clustering spec = {{ {} }}",
             self.clustering_spec.multiline_display().join(", ")
@@ -46,9 +47,11 @@ impl TreeDisplay for InMemoryScan {
                     "InMemoryScan:
 Schema = {},
 Size bytes = {},
+Num rows = {},
 Clustering spec = {{ {} }}",
                     self.schema.short_string(),
                     self.in_memory_info.size_bytes,
+                    self.in_memory_info.num_rows,
                     self.clustering_spec.multiline_display().join(", ")
                 )
             }
@@ -2,7 +2,7 @@ use daft_logical_plan::sink_info::OutputFileInfo;
 use daft_schema::schema::SchemaRef;
 use serde::{Deserialize, Serialize};
 
-use crate::PhysicalPlanRef;
+use crate::{ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TabularWriteJson {
@@ -10,6 +10,8 @@ pub struct TabularWriteJson {
     pub file_info: OutputFileInfo,
     // Upstream node.
     pub input: PhysicalPlanRef,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl TabularWriteJson {
@@ -22,6 +24,7 @@ impl TabularWriteJson {
             schema,
             file_info,
             input,
+            clustering_spec_cache: ClusteringSpecCache::default(),
         }
     }
 
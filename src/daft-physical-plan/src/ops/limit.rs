@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::PhysicalPlanRef;
+use crate::{ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Limit {
@@ -9,6 +9,8 @@ pub struct Limit {
     pub limit: i64,
     pub eager: bool,
     pub num_partitions: usize,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl Limit {
@@ -23,6 +25,7 @@ impl Limit {
             limit,
             eager,
             num_partitions,
+            clustering_spec_cache: ClusteringSpecCache::default(),
         }
     }
 
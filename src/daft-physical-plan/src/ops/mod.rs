@@ -1,6 +1,7 @@
 mod actor_pool_project;
 mod agg;
 mod broadcast_join;
+mod clustering_spec_cache;
 mod concat;
 mod csv;
 #[cfg(feature = "python")]
@@ -31,6 +32,7 @@ mod unpivot;
 pub use actor_pool_project::ActorPoolProject;
 pub use agg::Aggregate;
 pub use broadcast_join::BroadcastJoin;
+pub use clustering_spec_cache::ClusteringSpecCache;
 pub use concat::Concat;
 pub use csv::TabularWriteCsv;
 #[cfg(feature = "python")]
@@ -2,12 +2,16 @@ use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
-use crate::PhysicalPlan;
+use crate::{ops::ClusteringSpecCache, PhysicalPlan};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MonotonicallyIncreasingId {
     pub input: Arc<PhysicalPlan>,
     pub column_name: String,
+    /// Estimated ratio of output to input bytes per row, if the schemas were known at planning time.
+    pub row_size_ratio: Option<f64>,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl MonotonicallyIncreasingId {
@@ -15,9 +19,17 @@ impl MonotonicallyIncreasingId {
         Self {
             input,
             column_name: column_name.to_owned(),
+            row_size_ratio: None,
+            clustering_spec_cache: ClusteringSpecCache::default(),
         }
     }
 
+    #[must_use]
+    pub(crate) fn with_row_size_ratio(mut self, row_size_ratio: Option<f64>) -> Self {
+        self.row_size_ratio = row_size_ratio;
+        self
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         vec!["MonotonicallyIncreasingId".to_string()]
     }
@@ -2,7 +2,7 @@ use daft_dsl::ExprRef;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::PhysicalPlanRef;
+use crate::{ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Pivot {
@@ -13,6 +13,8 @@ pub struct Pivot {
     pub pivot_column: ExprRef,
     pub value_column: ExprRef,
     pub names: Vec<String>,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl Pivot {
@@ -29,6 +31,7 @@ impl Pivot {
             pivot_column,
             value_column,
             names,
+            clustering_spec_cache: ClusteringSpecCache::default(),
         }
     }
 
@@ -15,6 +15,8 @@ pub struct Project {
     pub input: PhysicalPlanRef,
     pub projection: Vec<ExprRef>,
     pub clustering_spec: Arc<ClusteringSpec>,
+    /// Estimated ratio of output to input bytes per row, if the schemas were known at planning time.
+    pub row_size_ratio: Option<f64>,
 }
 
 impl Project {
@@ -25,6 +27,7 @@ impl Project {
             input,
             projection,
             clustering_spec,
+            row_size_ratio: None,
         })
     }
 
@@ -42,9 +45,16 @@ impl Project {
             input,
             projection,
             clustering_spec,
+            row_size_ratio: None,
         })
     }
 
+    #[must_use]
+    pub(crate) fn with_row_size_ratio(mut self, row_size_ratio: Option<f64>) -> Self {
+        self.row_size_ratio = row_size_ratio;
+        self
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         res.push(format!(
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::PhysicalPlanRef;
+use crate::{ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Sample {
@@ -8,6 +8,8 @@ pub struct Sample {
     pub fraction: f64,
     pub with_replacement: bool,
     pub seed: Option<u64>,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl Sample {
@@ -22,6 +24,7 @@ impl Sample {
             fraction,
             with_replacement,
             seed,
+            clustering_spec_cache: ClusteringSpecCache::default(),
         }
     }
 
@@ -2,7 +2,7 @@ use daft_dsl::ExprRef;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::PhysicalPlanRef;
+use crate::{ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Sort {
@@ -12,6 +12,8 @@ pub struct Sort {
     pub descending: Vec<bool>,
     pub nulls_first: Vec<bool>,
     pub num_partitions: usize,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl Sort {
@@ -28,6 +30,7 @@ impl Sort {
             descending,
             nulls_first,
             num_partitions,
+            clustering_spec_cache: ClusteringSpecCache::default(),
         }
     }
 
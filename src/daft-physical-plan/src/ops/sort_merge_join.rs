@@ -4,7 +4,7 @@ use daft_dsl::ExprRef;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::PhysicalPlanRef;
+use crate::{ops::ClusteringSpecCache, PhysicalPlanRef};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SortMergeJoin {
@@ -13,10 +13,17 @@ pub struct SortMergeJoin {
     pub right: PhysicalPlanRef,
     pub left_on: Vec<ExprRef>,
     pub right_on: Vec<ExprRef>,
+    // Sort order of each join key that both sides are sorted by.
+    pub descending: Vec<bool>,
     pub join_type: JoinType,
     pub num_partitions: usize,
     pub left_is_larger: bool,
     pub needs_presort: bool,
+    /// Estimated bytes per row of the join key columns that both sides share and that are merged
+    /// into a single column in the join output.
+    pub duplicated_key_bytes_per_row: usize,
+    #[serde(skip)]
+    pub(crate) clustering_spec_cache: ClusteringSpecCache,
 }
 
 impl SortMergeJoin {
@@ -26,6 +33,7 @@ impl SortMergeJoin {
         right: PhysicalPlanRef,
         left_on: Vec<ExprRef>,
         right_on: Vec<ExprRef>,
+        descending: Vec<bool>,
         join_type: JoinType,
         num_partitions: usize,
         left_is_larger: bool,
@@ -36,13 +44,22 @@ impl SortMergeJoin {
             right,
             left_on,
             right_on,
+            descending,
             join_type,
             num_partitions,
             left_is_larger,
             needs_presort,
+            duplicated_key_bytes_per_row: 0,
+            clustering_spec_cache: ClusteringSpecCache::default(),
         }
     }
 
+    #[must_use]
+    pub(crate) fn with_duplicated_key_bytes_per_row(mut self, bytes_per_row: usize) -> Self {
+        self.duplicated_key_bytes_per_row = bytes_per_row;
+        self
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         res.push(format!("SortMergeJoin: Type = {}", self.join_type));
@@ -65,6 +82,12 @@ impl SortMergeJoin {
                 ));
             }
         }
+        if self.descending.iter().any(|d| *d) {
+            res.push(format!(
+                "Descending = {}",
+                self.descending.iter().map(|d| d.to_string()).join(", ")
+            ));
+        }
         res.push(format!("Num partitions = {}", self.num_partitions));
         res.push(format!("Left is larger = {}", self.left_is_larger));
         res.push(format!("Needs presort = {}", self.needs_presort));
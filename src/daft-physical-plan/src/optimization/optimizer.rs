@@ -103,6 +103,7 @@ mod tests {
                     limit,
                     eager,
                     num_partitions,
+                    ..
                 }) => {
                     if *limit >= self.cutoff {
                         Ok(Transformed::no(plan))
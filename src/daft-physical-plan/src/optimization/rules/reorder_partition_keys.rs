@@ -80,19 +80,20 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
             // we are hash partitioned but we might need to transform the expression
             match c.plan.as_ref() {
                 // these store their clustering spec inside
-                PhysicalPlan::Project(Project { input, projection, .. }) => {
+                PhysicalPlan::Project(Project { input, projection, row_size_ratio, .. }) => {
                     let new_plan = PhysicalPlan::Project(Project::new_with_clustering_spec(
                         input.clone(),
                         projection.clone(),
                         new_spec.into(),
-                    )?);
+                    )?.with_row_size_ratio(*row_size_ratio));
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
                 }
-                PhysicalPlan::ActorPoolProject(ActorPoolProject { input, projection, clustering_spec: _ }) => {
+                PhysicalPlan::ActorPoolProject(ActorPoolProject { input, projection, clustering_spec: _, row_size_ratio }) => {
                     let new_plan = PhysicalPlan::ActorPoolProject(ActorPoolProject {
                         input: input.clone(),
                         projection: projection.clone(),
                         clustering_spec: new_spec.into(),
+                        row_size_ratio: *row_size_ratio,
                     });
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
                 }
@@ -118,11 +119,11 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
                 }
                 PhysicalPlan::Aggregate(Aggregate { input, aggregations, .. }) => {
-                    let new_plan = PhysicalPlan::Aggregate(Aggregate {
-                        input: input.clone(),
-                        aggregations: aggregations.clone(),
-                        groupby: c.context.clone(),
-                    });
+                    let new_plan = PhysicalPlan::Aggregate(Aggregate::new(
+                        input.clone(),
+                        aggregations.clone(),
+                        c.context.clone(),
+                    ));
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
                 }
                 PhysicalPlan::ShuffleExchange(ShuffleExchange{input, strategy: ShuffleExchangeStrategy::NaiveFullyMaterializingMapReduce { .. }}) => {
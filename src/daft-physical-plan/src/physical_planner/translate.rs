@@ -31,7 +31,11 @@ use daft_logical_plan::{
     source_info::{PlaceHolderInfo, SourceInfo},
 };
 
-use crate::{ops::*, PhysicalPlan, PhysicalPlanRef};
+use crate::{
+    ops::*,
+    plan::{duplicated_join_key_bytes_per_row, row_size_ratio},
+    PhysicalPlan, PhysicalPlanRef,
+};
 
 pub(super) fn translate_single_logical_node(
     logical_plan: &LogicalPlan,
@@ -49,14 +53,10 @@ pub(super) fn translate_single_logical_node(
                 let scan_tasks = scan_op.0.to_scan_tasks(pushdowns.clone(), Some(cfg))?;
 
                 if scan_tasks.is_empty() {
-                    let clustering_spec =
-                        Arc::new(ClusteringSpec::Unknown(UnknownClusteringConfig::new(1)));
-
-                    Ok(PhysicalPlan::EmptyScan(EmptyScan::new(
-                        source_schema.clone(),
-                        clustering_spec,
-                    ))
-                    .arced())
+                    Ok(
+                        PhysicalPlan::EmptyScan(EmptyScan::from_schema(source_schema.clone()))
+                            .arced(),
+                    )
                 } else {
                     let clustering_spec = Arc::new(ClusteringSpec::Unknown(
                         UnknownClusteringConfig::new(scan_tasks.len()),
@@ -86,19 +86,26 @@ pub(super) fn translate_single_logical_node(
                 panic!("Placeholder {source_id} should not get to translation. This should have been optimized away");
             }
         },
-        LogicalPlan::Project(LogicalProject { projection, .. }) => {
+        LogicalPlan::Project(LogicalProject {
+            input, projection, ..
+        }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
-            Ok(
-                PhysicalPlan::Project(Project::try_new(input_physical, projection.clone())?)
-                    .arced(),
+            let row_size_ratio = row_size_ratio(&input.schema(), &logical_plan.schema());
+            Ok(PhysicalPlan::Project(
+                Project::try_new(input_physical, projection.clone())?
+                    .with_row_size_ratio(row_size_ratio),
             )
+            .arced())
         }
-        LogicalPlan::ActorPoolProject(LogicalActorPoolProject { projection, .. }) => {
+        LogicalPlan::ActorPoolProject(LogicalActorPoolProject {
+            input, projection, ..
+        }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
-            Ok(PhysicalPlan::ActorPoolProject(ActorPoolProject::try_new(
-                input_physical,
-                projection.clone(),
-            )?)
+            let row_size_ratio = row_size_ratio(&input.schema(), &logical_plan.schema());
+            Ok(PhysicalPlan::ActorPoolProject(
+                ActorPoolProject::try_new(input_physical, projection.clone())?
+                    .with_row_size_ratio(row_size_ratio),
+            )
             .arced())
         }
         LogicalPlan::Filter(LogicalFilter { predicate, .. }) => {
@@ -409,7 +416,9 @@ pub(super) fn translate_single_logical_node(
             join_strategy,
             ..
         }) => {
-            if left_on.is_empty() && right_on.is_empty() && join_type == &JoinType::Inner {
+            if join_type == &JoinType::Cross
+                || (left_on.is_empty() && right_on.is_empty() && join_type == &JoinType::Inner)
+            {
                 return Err(DaftError::not_implemented(
                     "Joins without join conditions (cross join) are not supported yet",
                 ));
@@ -417,6 +426,19 @@ pub(super) fn translate_single_logical_node(
             let mut right_physical = physical_children.pop().expect("requires 1 inputs");
             let mut left_physical = physical_children.pop().expect("requires 2 inputs");
 
+            // Right anti and semi joins are planned as anti and semi joins with the inputs swapped.
+            let (left, right, left_on, right_on, join_type) = match join_type {
+                JoinType::RightAnti => {
+                    (left_physical, right_physical) = (right_physical, left_physical);
+                    (right, left, right_on, left_on, &JoinType::Anti)
+                }
+                JoinType::RightSemi => {
+                    (left_physical, right_physical) = (right_physical, left_physical);
+                    (right, left, right_on, left_on, &JoinType::Semi)
+                }
+                _ => (left, right, left_on, right_on, join_type),
+            };
+
             let left_clustering_spec = left_physical.clustering_spec();
             let right_clustering_spec = right_physical.clustering_spec();
             let num_partitions = max(
@@ -459,6 +481,12 @@ pub(super) fn translate_single_logical_node(
                 };
             let left_stats = left_physical.approximate_stats();
             let right_stats = right_physical.approximate_stats();
+            let duplicated_key_bytes_per_row = duplicated_join_key_bytes_per_row(
+                &left.schema(),
+                &right.schema(),
+                left_on,
+                right_on,
+            );
 
             // For broadcast joins, ensure that the left side of the join is the smaller side.
             let (smaller_size_bytes, left_is_larger) =
@@ -496,10 +524,15 @@ pub(super) fn translate_single_logical_node(
                 }
 
                 let smaller_side_is_broadcastable = match join_type {
-                    JoinType::Inner => true,
-                    JoinType::Left | JoinType::Anti | JoinType::Semi => left_is_larger,
+                    JoinType::Inner | JoinType::Cross => true,
+                    JoinType::Left | JoinType::AsOf | JoinType::Anti | JoinType::Semi => {
+                        left_is_larger
+                    }
                     JoinType::Right => !left_is_larger,
                     JoinType::Outer => false,
+                    JoinType::RightAnti | JoinType::RightSemi => {
+                        unreachable!("Right anti and semi joins are planned with swapped inputs")
+                    }
                 };
 
                 // If larger table is not already partitioned on the join key AND the smaller table is under broadcast size threshold AND we are not broadcasting the side we are outer joining by, use broadcast join.
@@ -533,9 +566,9 @@ pub(super) fn translate_single_logical_node(
             match join_strategy {
                 JoinStrategy::Broadcast => {
                     let is_swapped = match (join_type, left_is_larger) {
-                        (JoinType::Left, _) => true,
+                        (JoinType::Left | JoinType::AsOf, _) => true,
                         (JoinType::Right, _) => false,
-                        (JoinType::Inner, left_is_larger) => left_is_larger,
+                        (JoinType::Inner | JoinType::Cross, left_is_larger) => left_is_larger,
                         (JoinType::Outer, _) => {
                             return Err(common_error::DaftError::ValueError(
                                 "Broadcast join does not support outer joins.".to_string(),
@@ -543,21 +576,29 @@ pub(super) fn translate_single_logical_node(
                         }
                         (JoinType::Anti, _) => true,
                         (JoinType::Semi, _) => true,
+                        (JoinType::RightAnti | JoinType::RightSemi, _) => {
+                            unreachable!(
+                                "Right anti and semi joins are planned with swapped inputs"
+                            )
+                        }
                     };
 
                     if is_swapped {
                         (left_physical, right_physical) = (right_physical, left_physical);
                     }
 
-                    Ok(PhysicalPlan::BroadcastJoin(BroadcastJoin::new(
-                        left_physical,
-                        right_physical,
-                        left_on.clone(),
-                        right_on.clone(),
-                        null_equals_nulls.clone(),
-                        *join_type,
-                        is_swapped,
-                    ))
+                    Ok(PhysicalPlan::BroadcastJoin(
+                        BroadcastJoin::new(
+                            left_physical,
+                            right_physical,
+                            left_on.clone(),
+                            right_on.clone(),
+                            null_equals_nulls.clone(),
+                            *join_type,
+                            is_swapped,
+                        )
+                        .with_duplicated_key_bytes_per_row(duplicated_key_bytes_per_row),
+                    )
                     .arced())
                 }
                 JoinStrategy::SortMerge => {
@@ -602,16 +643,22 @@ pub(super) fn translate_single_logical_node(
                         }
                         false
                     };
-                    Ok(PhysicalPlan::SortMergeJoin(SortMergeJoin::new(
-                        left_physical,
-                        right_physical,
-                        left_on.clone(),
-                        right_on.clone(),
-                        *join_type,
-                        num_partitions,
-                        left_is_larger,
-                        needs_presort,
-                    ))
+                    Ok(PhysicalPlan::SortMergeJoin(
+                        SortMergeJoin::new(
+                            left_physical,
+                            right_physical,
+                            left_on.clone(),
+                            right_on.clone(),
+                            // TODO(Clark): Use the inputs' sort orders once range-partitioned inputs with
+                            // descending sort orders are merge-joined.
+                            std::iter::repeat(false).take(left_on.len()).collect(),
+                            *join_type,
+                            num_partitions,
+                            left_is_larger,
+                            needs_presort,
+                        )
+                        .with_duplicated_key_bytes_per_row(duplicated_key_bytes_per_row),
+                    )
                     .arced())
                 }
                 JoinStrategy::Hash => {
@@ -664,14 +711,17 @@ pub(super) fn translate_single_logical_node(
                         )
                         .into();
                     }
-                    Ok(PhysicalPlan::HashJoin(HashJoin::new(
-                        left_physical,
-                        right_physical,
-                        left_on.clone(),
-                        right_on.clone(),
-                        null_equals_nulls.clone(),
-                        *join_type,
-                    ))
+                    Ok(PhysicalPlan::HashJoin(
+                        HashJoin::new(
+                            left_physical,
+                            right_physical,
+                            left_on.clone(),
+                            right_on.clone(),
+                            null_equals_nulls.clone(),
+                            *join_type,
+                        )
+                        .with_duplicated_key_bytes_per_row(duplicated_key_bytes_per_row),
+                    )
                     .arced())
                 }
             }
@@ -738,17 +788,17 @@ pub(super) fn translate_single_logical_node(
             }
         }
         LogicalPlan::MonotonicallyIncreasingId(LogicalMonotonicallyIncreasingId {
+            input,
             column_name,
             ..
         }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
-            Ok(
-                PhysicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId::new(
-                    input_physical,
-                    column_name,
-                ))
-                .arced(),
+            let row_size_ratio = row_size_ratio(&input.schema(), &logical_plan.schema());
+            Ok(PhysicalPlan::MonotonicallyIncreasingId(
+                MonotonicallyIncreasingId::new(input_physical, column_name)
+                    .with_row_size_ratio(row_size_ratio),
             )
+            .arced())
         }
         LogicalPlan::Intersect(_) => Err(DaftError::InternalError(
             "Intersect should already be optimized away".to_string(),
@@ -789,11 +839,26 @@ pub fn extract_agg_expr(expr: &ExprRef) -> DaftResult<AggExpr> {
                 }
                 AggExpr::Mean(e) => AggExpr::Mean(Expr::Alias(e, name.clone()).into()),
                 AggExpr::Stddev(e) => AggExpr::Stddev(Expr::Alias(e, name.clone()).into()),
+                AggExpr::Variance(e, ddof) => {
+                    AggExpr::Variance(Expr::Alias(e, name.clone()).into(), ddof)
+                }
+                AggExpr::VarianceState(e) => {
+                    AggExpr::VarianceState(Expr::Alias(e, name.clone()).into())
+                }
+                AggExpr::MergeVariance(e, ddof) => {
+                    AggExpr::MergeVariance(Expr::Alias(e, name.clone()).into(), ddof)
+                }
                 AggExpr::Min(e) => AggExpr::Min(Expr::Alias(e, name.clone()).into()),
                 AggExpr::Max(e) => AggExpr::Max(Expr::Alias(e, name.clone()).into()),
                 AggExpr::AnyValue(e, ignore_nulls) => {
                     AggExpr::AnyValue(Expr::Alias(e, name.clone()).into(), ignore_nulls)
                 }
+                AggExpr::First(e, ignore_nulls) => {
+                    AggExpr::First(Expr::Alias(e, name.clone()).into(), ignore_nulls)
+                }
+                AggExpr::Last(e, ignore_nulls) => {
+                    AggExpr::Last(Expr::Alias(e, name.clone()).into(), ignore_nulls)
+                }
                 AggExpr::List(e) => AggExpr::List(Expr::Alias(e, name.clone()).into()),
                 AggExpr::Concat(e) => AggExpr::Concat(Expr::Alias(e, name.clone()).into()),
                 AggExpr::MapGroups { func, inputs } => AggExpr::MapGroups {
@@ -903,68 +968,42 @@ pub fn populate_aggregation_stages(
                         .alias(output_name),
                 );
             }
-            AggExpr::Stddev(sub_expr) => {
-                // The stddev calculation we're performing here is:
-                // stddev(X) = sqrt(E(X^2) - E(X)^2)
-                // where X is the sub_expr.
+            AggExpr::Stddev(sub_expr) | AggExpr::Variance(sub_expr, _) => {
+                // Each partition is summarized by its (count, mean, M2) state, where M2 is the sum of
+                // squared deviations from the partition mean. Summing raw moments such as sum(X^2) instead
+                // cancels catastrophically when the values are large compared to their spread.
                 //
-                // First stage, we compute `sum(X^2)`, `sum(X)` and `count(X)`.
-                // Second stage, we `global_sqsum := sum(sum(X^2))`, `global_sum := sum(sum(X))` and `global_count := sum(count(X))` in order to get the global versions of the first stage.
-                // In the final projection, we then compute `sqrt((global_sqsum / global_count) - (global_sum / global_count) ^ 2)`.
-
-                // This is a workaround since we have different code paths for single stage and two stage aggregations.
-                // Currently all Std Dev types will be computed using floats.
-                let sub_expr = sub_expr.clone().cast(&DataType::Float64);
+                // First stage, we compute the state of each partition.
+                // Second stage, we merge the states pairwise with Chan et al.'s formula
+                // `M2 = M2_a + M2_b + delta^2 * n_a * n_b / n`, where `delta` is the difference of the means,
+                // and compute `M2 / (n - ddof)`, or null when there are not enough values.
+                // In the final projection, the stddev takes the square root of the population variance.
+                let ddof = match agg_expr {
+                    AggExpr::Variance(_, ddof) => *ddof,
+                    _ => 0,
+                };
                 // first stage aggregation
-                let sum_id = add_to_stage(
-                    AggExpr::Sum,
-                    sub_expr.clone(),
-                    schema,
-                    &mut first_stage_aggs,
-                );
-                let sq_sum_id = add_to_stage(
-                    |sub_expr| AggExpr::Sum(sub_expr.clone().mul(sub_expr)),
-                    sub_expr.clone(),
-                    schema,
-                    &mut first_stage_aggs,
-                );
-                let count_id = add_to_stage(
-                    |sub_expr| AggExpr::Count(sub_expr, CountMode::Valid),
+                let state_id = add_to_stage(
+                    AggExpr::VarianceState,
                     sub_expr.clone(),
                     schema,
                     &mut first_stage_aggs,
                 );
 
                 // second stage aggregation
-                let global_sum_id = add_to_stage(
-                    AggExpr::Sum,
-                    col(sum_id.clone()),
-                    schema,
-                    &mut second_stage_aggs,
-                );
-                let global_sq_sum_id = add_to_stage(
-                    AggExpr::Sum,
-                    col(sq_sum_id.clone()),
-                    schema,
-                    &mut second_stage_aggs,
-                );
-                let global_count_id = add_to_stage(
-                    AggExpr::Sum,
-                    col(count_id.clone()),
+                let variance_id = add_to_stage(
+                    |states| AggExpr::MergeVariance(states, ddof),
+                    col(state_id),
                     schema,
                     &mut second_stage_aggs,
                 );
 
                 // final projection
-                let g_sq_sum = col(global_sq_sum_id);
-                let g_sum = col(global_sum_id);
-                let g_count = col(global_count_id);
-                let left = g_sq_sum.div(g_count.clone());
-                let right = g_sum.div(g_count);
-                let right = right.clone().mul(right);
-                let result = sqrt::sqrt(left.sub(right)).alias(output_name);
-
-                final_exprs.push(result);
+                let result = match agg_expr {
+                    AggExpr::Stddev(_) => sqrt::sqrt(col(variance_id)),
+                    _ => col(variance_id),
+                };
+                final_exprs.push(result.alias(output_name));
             }
             AggExpr::Min(e) => {
                 let min_id = agg_expr.semantic_id(schema).id;
@@ -1011,6 +1050,44 @@ pub fn populate_aggregation_stages(
                     ));
                 final_exprs.push(col(any_of_any_id.clone()).alias(output_name));
             }
+            AggExpr::First(e, ignore_nulls) => {
+                let first_id = agg_expr.semantic_id(schema).id;
+                let first_of_first_id = AggExpr::First(col(first_id.clone()), *ignore_nulls)
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(first_id.clone())
+                    .or_insert(AggExpr::First(
+                        e.alias(first_id.clone()).clone(),
+                        *ignore_nulls,
+                    ));
+                second_stage_aggs
+                    .entry(first_of_first_id.clone())
+                    .or_insert(AggExpr::First(
+                        col(first_id.clone()).alias(first_of_first_id.clone()),
+                        *ignore_nulls,
+                    ));
+                final_exprs.push(col(first_of_first_id.clone()).alias(output_name));
+            }
+            AggExpr::Last(e, ignore_nulls) => {
+                let last_id = agg_expr.semantic_id(schema).id;
+                let last_of_last_id = AggExpr::Last(col(last_id.clone()), *ignore_nulls)
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(last_id.clone())
+                    .or_insert(AggExpr::Last(
+                        e.alias(last_id.clone()).clone(),
+                        *ignore_nulls,
+                    ));
+                second_stage_aggs
+                    .entry(last_of_last_id.clone())
+                    .or_insert(AggExpr::Last(
+                        col(last_id.clone()).alias(last_of_last_id.clone()),
+                        *ignore_nulls,
+                    ));
+                final_exprs.push(col(last_of_last_id.clone()).alias(output_name));
+            }
             AggExpr::List(e) => {
                 let list_id = agg_expr.semantic_id(schema).id;
                 let concat_of_list_id =
@@ -1105,6 +1182,12 @@ pub fn populate_aggregation_stages(
             AggExpr::MergeSketch(..) => {
                 unimplemented!("User-facing merge_sketch aggregation is not implemented")
             }
+            AggExpr::VarianceState(..) => {
+                unimplemented!("User-facing variance_state aggregation is not implemented")
+            }
+            AggExpr::MergeVariance(..) => {
+                unimplemented!("User-facing merge_variance aggregation is not implemented")
+            }
         }
     }
     (first_stage_aggs, second_stage_aggs, final_exprs)
@@ -1117,10 +1200,10 @@ mod tests {
     use common_daft_config::DaftExecutionConfig;
     use common_error::DaftResult;
     use daft_core::prelude::*;
-    use daft_dsl::{col, lit};
+    use daft_dsl::{col, lit, AggExpr};
     use daft_logical_plan::LogicalPlanBuilder;
 
-    use super::HashJoin;
+    use super::{populate_aggregation_stages, HashJoin};
     use crate::{
         physical_planner::logical_to_physical,
         test::{dummy_scan_node, dummy_scan_operator},
@@ -1423,4 +1506,72 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn variance_aggregation_stages() {
+        let schema: SchemaRef = Schema::new(vec![Field::new("a", DataType::Int64)])
+            .unwrap()
+            .into();
+        let (first_stage, second_stage, final_exprs) =
+            populate_aggregation_stages(&[AggExpr::Variance(col("a"), 1)], &schema, &[]);
+
+        // (count, mean, M2) partial state per partition, merged pairwise in the second stage
+        assert_eq!(first_stage.len(), 1);
+        assert!(first_stage
+            .values()
+            .all(|agg| matches!(agg, AggExpr::VarianceState(_))));
+        assert_eq!(second_stage.len(), 1);
+        assert!(second_stage
+            .values()
+            .all(|agg| matches!(agg, AggExpr::MergeVariance(_, 1))));
+        assert_eq!(final_exprs.len(), 1);
+        assert_eq!(final_exprs[0].name(), "a");
+
+        // The stddev shares the partial state and merges into the population variance.
+        let (first_stage, second_stage, final_exprs) = populate_aggregation_stages(
+            &[
+                AggExpr::Variance(col("a"), 1),
+                AggExpr::Stddev(col("a").alias("b")),
+            ],
+            &schema,
+            &[],
+        );
+        assert_eq!(first_stage.len(), 1);
+        assert_eq!(second_stage.len(), 2);
+        assert!(second_stage
+            .values()
+            .any(|agg| matches!(agg, AggExpr::MergeVariance(_, 0))));
+        assert_eq!(final_exprs[1].name(), "b");
+    }
+
+    #[test]
+    fn first_and_last_aggregation_stages() {
+        let schema: SchemaRef = Schema::new(vec![Field::new("a", DataType::Int64)])
+            .unwrap()
+            .into();
+        let (first_stage, second_stage, final_exprs) = populate_aggregation_stages(
+            &[
+                AggExpr::First(col("a"), true),
+                AggExpr::Last(col("a").alias("b"), false),
+            ],
+            &schema,
+            &[],
+        );
+
+        // first-of-firsts and last-of-lasts
+        assert_eq!(first_stage.len(), 2);
+        assert_eq!(second_stage.len(), 2);
+        for stage in [&first_stage, &second_stage] {
+            assert!(stage
+                .values()
+                .any(|agg| matches!(agg, AggExpr::First(_, true))));
+            assert!(stage
+                .values()
+                .any(|agg| matches!(agg, AggExpr::Last(_, false))));
+        }
+        assert_eq!(
+            final_exprs.iter().map(|e| e.name()).collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+    }
 }
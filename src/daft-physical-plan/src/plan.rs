@@ -1,9 +1,18 @@
-use std::{cmp::max, collections::HashSet, ops::Add, sync::Arc};
+use std::{
+    cmp::max,
+    collections::{HashMap, HashSet},
+    ops::Add,
+    sync::Arc,
+};
 
 use common_display::ascii::AsciiTreeDisplay;
+use daft_core::{join::JoinType, prelude::Schema};
+use daft_dsl::{join::get_common_join_keys, Expr, ExprRef};
 use daft_logical_plan::partitioning::{
     ClusteringSpec, HashClusteringConfig, RangeClusteringConfig, UnknownClusteringConfig,
 };
+use daft_scan::ScanTask;
+use daft_stats::TableStatistics;
 use serde::{Deserialize, Serialize};
 
 use super::ops::*;
@@ -48,6 +57,8 @@ pub struct ApproxStats {
     pub upper_bound_rows: Option<usize>,
     pub lower_bound_bytes: usize,
     pub upper_bound_bytes: Option<usize>,
+    /// Upper bounds on the number of distinct values per column, if known.
+    pub column_ndv: Option<HashMap<String, usize>>,
 }
 
 impl ApproxStats {
@@ -57,18 +68,207 @@ impl ApproxStats {
             upper_bound_rows: None,
             lower_bound_bytes: 0,
             upper_bound_bytes: None,
+            column_ndv: None,
         }
     }
     fn apply<F: Fn(usize) -> usize>(&self, f: F) -> Self {
         Self {
             lower_bound_rows: f(self.lower_bound_rows),
             upper_bound_rows: self.upper_bound_rows.map(&f),
-            lower_bound_bytes: f(self.lower_bound_rows),
+            lower_bound_bytes: f(self.lower_bound_bytes),
             upper_bound_bytes: self.upper_bound_bytes.map(&f),
+            // Dropping rows can only reduce the number of distinct values.
+            column_ndv: self.column_ndv.clone(),
+        }
+    }
+
+    /// Stats of the output of a filter that keeps an estimated `estimated_selectivity` fraction of the rows,
+    /// and provably at most a `max_selectivity` fraction.
+    ///
+    /// Estimates are only applied to the lower bounds, since the upper bounds must stay valid for decisions like
+    /// broadcasting the smaller side of a join.
+    fn filter(&self, estimated_selectivity: f64, max_selectivity: f64) -> Self {
+        let scale_down = |v: usize| ((v as f64) * estimated_selectivity).floor() as usize;
+        let scale_up = |v: usize| ((v as f64) * max_selectivity).ceil() as usize;
+        Self {
+            lower_bound_rows: scale_down(self.lower_bound_rows),
+            upper_bound_rows: self.upper_bound_rows.map(scale_up),
+            lower_bound_bytes: scale_down(self.lower_bound_bytes),
+            upper_bound_bytes: self.upper_bound_bytes.map(scale_up),
+            column_ndv: self.column_ndv.clone(),
+        }
+    }
+
+    /// Scales the byte bounds by `ratio`, keeping the row bounds.
+    fn scale_bytes(&self, ratio: f64) -> Self {
+        let scale = |v: usize| ((v as f64) * ratio).ceil() as usize;
+        Self {
+            lower_bound_rows: self.lower_bound_rows,
+            upper_bound_rows: self.upper_bound_rows,
+            lower_bound_bytes: scale(self.lower_bound_bytes),
+            upper_bound_bytes: self.upper_bound_bytes.map(scale),
+            column_ndv: self.column_ndv.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_column_ndv(mut self, column_ndv: HashMap<String, usize>) -> Self {
+        self.column_ndv = Some(column_ndv);
+        self
+    }
+
+    fn ndv_of(&self, expr: &Expr) -> Option<usize> {
+        match expr {
+            Expr::Column(name) => self.column_ndv.as_ref()?.get(name.as_ref()).copied(),
+            Expr::Alias(inner, _) => self.ndv_of(inner),
+            _ => None,
+        }
+    }
+
+    /// NDVs of the output columns of a projection. Only columns that are passed through, possibly under a new
+    /// name, keep their NDVs, since other expressions may redefine them.
+    fn project_column_ndv(&self, projection: &[ExprRef]) -> Option<HashMap<String, usize>> {
+        let column_ndv = projection
+            .iter()
+            .filter_map(|e| self.ndv_of(e).map(|ndv| (e.name().to_string(), ndv)))
+            .collect::<HashMap<_, _>>();
+        (!column_ndv.is_empty()).then_some(column_ndv)
+    }
+
+    /// Upper bound on the number of groups produced by grouping on `groupby`.
+    ///
+    /// This is the product of the NDVs of the grouping columns, if all of them are known. NDVs only count
+    /// non-null values and column statistics don't track null counts, so each column may also add a null group.
+    fn groupby_cardinality(&self, groupby: &[ExprRef]) -> Option<usize> {
+        groupby.iter().try_fold(1usize, |acc, e| {
+            self.ndv_of(e)
+                .map(|ndv| acc.saturating_mul(ndv.saturating_add(1)))
+        })
+    }
+
+    fn aggregate(&self, groupby: &[ExprRef]) -> Self {
+        // TODO we should use schema inference here
+        let est_bytes_per_row_lower = self.lower_bound_bytes / (self.lower_bound_rows.max(1));
+        let est_bytes_per_row_upper = self
+            .upper_bound_bytes
+            .and_then(|bytes| self.upper_bound_rows.map(|rows| bytes / rows.max(1)));
+        if groupby.is_empty() {
+            Self {
+                lower_bound_rows: self.lower_bound_rows.min(1),
+                upper_bound_rows: Some(1),
+                lower_bound_bytes: self.lower_bound_bytes.min(1) * est_bytes_per_row_lower,
+                upper_bound_bytes: est_bytes_per_row_upper,
+                column_ndv: None,
+            }
+        } else {
+            let upper_bound_rows = match (self.upper_bound_rows, self.groupby_cardinality(groupby))
+            {
+                (Some(rows), Some(groups)) => Some(rows.min(groups)),
+                (rows, groups) => rows.or(groups),
+            };
+            // we should use the new schema here
+            let upper_bound_bytes = match (upper_bound_rows, est_bytes_per_row_upper) {
+                (Some(rows), Some(bytes_per_row)) => Some(rows * bytes_per_row),
+                _ => self.upper_bound_bytes,
+            };
+            let column_ndv = self.column_ndv.as_ref().map(|ndv| {
+                groupby
+                    .iter()
+                    .filter_map(|e| {
+                        self.ndv_of(e)
+                            .map(|v| (e.name().to_string(), v.min(upper_bound_rows.unwrap_or(v))))
+                    })
+                    .collect::<HashMap<_, _>>()
+            });
+            Self {
+                lower_bound_rows: self.lower_bound_rows.min(1),
+                upper_bound_rows,
+                lower_bound_bytes: self.lower_bound_bytes.min(1) * est_bytes_per_row_lower,
+                upper_bound_bytes,
+                column_ndv: column_ndv.filter(|ndv| !ndv.is_empty()),
+            }
+        }
+    }
+
+    /// Estimates the stats of a join from the stats of its inputs.
+    ///
+    /// This assumes a primary-key + foreign-key join, which yields as many rows as the larger side. Each
+    /// output row is as wide as a row of both sides combined, minus `duplicated_key_bytes_per_row` for the
+    /// join key columns that are merged rather than emitted once per side.
+    ///
+    /// Semi and anti joins instead emit a subset of the rows of one side, with only that side's columns.
+    fn join(
+        left: &Self,
+        right: &Self,
+        duplicated_key_bytes_per_row: usize,
+        join_type: JoinType,
+    ) -> Self {
+        let filtered = |side: &Self| Self {
+            lower_bound_rows: 0,
+            upper_bound_rows: side.upper_bound_rows,
+            lower_bound_bytes: 0,
+            upper_bound_bytes: side.upper_bound_bytes,
+            column_ndv: side.column_ndv.clone(),
+        };
+        match join_type {
+            JoinType::Semi | JoinType::Anti => return filtered(left),
+            JoinType::RightSemi | JoinType::RightAnti => return filtered(right),
+            JoinType::Inner
+            | JoinType::Left
+            | JoinType::Right
+            | JoinType::Outer
+            | JoinType::Cross
+            | JoinType::AsOf => {}
+        }
+        let bytes_per_row = |bytes: usize, rows: usize| bytes / rows.max(1);
+        let upper_bound_rows = left
+            .upper_bound_rows
+            .and_then(|l| right.upper_bound_rows.map(|r| l.max(r)));
+        let upper_bound_bytes = match (
+            upper_bound_rows,
+            left.upper_bound_bytes.zip(left.upper_bound_rows),
+            right.upper_bound_bytes.zip(right.upper_bound_rows),
+        ) {
+            (Some(rows), Some((l_bytes, l_rows)), Some((r_bytes, r_rows))) => {
+                let row_width = (bytes_per_row(l_bytes, l_rows) + bytes_per_row(r_bytes, r_rows))
+                    .saturating_sub(duplicated_key_bytes_per_row);
+                Some(rows * row_width)
+            }
+            _ => None,
+        };
+        Self {
+            lower_bound_rows: 0,
+            upper_bound_rows,
+            lower_bound_bytes: 0,
+            upper_bound_bytes,
+            column_ndv: None,
         }
     }
 }
 
+/// Estimated bytes per row of the join key columns that appear on both sides of a join and are merged
+/// into a single output column, i.e. the bytes that summing the sizes of both sides would double-count.
+pub(crate) fn duplicated_join_key_bytes_per_row(
+    left_schema: &Schema,
+    right_schema: &Schema,
+    left_on: &[ExprRef],
+    right_on: &[ExprRef],
+) -> usize {
+    get_common_join_keys(left_on, right_on)
+        .filter(|name| right_schema.get_field(name).is_ok())
+        .filter_map(|name| left_schema.get_field(name).ok())
+        .filter_map(|field| field.dtype.estimate_size_bytes())
+        .map(|bytes| bytes.ceil() as usize)
+        .sum()
+}
+
+/// Ratio of the estimated bytes per row of `output_schema` to that of `input_schema`, based on
+/// per-dtype size estimates. `None` if the input row size can't be estimated.
+pub(crate) fn row_size_ratio(input_schema: &Schema, output_schema: &Schema) -> Option<f64> {
+    let input_row_size = input_schema.estimate_row_size_bytes();
+    (input_row_size > 0.).then(|| output_schema.estimate_row_size_bytes() / input_row_size)
+}
+
 impl Add for &ApproxStats {
     type Output = ApproxStats;
     fn add(self, rhs: Self) -> Self::Output {
@@ -81,6 +281,15 @@ impl Add for &ApproxStats {
             upper_bound_bytes: self
                 .upper_bound_bytes
                 .and_then(|l_ub| rhs.upper_bound_bytes.map(|v| v + l_ub)),
+            column_ndv: self.column_ndv.as_ref().and_then(|l_ndv| {
+                let r_ndv = rhs.column_ndv.as_ref()?;
+                Some(
+                    l_ndv
+                        .iter()
+                        .filter_map(|(name, l)| r_ndv.get(name).map(|r| (name.clone(), l + r)))
+                        .collect(),
+                )
+            }),
         }
     }
 }
@@ -91,7 +300,76 @@ impl PhysicalPlan {
     }
 
     pub fn clustering_spec(&self) -> Arc<ClusteringSpec> {
-        // TODO: add cache or something to avoid excessive recalculation
+        match self.clustering_spec_cache() {
+            Some(cache) => cache.get_or_init(|| self.compute_clustering_spec()),
+            None => self.compute_clustering_spec(),
+        }
+    }
+
+    /// The memoized clustering spec of ops that derive theirs from their inputs.
+    fn clustering_spec_cache(&self) -> Option<&ClusteringSpecCache> {
+        match self {
+            Self::Filter(Filter {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::Limit(Limit {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::Sample(Sample {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::Sort(Sort {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::Aggregate(Aggregate {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::Pivot(Pivot {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::Concat(Concat {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::HashJoin(HashJoin {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::BroadcastJoin(BroadcastJoin {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::SortMergeJoin(SortMergeJoin {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::TabularWriteParquet(TabularWriteParquet {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::TabularWriteCsv(TabularWriteCsv {
+                clustering_spec_cache,
+                ..
+            })
+            | Self::TabularWriteJson(TabularWriteJson {
+                clustering_spec_cache,
+                ..
+            }) => Some(clustering_spec_cache),
+            _ => None,
+        }
+    }
+
+    fn compute_clustering_spec(&self) -> Arc<ClusteringSpec> {
         match self {
             Self::InMemoryScan(InMemoryScan {
                 clustering_spec, ..
@@ -144,7 +422,8 @@ impl PhysicalPlan {
                 //
                 // If the groupby keys are the partition columns (very likely, since we often partition by hash on the groupby keys), the
                 // clustering spec is still valid
-                let input_partition_by = input.clustering_spec().partition_by();
+                let input_clustering_spec = input.clustering_spec();
+                let input_partition_by = input_clustering_spec.partition_by();
                 let input_partition_col_names: HashSet<&str> =
                     input_partition_by.iter().map(|e| e.name()).collect();
                 if aggregations
@@ -153,15 +432,15 @@ impl PhysicalPlan {
                     .any(|name| input_partition_col_names.contains(name))
                 {
                     ClusteringSpec::Unknown(UnknownClusteringConfig::new(
-                        input.clustering_spec().num_partitions(),
+                        input_clustering_spec.num_partitions(),
                     ))
                     .into()
                 } else {
-                    input.clustering_spec()
+                    input_clustering_spec
                 }
             }
             Self::Pivot(Pivot { input, .. }) => input.clustering_spec(),
-            Self::Concat(Concat { input, other }) => {
+            Self::Concat(Concat { input, other, .. }) => {
                 ClusteringSpec::Unknown(UnknownClusteringConfig::new(
                     input.clustering_spec().num_partitions()
                         + other.clustering_spec().num_partitions(),
@@ -198,6 +477,7 @@ impl PhysicalPlan {
                 left,
                 right,
                 left_on,
+                descending,
                 ..
             }) => ClusteringSpec::Range(RangeClusteringConfig::new(
                 max(
@@ -205,8 +485,7 @@ impl PhysicalPlan {
                     right.clustering_spec().num_partitions(),
                 ),
                 left_on.clone(),
-                // TODO(Clark): Propagate descending vec once sort-merge join supports descending sort orders.
-                std::iter::repeat(false).take(left_on.len()).collect(),
+                descending.clone(),
             ))
             .into(),
             Self::TabularWriteParquet(TabularWriteParquet { input, .. }) => input.clustering_spec(),
@@ -226,6 +505,7 @@ impl PhysicalPlan {
                 upper_bound_rows: Some(in_memory_info.num_rows),
                 lower_bound_bytes: in_memory_info.size_bytes,
                 upper_bound_bytes: Some(in_memory_info.size_bytes),
+                column_ndv: None,
             },
             Self::TabularScan(TabularScan { scan_tasks, .. }) => {
                 let mut stats = ApproxStats::empty();
@@ -240,6 +520,13 @@ impl PhysicalPlan {
                         .upper_bound_bytes
                         .and_then(|st_ub| in_memory_size.map(|ub| st_ub + ub));
                 }
+                // Column ranges from the scan statistics bound the number of distinct values.
+                if let Some(table_stats) = self.table_statistics() {
+                    let column_ndv = table_stats.estimate_column_ndv();
+                    if !column_ndv.is_empty() {
+                        stats = stats.with_column_ndv(column_ndv);
+                    }
+                }
                 stats
             }
             Self::EmptyScan(..) => ApproxStats {
@@ -247,17 +534,23 @@ impl PhysicalPlan {
                 upper_bound_rows: Some(0),
                 lower_bound_bytes: 0,
                 upper_bound_bytes: Some(0),
+                column_ndv: None,
             },
             // Assume no row/column pruning in cardinality-affecting operations.
             // TODO(Clark): Estimate row/column pruning to get a better size approximation.
-            Self::Filter(Filter { input, .. }) => {
-                let input_stats = input.approximate_stats();
-                ApproxStats {
-                    lower_bound_rows: 0,
-                    upper_bound_rows: input_stats.upper_bound_rows,
-                    lower_bound_bytes: 0,
-                    upper_bound_bytes: input_stats.upper_bound_bytes,
-                }
+            Self::Filter(Filter {
+                input, predicate, ..
+            }) => {
+                let (estimated_selectivity, max_selectivity) =
+                    input.table_statistics().map_or((0., 1.), |stats| {
+                        (
+                            stats.estimate_selectivity(predicate),
+                            stats.max_selectivity(predicate),
+                        )
+                    });
+                input
+                    .approximate_stats()
+                    .filter(estimated_selectivity, max_selectivity)
             }
             Self::Limit(Limit { input, limit, .. }) => {
                 let limit = *limit as usize;
@@ -277,13 +570,33 @@ impl PhysicalPlan {
                     upper_bound_rows: Some(new_upper_rows),
                     lower_bound_bytes: new_lower_rows * est_bytes_per_row_lower,
                     upper_bound_bytes: est_bytes_per_row_upper.map(|x| x * new_upper_rows),
+                    column_ndv: input_stats.column_ndv,
                 }
             }
-            Self::Project(Project { input, .. })
-            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. })
-            | Self::ActorPoolProject(ActorPoolProject { input, .. }) => {
-                // TODO(sammy), we need the schema to estimate the new size per row
-                input.approximate_stats()
+            Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
+                input,
+                row_size_ratio,
+                ..
+            }) => input
+                .approximate_stats()
+                .scale_bytes(row_size_ratio.unwrap_or(1.)),
+            Self::Project(Project {
+                input,
+                projection,
+                row_size_ratio,
+                ..
+            })
+            | Self::ActorPoolProject(ActorPoolProject {
+                input,
+                projection,
+                row_size_ratio,
+                ..
+            }) => {
+                let input_stats = input.approximate_stats();
+                ApproxStats {
+                    column_ndv: input_stats.project_column_ndv(projection),
+                    ..input_stats.scale_bytes(row_size_ratio.unwrap_or(1.))
+                }
             }
             Self::Sample(Sample {
                 input, fraction, ..
@@ -297,66 +610,63 @@ impl PhysicalPlan {
                     upper_bound_rows: None,
                     lower_bound_bytes: input_stats.lower_bound_bytes,
                     upper_bound_bytes: None,
+                    column_ndv: None,
                 }
             }
             // Propagate child approximation for operations that don't affect cardinality.
             Self::Sort(Sort { input, .. })
-            | Self::Pivot(Pivot { input, .. })
             | Self::ShuffleExchange(ShuffleExchange { input, .. }) => input.approximate_stats(),
-            Self::Concat(Concat { input, other }) => {
+            Self::Pivot(Pivot { input, .. }) => ApproxStats {
+                column_ndv: None,
+                ..input.approximate_stats()
+            },
+            Self::Concat(Concat { input, other, .. }) => {
                 &input.approximate_stats() + &other.approximate_stats()
             }
-            // Assume a simple sum of the sizes of both sides of the join for the post-join size.
-            // TODO(Clark): This will double-count join key columns, we should ensure that these are only counted once.
+            // Assume each output row carries the columns of both sides, with shared join key columns counted once.
             Self::BroadcastJoin(BroadcastJoin {
-                broadcaster: left,
-                receiver: right,
+                broadcaster,
+                receiver,
+                duplicated_key_bytes_per_row,
+                join_type,
+                is_swapped,
                 ..
-            })
-            | Self::HashJoin(HashJoin { left, right, .. })
-            | Self::SortMergeJoin(SortMergeJoin { left, right, .. }) => {
-                // assume a Primary-key + Foreign-Key join which would yield the max of the two tables
-                let left_stats = left.approximate_stats();
-                let right_stats = right.approximate_stats();
-
-                ApproxStats {
-                    lower_bound_rows: 0,
-                    upper_bound_rows: left_stats
-                        .upper_bound_rows
-                        .and_then(|l| right_stats.upper_bound_rows.map(|r| l.max(r))),
-                    lower_bound_bytes: 0,
-                    upper_bound_bytes: left_stats
-                        .upper_bound_bytes
-                        .and_then(|l| right_stats.upper_bound_bytes.map(|r| l.max(r))),
-                }
+            }) => {
+                // The broadcaster is the right side of the join when the inputs were swapped.
+                let (left, right) = if *is_swapped {
+                    (receiver, broadcaster)
+                } else {
+                    (broadcaster, receiver)
+                };
+                ApproxStats::join(
+                    &left.approximate_stats(),
+                    &right.approximate_stats(),
+                    *duplicated_key_bytes_per_row,
+                    *join_type,
+                )
             }
-            // TODO(Clark): Approximate post-aggregation sizes via grouping estimates + aggregation type.
+            Self::HashJoin(HashJoin {
+                left,
+                right,
+                duplicated_key_bytes_per_row,
+                join_type,
+                ..
+            })
+            | Self::SortMergeJoin(SortMergeJoin {
+                left,
+                right,
+                duplicated_key_bytes_per_row,
+                join_type,
+                ..
+            }) => ApproxStats::join(
+                &left.approximate_stats(),
+                &right.approximate_stats(),
+                *duplicated_key_bytes_per_row,
+                *join_type,
+            ),
+            // TODO(Clark): Approximate post-aggregation sizes via aggregation type.
             Self::Aggregate(Aggregate { input, groupby, .. }) => {
-                let input_stats = input.approximate_stats();
-                // TODO we should use schema inference here
-                let est_bytes_per_row_lower =
-                    input_stats.lower_bound_bytes / (input_stats.lower_bound_rows.max(1));
-                let est_bytes_per_row_upper = input_stats
-                    .upper_bound_bytes
-                    .and_then(|bytes| input_stats.upper_bound_rows.map(|rows| bytes / rows.max(1)));
-                if groupby.is_empty() {
-                    ApproxStats {
-                        lower_bound_rows: input_stats.lower_bound_rows.min(1),
-                        upper_bound_rows: Some(1),
-                        lower_bound_bytes: input_stats.lower_bound_bytes.min(1)
-                            * est_bytes_per_row_lower,
-                        upper_bound_bytes: est_bytes_per_row_upper,
-                    }
-                } else {
-                    // we should use the new schema here
-                    ApproxStats {
-                        lower_bound_rows: input_stats.lower_bound_rows.min(1),
-                        upper_bound_rows: input_stats.upper_bound_rows,
-                        lower_bound_bytes: input_stats.lower_bound_bytes.min(1)
-                            * est_bytes_per_row_lower,
-                        upper_bound_bytes: input_stats.upper_bound_bytes,
-                    }
-                }
+                input.approximate_stats().aggregate(groupby)
             }
             Self::Unpivot(Unpivot { input, values, .. }) => {
                 let input_stats = input.approximate_stats();
@@ -367,6 +677,7 @@ impl PhysicalPlan {
                     upper_bound_rows: input_stats.upper_bound_rows.map(|v| v * num_values),
                     lower_bound_bytes: input_stats.lower_bound_bytes,
                     upper_bound_bytes: input_stats.upper_bound_bytes,
+                    column_ndv: None,
                 }
             }
             // Post-write DataFrame will contain paths to files that were written.
@@ -381,6 +692,35 @@ impl PhysicalPlan {
         }
     }
 
+    /// Column range statistics of this node's output, if they can be derived from scan statistics.
+    ///
+    /// Statistics are only propagated through operations that can't widen column ranges.
+    pub fn table_statistics(&self) -> Option<TableStatistics> {
+        match self {
+            Self::TabularScan(TabularScan { scan_tasks, .. }) => {
+                let mut stats: Option<TableStatistics> = None;
+                for st in scan_tasks {
+                    let st_stats = st
+                        .as_any()
+                        .downcast_ref::<ScanTask>()?
+                        .statistics
+                        .as_ref()?;
+                    stats = Some(match stats {
+                        Some(acc) => acc.union(st_stats).ok()?,
+                        None => st_stats.clone(),
+                    });
+                }
+                stats
+            }
+            Self::Filter(Filter { input, .. })
+            | Self::Limit(Limit { input, .. })
+            | Self::Sample(Sample { input, .. })
+            | Self::Sort(Sort { input, .. })
+            | Self::ShuffleExchange(ShuffleExchange { input, .. }) => input.table_statistics(),
+            _ => None,
+        }
+    }
+
     pub fn children(&self) -> Vec<&Self> {
         match self {
             Self::InMemoryScan(..) => vec![],
@@ -414,7 +754,7 @@ impl PhysicalPlan {
             Self::SortMergeJoin(SortMergeJoin { left, right, .. }) => {
                 vec![left, right]
             }
-            Self::Concat(Concat { input, other }) => vec![input, other],
+            Self::Concat(Concat { input, other, .. }) => vec![input, other],
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 vec![input]
             }
@@ -427,12 +767,12 @@ impl PhysicalPlan {
                 Self::InMemoryScan(..) => panic!("Source nodes don't have children, with_new_children() should never be called for source ops"),
                 Self::TabularScan(..)
                 | Self::EmptyScan(..) => panic!("Source nodes don't have children, with_new_children() should never be called for source ops"),
-                Self::Project(Project { projection, clustering_spec, .. }) =>
+                Self::Project(Project { projection, clustering_spec, row_size_ratio, .. }) =>
                     Self::Project(Project::new_with_clustering_spec(
                     input.clone(), projection.clone(), clustering_spec.clone(),
-                ).unwrap()),
+                ).unwrap().with_row_size_ratio(*row_size_ratio)),
 
-                Self::ActorPoolProject(ActorPoolProject {projection, ..}) => Self::ActorPoolProject(ActorPoolProject::try_new(input.clone(), projection.clone()).unwrap()),
+                Self::ActorPoolProject(ActorPoolProject {projection, row_size_ratio, ..}) => Self::ActorPoolProject(ActorPoolProject::try_new(input.clone(), projection.clone()).unwrap().with_row_size_ratio(*row_size_ratio)),
                 Self::Filter(Filter { predicate, .. }) => Self::Filter(Filter::new(input.clone(), predicate.clone())),
                 Self::Limit(Limit { limit, eager, num_partitions, .. }) => Self::Limit(Limit::new(input.clone(), *limit, *eager, *num_partitions)),
                 Self::Explode(Explode { to_explode, .. }) => Self::Explode(Explode::try_new(input.clone(), to_explode.clone()).unwrap()),
@@ -445,7 +785,7 @@ impl PhysicalPlan {
                 Self::TabularWriteParquet(TabularWriteParquet { schema, file_info, .. }) => Self::TabularWriteParquet(TabularWriteParquet::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteCsv(TabularWriteCsv { schema, file_info, .. }) => Self::TabularWriteCsv(TabularWriteCsv::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteJson(TabularWriteJson { schema, file_info, .. }) => Self::TabularWriteJson(TabularWriteJson::new(schema.clone(), file_info.clone(), input.clone())),
-                Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { column_name, .. }) => Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId::new(input.clone(), column_name)),
+                Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { column_name, row_size_ratio, .. }) => Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId::new(input.clone(), column_name).with_row_size_ratio(*row_size_ratio)),
                 #[cfg(feature = "python")]
                 Self::IcebergWrite(IcebergWrite { schema, iceberg_info, .. }) => Self::IcebergWrite(IcebergWrite::new(schema.clone(), iceberg_info.clone(), input.clone())),
                 #[cfg(feature = "python")]
@@ -459,16 +799,17 @@ impl PhysicalPlan {
                 Self::InMemoryScan(..) => panic!("Source nodes don't have children, with_new_children() should never be called for source ops"),
                 Self::TabularScan(..)
                 | Self::EmptyScan(..) => panic!("Source nodes don't have children, with_new_children() should never be called for source ops"),
-                Self::HashJoin(HashJoin { left_on, right_on, null_equals_nulls, join_type, .. }) => Self::HashJoin(HashJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), null_equals_nulls.clone(), *join_type)),
+                Self::HashJoin(HashJoin { left_on, right_on, null_equals_nulls, join_type, duplicated_key_bytes_per_row, .. }) => Self::HashJoin(HashJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), null_equals_nulls.clone(), *join_type).with_duplicated_key_bytes_per_row(*duplicated_key_bytes_per_row)),
                 Self::BroadcastJoin(BroadcastJoin {
                     left_on,
                     right_on,
                     null_equals_nulls,
                     join_type,
                     is_swapped,
+                    duplicated_key_bytes_per_row,
                     ..
-                }) => Self::BroadcastJoin(BroadcastJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), null_equals_nulls.clone(), *join_type, *is_swapped)),
-                Self::SortMergeJoin(SortMergeJoin { left_on, right_on, join_type, num_partitions, left_is_larger, needs_presort, .. }) => Self::SortMergeJoin(SortMergeJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), *join_type, *num_partitions, *left_is_larger, *needs_presort)),
+                }) => Self::BroadcastJoin(BroadcastJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), null_equals_nulls.clone(), *join_type, *is_swapped).with_duplicated_key_bytes_per_row(*duplicated_key_bytes_per_row)),
+                Self::SortMergeJoin(SortMergeJoin { left_on, right_on, descending, join_type, num_partitions, left_is_larger, needs_presort, duplicated_key_bytes_per_row, .. }) => Self::SortMergeJoin(SortMergeJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), descending.clone(), *join_type, *num_partitions, *left_is_larger, *needs_presort).with_duplicated_key_bytes_per_row(*duplicated_key_bytes_per_row)),
                 Self::Concat(_) => Self::Concat(Concat::new(input1.clone(), input2.clone())),
                 _ => panic!("Physical op {:?} has one input, but got two", self),
             },
@@ -559,3 +900,339 @@ impl PhysicalPlan {
         s
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc, time::Instant};
+
+    use daft_core::prelude::*;
+    use daft_dsl::{binary_op, col, lit, AggExpr, Operator};
+    use daft_logical_plan::partitioning::{
+        ClusteringSpec, HashClusteringConfig, UnknownClusteringConfig,
+    };
+    use daft_stats::{ColumnRangeStatistics, TableStatistics};
+
+    use super::{duplicated_join_key_bytes_per_row, row_size_ratio, ApproxStats};
+    use crate::{
+        ops::{Aggregate, EmptyScan, Filter, Project, Sort},
+        PhysicalPlan, PhysicalPlanRef,
+    };
+
+    fn empty_scan(clustering_spec: ClusteringSpec) -> PhysicalPlanRef {
+        let schema = Arc::new(
+            Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Int64),
+            ])
+            .unwrap(),
+        );
+        PhysicalPlan::EmptyScan(EmptyScan::new(schema, clustering_spec.into())).arced()
+    }
+
+    fn input_stats() -> ApproxStats {
+        ApproxStats {
+            lower_bound_rows: 1000,
+            upper_bound_rows: Some(1000),
+            lower_bound_bytes: 8000,
+            upper_bound_bytes: Some(8000),
+            column_ndv: None,
+        }
+    }
+
+    #[test]
+    fn test_aggregate_uses_groupby_ndv() {
+        let stats = input_stats()
+            .with_column_ndv(HashMap::from([("a".to_string(), 3), ("b".to_string(), 50)]));
+
+        // Each key may also have a null group.
+        let agg = stats.aggregate(&[col("a")]);
+        assert_eq!(agg.upper_bound_rows, Some(4));
+        assert_eq!(agg.upper_bound_bytes, Some(32));
+        assert_eq!(agg.column_ndv, Some(HashMap::from([("a".to_string(), 3)])));
+
+        let agg = stats.aggregate(&[col("a"), col("b")]);
+        assert_eq!(agg.upper_bound_rows, Some(4 * 51));
+    }
+
+    #[test]
+    fn test_aggregate_counts_null_group_of_nullable_key() {
+        let key = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), Some(2), None, Some(1)].into_iter(),
+        )
+        .into_series();
+        let table_stats = TableStatistics {
+            columns: [("a".to_string(), ColumnRangeStatistics::from_series(&key))]
+                .into_iter()
+                .collect(),
+        };
+        let stats = input_stats().with_column_ndv(table_stats.estimate_column_ndv());
+
+        // Grouping yields the groups 1, 2 and null.
+        assert_eq!(
+            stats.column_ndv,
+            Some(HashMap::from([("a".to_string(), 2)]))
+        );
+        assert_eq!(stats.aggregate(&[col("a")]).upper_bound_rows, Some(3));
+    }
+
+    #[test]
+    fn test_column_statistics_ndv_tightens_aggregate_through_projection() {
+        let int_range = |lower: i64, upper: i64| {
+            ColumnRangeStatistics::new(
+                Some(Int64Array::from(("lower", vec![lower])).into_series()),
+                Some(Int64Array::from(("upper", vec![upper])).into_series()),
+            )
+            .unwrap()
+        };
+        let table_stats = TableStatistics {
+            columns: [
+                ("a".to_string(), int_range(1, 3)),
+                ("b".to_string(), int_range(0, 999)),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        let stats = input_stats().with_column_ndv(table_stats.estimate_column_ndv());
+
+        // A renamed column keeps its NDV, while a computed column does not.
+        let projection = [
+            col("a").alias("k"),
+            binary_op(Operator::Plus, col("b"), lit(1)).alias("b"),
+        ];
+        let projected = ApproxStats {
+            column_ndv: stats.project_column_ndv(&projection),
+            ..stats.scale_bytes(1.)
+        };
+        assert_eq!(
+            projected.column_ndv,
+            Some(HashMap::from([("k".to_string(), 3)]))
+        );
+        assert_eq!(projected.aggregate(&[col("k")]).upper_bound_rows, Some(4));
+        assert_eq!(
+            projected.aggregate(&[col("b")]).upper_bound_rows,
+            Some(1000)
+        );
+
+        // Without column statistics the estimate falls back to the input rows.
+        assert!(input_stats().project_column_ndv(&projection).is_none());
+        assert_eq!(
+            input_stats().aggregate(&[col("a")]).upper_bound_rows,
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_aggregate_without_ndv_falls_back_to_input_rows() {
+        let stats = input_stats().with_column_ndv(HashMap::from([("a".to_string(), 3)]));
+        assert_eq!(stats.aggregate(&[col("c")]).upper_bound_rows, Some(1000));
+        assert_eq!(
+            input_stats().aggregate(&[col("a")]).upper_bound_rows,
+            Some(1000)
+        );
+        assert_eq!(input_stats().aggregate(&[]).upper_bound_rows, Some(1));
+    }
+
+    #[test]
+    fn test_filter_only_shrinks_upper_bounds_when_proven() {
+        // A guessed selectivity only moves the lower bounds.
+        let selective = input_stats().filter(0.001, 1.);
+        let non_selective = input_stats().filter(0.9, 1.);
+        assert_eq!(selective.lower_bound_rows, 1);
+        assert_eq!(non_selective.lower_bound_rows, 900);
+        assert_eq!(non_selective.lower_bound_bytes, 7200);
+        for stats in [&selective, &non_selective] {
+            assert_eq!(stats.upper_bound_rows, Some(1000));
+            assert_eq!(stats.upper_bound_bytes, Some(8000));
+        }
+
+        // A predicate that the statistics prove to be always false empties the output.
+        let never = input_stats().filter(0., 0.);
+        assert_eq!(never.upper_bound_rows, Some(0));
+        assert_eq!(never.upper_bound_bytes, Some(0));
+    }
+
+    #[test]
+    fn test_apply_scales_bytes_from_bytes() {
+        let stats = input_stats().apply(|v| v / 2);
+        assert_eq!(stats.lower_bound_rows, 500);
+        assert_eq!(stats.upper_bound_rows, Some(500));
+        assert_eq!(stats.lower_bound_bytes, 4000);
+        assert_eq!(stats.upper_bound_bytes, Some(4000));
+    }
+
+    #[test]
+    fn test_join_stats_count_duplicated_keys_once() {
+        let left = input_stats();
+        let right = ApproxStats {
+            lower_bound_rows: 100,
+            upper_bound_rows: Some(100),
+            lower_bound_bytes: 1600,
+            upper_bound_bytes: Some(1600),
+            column_ndv: None,
+        };
+
+        // 8 bytes per left row and 16 bytes per right row over the larger side's 1000 rows.
+        let without_overlap = ApproxStats::join(&left, &right, 0, JoinType::Inner);
+        assert_eq!(without_overlap.upper_bound_rows, Some(1000));
+        assert_eq!(without_overlap.upper_bound_bytes, Some(24000));
+
+        let with_overlap = ApproxStats::join(&left, &right, 8, JoinType::Inner);
+        assert_eq!(with_overlap.upper_bound_rows, Some(1000));
+        assert_eq!(with_overlap.upper_bound_bytes, Some(16000));
+
+        let unknown = ApproxStats::join(&left, &ApproxStats::empty(), 8, JoinType::Inner);
+        assert_eq!(unknown.upper_bound_bytes, None);
+    }
+
+    #[test]
+    fn test_semi_and_anti_join_stats_keep_one_side() {
+        let left = input_stats();
+        let right = ApproxStats {
+            lower_bound_rows: 2000,
+            upper_bound_rows: Some(2000),
+            lower_bound_bytes: 32000,
+            upper_bound_bytes: Some(32000),
+            column_ndv: None,
+        };
+
+        // Neither the right side's columns nor its row count show up in the output.
+        for join_type in [JoinType::Semi, JoinType::Anti] {
+            let stats = ApproxStats::join(&left, &right, 8, join_type);
+            assert_eq!(stats.upper_bound_rows, Some(1000));
+            assert_eq!(stats.upper_bound_bytes, Some(8000));
+        }
+        for join_type in [JoinType::RightSemi, JoinType::RightAnti] {
+            let stats = ApproxStats::join(&left, &right, 8, join_type);
+            assert_eq!(stats.upper_bound_rows, Some(2000));
+            assert_eq!(stats.upper_bound_bytes, Some(32000));
+        }
+    }
+
+    #[test]
+    fn test_duplicated_join_key_bytes_per_row() {
+        let left = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int32),
+        ])
+        .unwrap();
+        let right = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Int32),
+        ])
+        .unwrap();
+        let int64_bytes = DataType::Int64.estimate_size_bytes().unwrap().ceil() as usize;
+
+        assert_eq!(
+            duplicated_join_key_bytes_per_row(&left, &right, &[col("a")], &[col("a")]),
+            int64_bytes
+        );
+        // Keys with different names on each side are both kept in the output.
+        assert_eq!(
+            duplicated_join_key_bytes_per_row(&left, &right, &[col("b")], &[col("c")]),
+            0
+        );
+        assert_eq!(
+            duplicated_join_key_bytes_per_row(
+                &left,
+                &right,
+                &[col("a"), col("b")],
+                &[col("a"), col("c")]
+            ),
+            int64_bytes
+        );
+    }
+
+    #[test]
+    fn test_projecting_away_string_column_reduces_bytes() {
+        let input = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("text", DataType::Utf8),
+        ])
+        .unwrap();
+        let output = Schema::new(vec![Field::new("id", DataType::Int64)]).unwrap();
+
+        let ratio = row_size_ratio(&input, &output).unwrap();
+        assert!(ratio < 1.);
+        let projected = input_stats().scale_bytes(ratio);
+        assert_eq!(projected.upper_bound_rows, Some(1000));
+        assert!(projected.upper_bound_bytes.unwrap() < 8000);
+        assert!(projected.lower_bound_bytes < 8000);
+
+        // Adding a column grows the estimate instead.
+        assert!(row_size_ratio(&output, &input).unwrap() > 1.);
+        // Nothing to scale from if the input row size is unknown.
+        assert_eq!(row_size_ratio(&Schema::empty(), &output), None);
+    }
+
+    #[test]
+    fn test_with_new_children_keeps_row_size_ratio() {
+        let scan = empty_scan(ClusteringSpec::Unknown(UnknownClusteringConfig::new(1)));
+        let project = PhysicalPlan::Project(
+            Project::try_new(scan.clone(), vec![col("a")])
+                .unwrap()
+                .with_row_size_ratio(Some(0.5)),
+        );
+        let PhysicalPlan::Project(rebuilt) = project.with_new_children(&[scan]) else {
+            panic!("expected a Project");
+        };
+        assert_eq!(rebuilt.row_size_ratio, Some(0.5));
+    }
+
+    #[test]
+    fn test_cached_clustering_spec_matches_computed() {
+        let scan = empty_scan(ClusteringSpec::Hash(HashClusteringConfig::new(
+            4,
+            vec![col("a")],
+        )));
+        let agg = PhysicalPlan::Aggregate(Aggregate::new(
+            scan,
+            vec![AggExpr::Sum(col("b"))],
+            vec![col("a")],
+        ));
+        let computed = agg.compute_clustering_spec();
+        assert_eq!(agg.clustering_spec(), computed);
+        // The second call is served from the cache.
+        assert_eq!(agg.clustering_spec(), computed);
+    }
+
+    #[test]
+    fn test_with_new_children_recomputes_clustering_spec() {
+        let sort = PhysicalPlan::Sort(Sort::new(
+            empty_scan(ClusteringSpec::Unknown(UnknownClusteringConfig::new(4))),
+            vec![col("a")],
+            vec![false],
+            vec![false],
+            4,
+        ));
+        assert_eq!(sort.clustering_spec().num_partitions(), 4);
+
+        let new_input = empty_scan(ClusteringSpec::Unknown(UnknownClusteringConfig::new(8)));
+        let sort = sort.with_new_children(&[new_input]);
+        assert_eq!(sort.clustering_spec().num_partitions(), 8);
+    }
+
+    #[test]
+    fn test_deep_plan_clustering_spec_is_fast() {
+        let mut plan = empty_scan(ClusteringSpec::Hash(HashClusteringConfig::new(
+            4,
+            vec![col("a")],
+        )));
+        // Each aggregate used to consult its input's clustering spec twice, so an uncached walk of this
+        // plan would take on the order of 2^depth calls.
+        for _ in 0..64 {
+            plan = PhysicalPlan::Aggregate(Aggregate::new(
+                plan,
+                vec![AggExpr::Sum(col("b"))],
+                vec![col("a")],
+            ))
+            .arced();
+            plan = PhysicalPlan::Filter(Filter::new(plan, col("b").gt(lit(0)))).arced();
+        }
+        let start = Instant::now();
+        for _ in 0..1000 {
+            assert_eq!(plan.clustering_spec().num_partitions(), 4);
+        }
+        assert!(start.elapsed().as_secs() < 5);
+    }
+}
@@ -278,10 +278,8 @@ pub fn delta_scan<T: AsRef<str>>(
     Python::with_gil(|py| {
         let io_config = io_config.unwrap_or_default();
 
-        let native_storage_config = NativeStorageConfig {
-            io_config: Some(io_config),
-            multithreaded_io,
-        };
+        let native_storage_config =
+            NativeStorageConfig::new_internal(multithreaded_io, Some(io_config));
 
         let py_storage_config: PyStorageConfig =
             Arc::new(StorageConfig::Native(Arc::new(native_storage_config))).into();
@@ -8,20 +8,20 @@ use common_scan_info::{PartitionField, Pushdowns, ScanOperator, ScanTaskLike, Sc
 use daft_core::{prelude::Utf8Array, series::IntoSeries};
 use daft_csv::CsvParseOptions;
 use daft_io::{parse_url, FileMetadata, IOClient, IOStatsContext, IOStatsRef};
-use daft_parquet::read::ParquetSchemaInferenceOptions;
+use daft_parquet::read::{read_parquet_metadata, ParquetSchemaInferenceOptions};
 use daft_schema::{
     dtype::DataType,
     field::Field,
     schema::{Schema, SchemaRef},
 };
-use daft_stats::PartitionSpec;
+use daft_stats::{PartitionSpec, TableMetadata};
 use daft_table::Table;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use snafu::Snafu;
 
 use crate::{
     hive::{hive_partitions_to_fields, hive_partitions_to_series, parse_hive_partitioning},
-    scan_task_iters::{merge_by_sizes, split_by_row_groups, BoxScanTaskIter},
+    scan_task_iters::{limit_by_known_rows, merge_by_sizes, split_by_row_groups, BoxScanTaskIter},
     storage_config::StorageConfig,
     ChunkSpec, DataSource, ScanTask,
 };
@@ -107,9 +107,8 @@ fn run_glob_parallel(
     runtime: RuntimeRef,
     io_stats: Option<IOStatsRef>,
     file_format: FileFormat,
+    num_parallel_tasks: usize,
 ) -> DaftResult<impl Iterator<Item = DaftResult<FileMetadata>>> {
-    let num_parallel_tasks = 64;
-
     let owned_runtime = runtime.clone();
     let boxstream = futures::stream::iter(glob_paths.into_iter().map(move |path| {
         let (_, parsed_glob_path) = parse_url(&path).unwrap();
@@ -168,6 +167,7 @@ impl GlobScanOperator {
             io_runtime,
             Some(io_stats.clone()),
             file_format,
+            self.storage_config.glob_concurrency(),
         )?;
         let FileMetadata {
             filepath: first_filepath,
@@ -280,7 +280,24 @@ impl GlobScanOperator {
                     }
                 };
                 match user_provided_schema {
-                    Some(hint) => Arc::new(inferred_schema.apply_hints(&hint)?),
+                    Some(hint) => {
+                        // Hints for columns that are neither in the file nor generated are ignored,
+                        // so that a single hint can be shared across differently shaped sources.
+                        let ignored_hints = hint
+                            .names()
+                            .into_iter()
+                            .filter(|name| {
+                                !inferred_schema.fields.contains_key(name)
+                                    && !generated_fields.fields.contains_key(name)
+                            })
+                            .collect::<Vec<_>>();
+                        if !ignored_hints.is_empty() {
+                            log::debug!(
+                                "Ignoring schema hints for columns not found in {first_filepath}: {ignored_hints:?}"
+                            );
+                        }
+                        Arc::new(inferred_schema.apply_hints(&hint)?)
+                    }
                     None => Arc::new(inferred_schema),
                 }
             }
@@ -372,10 +389,11 @@ impl ScanOperator for GlobScanOperator {
 
         let files = run_glob_parallel(
             self.glob_paths.clone(),
-            io_client,
-            io_runtime,
-            Some(io_stats),
+            io_client.clone(),
+            io_runtime.clone(),
+            Some(io_stats.clone()),
             file_format,
+            self.storage_config.glob_concurrency(),
         )?;
 
         let file_format_config = self.file_format_config.clone();
@@ -391,6 +409,24 @@ impl ScanOperator for GlobScanOperator {
         } else {
             None
         };
+        // With a limit and no filters, reading each Parquet footer gives an exact row count per file,
+        // which lets us stop globbing as soon as the limit is known to be satisfied.
+        let footer_field_id_mapping = match (
+            self.file_format_config.as_ref(),
+            self.storage_config.as_ref(),
+            &row_groups,
+        ) {
+            (
+                FileFormatConfig::Parquet(ParquetSourceConfig {
+                    field_id_mapping, ..
+                }),
+                StorageConfig::Native(_),
+                None,
+            ) if pushdowns.limit.is_some() && pushdowns.filters.is_none() => {
+                Some(field_id_mapping.clone())
+            }
+            _ => None,
+        };
         let file_path_column = self.file_path_column.clone();
         let hive_partitioning = self.hive_partitioning;
         let partition_fields = self
@@ -445,16 +481,31 @@ impl ScanOperator for GlobScanOperator {
                     .and_then(|rgs| rgs.get(idx).cloned())
                     .flatten();
                 let chunk_spec = row_group.map(ChunkSpec::Parquet);
+                let parquet_metadata = footer_field_id_mapping
+                    .as_ref()
+                    .map(|field_id_mapping| {
+                        io_runtime.block_on_current_thread(read_parquet_metadata(
+                            &path,
+                            io_client.clone(),
+                            Some(io_stats.clone()),
+                            field_id_mapping.clone(),
+                        ))
+                    })
+                    .transpose()?
+                    .map(Arc::new);
+                let metadata = parquet_metadata
+                    .as_ref()
+                    .map(|m| TableMetadata { length: m.num_rows });
                 Ok(Some(ScanTask::new(
                     vec![DataSource::File {
                         path,
                         chunk_spec,
                         size_bytes,
                         iceberg_delete_files: None,
-                        metadata: None,
+                        metadata,
                         partition_spec,
                         statistics: None,
-                        parquet_metadata: None,
+                        parquet_metadata,
                     }],
                     file_format_config.clone(),
                     schema.clone(),
@@ -470,6 +521,12 @@ impl ScanOperator for GlobScanOperator {
             }
         }));
 
+        if footer_field_id_mapping.is_some()
+            && let Some(limit) = pushdowns.limit
+        {
+            scan_tasks = limit_by_known_rows(scan_tasks, limit);
+        }
+
         if let Some(cfg) = cfg {
             scan_tasks = split_by_row_groups(
                 scan_tasks,
@@ -254,4 +254,25 @@ mod tests {
         assert_eq!(partitions.get("empty_key"), Some(&"".to_string()));
         assert_eq!(partitions.get("another"), Some(&"".to_string()));
     }
+
+    #[test]
+    fn test_hive_partitions_to_fields_and_series() -> DaftResult<()> {
+        let uri = "s3://bucket/year=2021/month=03/region=__HIVE_DEFAULT_PARTITION__/part.parquet";
+        let partitions = parse_hive_partitioning(uri)?;
+
+        let fields = hive_partitions_to_fields(&partitions);
+        let names = fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["year", "month", "region"]);
+        assert_eq!(fields[0].dtype, DaftDataType::Int64);
+        assert_eq!(fields[1].dtype, DaftDataType::Int64);
+        // Null partition values can't be inferred, so they fall back to strings.
+        assert_eq!(fields[2].dtype, DaftDataType::Utf8);
+
+        let series = hive_partitions_to_series(&partitions, &Schema::new(fields)?)?;
+        assert_eq!(series.len(), 3);
+        assert_eq!(series[0].i64()?.get(0), Some(2021));
+        assert_eq!(series[1].i64()?.get(0), Some(3));
+        assert_eq!(series[2].null_count(), 1);
+        Ok(())
+    }
 }
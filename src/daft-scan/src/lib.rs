@@ -771,12 +771,12 @@ mod test {
     use common_error::DaftResult;
     use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
     use common_scan_info::{Pushdowns, ScanOperator};
-    use daft_schema::{schema::Schema, time_unit::TimeUnit};
+    use daft_schema::{dtype::DataType, field::Field, schema::Schema, time_unit::TimeUnit};
     use itertools::Itertools;
 
     use crate::{
         glob::GlobScanOperator,
-        storage_config::{NativeStorageConfig, StorageConfig},
+        storage_config::{NativeStorageConfig, StorageConfig, DEFAULT_GLOB_CONCURRENCY},
         DataSource, ScanTask,
     };
 
@@ -814,6 +814,16 @@ mod test {
     }
 
     fn make_glob_scan_operator(num_sources: usize) -> GlobScanOperator {
+        make_glob_scan_operator_with_storage_config(
+            num_sources,
+            NativeStorageConfig::new_internal(false, None),
+        )
+    }
+
+    fn make_glob_scan_operator_with_storage_config(
+        num_sources: usize,
+        storage_config: NativeStorageConfig,
+    ) -> GlobScanOperator {
         let file_format_config: FileFormatConfig = FileFormatConfig::Parquet(ParquetSourceConfig {
             coerce_int96_timestamp_unit: TimeUnit::Seconds,
             field_id_mapping: None,
@@ -830,9 +840,7 @@ mod test {
         let glob_scan_operator: GlobScanOperator = GlobScanOperator::try_new(
             sources,
             Arc::new(file_format_config),
-            Arc::new(StorageConfig::Native(Arc::new(
-                NativeStorageConfig::new_internal(false, None),
-            ))),
+            Arc::new(StorageConfig::Native(Arc::new(storage_config))),
             false,
             Some(Arc::new(Schema::empty())),
             None,
@@ -886,4 +894,71 @@ mod test {
         assert_eq!(condensed, "ScanTask:\nSources = [File {test0}, File {test1}, File {test2}, File {test3}, File {test4}, File {test5}]\nPushdowns = \n".to_string());
         Ok(())
     }
+
+    fn infer_mvp_schema_with_hints(hints: Option<Schema>) -> DaftResult<Arc<Schema>> {
+        let file_format_config = FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit: TimeUnit::Seconds,
+            field_id_mapping: None,
+            row_groups: None,
+            chunk_size: None,
+        });
+        let glob_scan_operator = GlobScanOperator::try_new(
+            vec!["../../tests/assets/parquet-data/mvp.parquet".to_string()],
+            Arc::new(file_format_config),
+            Arc::new(StorageConfig::Native(Arc::new(
+                NativeStorageConfig::new_internal(false, None),
+            ))),
+            true,
+            hints.map(Arc::new),
+            None,
+            false,
+        )?;
+        Ok(glob_scan_operator.schema())
+    }
+
+    #[test]
+    fn test_glob_ignores_hints_for_unknown_columns() -> DaftResult<()> {
+        let inferred = infer_mvp_schema_with_hints(None)?;
+        let hinted = infer_mvp_schema_with_hints(Some(Schema::new(vec![Field::new(
+            "not_a_column",
+            DataType::Utf8,
+        )])?))?;
+        assert_eq!(hinted, inferred);
+        Ok(())
+    }
+
+    #[test]
+    fn test_glob_concurrency_defaults_and_overrides() {
+        let default_config = StorageConfig::Native(Arc::new(NativeStorageConfig::default()));
+        assert_eq!(default_config.glob_concurrency(), DEFAULT_GLOB_CONCURRENCY);
+
+        let low_config = StorageConfig::Native(Arc::new(
+            NativeStorageConfig::default().with_glob_concurrency(Some(1)),
+        ));
+        assert_eq!(low_config.glob_concurrency(), 1);
+    }
+
+    #[test]
+    fn test_glob_with_low_concurrency_yields_every_file() -> DaftResult<()> {
+        let glob_scan_operator = make_glob_scan_operator_with_storage_config(
+            8,
+            NativeStorageConfig::new_internal(false, None).with_glob_concurrency(Some(1)),
+        );
+        let scan_tasks = glob_scan_operator.to_scan_tasks(Pushdowns::default(), None)?;
+        assert_eq!(scan_tasks.len(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_glob_limit_stops_once_row_counts_satisfy_limit() -> DaftResult<()> {
+        let glob_scan_operator = make_glob_scan_operator(8);
+
+        let unlimited = glob_scan_operator.to_scan_tasks(Pushdowns::default(), None)?;
+        assert_eq!(unlimited.len(), 8);
+
+        let limited =
+            glob_scan_operator.to_scan_tasks(Pushdowns::default().with_limit(Some(1)), None)?;
+        assert_eq!(limited.len(), 1);
+        Ok(())
+    }
 }
@@ -13,6 +13,34 @@ use crate::{
 
 pub(crate) type BoxScanTaskIter<'a> = Box<dyn Iterator<Item = DaftResult<ScanTaskRef>> + 'a>;
 
+/// Stops pulling ScanTasks once the tasks yielded so far are known to hold at least `limit` rows
+///
+/// Only tasks with an exact [`ScanTask::num_rows()`] count towards the limit, so this never drops a
+/// task that may be needed to satisfy it. At least one task is always yielded so that an empty
+/// result still carries its schema. Since the iterator is lazy, stopping early also stops whatever
+/// is producing `scan_tasks` (e.g. a glob stream).
+#[must_use]
+pub(crate) fn limit_by_known_rows(
+    mut scan_tasks: BoxScanTaskIter,
+    limit: usize,
+) -> BoxScanTaskIter {
+    let mut remaining = limit;
+    let mut done = false;
+    Box::new(std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let next = scan_tasks.next()?;
+        if let Ok(scan_task) = &next
+            && let Some(num_rows) = scan_task.num_rows()
+        {
+            remaining = remaining.saturating_sub(num_rows);
+        }
+        done = remaining == 0;
+        Some(next)
+    }))
+}
+
 /// Coalesces ScanTasks by their [`ScanTask::estimate_in_memory_size_bytes()`]
 ///
 /// NOTE: `min_size_bytes` and `max_size_bytes` are only parameters for the algorithm used for merging ScanTasks,
@@ -13,6 +13,9 @@ use {
     std::hash::{Hash, Hasher},
 };
 
+/// Default number of glob paths expanded concurrently when `glob_concurrency` is unset.
+pub const DEFAULT_GLOB_CONCURRENCY: usize = 64;
+
 /// Configuration for interacting with a particular storage backend, using a particular
 /// I/O layer implementation.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
@@ -51,6 +54,19 @@ impl StorageConfig {
         }
     }
 
+    /// Maximum number of glob paths to expand concurrently when listing files.
+    #[must_use]
+    pub fn glob_concurrency(&self) -> usize {
+        match self {
+            Self::Native(cfg) => cfg
+                .glob_concurrency
+                .unwrap_or(DEFAULT_GLOB_CONCURRENCY)
+                .max(1),
+            #[cfg(feature = "python")]
+            Self::Python(_) => DEFAULT_GLOB_CONCURRENCY,
+        }
+    }
+
     #[must_use]
     pub fn var_name(&self) -> &'static str {
         match self {
@@ -76,6 +92,8 @@ impl StorageConfig {
 pub struct NativeStorageConfig {
     pub io_config: Option<IOConfig>,
     pub multithreaded_io: bool,
+    /// Maximum number of glob paths to expand concurrently, defaulting to [`DEFAULT_GLOB_CONCURRENCY`].
+    pub glob_concurrency: Option<usize>,
 }
 
 impl NativeStorageConfig {
@@ -84,6 +102,15 @@ impl NativeStorageConfig {
         Self {
             io_config,
             multithreaded_io,
+            glob_concurrency: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_glob_concurrency(self, glob_concurrency: Option<usize>) -> Self {
+        Self {
+            glob_concurrency,
+            ..self
         }
     }
 
@@ -97,6 +124,9 @@ impl NativeStorageConfig {
             ));
         }
         res.push(format!("Use multithreading = {}", self.multithreaded_io));
+        if let Some(glob_concurrency) = self.glob_concurrency {
+            res.push(format!("Glob concurrency = {glob_concurrency}"));
+        }
         res
     }
 }
@@ -111,9 +141,21 @@ impl Default for NativeStorageConfig {
 #[pymethods]
 impl NativeStorageConfig {
     #[new]
-    #[must_use]
-    pub fn new(multithreaded_io: bool, io_config: Option<python::IOConfig>) -> Self {
-        Self::new_internal(multithreaded_io, io_config.map(|c| c.config))
+    #[pyo3(signature = (multithreaded_io, io_config, glob_concurrency=None))]
+    pub fn new(
+        multithreaded_io: bool,
+        io_config: Option<python::IOConfig>,
+        glob_concurrency: Option<usize>,
+    ) -> PyResult<Self> {
+        if glob_concurrency == Some(0) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "glob_concurrency must be a positive integer",
+            ));
+        }
+        Ok(
+            Self::new_internal(multithreaded_io, io_config.map(|c| c.config))
+                .with_glob_concurrency(glob_concurrency),
+        )
     }
 
     #[getter]
@@ -127,6 +169,12 @@ impl NativeStorageConfig {
     pub fn multithreaded_io(&self) -> bool {
         self.multithreaded_io
     }
+
+    #[getter]
+    #[must_use]
+    pub fn glob_concurrency(&self) -> Option<usize> {
+        self.glob_concurrency
+    }
 }
 
 /// Storage configuration for the legacy Python I/O layer.
@@ -352,7 +352,9 @@ fn physical_plan_to_partition_tasks(
             Ok(py_iter.into())
         }
 
-        PhysicalPlan::Filter(Filter { input, predicate }) => {
+        PhysicalPlan::Filter(Filter {
+            input, predicate, ..
+        }) => {
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
             let expressions_mod =
@@ -383,6 +385,7 @@ fn physical_plan_to_partition_tasks(
             limit,
             eager,
             num_partitions,
+            ..
         }) => {
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
@@ -441,6 +444,7 @@ fn physical_plan_to_partition_tasks(
             fraction,
             with_replacement,
             seed,
+            ..
         }) => {
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
@@ -453,6 +457,7 @@ fn physical_plan_to_partition_tasks(
         PhysicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
             input,
             column_name,
+            ..
         }) => {
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
@@ -468,6 +473,7 @@ fn physical_plan_to_partition_tasks(
             descending,
             nulls_first,
             num_partitions,
+            ..
         }) => {
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
@@ -639,6 +645,7 @@ fn physical_plan_to_partition_tasks(
             pivot_column,
             value_column,
             names,
+            ..
         }) => {
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
@@ -660,7 +667,7 @@ fn physical_plan_to_partition_tasks(
                 ))?;
             Ok(py_iter.into())
         }
-        PhysicalPlan::Concat(Concat { other, input }) => {
+        PhysicalPlan::Concat(Concat { other, input, .. }) => {
             let upstream_input_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
             let upstream_other_iter =
@@ -714,6 +721,7 @@ fn physical_plan_to_partition_tasks(
             num_partitions,
             left_is_larger,
             needs_presort,
+            ..
         }) => {
             let left_iter = physical_plan_to_partition_tasks(left, py, psets, actor_pool_manager)?;
             let right_iter =
@@ -761,6 +769,7 @@ fn physical_plan_to_partition_tasks(
             null_equals_nulls,
             join_type,
             is_swapped,
+            ..
         }) => {
             let upstream_left_iter =
                 physical_plan_to_partition_tasks(left, py, psets, actor_pool_manager)?;
@@ -799,6 +808,7 @@ fn physical_plan_to_partition_tasks(
                     io_config,
                 },
             input,
+            ..
         }) => tabular_write(
             py,
             physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?,
@@ -820,6 +830,7 @@ fn physical_plan_to_partition_tasks(
                     io_config,
                 },
             input,
+            ..
         }) => tabular_write(
             py,
             physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?,
@@ -841,6 +852,7 @@ fn physical_plan_to_partition_tasks(
                     io_config,
                 },
             input,
+            ..
         }) => tabular_write(
             py,
             physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?,
@@ -137,8 +137,16 @@ impl Field {
                     metadata: self.metadata.clone(),
                 })
             }
+            DataType::Map { key, value } => Ok(Self {
+                name: self.name.clone(),
+                dtype: DataType::Struct(vec![
+                    Self::new("key", key.as_ref().clone()),
+                    Self::new("value", value.as_ref().clone()),
+                ]),
+                metadata: self.metadata.clone(),
+            }),
             _ => Err(DaftError::ValueError(format!(
-                "Column \"{}\" with dtype {} cannot be exploded, must be a List or FixedSizeList column.",
+                "Column \"{}\" with dtype {} cannot be exploded, must be a List, FixedSizeList or Map column.",
                 self.name, self.dtype,
             ))),
         }
@@ -16,6 +16,7 @@ pub enum ImageFormat {
     TIFF,
     GIF,
     BMP,
+    WEBP,
 }
 
 #[cfg(feature = "python")]
@@ -39,9 +40,9 @@ impl ImageFormat {
 
 impl ImageFormat {
     pub fn iterator() -> std::slice::Iter<'static, Self> {
-        use ImageFormat::{BMP, GIF, JPEG, PNG, TIFF};
+        use ImageFormat::{BMP, GIF, JPEG, PNG, TIFF, WEBP};
 
-        static FORMATS: [ImageFormat; 5] = [PNG, JPEG, TIFF, GIF, BMP];
+        static FORMATS: [ImageFormat; 6] = [PNG, JPEG, TIFF, GIF, BMP, WEBP];
         FORMATS.iter()
     }
 }
@@ -50,7 +51,7 @@ impl FromStr for ImageFormat {
     type Err = DaftError;
 
     fn from_str(format: &str) -> DaftResult<Self> {
-        use ImageFormat::{BMP, GIF, JPEG, PNG, TIFF};
+        use ImageFormat::{BMP, GIF, JPEG, PNG, TIFF, WEBP};
 
         match format {
             "PNG" => Ok(PNG),
@@ -58,6 +59,7 @@ impl FromStr for ImageFormat {
             "TIFF" => Ok(TIFF),
             "GIF" => Ok(GIF),
             "BMP" => Ok(BMP),
+            "WEBP" => Ok(WEBP),
             _ => Err(DaftError::TypeError(format!(
                 "Image format {} is not supported; only the following formats are supported: {:?}",
                 format,
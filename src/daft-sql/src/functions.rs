@@ -10,9 +10,10 @@ use sqlparser::ast::{
 use crate::{
     error::{PlannerError, SQLPlannerResult},
     modules::{
-        hashing, SQLModule, SQLModuleAggs, SQLModuleConfig, SQLModuleFloat, SQLModuleImage,
-        SQLModuleJson, SQLModuleList, SQLModuleMap, SQLModuleNumeric, SQLModulePartitioning,
-        SQLModulePython, SQLModuleSketch, SQLModuleStructs, SQLModuleTemporal, SQLModuleUtf8,
+        hashing, SQLModule, SQLModuleAggs, SQLModuleConditional, SQLModuleConfig, SQLModuleFloat,
+        SQLModuleImage, SQLModuleJson, SQLModuleList, SQLModuleMap, SQLModuleNumeric,
+        SQLModulePartitioning, SQLModulePython, SQLModuleSketch, SQLModuleStructs,
+        SQLModuleTemporal, SQLModuleUtf8,
     },
     planner::SQLPlanner,
     unsupported_sql_err,
@@ -22,6 +23,7 @@ use crate::{
 pub(crate) static SQL_FUNCTIONS: Lazy<SQLFunctions> = Lazy::new(|| {
     let mut functions = SQLFunctions::new();
     functions.register::<SQLModuleAggs>();
+    functions.register::<SQLModuleConditional>();
     functions.register::<SQLModuleFloat>();
     functions.register::<SQLModuleHashing>();
     functions.register::<SQLModuleImage>();
@@ -27,7 +27,12 @@ mod tests {
 
     use catalog::SQLCatalog;
     use daft_core::prelude::*;
-    use daft_dsl::{col, lit, Expr, OuterReferenceColumn, Subquery};
+    use daft_dsl::{col, lit, null_lit, Expr, ExprRef, OuterReferenceColumn, Subquery};
+    use daft_functions::{
+        coalesce::coalesce,
+        image::encode::{encode, ImageEncode},
+        utf8::{length, lower, lstrip, rstrip, upper},
+    };
     use daft_logical_plan::{
         logical_plan::Source, source_info::PlaceHolderInfo, ClusteringSpec, LogicalPlan,
         LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
@@ -52,6 +57,9 @@ mod tests {
                 Field::new("date", DataType::Date),
                 Field::new("time", DataType::Time(TimeUnit::Microseconds)),
                 Field::new("list_utf8", DataType::new_list(DataType::Utf8)),
+                Field::new("img", DataType::Image(None)),
+                Field::new("img_rgb", DataType::Image(Some(ImageMode::RGB))),
+                Field::new("bytes", DataType::Binary),
             ])
             .unwrap(),
         );
@@ -159,6 +167,12 @@ mod tests {
     #[case::orderby_multi("select * from tbl1 order by i32 desc, f32 asc")]
     #[case::whenthen("select case when i32 = 1 then 'a' else 'b' end from tbl1")]
     #[case::globalagg("select max(i32) from tbl1")]
+    #[case::variance(
+        "select variance(i32) as v, var_samp(i64) as vs, var_pop(f32) as vp from tbl1"
+    )]
+    #[case::stddev(
+        "select stddev(i32) as s, stddev_samp(i64) as ss, stddev_pop(f32) as sp from tbl1"
+    )]
     #[case::cte("with cte as (select * from tbl1) select * from cte")]
     fn test_compiles(mut planner: SQLPlanner, #[case] query: &str) -> SQLPlannerResult<()> {
         let plan = planner.plan_sql(query);
@@ -337,6 +351,7 @@ mod tests {
     #[case::floor("select floor(i32) as floor from tbl1")]
     #[case::sign("select sign(i32) as sign from tbl1")]
     #[case::round("select round(i32, 1) as round from tbl1")]
+    #[case::round_no_precision("select round(f64) as round from tbl1")]
     #[case::sqrt("select sqrt(i32) as sqrt from tbl1")]
     #[case::sin("select sin(i32) as sin from tbl1")]
     #[case::cos("select cos(i32) as cos from tbl1")]
@@ -364,6 +379,10 @@ mod tests {
     #[case::upper("select upper(utf8) as upper from tbl1")]
     #[case::lstrip("select lstrip(utf8) as lstrip from tbl1")]
     #[case::rstrip("select rstrip(utf8) as rstrip from tbl1")]
+    #[case::ltrim("select ltrim(utf8) as ltrim from tbl1")]
+    #[case::rtrim("select rtrim(utf8) as rtrim from tbl1")]
+    #[case::trim("select trim(utf8) as trim from tbl1")]
+    #[case::trim_leading("select trim(leading from utf8) as trim from tbl1")]
     #[case::reverse("select reverse(utf8) as reverse from tbl1")]
     #[case::capitalize("select capitalize(utf8) as capitalize from tbl1")]
     #[case::left("select left(utf8, 1) as left from tbl1")]
@@ -389,6 +408,94 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::upper("select upper(utf8) as s from tbl1", upper(col("utf8")))]
+    #[case::lower("select lower(utf8) as s from tbl1", lower(col("utf8")))]
+    #[case::length("select length(utf8) as s from tbl1", length(col("utf8")))]
+    #[case::ltrim("select ltrim(utf8) as s from tbl1", lstrip(col("utf8")))]
+    #[case::rtrim("select rtrim(utf8) as s from tbl1", rstrip(col("utf8")))]
+    #[case::trim("select trim(utf8) as s from tbl1", lstrip(rstrip(col("utf8"))))]
+    #[case::trim_trailing("select trim(trailing from utf8) as s from tbl1", rstrip(col("utf8")))]
+    fn test_string_funcs(
+        mut planner: SQLPlanner,
+        tbl_1: LogicalPlanRef,
+        #[case] query: &str,
+        #[case] expected_expr: ExprRef,
+    ) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query)?;
+
+        let expected = LogicalPlanBuilder::new(tbl_1, None)
+            .select(vec![expected_expr.alias("s")])?
+            .build();
+
+        assert_eq!(plan, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_numeric_funcs_types(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let sql = "select abs(i32) as abs, ceil(i64) as ceil, floor(i32) as floor, sqrt(i32) as sqrt, round(f64) as round, round(f32, 2) as round2 from tbl1";
+        let plan = planner.plan_sql(sql)?;
+        let schema = plan.schema();
+
+        assert_eq!(schema.get_field("abs")?.dtype, DataType::Int32);
+        assert_eq!(schema.get_field("ceil")?.dtype, DataType::Int64);
+        assert_eq!(schema.get_field("floor")?.dtype, DataType::Int32);
+        assert_eq!(schema.get_field("sqrt")?.dtype, DataType::Float64);
+        assert_eq!(schema.get_field("round")?.dtype, DataType::Float64);
+        assert_eq!(schema.get_field("round2")?.dtype, DataType::Float32);
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_coalesce(mut planner: SQLPlanner, tbl_1: LogicalPlanRef) -> SQLPlannerResult<()> {
+        let sql = "select coalesce(i32, i64, 0) as c from tbl1";
+        let plan = planner.plan_sql(sql)?;
+        assert_eq!(plan.schema().get_field("c")?.dtype, DataType::Int64);
+
+        let expected = LogicalPlanBuilder::new(tbl_1, None)
+            .select(vec![
+                coalesce(vec![col("i32"), col("i64"), lit(0i64)]).alias("c")
+            ])?
+            .build();
+        assert_eq!(plan, expected);
+
+        assert!(planner.plan_sql("select coalesce() from tbl1").is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::nested(
+        "select case when i32 > 1 then case when i64 > 1 then 'a' else 'b' end else 'c' end as s from tbl1",
+        col("i32").gt(lit(1i64)).fill_null(lit(false)).if_else(
+            col("i64").gt(lit(1i64)).fill_null(lit(false)).if_else(lit("a"), lit("b")),
+            lit("c"),
+        )
+    )]
+    #[case::operand(
+        "select case i32 when 1 then 'a' when 2 then 'b' end as s from tbl1",
+        col("i32").eq(lit(1i64)).fill_null(lit(false)).if_else(
+            lit("a"),
+            col("i32").eq(lit(2i64)).fill_null(lit(false)).if_else(lit("b"), null_lit()),
+        )
+    )]
+    fn test_case_when(
+        mut planner: SQLPlanner,
+        tbl_1: LogicalPlanRef,
+        #[case] query: &str,
+        #[case] expected_expr: ExprRef,
+    ) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query)?;
+
+        let expected = LogicalPlanBuilder::new(tbl_1, None)
+            .select(vec![expected_expr.alias("s")])?
+            .build();
+
+        assert_eq!(plan, expected);
+        assert_eq!(plan.schema().get_field("s")?.dtype, DataType::Utf8);
+        Ok(())
+    }
+
     #[rstest]
     fn test_global_agg(mut planner: SQLPlanner, tbl_1: LogicalPlanRef) -> SQLPlannerResult<()> {
         let sql = "select max(i32) from tbl1";
@@ -486,4 +593,112 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case::positional("select image_encode(img, 'jpeg', 90) as e from tbl1")]
+    #[case::named("select image_encode(img, image_format => 'JPEG', quality => 90) as e from tbl1")]
+    fn test_image_encode_with_quality(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+        tbl_1: LogicalPlanRef,
+    ) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query)?;
+
+        let image_encode = ImageEncode {
+            image_format: ImageFormat::JPEG,
+            quality: Some(90),
+        };
+        let expected = LogicalPlanBuilder::new(tbl_1, None)
+            .select(vec![encode(col("img"), image_encode).alias("e")])?
+            .build();
+
+        assert_eq!(plan, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::png("select image_encode(img, 'png') from tbl1")]
+    #[case::webp("select image_encode(img, image_format => 'webp') from tbl1")]
+    #[case::jpeg_without_quality("select image_encode(img, 'jpeg') from tbl1")]
+    fn test_image_encode_compiles(mut planner: SQLPlanner, #[case] query: &str) {
+        let plan = planner.plan_sql(query);
+        assert!(plan.is_ok(), "query: {query}\nerror: {plan:?}");
+    }
+
+    #[rstest]
+    #[case::lossless_format("select image_encode(img, 'png', 90) from tbl1")]
+    #[case::webp("select image_encode(img, 'webp', 90) from tbl1")]
+    #[case::quality_too_high("select image_encode(img, 'jpeg', 101) from tbl1")]
+    #[case::quality_zero("select image_encode(img, 'jpeg', 0) from tbl1")]
+    #[case::unknown_format("select image_encode(img, 'avif') from tbl1")]
+    fn test_image_encode_invalid_args(mut planner: SQLPlanner, #[case] query: &str) {
+        let plan = planner.plan_sql(query);
+        assert!(plan.is_err(), "query: {query}\nplan: {plan:?}");
+    }
+
+    #[rstest]
+    #[case::mixed_shape(
+        "select image_resize(img, 32, 16) as r from tbl1",
+        DataType::Image(None)
+    )]
+    #[case::fixed_mode(
+        "select image_resize(img_rgb, 32, 16) as r from tbl1",
+        DataType::FixedShapeImage(ImageMode::RGB, 16, 32)
+    )]
+    #[case::named(
+        "select image_resize(img_rgb, width => 32, height => 16) as r from tbl1",
+        DataType::FixedShapeImage(ImageMode::RGB, 16, 32)
+    )]
+    fn test_image_resize_dtype(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+        #[case] expected_dtype: DataType,
+    ) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query)?;
+        assert_eq!(plan.schema().get_field("r")?.dtype, expected_dtype);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::zero_width("select image_resize(img, 0, 16) from tbl1")]
+    #[case::negative_height("select image_resize(img, 32, -1) from tbl1")]
+    #[case::non_literal("select image_resize(img, i64, 16) from tbl1")]
+    #[case::missing_height("select image_resize(img, 32) from tbl1")]
+    fn test_image_resize_invalid_args(mut planner: SQLPlanner, #[case] query: &str) {
+        let plan = planner.plan_sql(query);
+        assert!(plan.is_err(), "query: {query}\nplan: {plan:?}");
+    }
+
+    #[rstest]
+    #[case::no_shape("select image_decode(bytes) as d from tbl1", DataType::Image(None))]
+    #[case::shape(
+        "select image_decode(bytes, shape => (224, 112, 3)) as d from tbl1",
+        DataType::FixedShapeImage(ImageMode::RGB, 224, 112)
+    )]
+    #[case::shape_and_mode(
+        "select image_decode(bytes, mode => 'LA', shape => (4, 4, 2), on_error => 'null') as d from tbl1",
+        DataType::FixedShapeImage(ImageMode::LA, 4, 4)
+    )]
+    fn test_image_decode_dtype(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+        #[case] expected_dtype: DataType,
+    ) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query)?;
+        assert_eq!(plan.schema().get_field("d")?.dtype, expected_dtype);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::mode_channel_mismatch(
+        "select image_decode(bytes, mode => 'RGB', shape => (4, 4, 1)) from tbl1"
+    )]
+    #[case::too_many_channels("select image_decode(bytes, shape => (4, 4, 5)) from tbl1")]
+    #[case::zero_dim("select image_decode(bytes, shape => (0, 4, 3)) from tbl1")]
+    #[case::wrong_arity("select image_decode(bytes, shape => (4, 4)) from tbl1")]
+    #[case::not_a_tuple("select image_decode(bytes, shape => 4) from tbl1")]
+    fn test_image_decode_invalid_shape(mut planner: SQLPlanner, #[case] query: &str) {
+        let plan = planner.plan_sql(query);
+        assert!(plan.is_err(), "query: {query}\nplan: {plan:?}");
+    }
 }
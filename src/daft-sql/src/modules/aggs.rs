@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use daft_dsl::{col, AggExpr, Expr, ExprRef, LiteralValue};
+use daft_functions::numeric::sqrt::sqrt;
 use sqlparser::ast::{FunctionArg, FunctionArgExpr};
 
 use super::SQLModule;
@@ -16,7 +17,7 @@ pub struct SQLModuleAggs;
 
 impl SQLModule for SQLModuleAggs {
     fn register(parent: &mut SQLFunctions) {
-        use AggExpr::{Count, Max, Mean, Min, Stddev, Sum};
+        use AggExpr::{Count, Max, Mean, Min, Stddev, Sum, Variance};
         // HACK TO USE AggExpr as an enum rather than a
         let nil = Arc::new(Expr::Literal(LiteralValue::Null));
         parent.add_fn(
@@ -29,7 +30,11 @@ impl SQLModule for SQLModuleAggs {
         parent.add_fn("min", Min(nil.clone()));
         parent.add_fn("max", Max(nil.clone()));
         parent.add_fn("stddev", Stddev(nil.clone()));
-        parent.add_fn("stddev_samp", Stddev(nil));
+        parent.add_fn("stddev_pop", Stddev(nil.clone()));
+        parent.add_fn("stddev_samp", StddevSamp);
+        parent.add_fn("variance", Variance(nil.clone(), 1));
+        parent.add_fn("var_samp", Variance(nil.clone(), 1));
+        parent.add_fn("var_pop", Variance(nil, 0));
     }
 }
 
@@ -52,6 +57,8 @@ impl SQLFunction for AggExpr {
             Self::Min(_) => static_docs::MIN_DOCSTRING.to_string(),
             Self::Max(_) => static_docs::MAX_DOCSTRING.to_string(),
             Self::Stddev(_) => static_docs::STDDEV_DOCSTRING.to_string(),
+            Self::Variance(_, 0) => static_docs::VAR_POP_DOCSTRING.replace("{}", alias),
+            Self::Variance(..) => static_docs::VAR_SAMP_DOCSTRING.replace("{}", alias),
             e => unimplemented!("Need to implement docstrings for {e}"),
         }
     }
@@ -63,12 +70,34 @@ impl SQLFunction for AggExpr {
             | Self::Mean(_)
             | Self::Min(_)
             | Self::Max(_)
-            | Self::Stddev(_) => &["input"],
+            | Self::Stddev(_)
+            | Self::Variance(..) => &["input"],
             e => unimplemented!("Need to implement arg names for {e}"),
         }
     }
 }
 
+/// Sample standard deviation, i.e. the square root of the sample variance.
+///
+/// [`AggExpr::Stddev`] is the population standard deviation, so this is planned on top of [`AggExpr::Variance`].
+struct StddevSamp;
+
+impl SQLFunction for StddevSamp {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        let inputs = self.args_to_expr_unnamed(inputs, planner)?;
+        ensure!(inputs.len() == 1, "stddev_samp takes exactly one argument");
+        Ok(sqrt(inputs[0].clone().variance(1)))
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        static_docs::STDDEV_SAMP_DOCSTRING.to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input"]
+    }
+}
+
 fn handle_count(inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
     Ok(match inputs {
         [FunctionArg::Unnamed(FunctionArgExpr::Wildcard)] => match planner.relation_opt() {
@@ -111,6 +140,8 @@ pub fn to_expr(expr: &AggExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
         AggExpr::ApproxPercentile(_) => unsupported_sql_err!("approx_percentile"),
         AggExpr::ApproxSketch(_, _) => unsupported_sql_err!("approx_sketch"),
         AggExpr::MergeSketch(_, _) => unsupported_sql_err!("merge_sketch"),
+        AggExpr::VarianceState(_) => unsupported_sql_err!("variance_state"),
+        AggExpr::MergeVariance(_, _) => unsupported_sql_err!("merge_variance"),
         AggExpr::Mean(_) => {
             ensure!(args.len() == 1, "mean takes exactly one argument");
             Ok(args[0].clone().mean())
@@ -119,6 +150,10 @@ pub fn to_expr(expr: &AggExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
             ensure!(args.len() == 1, "stddev takes exactly one argument");
             Ok(args[0].clone().stddev())
         }
+        AggExpr::Variance(_, ddof) => {
+            ensure!(args.len() == 1, "variance takes exactly one argument");
+            Ok(args[0].clone().variance(*ddof))
+        }
         AggExpr::Min(_) => {
             ensure!(args.len() == 1, "min takes exactly one argument");
             Ok(args[0].clone().min())
@@ -128,6 +163,8 @@ pub fn to_expr(expr: &AggExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
             Ok(args[0].clone().max())
         }
         AggExpr::AnyValue(_, _) => unsupported_sql_err!("any_value"),
+        AggExpr::First(_, _) => unsupported_sql_err!("first"),
+        AggExpr::Last(_, _) => unsupported_sql_err!("last"),
         AggExpr::List(_) => unsupported_sql_err!("list"),
         AggExpr::Concat(_) => unsupported_sql_err!("concat"),
         AggExpr::MapGroups { .. } => unsupported_sql_err!("map_groups"),
@@ -332,7 +369,7 @@ Example:
     (Showing first 1 of 1 rows)";
 
     pub(crate) const STDDEV_DOCSTRING: &str =
-        "Calculates the standard deviation of non-null elements in the input expression.
+        "Calculates the population standard deviation of non-null elements in the input expression.
 
 Example:
 
@@ -357,6 +394,44 @@ Example:
     ╰───────╯
     (Showing first 3 of 3 rows)
 
+.. code-block:: text
+    :caption: Output
+
+    ╭──────────────╮
+    │ x            │
+    │ ---          │
+    │ Float64      │
+    ╞══════════════╡
+    │ 50           │
+    ╰──────────────╯
+    (Showing first 1 of 1 rows)";
+
+    pub(crate) const STDDEV_SAMP_DOCSTRING: &str =
+        "Calculates the sample standard deviation of non-null elements in the input expression.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT stddev_samp(x) FROM tbl
+
+.. code-block:: text
+    :caption: Input
+
+    ╭───────╮
+    │ x     │
+    │ ---   │
+    │ Int64 │
+    ╞═══════╡
+    │ 100   │
+    ├╌╌╌╌╌╌╌┤
+    │ 200   │
+    ├╌╌╌╌╌╌╌┤
+    │ null  │
+    ╰───────╯
+    (Showing first 3 of 3 rows)
+
 .. code-block:: text
     :caption: Output
 
@@ -368,4 +443,80 @@ Example:
     │ 70.710678118 │
     ╰──────────────╯
     (Showing first 1 of 1 rows)";
+
+    pub(crate) const VAR_SAMP_DOCSTRING: &str =
+        "Calculates the sample variance of non-null elements in the input expression.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT {}(x) FROM tbl
+
+.. code-block:: text
+    :caption: Input
+
+    ╭───────╮
+    │ x     │
+    │ ---   │
+    │ Int64 │
+    ╞═══════╡
+    │ 100   │
+    ├╌╌╌╌╌╌╌┤
+    │ 200   │
+    ├╌╌╌╌╌╌╌┤
+    │ null  │
+    ╰───────╯
+    (Showing first 3 of 3 rows)
+
+.. code-block:: text
+    :caption: Output
+
+    ╭─────────╮
+    │ x       │
+    │ ---     │
+    │ Float64 │
+    ╞═════════╡
+    │ 5000    │
+    ╰─────────╯
+    (Showing first 1 of 1 rows)";
+
+    pub(crate) const VAR_POP_DOCSTRING: &str =
+        "Calculates the population variance of non-null elements in the input expression.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT {}(x) FROM tbl
+
+.. code-block:: text
+    :caption: Input
+
+    ╭───────╮
+    │ x     │
+    │ ---   │
+    │ Int64 │
+    ╞═══════╡
+    │ 100   │
+    ├╌╌╌╌╌╌╌┤
+    │ 200   │
+    ├╌╌╌╌╌╌╌┤
+    │ null  │
+    ╰───────╯
+    (Showing first 3 of 3 rows)
+
+.. code-block:: text
+    :caption: Output
+
+    ╭─────────╮
+    │ x       │
+    │ ---     │
+    │ Float64 │
+    ╞═════════╡
+    │ 2500    │
+    ╰─────────╯
+    (Showing first 1 of 1 rows)";
 }
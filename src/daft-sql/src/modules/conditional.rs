@@ -0,0 +1,43 @@
+use daft_functions::coalesce::coalesce;
+
+use super::SQLModule;
+use crate::{
+    functions::{SQLFunction, SQLFunctions},
+    invalid_operation_err,
+};
+
+pub struct SQLModuleConditional;
+
+impl SQLModule for SQLModuleConditional {
+    fn register(parent: &mut SQLFunctions) {
+        parent.add_fn("coalesce", SQLCoalesce);
+    }
+}
+
+pub struct SQLCoalesce;
+
+impl SQLFunction for SQLCoalesce {
+    fn to_expr(
+        &self,
+        inputs: &[sqlparser::ast::FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> crate::error::SQLPlannerResult<daft_dsl::ExprRef> {
+        if inputs.is_empty() {
+            invalid_operation_err!("Expected at least 1 input arg");
+        }
+        let inputs = inputs
+            .iter()
+            .map(|arg| planner.plan_function_arg(arg))
+            .collect::<crate::error::SQLPlannerResult<Vec<_>>>()?;
+        Ok(coalesce(inputs))
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Returns the first non-null value among its arguments, cast to their common supertype."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "..."]
+    }
+}
@@ -1,8 +1,10 @@
+use daft_core::prelude::*;
 use daft_dsl::{Expr, ExprRef, LiteralValue};
 use daft_functions::image::decode::{decode, ImageDecode};
 use sqlparser::ast::FunctionArg;
 
 use crate::{
+    ensure,
     error::{PlannerError, SQLPlannerResult},
     functions::{SQLFunction, SQLFunctionArguments},
     unsupported_sql_err,
@@ -35,13 +37,47 @@ impl TryFrom<SQLFunctionArguments> for ImageDecode {
             .transpose()?
             .unwrap_or(true);
 
+        let shape = args
+            .get_named("shape")
+            .map(|arg| match arg.as_ref() {
+                Expr::Literal(LiteralValue::Series(s)) => parse_shape(s),
+                _ => unsupported_sql_err!(
+                    "Expected shape to be a tuple of (height, width, channels)"
+                ),
+            })
+            .transpose()?;
+
         Ok(Self {
             mode,
             raise_on_error,
+            shape,
         })
     }
 }
 
+/// Parses a `(height, width, channels)` tuple literal of positive integers.
+fn parse_shape(s: &Series) -> SQLPlannerResult<(u32, u32, u16)> {
+    let values = match s.data_type() {
+        DataType::FixedSizeList(child, 3) if child.is_integer() => {
+            s.fixed_size_list()?.flat_child.cast(&DataType::Int64)?
+        }
+        _ => unsupported_sql_err!("Expected shape to be a tuple of (height, width, channels)"),
+    };
+    let values = values.i64()?;
+    let dims = (0..3)
+        .map(|i| match values.get(i).map(u32::try_from) {
+            Some(Ok(v)) if v > 0 => Ok(v),
+            _ => unsupported_sql_err!("Expected shape dimensions to be positive integers"),
+        })
+        .collect::<SQLPlannerResult<Vec<_>>>()?;
+    ensure!(
+        dims[2] <= 4,
+        "Images can have at most 4 channels, got {}",
+        dims[2]
+    );
+    Ok((dims[0], dims[1], dims[2] as u16))
+}
+
 impl SQLFunction for SQLImageDecode {
     fn to_expr(
         &self,
@@ -55,7 +91,7 @@ impl SQLFunction for SQLImageDecode {
             }
             [input, args @ ..] => {
                 let input = planner.plan_function_arg(input)?;
-                let args = planner.plan_function_args(args, &["mode", "on_error"], 0)?;
+                let args = planner.plan_function_args(args, &["mode", "on_error", "shape"], 0)?;
                 Ok(decode(input, Some(args)))
             }
             _ => unsupported_sql_err!("Invalid arguments for image_decode: '{inputs:?}'"),
@@ -63,10 +99,10 @@ impl SQLFunction for SQLImageDecode {
     }
 
     fn docstrings(&self, _alias: &str) -> String {
-        "Decodes an image from binary data. Optionally, you can specify the image mode and error handling behavior.".to_string()
+        "Decodes an image from binary data. Optionally, you can specify the image mode, an expected (height, width, channels) shape, and error handling behavior.".to_string()
     }
 
     fn arg_names(&self) -> &'static [&'static str] {
-        &["input", "mode", "on_error"]
+        &["input", "mode", "on_error", "shape"]
     }
 }
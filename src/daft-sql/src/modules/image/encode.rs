@@ -16,10 +16,12 @@ impl TryFrom<SQLFunctionArguments> for ImageEncode {
     fn try_from(args: SQLFunctionArguments) -> Result<Self, Self::Error> {
         let image_format = args
             .get_named("image_format")
+            .or_else(|| args.get_positional(0))
             .map(|arg| match arg.as_ref() {
-                Expr::Literal(LiteralValue::Utf8(s)) => {
-                    s.parse().map_err(|e: DaftError| PlannerError::from(e))
-                }
+                Expr::Literal(LiteralValue::Utf8(s)) => s
+                    .to_uppercase()
+                    .parse()
+                    .map_err(|e: DaftError| PlannerError::from(e)),
                 _ => unsupported_sql_err!("Expected image_format to be a string"),
             })
             .transpose()?
@@ -27,7 +29,16 @@ impl TryFrom<SQLFunctionArguments> for ImageEncode {
                 PlannerError::unsupported_sql("Expected image_format argument".to_string())
             })?;
 
-        Ok(Self { image_format })
+        let quality = args
+            .get_named("quality")
+            .or_else(|| args.get_positional(1))
+            .map(|arg| match arg.as_ref() {
+                Expr::Literal(LiteralValue::Int64(i)) => Ok(*i),
+                _ => unsupported_sql_err!("Expected quality to be a number"),
+            })
+            .transpose()?;
+
+        Ok(Self::try_new(image_format, quality)?)
     }
 }
 
@@ -40,7 +51,7 @@ impl SQLFunction for SQLImageEncode {
         match inputs {
             [input, args @ ..] => {
                 let input = planner.plan_function_arg(input)?;
-                let args = planner.plan_function_args(args, &["image_format"], 0)?;
+                let args = planner.plan_function_args(args, &["image_format", "quality"], 2)?;
                 Ok(encode(input, args))
             }
             _ => unsupported_sql_err!("Invalid arguments for image_encode: '{inputs:?}'"),
@@ -48,10 +59,10 @@ impl SQLFunction for SQLImageEncode {
     }
 
     fn docstrings(&self, _alias: &str) -> String {
-        "Encodes an image into the specified image file format, returning a binary column of encoded bytes.".to_string()
+        "Encodes an image into the specified image file format ('png', 'jpeg', 'webp', ...), returning a binary column of encoded bytes. An optional quality (1-100) may be given for JPEG.".to_string()
     }
 
     fn arg_names(&self) -> &'static [&'static str] {
-        &["input_image", "image_format"]
+        &["input_image", "image_format", "quality"]
     }
 }
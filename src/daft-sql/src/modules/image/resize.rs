@@ -16,6 +16,7 @@ impl TryFrom<SQLFunctionArguments> for ImageResize {
     fn try_from(args: SQLFunctionArguments) -> Result<Self, Self::Error> {
         let width = args
             .get_named("w")
+            .or_else(|| args.get_named("width"))
             .or_else(|| args.get_positional(0))
             .map(|arg| match arg.as_ref() {
                 Expr::Literal(LiteralValue::Int64(i)) => Ok(*i),
@@ -28,6 +29,7 @@ impl TryFrom<SQLFunctionArguments> for ImageResize {
 
         let height = args
             .get_named("h")
+            .or_else(|| args.get_named("height"))
             .or_else(|| args.get_positional(1))
             .map(|arg| match arg.as_ref() {
                 Expr::Literal(LiteralValue::Int64(i)) => Ok(*i),
@@ -38,8 +40,8 @@ impl TryFrom<SQLFunctionArguments> for ImageResize {
                 PlannerError::unsupported_sql("Expected height to be provided".to_string())
             })?;
 
-        ensure!(width > 0, "Width can not be negative: {width}");
-        ensure!(height > 0, "Height can not be negative: {height}");
+        ensure!(width > 0, "Width must be a positive integer: {width}");
+        ensure!(height > 0, "Height must be a positive integer: {height}");
 
         Ok(Self {
             width: width as u32,
@@ -58,7 +60,7 @@ impl SQLFunction for SQLImageResize {
             [input, args @ ..] => {
                 let input = planner.plan_function_arg(input)?;
                 let ImageResize { width, height } =
-                    planner.plan_function_args(args, &["w", "h"], 2)?;
+                    planner.plan_function_args(args, &["w", "h", "width", "height"], 2)?;
                 Ok(resize(input, width, height))
             }
             _ => unsupported_sql_err!("Invalid arguments for image_resize: '{inputs:?}'"),
@@ -1,6 +1,7 @@
 use crate::functions::SQLFunctions;
 
 pub mod aggs;
+pub mod conditional;
 pub mod config;
 pub mod float;
 pub mod hashing;
@@ -17,6 +18,7 @@ pub mod temporal;
 pub mod utf8;
 
 pub use aggs::SQLModuleAggs;
+pub use conditional::SQLModuleConditional;
 pub use config::SQLModuleConfig;
 pub use float::SQLModuleFloat;
 pub use image::SQLModuleImage;
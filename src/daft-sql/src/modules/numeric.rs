@@ -95,7 +95,7 @@ impl SQLFunction for SQLNumericExpr {
             Self::Ceil => "Rounds a number up to the nearest integer.",
             Self::Exp => "Calculates the exponential of a number (e^x).",
             Self::Floor => "Rounds a number down to the nearest integer.",
-            Self::Round => "Rounds a number to a specified number of decimal places.",
+            Self::Round => "Rounds a number to a specified number of decimal places, or to the nearest integer if omitted.",
             Self::Sign => "Returns the sign of a number (-1, 0, or 1).",
             Self::Sqrt => "Calculates the square root of a number.",
             Self::Sin => "Calculates the sine of an angle in radians.",
@@ -170,11 +170,15 @@ fn to_expr(expr: &SQLNumericExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef>
             Ok(sign(args[0].clone()))
         }
         SQLNumericExpr::Round => {
-            ensure!(args.len() == 2, "round takes exactly two arguments");
-            let precision = match args[1].as_ref().as_literal() {
-                Some(LiteralValue::Int32(i)) => *i,
-                Some(LiteralValue::UInt32(u)) => *u as i32,
-                Some(LiteralValue::Int64(i)) => *i as i32,
+            ensure!(
+                matches!(args.len(), 1 | 2),
+                "round takes one or two arguments"
+            );
+            let precision = match args.get(1).map(|arg| arg.as_ref().as_literal()) {
+                None => 0,
+                Some(Some(LiteralValue::Int32(i))) => *i,
+                Some(Some(LiteralValue::UInt32(u))) => *u as i32,
+                Some(Some(LiteralValue::Int64(i))) => *i as i32,
                 _ => invalid_operation_err!("round precision must be an integer"),
             };
             Ok(round(args[0].clone(), precision))
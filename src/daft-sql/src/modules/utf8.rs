@@ -166,6 +166,8 @@ impl SQLModule for SQLModuleUtf8 {
         parent.add_fn("upper", SQLUtf8Upper);
         parent.add_fn("lstrip", SQLUtf8Lstrip);
         parent.add_fn("rstrip", SQLUtf8Rstrip);
+        parent.add_fn("ltrim", SQLUtf8Lstrip);
+        parent.add_fn("rtrim", SQLUtf8Rstrip);
         parent.add_fn("reverse", SQLUtf8Reverse);
         parent.add_fn("capitalize", SQLUtf8Capitalize);
         parent.add_fn("left", SQLUtf8Left);
@@ -16,14 +16,14 @@ use daft_dsl::{
 };
 use daft_functions::{
     numeric::{ceil::ceil, floor::floor},
-    utf8::{ilike, like, to_date, to_datetime},
+    utf8::{ilike, like, lstrip, rstrip, to_date, to_datetime},
 };
 use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
 use sqlparser::{
     ast::{
         ArrayElemTypeDef, BinaryOperator, CastKind, DateTimeField, Distinct, ExactNumberInfo,
         ExcludeSelectItem, GroupByExpr, Ident, Query, SelectItem, SetExpr, Statement, StructField,
-        Subscript, TableAlias, TableWithJoins, TimezoneInfo, UnaryOperator, Value,
+        Subscript, TableAlias, TableWithJoins, TimezoneInfo, TrimWhereField, UnaryOperator, Value,
         WildcardAdditionalOptions, With,
     },
     dialect::GenericDialect,
@@ -899,7 +899,10 @@ impl<'a> SQLPlanner<'a> {
         for join in &from.joins {
             use sqlparser::ast::{
                 JoinConstraint,
-                JoinOperator::{FullOuter, Inner, LeftAnti, LeftOuter, LeftSemi, RightOuter},
+                JoinOperator::{
+                    FullOuter, Inner, LeftAnti, LeftOuter, LeftSemi, RightAnti, RightOuter,
+                    RightSemi,
+                },
             };
             let right_rel = self.plan_relation(&join.relation)?;
             let right_rel_name = right_rel.get_name();
@@ -919,6 +922,8 @@ impl<'a> SQLPlanner<'a> {
                 FullOuter(constraint) => (JoinType::Outer, constraint),
                 LeftSemi(constraint) => (JoinType::Semi, constraint),
                 LeftAnti(constraint) => (JoinType::Anti, constraint),
+                RightSemi(constraint) => (JoinType::RightSemi, constraint),
+                RightAnti(constraint) => (JoinType::RightAnti, constraint),
 
                 _ => unsupported_sql_err!("Unsupported join type: {:?}", join.join_operator),
             };
@@ -1320,11 +1325,12 @@ impl<'a> SQLPlanner<'a> {
             SQLExpr::IsNotUnknown(_) => {
                 unsupported_sql_err!("IS NOT UNKNOWN")
             }
-            SQLExpr::IsDistinctFrom(_, _) => {
-                unsupported_sql_err!("IS DISTINCT FROM")
-            }
-            SQLExpr::IsNotDistinctFrom(_, _) => {
-                unsupported_sql_err!("IS NOT DISTINCT FROM")
+            SQLExpr::IsDistinctFrom(left, right) => Ok(self
+                .plan_expr(left)?
+                .eq_null_safe(self.plan_expr(right)?)
+                .not()),
+            SQLExpr::IsNotDistinctFrom(left, right) => {
+                Ok(self.plan_expr(left)?.eq_null_safe(self.plan_expr(right)?))
             }
             SQLExpr::InList {
                 expr,
@@ -1463,7 +1469,20 @@ impl<'a> SQLPlanner<'a> {
             SQLExpr::Substring { special: false, .. } => {
                 unsupported_sql_err!("`SUBSTRING(expr [FROM start] [FOR len])` syntax")
             }
-            SQLExpr::Trim { .. } => unsupported_sql_err!("TRIM"),
+            SQLExpr::Trim {
+                expr,
+                trim_where,
+                trim_what: None,
+                trim_characters: None,
+            } => {
+                let expr = self.plan_expr(expr)?;
+                Ok(match trim_where {
+                    None | Some(TrimWhereField::Both) => lstrip(rstrip(expr)),
+                    Some(TrimWhereField::Leading) => lstrip(expr),
+                    Some(TrimWhereField::Trailing) => rstrip(expr),
+                })
+            }
+            SQLExpr::Trim { .. } => unsupported_sql_err!("TRIM with custom characters"),
             SQLExpr::Overlay { .. } => unsupported_sql_err!("OVERLAY"),
             SQLExpr::Collate { .. } => unsupported_sql_err!("COLLATE"),
             SQLExpr::Nested(e) => self.plan_expr(e),
@@ -1487,16 +1506,19 @@ impl<'a> SQLPlanner<'a> {
                 results,
                 else_result,
             } => {
-                if operand.is_some() {
-                    unsupported_sql_err!("CASE with operand not yet supported");
-                }
                 if results.len() != conditions.len() {
                     unsupported_sql_err!("CASE with different number of conditions and results");
                 }
 
+                let operand = operand
+                    .as_ref()
+                    .map(|operand| self.plan_expr(operand))
+                    .transpose()?;
+
+                // a CASE without an ELSE evaluates to NULL when no condition matches
                 let else_expr = match else_result {
                     Some(expr) => self.plan_expr(expr)?,
-                    None => unsupported_sql_err!("CASE with no else result"),
+                    None => null_lit(),
                 };
 
                 // we need to traverse from back to front to build the if else chain
@@ -1504,9 +1526,13 @@ impl<'a> SQLPlanner<'a> {
                 conditions.iter().zip(results.iter()).rev().try_fold(
                     else_expr,
                     |else_expr, (condition, result)| {
-                        let cond = self.plan_expr(condition)?;
+                        let cond = match &operand {
+                            Some(operand) => operand.clone().eq(self.plan_expr(condition)?),
+                            None => self.plan_expr(condition)?,
+                        };
                         let res = self.plan_expr(result)?;
-                        Ok(cond.if_else(res, else_expr))
+                        // a NULL condition does not match, so fall through to the next branch
+                        Ok(cond.fill_null(lit(false)).if_else(res, else_expr))
                     },
                 )
             }
@@ -1766,6 +1792,7 @@ impl<'a> SQLPlanner<'a> {
             BinaryOperator::Multiply => Ok(Operator::Multiply),
             BinaryOperator::Divide => Ok(Operator::TrueDivide),
             BinaryOperator::Eq => Ok(Operator::Eq),
+            BinaryOperator::Spaceship => Ok(Operator::EqNullSafe),
             BinaryOperator::Modulo => Ok(Operator::Modulus),
             BinaryOperator::Gt => Ok(Operator::Gt),
             BinaryOperator::Lt => Ok(Operator::Lt),
@@ -1,3 +1,4 @@
+use daft_core::prelude::*;
 use snafu::ResultExt;
 
 use super::ColumnRangeStatistics;
@@ -38,3 +39,54 @@ impl std::ops::Sub for &ColumnRangeStatistics {
         }
     }
 }
+
+impl std::ops::Div for &ColumnRangeStatistics {
+    type Output = crate::Result<ColumnRangeStatistics>;
+    fn div(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (ColumnRangeStatistics::Missing, _) | (_, ColumnRangeStatistics::Missing) => {
+                Ok(ColumnRangeStatistics::Missing)
+            }
+            (
+                ColumnRangeStatistics::Loaded(s_lower, s_upper),
+                ColumnRangeStatistics::Loaded(r_lower, r_upper),
+            ) => {
+                if !s_lower.data_type().is_numeric() || !r_lower.data_type().is_numeric() {
+                    return Ok(ColumnRangeStatistics::Missing);
+                }
+                let bound = |s: &Series| -> crate::Result<Option<f64>> {
+                    let s = s.cast(&DataType::Float64).context(DaftCoreComputeSnafu)?;
+                    Ok(s.f64().context(DaftCoreComputeSnafu)?.get(0))
+                };
+                let (Some(s_lower), Some(s_upper), Some(r_lower), Some(r_upper)) = (
+                    bound(s_lower)?,
+                    bound(s_upper)?,
+                    bound(r_lower)?,
+                    bound(r_upper)?,
+                ) else {
+                    return Ok(ColumnRangeStatistics::Missing);
+                };
+                // Dividing by values arbitrarily close to zero is unbounded, so a divisor range
+                // that spans zero tells us nothing about the quotient.
+                if r_lower <= 0. && r_upper >= 0. {
+                    return Ok(ColumnRangeStatistics::Missing);
+                }
+                let quotients = [
+                    s_lower / r_lower,
+                    s_lower / r_upper,
+                    s_upper / r_lower,
+                    s_upper / r_upper,
+                ];
+                if quotients.iter().any(|q| q.is_nan()) {
+                    return Ok(ColumnRangeStatistics::Missing);
+                }
+                let lower = quotients.into_iter().fold(f64::INFINITY, f64::min);
+                let upper = quotients.into_iter().fold(f64::NEG_INFINITY, f64::max);
+                Ok(ColumnRangeStatistics::Loaded(
+                    Float64Array::from(("lower", vec![lower])).into_series(),
+                    Float64Array::from(("upper", vec![upper])).into_series(),
+                ))
+            }
+        }
+    }
+}
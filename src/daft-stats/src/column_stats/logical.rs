@@ -66,3 +66,32 @@ impl std::ops::BitOr for &ColumnRangeStatistics {
         Ok(ColumnRangeStatistics::from_truth_value(nv))
     }
 }
+
+impl ColumnRangeStatistics {
+    /// Ranges only carry bounds, not null counts, so a column is only known to be entirely null when
+    /// both of its bounds are null. Otherwise it may or may not contain nulls.
+    fn is_all_null(&self) -> Option<bool> {
+        match self {
+            Self::Missing => None,
+            Self::Loaded(lower, upper) => Some(!lower.is_valid(0) && !upper.is_valid(0)),
+        }
+    }
+
+    pub fn is_null(&self) -> crate::Result<Self> {
+        let tv = if self.is_all_null() == Some(true) {
+            TruthValue::True
+        } else {
+            TruthValue::Maybe
+        };
+        Ok(Self::from_truth_value(tv))
+    }
+
+    pub fn not_null(&self) -> crate::Result<Self> {
+        let tv = if self.is_all_null() == Some(true) {
+            TruthValue::False
+        } else {
+            TruthValue::Maybe
+        };
+        Ok(Self::from_truth_value(tv))
+    }
+}
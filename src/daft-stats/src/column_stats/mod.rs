@@ -266,4 +266,53 @@ mod test {
 
         Ok(())
     }
+
+    fn int_range(lower: i64, upper: i64) -> crate::Result<ColumnRangeStatistics> {
+        ColumnRangeStatistics::new(
+            Some(Int64Array::from(("lower", vec![lower])).into_series()),
+            Some(Int64Array::from(("upper", vec![upper])).into_series()),
+        )
+    }
+
+    fn f64_bounds(stats: &ColumnRangeStatistics) -> (f64, f64) {
+        let ColumnRangeStatistics::Loaded(lower, upper) = stats else {
+            panic!("expected loaded stats");
+        };
+        (
+            lower.f64().unwrap().get(0).unwrap(),
+            upper.f64().unwrap().get(0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_div() -> crate::Result<()> {
+        let quotient = (&int_range(10, 20)? / &int_range(2, 5)?)?;
+        assert_eq!(f64_bounds(&quotient), (2., 10.));
+
+        // Signs flip the order of the bounds.
+        let quotient = (&int_range(-20, 10)? / &int_range(-5, -2)?)?;
+        assert_eq!(f64_bounds(&quotient), (-5., 10.));
+
+        let quotient = (&int_range(10, 20)? / &ColumnRangeStatistics::Missing)?;
+        assert_eq!(quotient, ColumnRangeStatistics::Missing);
+        Ok(())
+    }
+
+    #[test]
+    fn test_div_by_zero_spanning_range_is_unbounded() -> crate::Result<()> {
+        let numerator = int_range(10, 20)?;
+        assert_eq!(
+            (&numerator / &int_range(-1, 1)?)?,
+            ColumnRangeStatistics::Missing
+        );
+        assert_eq!(
+            (&numerator / &int_range(0, 4)?)?,
+            ColumnRangeStatistics::Missing
+        );
+        assert_eq!(
+            (&numerator / &int_range(0, 0)?)?,
+            ColumnRangeStatistics::Missing
+        );
+        Ok(())
+    }
 }
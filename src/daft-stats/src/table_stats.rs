@@ -10,7 +10,7 @@ use daft_dsl::{Expr, ExprRef};
 use daft_table::Table;
 use indexmap::{IndexMap, IndexSet};
 
-use crate::column_stats::ColumnRangeStatistics;
+use crate::column_stats::{ColumnRangeStatistics, TruthValue};
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct TableStatistics {
@@ -65,6 +65,48 @@ impl TableStatistics {
         Ok(Self { columns })
     }
 
+    /// Combines the statistics of two partitions that share a schema, e.g. row groups of one file.
+    ///
+    /// Unlike [`TableStatistics::union`], this requires both sides to have the same columns in the
+    /// same order with matching types. A column that is entirely null on one side, and so has null
+    /// bounds, takes the other side's range.
+    pub fn merge(&self, other: &Self) -> DaftResult<Self> {
+        if !self.columns.keys().eq(other.columns.keys()) {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Cannot merge table statistics with different columns: [{}] vs [{}]",
+                self.columns.keys().cloned().collect::<Vec<_>>().join(", "),
+                other.columns.keys().cloned().collect::<Vec<_>>().join(", ")
+            )));
+        }
+        let mut columns = IndexMap::with_capacity(self.columns.len());
+        for ((name, l), r) in self.columns.iter().zip(other.columns.values()) {
+            let merged = match (l, r) {
+                (
+                    ColumnRangeStatistics::Loaded(l_lower, l_upper),
+                    ColumnRangeStatistics::Loaded(r_lower, r_upper),
+                ) => {
+                    if l_lower.data_type() != r_lower.data_type() {
+                        return Err(DaftError::SchemaMismatch(format!(
+                            "Cannot merge table statistics for column {name} with different types: {} vs {}",
+                            l_lower.data_type(),
+                            r_lower.data_type()
+                        )));
+                    }
+                    if !l_lower.is_valid(0) && !l_upper.is_valid(0) {
+                        r.clone()
+                    } else if !r_lower.is_valid(0) && !r_upper.is_valid(0) {
+                        l.clone()
+                    } else {
+                        l.union(r)?
+                    }
+                }
+                _ => ColumnRangeStatistics::Missing,
+            };
+            columns.insert(name.clone(), merged);
+        }
+        Ok(Self { columns })
+    }
+
     pub fn eval_expression_list(
         &self,
         exprs: &[ExprRef],
@@ -134,10 +176,14 @@ impl TableStatistics {
             }
             Expr::Literal(lit_value) => lit_value.try_into(),
             Expr::Not(col) => self.eval_expression(col)?.not(),
+            Expr::IsNull(col) => self.eval_expression(col)?.is_null(),
+            Expr::NotNull(col) => self.eval_expression(col)?.not_null(),
             Expr::BinaryOp { op, left, right } => {
                 let lhs = self.eval_expression(left)?;
                 let rhs = self.eval_expression(right)?;
-                use daft_dsl::Operator::{And, Eq, Gt, GtEq, Lt, LtEq, Minus, NotEq, Or, Plus};
+                use daft_dsl::Operator::{
+                    And, Eq, Gt, GtEq, Lt, LtEq, Minus, NotEq, Or, Plus, TrueDivide,
+                };
                 match op {
                     Lt => lhs.lt(&rhs),
                     LtEq => lhs.lte(&rhs),
@@ -147,6 +193,7 @@ impl TableStatistics {
                     Gt => lhs.gt(&rhs),
                     Plus => &lhs + &rhs,
                     Minus => &lhs - &rhs,
+                    TrueDivide => &lhs / &rhs,
                     And => lhs.bitand(&rhs),
                     Or => lhs.bitor(&rhs),
                     _ => Ok(ColumnRangeStatistics::Missing),
@@ -156,6 +203,134 @@ impl TableStatistics {
         }
     }
 
+    /// Estimates the fraction of rows that satisfy `predicate`, in `[0, 1]`.
+    ///
+    /// Predicates that the statistics prove to be always false or always true yield exactly 0 or 1.
+    /// Otherwise, comparisons between a column and a literal are estimated from the column's range,
+    /// assuming values are uniformly distributed, and conjunctions / disjunctions assume independence.
+    #[must_use]
+    pub fn estimate_selectivity(&self, predicate: &Expr) -> f64 {
+        const DEFAULT_SELECTIVITY: f64 = 0.5;
+        const DEFAULT_EQ_SELECTIVITY: f64 = 0.1;
+        use daft_dsl::Operator::{And, Eq, Gt, GtEq, Lt, LtEq, NotEq, Or};
+
+        match self
+            .eval_expression(predicate)
+            .ok()
+            .as_ref()
+            .and_then(truth_value)
+        {
+            Some(TruthValue::False) => return 0.,
+            Some(TruthValue::True) => return 1.,
+            Some(TruthValue::Maybe) | None => {}
+        }
+        match predicate {
+            Expr::Alias(e, _) => self.estimate_selectivity(e),
+            Expr::Not(e) => 1. - self.estimate_selectivity(e),
+            Expr::BinaryOp {
+                op: And,
+                left,
+                right,
+            } => self.estimate_selectivity(left) * self.estimate_selectivity(right),
+            Expr::BinaryOp {
+                op: Or,
+                left,
+                right,
+            } => {
+                let l = self.estimate_selectivity(left);
+                let r = self.estimate_selectivity(right);
+                l + r - l * r
+            }
+            Expr::BinaryOp {
+                op: op @ (Eq | NotEq),
+                left,
+                right,
+            } => {
+                let eq = self
+                    .column_and_literal(left, right)
+                    .and_then(|(col, _)| distinct_count_upper_bound(&col))
+                    .map_or(DEFAULT_EQ_SELECTIVITY, |ndv| 1. / ndv.max(1.));
+                if *op == Eq {
+                    eq
+                } else {
+                    1. - eq
+                }
+            }
+            Expr::BinaryOp {
+                op: op @ (Lt | LtEq | Gt | GtEq),
+                left,
+                right,
+            } => {
+                // Normalize to `column <op> literal`.
+                let (col, lit, col_is_lhs) = match self.column_and_literal(left, right) {
+                    Some((col, lit)) => (col, lit, matches!(left.as_ref(), Expr::Column(_))),
+                    None => return DEFAULT_SELECTIVITY,
+                };
+                let (Some((min, max)), Some((value, _))) =
+                    (numeric_bounds(&col), numeric_bounds(&lit))
+                else {
+                    return DEFAULT_SELECTIVITY;
+                };
+                if max <= min {
+                    return DEFAULT_SELECTIVITY;
+                }
+                let below = ((value - min) / (max - min)).clamp(0., 1.);
+                let is_upper_bound = matches!(op, Lt | LtEq) == col_is_lhs;
+                if is_upper_bound {
+                    below
+                } else {
+                    1. - below
+                }
+            }
+            _ => DEFAULT_SELECTIVITY,
+        }
+    }
+
+    /// Fraction of rows that provably bound those satisfying `predicate`: 0 if the statistics prove it is always
+    /// false and 1 otherwise. Unlike [`TableStatistics::estimate_selectivity`], this never underestimates.
+    #[must_use]
+    pub fn max_selectivity(&self, predicate: &Expr) -> f64 {
+        match self
+            .eval_expression(predicate)
+            .ok()
+            .as_ref()
+            .and_then(truth_value)
+        {
+            Some(TruthValue::False) => 0.,
+            _ => 1.,
+        }
+    }
+
+    /// Upper bounds on the number of distinct non-null values of each column whose range bounds it,
+    /// i.e. integer and boolean columns.
+    #[must_use]
+    pub fn estimate_column_ndv(&self) -> HashMap<String, usize> {
+        self.columns
+            .iter()
+            .filter_map(|(name, stats)| {
+                distinct_count_upper_bound(stats).map(|ndv| (name.clone(), ndv as usize))
+            })
+            .collect()
+    }
+
+    /// Evaluates the statistics of a `column <op> literal` (or `literal <op> column`) comparison's operands,
+    /// returning the column's statistics followed by the literal's.
+    fn column_and_literal(
+        &self,
+        left: &Expr,
+        right: &Expr,
+    ) -> Option<(ColumnRangeStatistics, ColumnRangeStatistics)> {
+        let (col, lit) = match (left, right) {
+            (Expr::Column(_), Expr::Literal(_)) => (left, right),
+            (Expr::Literal(_), Expr::Column(_)) => (right, left),
+            _ => return None,
+        };
+        Some((
+            self.eval_expression(col).ok()?,
+            self.eval_expression(lit).ok()?,
+        ))
+    }
+
     pub fn cast_to_schema(&self, schema: SchemaRef) -> crate::Result<Self> {
         self.cast_to_schema_with_fill(schema, None)
     }
@@ -184,6 +359,47 @@ impl TableStatistics {
     }
 }
 
+/// Like [`ColumnRangeStatistics::to_truth_value`], but returns `None` for non-boolean or null bounds.
+fn truth_value(stats: &ColumnRangeStatistics) -> Option<TruthValue> {
+    let ColumnRangeStatistics::Loaded(lower, upper) = stats else {
+        return Some(TruthValue::Maybe);
+    };
+    match (lower.bool().ok()?.get(0)?, upper.bool().ok()?.get(0)?) {
+        (false, false) => Some(TruthValue::False),
+        (true, true) => Some(TruthValue::True),
+        _ => Some(TruthValue::Maybe),
+    }
+}
+
+/// Upper bound on the number of distinct non-null values of a column, if its range bounds it.
+fn distinct_count_upper_bound(stats: &ColumnRangeStatistics) -> Option<f64> {
+    let ColumnRangeStatistics::Loaded(lower, upper) = stats else {
+        return None;
+    };
+    if lower.data_type() == &DataType::Boolean {
+        let (lower, upper) = (lower.bool().ok()?.get(0)?, upper.bool().ok()?.get(0)?);
+        return Some(if lower == upper { 1. } else { 2. });
+    }
+    // The range of an integer column bounds its number of distinct values.
+    if !lower.data_type().is_integer() {
+        return None;
+    }
+    let (min, max) = numeric_bounds(stats)?;
+    Some(max - min + 1.)
+}
+
+/// Returns the lower and upper bounds of numeric statistics as `f64`s.
+fn numeric_bounds(stats: &ColumnRangeStatistics) -> Option<(f64, f64)> {
+    let ColumnRangeStatistics::Loaded(lower, upper) = stats else {
+        return None;
+    };
+    if !lower.data_type().is_numeric() {
+        return None;
+    }
+    let to_f64 = |s: &Series| s.cast(&DataType::Float64).ok()?.f64().ok()?.get(0);
+    Some((to_f64(lower)?, to_f64(upper)?))
+}
+
 impl Display for TableStatistics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let columns = self
@@ -199,12 +415,14 @@ impl Display for TableStatistics {
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
+
     use daft_core::prelude::*;
-    use daft_dsl::{col, lit};
+    use daft_dsl::{binary_op, col, lit, ExprRef, Operator};
     use daft_table::Table;
 
     use super::TableStatistics;
-    use crate::column_stats::TruthValue;
+    use crate::column_stats::{ColumnRangeStatistics, TruthValue};
 
     #[test]
     fn test_equal() -> crate::Result<()> {
@@ -238,4 +456,171 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_merge() -> common_error::DaftResult<()> {
+        let first = Table::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![3, 5, 4])).into_series(),
+            Utf8Array::from(("b", ["m", "n", "o"].as_slice())).into_series(),
+            Int64Array::from_iter(
+                Field::new("c", DataType::Int64),
+                vec![None, None, None].into_iter(),
+            )
+            .into_series(),
+        ])?;
+        let second = Table::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![7, 1])).into_series(),
+            Utf8Array::from(("b", ["k", "l"].as_slice())).into_series(),
+            Int64Array::from(("c", vec![10, 20])).into_series(),
+        ])?;
+
+        let merged =
+            TableStatistics::from_table(&first).merge(&TableStatistics::from_table(&second))?;
+        let bounds = |name: &str| match &merged.columns[name] {
+            ColumnRangeStatistics::Loaded(lower, upper) => (lower.clone(), upper.clone()),
+            ColumnRangeStatistics::Missing => panic!("expected loaded stats for {name}"),
+        };
+
+        let (lower, upper) = bounds("a");
+        assert_eq!(lower.i64()?.get(0), Some(1));
+        assert_eq!(upper.i64()?.get(0), Some(7));
+        let (lower, upper) = bounds("b");
+        assert_eq!(lower.utf8()?.get(0), Some("k"));
+        assert_eq!(upper.utf8()?.get(0), Some("o"));
+        // An all-null column on one side doesn't widen the other side's range.
+        let (lower, upper) = bounds("c");
+        assert_eq!(lower.i64()?.get(0), Some(10));
+        assert_eq!(upper.i64()?.get(0), Some(20));
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_rejects_schema_mismatch() -> common_error::DaftResult<()> {
+        let ints =
+            Table::from_nonempty_columns(vec![Int64Array::from(("a", vec![1, 2])).into_series()])?;
+        let strings =
+            Table::from_nonempty_columns(vec![
+                Utf8Array::from(("a", ["x", "y"].as_slice())).into_series()
+            ])?;
+        let renamed =
+            Table::from_nonempty_columns(vec![Int64Array::from(("b", vec![1, 2])).into_series()])?;
+
+        let stats = TableStatistics::from_table(&ints);
+        assert!(stats.merge(&TableStatistics::from_table(&strings)).is_err());
+        assert!(stats.merge(&TableStatistics::from_table(&renamed)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_eval_true_divide() -> crate::Result<()> {
+        let table =
+            Table::from_nonempty_columns(vec![Int64Array::from(("a", vec![10, 20])).into_series()])
+                .unwrap();
+        let table_stats = TableStatistics::from_table(&table);
+
+        let expr = binary_op(Operator::TrueDivide, col("a"), lit(5));
+        let ColumnRangeStatistics::Loaded(lower, upper) = table_stats.eval_expression(&expr)?
+        else {
+            panic!("expected loaded stats");
+        };
+        assert_eq!(lower.f64().unwrap().get(0), Some(2.));
+        assert_eq!(upper.f64().unwrap().get(0), Some(4.));
+
+        let expr = binary_op(Operator::TrueDivide, col("a"), lit(0));
+        assert_eq!(
+            table_stats.eval_expression(&expr)?,
+            ColumnRangeStatistics::Missing
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_eval_is_null_and_not_null() -> crate::Result<()> {
+        let table = Table::from_nonempty_columns(vec![
+            Int64Array::from_iter(
+                Field::new("all_null", DataType::Int64),
+                [None, None].into_iter(),
+            )
+            .into_series(),
+            Int64Array::from_iter(
+                Field::new("some_null", DataType::Int64),
+                [Some(1), None].into_iter(),
+            )
+            .into_series(),
+            Int64Array::from(("no_null", vec![1, 2])).into_series(),
+        ])
+        .unwrap();
+        let table_stats = TableStatistics::from_table(&table);
+        let truth = |expr: ExprRef| {
+            table_stats
+                .eval_expression(&expr)
+                .map(|s| s.to_truth_value())
+        };
+
+        assert_eq!(truth(col("all_null").is_null())?, TruthValue::True);
+        assert_eq!(truth(col("all_null").not_null())?, TruthValue::False);
+        // Ranges don't track null counts, so nulls can't be ruled out or confirmed otherwise.
+        assert_eq!(truth(col("some_null").is_null())?, TruthValue::Maybe);
+        assert_eq!(truth(col("some_null").not_null())?, TruthValue::Maybe);
+        assert_eq!(truth(col("no_null").is_null())?, TruthValue::Maybe);
+        assert_eq!(truth(col("no_null").not_null())?, TruthValue::Maybe);
+
+        assert_eq!(truth(col("all_null").is_null().not())?, TruthValue::False);
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_selectivity() {
+        let table = Table::from_nonempty_columns(vec![Int64Array::from((
+            "a",
+            (0..1000).collect::<Vec<i64>>(),
+        ))
+        .into_series()])
+        .unwrap();
+        let table_stats = TableStatistics::from_table(&table);
+
+        // Equality on a unique column is highly selective.
+        let selective = table_stats.estimate_selectivity(&col("a").eq(lit(500)));
+        assert!(selective > 0. && selective <= 0.001 + f64::EPSILON);
+
+        // A wide range predicate keeps most rows.
+        let non_selective = table_stats.estimate_selectivity(&col("a").gt(lit(100)));
+        assert!((non_selective - 0.9).abs() < 0.01);
+        assert!(selective < non_selective);
+
+        // Flipped operands are normalized.
+        let flipped = table_stats.estimate_selectivity(&lit(100).lt(col("a")));
+        assert!((flipped - non_selective).abs() < f64::EPSILON);
+
+        // Predicates that are proven false or true by the statistics.
+        assert!(table_stats.estimate_selectivity(&col("a").lt(lit(0))).abs() < f64::EPSILON);
+        assert!(
+            (table_stats.estimate_selectivity(&col("a").gt_eq(lit(0))) - 1.).abs() < f64::EPSILON
+        );
+
+        // Conjunctions assume independence.
+        let and =
+            table_stats.estimate_selectivity(&col("a").gt(lit(100)).and(col("a").lt(lit(600))));
+        assert!((and - 0.9 * 0.6).abs() < 0.01);
+
+        // Only predicates that are proven false bound the selectivity.
+        assert!(table_stats.max_selectivity(&col("a").lt(lit(0))).abs() < f64::EPSILON);
+        assert!((table_stats.max_selectivity(&col("a").eq(lit(500))) - 1.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_estimate_column_ndv() -> common_error::DaftResult<()> {
+        let table = Table::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![5, 7, 6, 5])).into_series(),
+            BooleanArray::from(("b", [true, true, true, true].as_slice())).into_series(),
+            Float64Array::from(("c", vec![1., 2., 3., 4.])).into_series(),
+        ])?;
+        let ndv = TableStatistics::from_table(&table).estimate_column_ndv();
+        // Only integer and boolean ranges bound the number of distinct values.
+        assert_eq!(
+            ndv,
+            HashMap::from([("a".to_string(), 3), ("b".to_string(), 1)])
+        );
+        Ok(())
+    }
 }
@@ -8,6 +8,9 @@ use crate::Table;
 
 pub struct GrowableTable<'a> {
     growables: Vec<Box<dyn Growable + 'a>>,
+    /// Run of consecutive rows from [`GrowableTable::extend_row`] that has not been copied yet,
+    /// as `(table index, start row, length)`.
+    pending_run: Option<(usize, usize, usize)>,
 }
 
 impl<'a> GrowableTable<'a> {
@@ -43,11 +46,39 @@ impl<'a> GrowableTable<'a> {
             .zip(first_schema.fields.values())
             .map(|(vector, f)| make_growable(&f.name, &f.dtype, vector, use_validity, capacity))
             .collect::<Vec<_>>();
-        Ok(Self { growables })
+        Ok(Self {
+            growables,
+            pending_run: None,
+        })
     }
 
     /// This function panics if the range is out of bounds, i.e. if `start + len >= array.len()`.
     pub fn extend(&mut self, index: usize, start: usize, len: usize) {
+        self.flush_pending_run();
+        self.extend_growables(index, start, len);
+    }
+
+    /// Appends a single row, coalescing runs of consecutive rows from the same table into one
+    /// [`GrowableTable::extend`] so that per-row callers don't pay a per-row copy.
+    pub fn extend_row(&mut self, index: usize, row: usize) {
+        match &mut self.pending_run {
+            Some((run_index, start, len)) if *run_index == index && *start + *len == row => {
+                *len += 1;
+            }
+            _ => {
+                self.flush_pending_run();
+                self.pending_run = Some((index, row, 1));
+            }
+        }
+    }
+
+    fn flush_pending_run(&mut self) {
+        if let Some((index, start, len)) = self.pending_run.take() {
+            self.extend_growables(index, start, len);
+        }
+    }
+
+    fn extend_growables(&mut self, index: usize, start: usize, len: usize) {
         if !self.growables.is_empty() {
             self.growables
                 .iter_mut()
@@ -57,6 +88,7 @@ impl<'a> GrowableTable<'a> {
 
     /// Extends this [`Growable`] with null elements
     pub fn add_nulls(&mut self, additional: usize) {
+        self.flush_pending_run();
         if !self.growables.is_empty() {
             self.growables
                 .iter_mut()
@@ -64,8 +96,18 @@ impl<'a> GrowableTable<'a> {
         }
     }
 
+    /// Reserves capacity for at least `additional` more rows in every column, so that a following
+    /// run of [`GrowableTable::extend`] or [`GrowableTable::add_nulls`] calls does not reallocate
+    /// incrementally.
+    pub fn reserve(&mut self, additional: usize) {
+        self.growables
+            .iter_mut()
+            .for_each(|g| g.reserve(additional));
+    }
+
     /// Builds an array from the [`Growable`]
     pub fn build(&mut self) -> DaftResult<Table> {
+        self.flush_pending_run();
         if self.growables.is_empty() {
             Table::empty(None)
         } else {
@@ -78,3 +120,49 @@ impl<'a> GrowableTable<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+
+    use super::GrowableTable;
+    use crate::Table;
+
+    fn keys_table(values: Vec<i64>) -> DaftResult<Table> {
+        Table::from_nonempty_columns(vec![Int64Array::from(("key", values)).into_series()])
+    }
+
+    #[test]
+    fn test_extend_row_matches_single_row_extends() -> DaftResult<()> {
+        let first = keys_table(vec![0, 1, 2, 3])?;
+        let second = keys_table(vec![10, 11, 12])?;
+        let tables = [&first, &second];
+        let rows = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 0),
+            (1, 1),
+            (0, 3),
+            (0, 3),
+            (1, 2),
+        ];
+
+        let mut expected = GrowableTable::new(&tables, true, 0)?;
+        let mut coalesced = GrowableTable::new(&tables, true, 0)?;
+        for (i, &(index, row)) in rows.iter().enumerate() {
+            expected.extend(index, row, 1);
+            coalesced.extend_row(index, row);
+            if i == 4 {
+                expected.add_nulls(1);
+                coalesced.add_nulls(1);
+            }
+        }
+
+        let expected = expected.build()?;
+        assert_eq!(expected.len(), rows.len() + 1);
+        assert_eq!(coalesced.build()?, expected);
+        Ok(())
+    }
+}
@@ -31,7 +31,9 @@ mod probeable;
 mod repr_html;
 
 pub use growable::GrowableTable;
-pub use probeable::{make_probeable_builder, ProbeState, Probeable, ProbeableBuilder};
+pub use probeable::{
+    make_probeable_builder, ProbeState, ProbeTableBuilder, Probeable, ProbeableBuilder,
+};
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -52,12 +54,47 @@ fn _validate_schema(schema: &Schema, columns: &[Series]) -> DaftResult<()> {
     if schema.fields.len() != columns.len() {
         return Err(DaftError::SchemaMismatch(format!("While building a Table, we found that the number of fields did not match between the schema and the input columns.\n {:?}\n vs\n {:?}", schema.fields.len(), columns.len())));
     }
-    for (field, series) in schema.fields.values().zip(columns.iter()) {
-        if field != series.field() {
-            return Err(DaftError::SchemaMismatch(format!("While building a Table, we found that the Schema Field and the Series Field  did not match. schema field: {field} vs series field: {}", series.field())));
+    let mut name_mismatches = vec![];
+    let mut dtype_mismatches = vec![];
+    let mut other_mismatches = vec![];
+    for (idx, (field, series)) in schema.fields.values().zip(columns.iter()).enumerate() {
+        let series_field = series.field();
+        if field.name != series_field.name {
+            name_mismatches.push(format!(
+                "  at position {idx}: expected {} but found {}",
+                field.name, series_field.name
+            ));
+        } else if field.dtype != series_field.dtype {
+            dtype_mismatches.push(format!(
+                "  {}: expected {} but found {}",
+                field.name, field.dtype, series_field.dtype
+            ));
+        } else if field != series_field {
+            other_mismatches.push(format!(
+                "  schema field: {field} vs series field: {series_field}"
+            ));
+        }
+    }
+    if name_mismatches.is_empty() && dtype_mismatches.is_empty() && other_mismatches.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = "While building a Table, we found that the Schema Fields and the Series Fields did not match.".to_string();
+    for (header, mismatches) in [
+        ("Column name mismatches:", name_mismatches),
+        ("Column dtype mismatches:", dtype_mismatches),
+        ("Other field mismatches:", other_mismatches),
+    ] {
+        if !mismatches.is_empty() {
+            message.push('\n');
+            message.push_str(header);
+            for mismatch in mismatches {
+                message.push('\n');
+                message.push_str(&mismatch);
+            }
         }
     }
-    Ok(())
+    Err(DaftError::SchemaMismatch(message))
 }
 
 impl Table {
@@ -153,13 +190,20 @@ impl Table {
     }
 
     pub fn empty(schema: Option<SchemaRef>) -> DaftResult<Self> {
-        let schema = schema.unwrap_or_else(|| Schema::empty().into());
-        let mut columns: Vec<Series> = Vec::with_capacity(schema.names().len());
-        for (field_name, field) in &schema.fields {
-            let series = Series::empty(field_name, &field.dtype);
-            columns.push(series);
-        }
-        Ok(Self::new_unchecked(schema, columns, 0))
+        Ok(Self::empty_with_schema(
+            schema.unwrap_or_else(|| Schema::empty().into()),
+        ))
+    }
+
+    /// Create a zero-row Table with a correctly typed empty column for every field of `schema`.
+    #[must_use]
+    pub fn empty_with_schema(schema: SchemaRef) -> Self {
+        let columns = schema
+            .fields
+            .iter()
+            .map(|(field_name, field)| Series::empty(field_name, &field.dtype))
+            .collect();
+        Self::new_unchecked(schema, columns, 0)
     }
 
     /// Create a Table from a set of columns.
@@ -276,9 +320,14 @@ impl Table {
         column_name: &str,
     ) -> DaftResult<Self> {
         // Use the leftmost 28 bits for the partition number and the rightmost 36 bits for the row number
-        let start = (partition_num << 36) + offset;
-        let end = start + self.len() as u64;
-        let ids = (start..end).step_by(1).collect::<Vec<_>>();
+        self.add_dense_id(column_name, (partition_num << 36) + offset)
+    }
+
+    /// Prepends a column of consecutive ids `start..start + len`.
+    ///
+    /// When all rows live in a single partition, this is the whole of [`Self::add_monotonically_increasing_id`].
+    pub fn add_dense_id(&self, column_name: &str, start: u64) -> DaftResult<Self> {
+        let ids = (start..start + self.len() as u64).collect::<Vec<_>>();
         let id_series = UInt64Array::from((column_name, ids)).into_series();
         Self::from_nonempty_columns([&[id_series], &self.columns[..]].concat())
     }
@@ -367,10 +416,62 @@ impl Table {
     }
 
     pub fn take(&self, idx: &Series) -> DaftResult<Self> {
-        let new_series: DaftResult<Vec<_>> = self.columns.iter().map(|s| s.take(idx)).collect();
+        let idx = self.resolve_take_indices(idx)?;
+        let new_series: DaftResult<Vec<_>> = self.columns.iter().map(|s| s.take(&idx)).collect();
         Self::new_with_size(self.schema.clone(), new_series?, idx.len())
     }
 
+    /// Bounds checks `idx` against this table and resolves negative indices, which count back from the last row.
+    /// Null indices are preserved so that they produce null rows.
+    fn resolve_take_indices(&self, idx: &Series) -> DaftResult<Series> {
+        let num_rows = self.len();
+        let out_of_bounds = |i: &dyn Display| {
+            DaftError::ValueError(format!(
+                "Index {i} is out of bounds for Table with {num_rows} rows"
+            ))
+        };
+        match idx.data_type() {
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                let idx = idx.cast(&DataType::UInt64)?;
+                if let Some(i) = idx
+                    .u64()?
+                    .into_iter()
+                    .flatten()
+                    .find(|i| *i as usize >= num_rows)
+                {
+                    return Err(out_of_bounds(&i));
+                }
+                Ok(idx)
+            }
+            dtype if dtype.is_integer() => {
+                let resolved = idx
+                    .cast(&DataType::Int64)?
+                    .i64()?
+                    .into_iter()
+                    .map(|i| {
+                        i.map(|i| {
+                            let resolved = if i < 0 { i + num_rows as i64 } else { i };
+                            if resolved < 0 || resolved as usize >= num_rows {
+                                Err(out_of_bounds(&i))
+                            } else {
+                                Ok(resolved as u64)
+                            }
+                        })
+                        .transpose()
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                Ok(UInt64Array::from_iter(
+                    Field::new(idx.name(), DataType::UInt64),
+                    resolved.into_iter(),
+                )
+                .into_series())
+            }
+            dtype => Err(DaftError::TypeError(format!(
+                "Table take requires an integer index Series, but we got {dtype}"
+            ))),
+        }
+    }
+
     pub fn concat<T: AsRef<Self>>(tables: &[T]) -> DaftResult<Self> {
         if tables.is_empty() {
             return Err(DaftError::ValueError(
@@ -498,11 +599,22 @@ impl Table {
             }
             AggExpr::Mean(expr) => self.eval_expression(expr)?.mean(groups),
             AggExpr::Stddev(expr) => self.eval_expression(expr)?.stddev(groups),
+            AggExpr::Variance(expr, ddof) => self.eval_expression(expr)?.variance(groups, *ddof),
+            AggExpr::VarianceState(expr) => self.eval_expression(expr)?.variance_state(groups),
+            AggExpr::MergeVariance(expr, ddof) => {
+                self.eval_expression(expr)?.merge_variance(groups, *ddof)
+            }
             AggExpr::Min(expr) => self.eval_expression(expr)?.min(groups),
             AggExpr::Max(expr) => self.eval_expression(expr)?.max(groups),
             &AggExpr::AnyValue(ref expr, ignore_nulls) => {
                 self.eval_expression(expr)?.any_value(groups, ignore_nulls)
             }
+            &AggExpr::First(ref expr, ignore_nulls) => {
+                self.eval_expression(expr)?.first(groups, ignore_nulls)
+            }
+            &AggExpr::Last(ref expr, ignore_nulls) => {
+                self.eval_expression(expr)?.last(groups, ignore_nulls)
+            }
             AggExpr::List(expr) => self.eval_expression(expr)?.agg_list(groups),
             AggExpr::Concat(expr) => self.eval_expression(expr)?.agg_concat(groups),
             AggExpr::MapGroups { .. } => Err(DaftError::ValueError(
@@ -553,12 +665,16 @@ impl Table {
                     Lt => Ok(lhs.lt(&rhs)?.into_series()),
                     LtEq => Ok(lhs.lte(&rhs)?.into_series()),
                     Eq => Ok(lhs.equal(&rhs)?.into_series()),
+                    EqNullSafe => Ok(lhs.eq_null_safe(&rhs)?.into_series()),
                     NotEq => Ok(lhs.not_equal(&rhs)?.into_series()),
                     GtEq => Ok(lhs.gte(&rhs)?.into_series()),
                     Gt => Ok(lhs.gt(&rhs)?.into_series()),
                     And => lhs.and(&rhs),
                     Or => lhs.or(&rhs),
                     Xor => lhs.xor(&rhs),
+                    BitwiseAnd => lhs.bitwise_and(&rhs),
+                    BitwiseOr => lhs.bitwise_or(&rhs),
+                    BitwiseXor => lhs.bitwise_xor(&rhs),
                     ShiftLeft => lhs.shift_left(&rhs),
                     ShiftRight => lhs.shift_right(&rhs),
                 }
@@ -915,12 +1031,69 @@ impl<'a> IntoIterator for &'a Table {
 
 #[cfg(test)]
 mod test {
-    use common_error::DaftResult;
+    use std::sync::Arc;
+
+    use common_error::{DaftError, DaftResult};
     use daft_core::prelude::*;
-    use daft_dsl::col;
+    use daft_dsl::{col, lit};
 
     use crate::Table;
 
+    #[test]
+    fn empty_with_schema_has_typed_columns() -> DaftResult<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+            Field::new("c", DataType::List(Box::new(DataType::Float32))),
+        ])?);
+        let table = Table::empty_with_schema(schema.clone());
+        assert_eq!(table.len(), 0);
+        assert_eq!(table.schema, schema);
+        let dtypes = table
+            .columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dtypes,
+            vec![
+                DataType::Int64,
+                DataType::Utf8,
+                DataType::List(Box::new(DataType::Float32))
+            ]
+        );
+        assert!(table.columns.iter().all(|c| c.is_empty()));
+        Ok(())
+    }
+
+    #[test]
+    fn eq_null_safe_expression() -> DaftResult<()> {
+        let a = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), None, Some(1), None].into_iter(),
+        )
+        .into_series();
+        let b = Int32Array::from_iter(
+            Field::new("b", DataType::Int32),
+            vec![Some(1), Some(1), None, None].into_iter(),
+        )
+        .into_series();
+        let table = Table::from_nonempty_columns(vec![a, b])?;
+        let expr = col("a").eq_null_safe(col("b"));
+        assert_eq!(expr.to_string(), "col(a) <=> col(b)");
+        assert_eq!(expr.to_field(&table.schema)?.dtype, DataType::Boolean);
+
+        let result = table.eval_expression(&expr)?;
+        assert_eq!(
+            result.bool()?.into_iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(false), Some(false), Some(true)]
+        );
+
+        let mismatched = col("a").eq_null_safe(lit("x"));
+        assert!(mismatched.to_field(&table.schema).is_err());
+        Ok(())
+    }
+
     #[test]
     fn add_int_and_float_expression() -> DaftResult<()> {
         let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
@@ -942,4 +1115,186 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn bitwise_and_with_literal_mask() -> DaftResult<()> {
+        let flags = Int64Array::from(("flags", vec![0x1F, 0xF0, 0x0A])).into_series();
+        let table = Table::from_nonempty_columns(vec![flags])?;
+
+        let result = table.eval_expression(&col("flags").bitwise_and(lit(0x0F)))?;
+        assert_eq!(*result.data_type(), DataType::Int64);
+        assert_eq!(result.i64()?.as_slice(), &[0x0F, 0x00, 0x0A]);
+
+        let result = table.eval_expression(&col("flags").bitwise_xor(lit(0xFF)))?;
+        assert_eq!(result.i64()?.as_slice(), &[0xE0, 0x0F, 0xF5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_if_else_with_null_predicates() -> DaftResult<()> {
+        let a = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), Some(5), None, Some(10)].into_iter(),
+        )
+        .into_series();
+        let table = Table::from_nonempty_columns(vec![a])?;
+
+        // a < 3 ? 0 : (a < 8 ? 1 : 2)
+        let inner = col("a").lt(lit(8)).if_else(lit(1), lit(2));
+        let outer = col("a").lt(lit(3)).if_else(lit(0), inner);
+        let result = table.eval_expression(&outer)?;
+        assert_eq!(*result.data_type(), DataType::Int32);
+        let result = result.i32()?;
+        assert_eq!(result.get(0), Some(0));
+        assert_eq!(result.get(1), Some(1));
+        assert_eq!(result.get(2), None);
+        assert_eq!(result.get(3), Some(2));
+
+        // a null predicate treated as false falls through to the else branch
+        let outer = col("a").lt(lit(3)).fill_null(lit(false)).if_else(
+            lit(0),
+            col("a")
+                .lt(lit(8))
+                .fill_null(lit(false))
+                .if_else(lit(1), lit(2)),
+        );
+        let result = table.eval_expression(&outer)?;
+        assert_eq!(result.i32()?.as_slice(), &[0, 1, 2, 2]);
+
+        // non-boolean predicates are rejected
+        assert!(table
+            .eval_expression(&col("a").if_else(lit(0), lit(1)))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn schema_mismatch_reports_all_mismatched_columns() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+            Field::new("d", DataType::Int64),
+        ])?;
+        let columns = vec![
+            Int64Array::from(("a", vec![1])).into_series(),
+            Float64Array::from(("b", vec![1.])).into_series(),
+            Int64Array::from(("c", vec![1])).into_series(),
+            Int64Array::from(("e", vec![1])).into_series(),
+        ];
+
+        let Err(DaftError::SchemaMismatch(message)) = Table::new_with_size(schema, columns, 1)
+        else {
+            panic!("expected a schema mismatch");
+        };
+        assert!(message.contains("b: expected Int64 but found Float64"));
+        assert!(message.contains("c: expected Utf8 but found Int64"));
+        assert!(message.contains("at position 3: expected d but found e"));
+        let names_at = message.find("Column name mismatches:").unwrap();
+        let dtypes_at = message.find("Column dtype mismatches:").unwrap();
+        assert!(names_at < dtypes_at);
+        assert!(!message.contains(" a: "));
+        Ok(())
+    }
+
+    #[test]
+    fn take_with_negative_indices() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![10, 20, 30])).into_series();
+        let table = Table::from_nonempty_columns(vec![a])?;
+
+        let idx = Int32Array::from(("idx", vec![-1, 0, -3])).into_series();
+        let result = table.take(&idx)?;
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.get_column("a")?.i64()?.as_slice(), &[30, 10, 10]);
+        Ok(())
+    }
+
+    #[test]
+    fn take_out_of_bounds_errors() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![10, 20, 30])).into_series();
+        let table = Table::from_nonempty_columns(vec![a])?;
+
+        let idx = UInt64Array::from(("idx", vec![0, 3])).into_series();
+        assert!(matches!(table.take(&idx), Err(DaftError::ValueError(_))));
+
+        let idx = Int64Array::from(("idx", vec![-4])).into_series();
+        assert!(matches!(table.take(&idx), Err(DaftError::ValueError(_))));
+
+        let idx = Float64Array::from(("idx", vec![0.])).into_series();
+        assert!(matches!(table.take(&idx), Err(DaftError::TypeError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn take_with_null_index_produces_null_row() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![10, 20, 30])).into_series();
+        let b = Utf8Array::from(("b", ["x", "y", "z"].as_slice())).into_series();
+        let table = Table::from_nonempty_columns(vec![a, b])?;
+
+        let idx = Int64Array::from_iter(
+            Field::new("idx", DataType::Int64),
+            vec![Some(1), None, Some(-1)].into_iter(),
+        )
+        .into_series();
+        let result = table.take(&idx)?;
+        assert_eq!(result.len(), 3);
+        for name in ["a", "b"] {
+            let column = result.get_column(name)?;
+            assert!(column.is_valid(0));
+            assert!(!column.is_valid(1));
+            assert!(column.is_valid(2));
+        }
+        assert_eq!(result.get_column("b")?.utf8()?.get(2), Some("z"));
+        Ok(())
+    }
+
+    #[test]
+    fn monotonically_increasing_id_is_unique_across_partitions() -> DaftResult<()> {
+        let table = Table::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![10, 20, 30])).into_series()
+        ])?;
+
+        let single = table.add_monotonically_increasing_id(0, 0, "id")?;
+        assert_eq!(single.get_column("id")?.u64()?.as_slice(), &[0, 1, 2]);
+        assert_eq!(
+            single.get_column("id")?.u64()?.as_slice(),
+            table
+                .add_dense_id("id", 0)?
+                .get_column("id")?
+                .u64()?
+                .as_slice()
+        );
+
+        let mut ids = single.get_column("id")?.u64()?.as_slice().to_vec();
+        for partition_num in 1..4 {
+            let with_id = table.add_monotonically_increasing_id(partition_num, 0, "id")?;
+            ids.extend_from_slice(with_id.get_column("id")?.u64()?.as_slice());
+        }
+        let num_ids = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), num_ids);
+        Ok(())
+    }
+
+    #[test]
+    fn sample_with_seed_is_reproducible() -> DaftResult<()> {
+        let table = Table::from_nonempty_columns(vec![Int64Array::from((
+            "a",
+            (0..100).collect::<Vec<_>>(),
+        ))
+        .into_series()])?;
+
+        for with_replacement in [false, true] {
+            let first = table.sample_by_fraction(0.3, with_replacement, Some(7))?;
+            let second = table.sample_by_fraction(0.3, with_replacement, Some(7))?;
+            assert_eq!(first.len(), 30);
+            assert_eq!(
+                first.get_column("a")?.i64()?.as_slice(),
+                second.get_column("a")?.i64()?.as_slice()
+            );
+        }
+        Ok(())
+    }
 }
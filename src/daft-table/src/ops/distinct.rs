@@ -0,0 +1,78 @@
+use common_error::DaftResult;
+use daft_core::{array::ops::IntoGroups, datatypes::UInt64Array, series::IntoSeries};
+use daft_dsl::ExprRef;
+
+use crate::Table;
+
+impl Table {
+    /// Keeps the first row for each distinct value of `on`, or of the whole row when `on` is `None`.
+    ///
+    /// Nulls compare equal to each other, and surviving rows keep their original relative order.
+    pub fn distinct(&self, on: Option<&[ExprRef]>) -> DaftResult<Self> {
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+        let keys = match on {
+            Some(on) => self.eval_expression_list(on)?,
+            None => self.clone(),
+        };
+        let (mut first_indices, _) = keys.make_groups()?;
+        first_indices.sort_unstable();
+        self.take(&UInt64Array::from(("idx", first_indices)).into_series())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use crate::Table;
+
+    fn i64_values(table: &Table, name: &str) -> DaftResult<Vec<Option<i64>>> {
+        Ok(table
+            .get_column(name)?
+            .i64()?
+            .into_iter()
+            .map(|v| v.copied())
+            .collect())
+    }
+
+    #[test]
+    fn test_distinct_full_rows_with_nulls() -> DaftResult<()> {
+        let table = Table::from_nonempty_columns(vec![
+            Int64Array::from_iter(
+                Field::new("a", DataType::Int64),
+                vec![Some(1), None, Some(1), None, Some(2)].into_iter(),
+            )
+            .into_series(),
+            Utf8Array::from_iter(
+                "b",
+                vec![Some("x"), Some("y"), Some("x"), Some("y"), Some("x")].into_iter(),
+            )
+            .into_series(),
+        ])?;
+
+        let distinct = table.distinct(None)?;
+        // The two (null, "y") rows are duplicates of each other.
+        assert_eq!(i64_values(&distinct, "a")?, vec![Some(1), None, Some(2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_distinct_on_subset_keeps_first_row() -> DaftResult<()> {
+        let table = Table::from_nonempty_columns(vec![
+            Utf8Array::from(("key", ["a", "b", "a", "b", "c"].as_slice())).into_series(),
+            Int64Array::from(("value", vec![1, 2, 3, 4, 5])).into_series(),
+        ])?;
+
+        let distinct = table.distinct(Some(&[col("key")]))?;
+        assert_eq!(distinct.column_names(), vec!["key", "value"]);
+        assert_eq!(
+            i64_values(&distinct, "value")?,
+            vec![Some(1), Some(2), Some(5)]
+        );
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use common_error::{DaftError, DaftResult};
 use daft_core::{
     array::ops::as_arrow::AsArrow,
     count_mode::CountMode,
-    datatypes::{DataType, UInt64Array},
+    datatypes::{DataType, Field, UInt64Array},
     series::{IntoSeries, Series},
 };
 use daft_dsl::Expr;
@@ -18,8 +18,46 @@ fn lengths_to_indices(lengths: &UInt64Array, capacity: usize) -> DaftResult<UInt
     Ok(UInt64Array::from(("indices", indices)))
 }
 
+/// The position of each exploded element within its original list. Null and empty lists explode to
+/// a single null element, which gets a null position.
+fn lengths_to_positions(
+    name: &str,
+    lengths: &UInt64Array,
+    capacity: usize,
+) -> DaftResult<UInt64Array> {
+    let mut positions = Vec::with_capacity(capacity);
+    for l in lengths.as_arrow() {
+        match l {
+            Some(&l) if l > 0 => positions.extend((0..l).map(Some)),
+            _ => positions.push(None),
+        }
+    }
+    Ok(UInt64Array::from_iter(
+        Field::new(name, DataType::UInt64),
+        positions.into_iter(),
+    ))
+}
+
 impl Table {
     pub fn explode<E: AsRef<Expr>>(&self, exprs: &[E]) -> DaftResult<Self> {
+        self.explode_impl(exprs, None)
+    }
+
+    /// Explodes like [`Table::explode`], additionally appending a `UInt64` column named
+    /// `index_column` holding each element's position within its original list.
+    pub fn explode_with_index<E: AsRef<Expr>>(
+        &self,
+        exprs: &[E],
+        index_column: &str,
+    ) -> DaftResult<Self> {
+        self.explode_impl(exprs, Some(index_column))
+    }
+
+    fn explode_impl<E: AsRef<Expr>>(
+        &self,
+        exprs: &[E],
+        index_column: Option<&str>,
+    ) -> DaftResult<Self> {
         if exprs.is_empty() {
             return Err(DaftError::ValueError(format!(
                 "Explode needs at least 1 expression, received: {}",
@@ -41,10 +79,10 @@ impl Table {
                         let evaluated = self.eval_expression(expr)?;
                         if !matches!(
                             evaluated.data_type(),
-                            DataType::List(..) | DataType::FixedSizeList(..)
+                            DataType::List(..) | DataType::FixedSizeList(..) | DataType::Map { .. }
                         ) {
                             return Err(DaftError::ValueError(format!(
-                            "Expected Expression for series: `{exploded_name}` to be a List Type, but is {}",
+                            "Expected Expression for series: `{exploded_name}` to be a List or Map Type, but is {}",
                             evaluated.data_type()
                         )));
                         }
@@ -94,6 +132,11 @@ impl Table {
             }
         }
         new_series.extend_from_slice(exploded_columns.as_slice());
+        if let Some(index_column) = index_column {
+            new_series.push(
+                lengths_to_positions(index_column, &first_len, capacity_expected)?.into_series(),
+            );
+        }
         Self::from_nonempty_columns(new_series)
     }
 }
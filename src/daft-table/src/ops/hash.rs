@@ -23,6 +23,21 @@ impl Table {
         Ok(hash_so_far)
     }
 
+    /// Like [`Self::hash_rows`], but every row starts from `seed`, so different seeds give
+    /// independent hashes for the same rows.
+    pub fn hash_rows_with_seed(&self, seed: u64) -> DaftResult<UInt64Array> {
+        if self.num_columns() == 0 {
+            return Err(DaftError::ValueError(
+                "Attempting to Hash Table with no columns".to_string(),
+            ));
+        }
+        let mut hash_so_far = UInt64Array::from(("seed", vec![seed; self.len()]));
+        for c in &self.columns {
+            hash_so_far = c.hash(Some(&hash_so_far))?;
+        }
+        Ok(hash_so_far)
+    }
+
     pub fn to_probe_hash_table(
         &self,
     ) -> DaftResult<HashMap<IndexHash, Vec<u64>, IdentityBuildHasher>> {
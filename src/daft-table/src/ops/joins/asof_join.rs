@@ -0,0 +1,328 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::*, utils::supertype::try_get_supertype};
+use daft_dsl::{
+    join::{get_common_join_keys, infer_join_schema},
+    ExprRef,
+};
+
+use super::add_non_join_key_columns;
+use crate::Table;
+
+/// Sort keys of one side of an asof join, widened to a type whose values can be ordered and subtracted.
+enum AsofKeys {
+    Int(Vec<Option<i64>>),
+    Float(Vec<Option<f64>>),
+}
+
+impl AsofKeys {
+    fn new(series: &Series, dtype: &DataType) -> DaftResult<Self> {
+        let physical = series.cast(dtype)?.as_physical()?;
+        if physical.data_type().is_integer() {
+            let keys = physical.cast(&DataType::Int64)?;
+            Ok(Self::Int(
+                keys.i64()?.into_iter().map(|v| v.copied()).collect(),
+            ))
+        } else if physical.data_type().is_floating() {
+            let keys = physical.cast(&DataType::Float64)?;
+            Ok(Self::Float(
+                keys.f64()?
+                    .into_iter()
+                    .map(|v| v.copied().filter(|v| !v.is_nan()))
+                    .collect(),
+            ))
+        } else {
+            Err(DaftError::TypeError(format!(
+                "Asof join keys must be numeric or temporal, got: {dtype}"
+            )))
+        }
+    }
+}
+
+/// Finds the matching right row for every left row with a single merge-style pass over both sides.
+///
+/// Both key columns must be sorted ascending; null keys may appear anywhere and never match.
+fn asof_indices<K: PartialOrd + Copy>(
+    left: &[Option<K>],
+    right: &[Option<K>],
+    direction: AsofDirection,
+    tolerance: Option<f64>,
+    distance: impl Fn(K, K) -> f64,
+) -> DaftResult<Vec<Option<u64>>> {
+    for (keys, side) in [(left, "left"), (right, "right")] {
+        let mut non_null = keys.iter().flatten();
+        if let Some(mut prev) = non_null.next() {
+            for key in non_null {
+                if key < prev {
+                    return Err(DaftError::ValueError(format!(
+                        "Asof join requires the {side} join key to be sorted in ascending order"
+                    )));
+                }
+                prev = key;
+            }
+        }
+    }
+
+    let right_valid = right
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, key)| key.map(|key| (idx, key)))
+        .collect::<Vec<_>>();
+    let within_tolerance = |a: K, b: K| tolerance.map_or(true, |tol| distance(a, b) <= tol);
+
+    // `pos` is the number of valid right rows whose key is strictly less than the current left key,
+    // and `end` the number whose key is less than or equal to it.
+    let mut pos = 0;
+    let mut end = 0;
+    Ok(left
+        .iter()
+        .map(|key| {
+            let key = (*key)?;
+            while pos < right_valid.len() && right_valid[pos].1 < key {
+                pos += 1;
+            }
+            end = end.max(pos);
+            while end < right_valid.len() && right_valid[end].1 <= key {
+                end += 1;
+            }
+            let backward = end
+                .checked_sub(1)
+                .map(|i| right_valid[i])
+                .filter(|(_, r)| within_tolerance(key, *r));
+            let forward = right_valid
+                .get(pos)
+                .copied()
+                .filter(|(_, r)| within_tolerance(*r, key));
+            let matched = match direction {
+                AsofDirection::Backward => backward,
+                AsofDirection::Forward => forward,
+                AsofDirection::Nearest => match (backward, forward) {
+                    (Some(b), Some(f)) if distance(f.1, key) < distance(key, b.1) => Some(f),
+                    (Some(b), _) => Some(b),
+                    (None, f) => f,
+                },
+            };
+            matched.map(|(idx, _)| idx as u64)
+        })
+        .collect())
+}
+
+impl Table {
+    /// Joins each left row to the nearest right row by `left_on` / `right_on` in the given
+    /// `direction`, keeping every left row and filling the right columns with nulls where there is
+    /// no match within `tolerance`.
+    ///
+    /// Both tables must already be sorted ascending by their join key.
+    pub fn asof_join(
+        &self,
+        right: &Self,
+        left_on: &ExprRef,
+        right_on: &ExprRef,
+        direction: AsofDirection,
+        tolerance: Option<f64>,
+    ) -> DaftResult<Self> {
+        if let Some(tolerance) = tolerance
+            && (tolerance.is_nan() || tolerance < 0.0)
+        {
+            return Err(DaftError::ValueError(format!(
+                "Asof join tolerance must be non-negative, got: {tolerance}"
+            )));
+        }
+        let left_on = std::slice::from_ref(left_on);
+        let right_on = std::slice::from_ref(right_on);
+        let common_join_keys = get_common_join_keys(left_on, right_on).collect::<Vec<_>>();
+        if let Some(name) = right.schema.names().into_iter().find(|name| {
+            self.schema.fields.contains_key(name)
+                && !common_join_keys.iter().any(|k| k.as_ref() == name)
+        }) {
+            return Err(DaftError::ValueError(format!(
+                "Asof join inputs must have distinct non-key column names, but both sides have column: {name}"
+            )));
+        }
+        let join_schema = infer_join_schema(
+            &self.schema,
+            &right.schema,
+            left_on,
+            right_on,
+            JoinType::AsOf,
+        )?;
+
+        let lkey = self
+            .eval_expression_list(left_on)?
+            .get_column_by_index(0)?
+            .clone();
+        let rkey = right
+            .eval_expression_list(right_on)?
+            .get_column_by_index(0)?
+            .clone();
+        let key_type = try_get_supertype(lkey.data_type(), rkey.data_type())?;
+
+        let ridx = match (
+            AsofKeys::new(&lkey, &key_type)?,
+            AsofKeys::new(&rkey, &key_type)?,
+        ) {
+            (AsofKeys::Int(l), AsofKeys::Int(r)) => {
+                asof_indices(&l, &r, direction, tolerance, |a, b| {
+                    (i128::from(a) - i128::from(b)) as f64
+                })?
+            }
+            (AsofKeys::Float(l), AsofKeys::Float(r)) => {
+                asof_indices(&l, &r, direction, tolerance, |a, b| a - b)?
+            }
+            _ => unreachable!("Asof join keys are cast to the same type"),
+        };
+
+        let num_rows = self.len();
+        let lidx = UInt64Array::from(("left_indices", (0..num_rows as u64).collect::<Vec<_>>()))
+            .into_series();
+        let ridx = UInt64Array::from_iter(
+            Field::new("right_indices", DataType::UInt64),
+            ridx.into_iter(),
+        )
+        .into_series();
+
+        // Every left row is kept, so shared key columns take their values from the left side.
+        let join_series = common_join_keys
+            .iter()
+            .map(|name| self.get_column(name).cloned())
+            .collect::<DaftResult<Vec<_>>>()?;
+        let join_series = add_non_join_key_columns(self, right, lidx, ridx, join_series)?;
+        Self::new_with_size(join_schema, join_series, num_rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use crate::Table;
+
+    fn trades() -> DaftResult<Table> {
+        Table::from_nonempty_columns(vec![
+            Int64Array::from(("time", vec![1, 5, 10, 12])).into_series(),
+            Utf8Array::from(("trade", ["a", "b", "c", "d"].as_slice())).into_series(),
+        ])
+    }
+
+    fn quotes() -> DaftResult<Table> {
+        Table::from_nonempty_columns(vec![
+            Int64Array::from(("time", vec![2, 5, 9])).into_series(),
+            Float64Array::from(("quote", vec![1.0, 2.0, 3.0])).into_series(),
+        ])
+    }
+
+    fn quote_column(table: &Table) -> DaftResult<Vec<Option<f64>>> {
+        Ok(table
+            .get_column("quote")?
+            .f64()?
+            .into_iter()
+            .map(|v| v.copied())
+            .collect())
+    }
+
+    #[test]
+    fn test_asof_join_backward() -> DaftResult<()> {
+        let result = trades()?.asof_join(
+            &quotes()?,
+            &col("time"),
+            &col("time"),
+            AsofDirection::Backward,
+            None,
+        )?;
+        assert_eq!(result.schema.names(), vec!["time", "trade", "quote"]);
+        assert_eq!(
+            result.get_column("time")?.i64()?.as_slice(),
+            &[1, 5, 10, 12]
+        );
+        assert_eq!(
+            quote_column(&result)?,
+            vec![None, Some(2.0), Some(3.0), Some(3.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_asof_join_backward_with_tolerance() -> DaftResult<()> {
+        let result = trades()?.asof_join(
+            &quotes()?,
+            &col("time"),
+            &col("time"),
+            AsofDirection::Backward,
+            Some(1.0),
+        )?;
+        assert_eq!(
+            quote_column(&result)?,
+            vec![None, Some(2.0), Some(3.0), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_asof_join_forward_and_nearest() -> DaftResult<()> {
+        let forward = trades()?.asof_join(
+            &quotes()?,
+            &col("time"),
+            &col("time"),
+            AsofDirection::Forward,
+            None,
+        )?;
+        assert_eq!(
+            quote_column(&forward)?,
+            vec![Some(1.0), Some(2.0), None, None]
+        );
+
+        let nearest = trades()?.asof_join(
+            &quotes()?,
+            &col("time"),
+            &col("time"),
+            AsofDirection::Nearest,
+            None,
+        )?;
+        assert_eq!(
+            quote_column(&nearest)?,
+            vec![Some(1.0), Some(2.0), Some(3.0), Some(3.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_asof_join_skips_null_keys_and_rejects_unsorted() -> DaftResult<()> {
+        let left = Table::from_nonempty_columns(vec![Int64Array::from_iter(
+            Field::new("time", DataType::Int64),
+            vec![Some(3), None, Some(6)].into_iter(),
+        )
+        .into_series()])?;
+        let right = Table::from_nonempty_columns(vec![
+            Int64Array::from_iter(
+                Field::new("time", DataType::Int64),
+                vec![Some(1), None, Some(4)].into_iter(),
+            )
+            .into_series(),
+            Float64Array::from(("quote", vec![1.0, 2.0, 3.0])).into_series(),
+        ])?;
+        let result = left.asof_join(
+            &right,
+            &col("time"),
+            &col("time"),
+            AsofDirection::Backward,
+            None,
+        )?;
+        assert_eq!(quote_column(&result)?, vec![Some(1.0), None, Some(3.0)]);
+
+        let unsorted = Table::from_nonempty_columns(vec![
+            Int64Array::from(("time", vec![5, 2])).into_series(),
+            Float64Array::from(("quote", vec![1.0, 2.0])).into_series(),
+        ])?;
+        assert!(left
+            .asof_join(
+                &unsorted,
+                &col("time"),
+                &col("time"),
+                AsofDirection::Backward,
+                None
+            )
+            .is_err());
+        Ok(())
+    }
+}
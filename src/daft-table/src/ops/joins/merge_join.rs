@@ -41,7 +41,11 @@ enum MergeJoinState {
     StagedRightEqualRun(usize),
 }
 
-pub fn merge_inner_join(left: &Table, right: &Table) -> DaftResult<(Series, Series)> {
+pub fn merge_inner_join(
+    left: &Table,
+    right: &Table,
+    descending: &[bool],
+) -> DaftResult<(Series, Series)> {
     if left.num_columns() != right.num_columns() {
         return Err(DaftError::ValueError(format!(
             "Mismatch of join on clauses: left: {:?} vs right: {:?}",
@@ -49,6 +53,13 @@ pub fn merge_inner_join(left: &Table, right: &Table) -> DaftResult<(Series, Seri
             right.num_columns()
         )));
     }
+    if left.num_columns() != descending.len() {
+        return Err(DaftError::ValueError(format!(
+            "Mismatch of join on clauses and descending: {} vs {}",
+            left.num_columns(),
+            descending.len()
+        )));
+    }
     if left.num_columns() == 0 {
         return Err(DaftError::ValueError(
             "No columns were passed in to join on".to_string(),
@@ -80,11 +91,16 @@ pub fn merge_inner_join(left: &Table, right: &Table) -> DaftResult<(Series, Seri
 
     // Construct comparator over all join keys.
     let mut cmp_list = Vec::with_capacity(left.num_columns());
-    for (left_series, right_series) in left.columns.iter().zip(right.columns.iter()) {
+    for ((left_series, right_series), desc) in left
+        .columns
+        .iter()
+        .zip(right.columns.iter())
+        .zip(descending.iter())
+    {
         cmp_list.push(build_partial_compare_with_nulls(
             left_series.to_arrow().as_ref(),
             right_series.to_arrow().as_ref(),
-            false,
+            *desc,
         )?);
     }
     let combined_comparator = |a_idx: usize, b_idx: usize| -> Option<Ordering> {
@@ -10,6 +10,7 @@ use hash_join::hash_semi_anti_join;
 
 use self::hash_join::{hash_inner_join, hash_left_right_join, hash_outer_join};
 use crate::Table;
+mod asof_join;
 mod hash_join;
 mod merge_join;
 
@@ -70,6 +71,55 @@ fn add_non_join_key_columns(
 }
 
 impl Table {
+    /// Default upper bound on the number of rows [`Table::cross_join`] may produce.
+    pub const DEFAULT_CROSS_JOIN_MAX_ROWS: usize = 100_000_000;
+
+    /// Cartesian product of `self` and `right`: every left row is repeated once per right row, and
+    /// the right table is tiled once per left row.
+    ///
+    /// Errors if the product would exceed [`Table::DEFAULT_CROSS_JOIN_MAX_ROWS`] rows; use
+    /// [`Table::cross_join_with_max_rows`] to choose a different cap.
+    pub fn cross_join(&self, right: &Self) -> DaftResult<Self> {
+        self.cross_join_with_max_rows(right, Self::DEFAULT_CROSS_JOIN_MAX_ROWS)
+    }
+
+    pub fn cross_join_with_max_rows(&self, right: &Self, max_rows: usize) -> DaftResult<Self> {
+        let (left_len, right_len) = (self.len(), right.len());
+        let num_rows = left_len
+            .checked_mul(right_len)
+            .filter(|num_rows| *num_rows <= max_rows)
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Cross join of {left_len} rows with {right_len} rows exceeds the maximum of {max_rows} output rows"
+                ))
+            })?;
+
+        if let Some(name) = right
+            .schema
+            .names()
+            .into_iter()
+            .find(|name| self.schema.fields.contains_key(name))
+        {
+            return Err(DaftError::ValueError(format!(
+                "Cross join inputs must have distinct column names, but both sides have column: {name}"
+            )));
+        }
+        let join_schema =
+            infer_join_schema(&self.schema, &right.schema, &[], &[], JoinType::Cross)?;
+
+        let left_idx = (0..left_len as u64)
+            .flat_map(|i| std::iter::repeat(i).take(right_len))
+            .collect::<Vec<_>>();
+        let right_idx = (0..left_len)
+            .flat_map(|_| 0..right_len as u64)
+            .collect::<Vec<_>>();
+        let lidx = UInt64Array::from(("left_indices", left_idx)).into_series();
+        let ridx = UInt64Array::from(("right_indices", right_idx)).into_series();
+
+        let join_series = add_non_join_key_columns(self, right, lidx, ridx, vec![])?;
+        Self::new_with_size(join_schema, join_series, num_rows)
+    }
+
     pub fn hash_join(
         &self,
         right: &Self,
@@ -107,14 +157,32 @@ impl Table {
             JoinType::Anti => {
                 hash_semi_anti_join(self, right, left_on, right_on, null_equals_nulls, true)
             }
+            JoinType::RightSemi => {
+                hash_semi_anti_join(right, self, right_on, left_on, null_equals_nulls, false)
+            }
+            JoinType::RightAnti => {
+                hash_semi_anti_join(right, self, right_on, left_on, null_equals_nulls, true)
+            }
+            JoinType::Cross => Err(DaftError::ValueError(
+                "Cross joins do not take join keys; use Table::cross_join instead".to_string(),
+            )),
+            JoinType::AsOf => Err(DaftError::ValueError(
+                "Asof joins are not hash joins; use Table::asof_join instead".to_string(),
+            )),
         }
     }
 
+    /// Inner joins `self` and `right` by merging their join keys, where each join key column is
+    /// sorted in the order given by the matching entry in `descending` (with nulls first when
+    /// descending, and last when ascending).
+    ///
+    /// If `is_sorted` is false, both sides are sorted in that order before merging.
     pub fn sort_merge_join(
         &self,
         right: &Self,
         left_on: &[ExprRef],
         right_on: &[ExprRef],
+        descending: &[bool],
         is_sorted: bool,
     ) -> DaftResult<Self> {
         // sort first and then call join recursively
@@ -124,35 +192,15 @@ impl Table {
                     "No columns were passed in to join on".to_string(),
                 ));
             }
-            let left = self.sort(
-                left_on,
-                std::iter::repeat(false)
-                    .take(left_on.len())
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-                std::iter::repeat(false)
-                    .take(left_on.len())
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-            )?;
+            let left = self.sort(left_on, descending, descending)?;
             if right_on.is_empty() {
                 return Err(DaftError::ValueError(
                     "No columns were passed in to join on".to_string(),
                 ));
             }
-            let right = right.sort(
-                right_on,
-                std::iter::repeat(false)
-                    .take(right_on.len())
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-                std::iter::repeat(false)
-                    .take(right_on.len())
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-            )?;
-
-            return left.sort_merge_join(&right, left_on, right_on, true);
+            let right = right.sort(right_on, descending, descending)?;
+
+            return left.sort_merge_join(&right, left_on, right_on, descending, true);
         }
 
         let join_schema = infer_join_schema(
@@ -166,7 +214,7 @@ impl Table {
         let rtable = right.eval_expression_list(right_on)?;
 
         let (ltable, rtable) = match_types_for_tables(&ltable, &rtable)?;
-        let (lidx, ridx) = merge_join::merge_inner_join(&ltable, &rtable)?;
+        let (lidx, ridx) = merge_join::merge_inner_join(&ltable, &rtable, descending)?;
 
         let mut join_series = get_common_join_keys(left_on, right_on)
             .map(|name| {
@@ -197,3 +245,106 @@ impl Table {
         Self::new_with_size(join_schema, join_series, num_rows)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use crate::Table;
+
+    fn table(columns: Vec<Series>) -> DaftResult<Table> {
+        Table::from_nonempty_columns(columns)
+    }
+
+    #[test]
+    fn test_cross_join_is_cartesian_product() -> DaftResult<()> {
+        let left = table(vec![
+            Int64Array::from(("a", vec![1, 2, 3])).into_series(),
+            Utf8Array::from(("b", ["x", "y", "z"].as_slice())).into_series(),
+        ])?;
+        let right = table(vec![Float64Array::from(("c", vec![0.5, 1.5])).into_series()])?;
+
+        let result = left.cross_join(&right)?;
+        assert_eq!(result.len(), 6);
+        assert_eq!(result.schema.names(), vec!["a", "b", "c"]);
+        assert_eq!(
+            result.get_column("a")?.i64()?.as_slice(),
+            &[1, 1, 2, 2, 3, 3]
+        );
+        assert_eq!(
+            result
+                .get_column("b")?
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![
+                Some("x"),
+                Some("x"),
+                Some("y"),
+                Some("y"),
+                Some("z"),
+                Some("z")
+            ]
+        );
+        assert_eq!(
+            result.get_column("c")?.f64()?.as_slice(),
+            &[0.5, 1.5, 0.5, 1.5, 0.5, 1.5]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_join_with_empty_side() -> DaftResult<()> {
+        let left = table(vec![Int64Array::from(("a", vec![1, 2])).into_series()])?;
+        let right = Table::empty(Some(
+            Schema::new(vec![Field::new("c", DataType::Utf8)])?.into(),
+        ))?;
+        let result = left.cross_join(&right)?;
+        assert_eq!(result.len(), 0);
+        assert_eq!(result.schema.names(), vec!["a", "c"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_join_enforces_row_cap() -> DaftResult<()> {
+        let left = table(vec![Int64Array::from(("a", vec![1, 2, 3])).into_series()])?;
+        let right = table(vec![Int64Array::from(("c", vec![1, 2])).into_series()])?;
+        assert_eq!(left.cross_join_with_max_rows(&right, 6)?.len(), 6);
+        assert!(left.cross_join_with_max_rows(&right, 5).is_err());
+        assert!(left.cross_join(&left).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_merge_join_descending() -> DaftResult<()> {
+        let left = table(vec![
+            Int64Array::from(("k", vec![9, 7, 7, 4, 1])).into_series(),
+            Utf8Array::from(("l", ["a", "b", "c", "d", "e"].as_slice())).into_series(),
+        ])?;
+        let right = table(vec![
+            Int64Array::from(("k", vec![8, 7, 4, 4, 0])).into_series(),
+            Utf8Array::from(("r", ["v", "w", "x", "y", "z"].as_slice())).into_series(),
+        ])?;
+        let on = [col("k")];
+
+        let joined = left.sort_merge_join(&right, &on, &on, &[true], true)?;
+        assert_eq!(joined.get_column("k")?.i64()?.as_slice(), &[7, 7, 4, 4]);
+        let pairs = joined
+            .get_column("l")?
+            .utf8()?
+            .as_arrow()
+            .values_iter()
+            .zip(joined.get_column("r")?.utf8()?.as_arrow().values_iter())
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, vec![("b", "w"), ("c", "w"), ("d", "x"), ("d", "y")]);
+
+        // Presorting in descending order finds the same matches from unsorted inputs.
+        let shuffled = left.take(&UInt64Array::from(("idx", vec![3, 0, 4, 2, 1])).into_series())?;
+        let presorted = shuffled.sort_merge_join(&right, &on, &on, &[true], false)?;
+        assert_eq!(presorted.get_column("k")?.i64()?.as_slice(), &[7, 7, 4, 4]);
+        Ok(())
+    }
+}
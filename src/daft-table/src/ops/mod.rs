@@ -1,4 +1,5 @@
 mod agg;
+mod distinct;
 mod explode;
 mod groups;
 pub mod hash;
@@ -50,10 +50,14 @@ impl Table {
             .collect::<DaftResult<Vec<_>>>()
     }
 
+    /// Scatters rows into `num_partitions` tables by the hash of `exprs` modulo `num_partitions`.
+    ///
+    /// The split is deterministic for a given `seed`, and empty partitions keep the schema.
     pub fn partition_by_hash(
         &self,
         exprs: &[ExprRef],
         num_partitions: usize,
+        seed: Option<u64>,
     ) -> DaftResult<Vec<Self>> {
         if num_partitions == 0 {
             return Err(DaftError::ValueError(
@@ -61,13 +65,15 @@ impl Table {
             ));
         }
 
-        let targets = self
-            .eval_expression_list(exprs)?
-            .hash_rows()?
-            .rem(&UInt64Array::from((
-                "num_partitions",
-                [num_partitions as u64].as_slice(),
-            )))?;
+        let keys = self.eval_expression_list(exprs)?;
+        let hashes = match seed {
+            Some(seed) => keys.hash_rows_with_seed(seed)?,
+            None => keys.hash_rows()?,
+        };
+        let targets = hashes.rem(&UInt64Array::from((
+            "num_partitions",
+            [num_partitions as u64].as_slice(),
+        )))?;
         self.partition_by_index(&targets, num_partitions)
     }
 
@@ -87,6 +93,29 @@ impl Table {
         self.partition_by_index(&targets, num_partitions)
     }
 
+    /// Deals rows out cyclically so partition sizes differ by at most one.
+    ///
+    /// Row `i` goes to partition `(start + i) % num_partitions`, which lets successive tables
+    /// continue the rotation where the previous one left off.
+    pub fn partition_by_round_robin(
+        &self,
+        num_partitions: usize,
+        start: usize,
+    ) -> DaftResult<Vec<Self>> {
+        if num_partitions == 0 {
+            return Err(DaftError::ValueError(
+                "Can not partition a Table by 0 partitions".to_string(),
+            ));
+        }
+        let start = start % num_partitions;
+        let values = (0..self.len())
+            .map(|i| ((start + i) % num_partitions) as u64)
+            .collect::<Vec<_>>();
+        let targets = UInt64Array::from(("idx", values));
+
+        self.partition_by_index(&targets, num_partitions)
+    }
+
     pub fn partition_by_range(
         &self,
         partition_keys: &[ExprRef],
@@ -126,3 +126,65 @@ impl Table {
         Self::from_nonempty_columns([&group_keys_table.columns[..], &pivoted_cols[..]].concat())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use crate::Table;
+
+    fn long_table() -> DaftResult<Table> {
+        Table::from_nonempty_columns(vec![
+            Utf8Array::from(("group", ["A", "A", "B"].as_slice())).into_series(),
+            Int64Array::from(("pivot", vec![1, 2, 1])).into_series(),
+            Int64Array::from(("value", vec![10, 20, 30])).into_series(),
+        ])
+    }
+
+    fn column_values(table: &Table, name: &str) -> DaftResult<Vec<Option<i64>>> {
+        Ok(table
+            .get_column(name)?
+            .i64()?
+            .into_iter()
+            .map(|v| v.copied())
+            .collect())
+    }
+
+    #[test]
+    fn test_pivot_long_to_wide_fills_missing_with_null() -> DaftResult<()> {
+        let pivoted = long_table()?
+            .pivot(
+                &[col("group")],
+                col("pivot"),
+                col("value"),
+                vec!["1".to_string(), "2".to_string()],
+            )?
+            .sort(&[col("group")], &[false], &[false])?;
+
+        assert_eq!(pivoted.column_names(), vec!["group", "1", "2"]);
+        assert_eq!(column_values(&pivoted, "1")?, vec![Some(10), Some(30)]);
+        // There is no (B, 2) row, so that cell is null.
+        assert_eq!(column_values(&pivoted, "2")?, vec![Some(20), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pivot_names_select_and_add_columns() -> DaftResult<()> {
+        let pivoted = long_table()?
+            .pivot(
+                &[col("group")],
+                col("pivot"),
+                col("value"),
+                vec!["1".to_string(), "3".to_string()],
+            )?
+            .sort(&[col("group")], &[false], &[false])?;
+
+        // Pivot values missing from `names` are dropped, names missing from the data are all null.
+        assert_eq!(pivoted.column_names(), vec!["group", "1", "3"]);
+        assert_eq!(column_values(&pivoted, "1")?, vec![Some(10), Some(30)]);
+        assert_eq!(column_values(&pivoted, "3")?, vec![None, None]);
+        Ok(())
+    }
+}
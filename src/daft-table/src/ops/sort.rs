@@ -1,5 +1,9 @@
 use common_error::{DaftError, DaftResult};
-use daft_core::series::Series;
+use daft_core::{
+    array::ops::{build_multi_array_bicompare, top_n_multiple},
+    datatypes::UInt64Array,
+    series::{IntoSeries, Series},
+};
 use daft_dsl::ExprRef;
 
 use crate::Table;
@@ -15,6 +19,23 @@ impl Table {
         self.take(&argsort)
     }
 
+    /// Returns the first `n` rows of [`Table::sort`] without fully sorting the table.
+    pub fn top_n(
+        &self,
+        sort_keys: &[ExprRef],
+        descending: &[bool],
+        nulls_first: &[bool],
+        n: usize,
+    ) -> DaftResult<Self> {
+        if n >= self.len() {
+            return self.sort(sort_keys, descending, nulls_first);
+        }
+        let keys = self.eval_expression_list(sort_keys)?;
+        let keys = keys.columns.iter().collect::<Vec<_>>();
+        let indices = top_n_multiple(&keys, descending, nulls_first, n)?;
+        self.take(&indices.into_series())
+    }
+
     pub fn argsort(
         &self,
         sort_keys: &[ExprRef],
@@ -36,4 +57,135 @@ impl Table {
             Series::argsort_multikey(expr_result.columns.as_slice(), descending, nulls_first)
         }
     }
+
+    /// Merges two tables that are each already sorted by `sort_keys` into a single sorted table.
+    ///
+    /// The merge is stable: rows that compare equal keep their relative order, with rows from
+    /// `left` placed before rows from `right`.
+    pub fn merge_sorted(
+        left: &Self,
+        right: &Self,
+        sort_keys: &[ExprRef],
+        descending: &[bool],
+    ) -> DaftResult<Self> {
+        if left.schema != right.schema {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Schema Mismatch in merge_sorted: left: {} vs right: {}",
+                left.schema, right.schema
+            )));
+        }
+        if sort_keys.len() != descending.len() {
+            return Err(DaftError::ValueError(format!(
+                "sort_keys and descending length must match, got {} vs {}",
+                sort_keys.len(),
+                descending.len()
+            )));
+        }
+        if left.is_empty() || right.is_empty() {
+            return Self::concat(&[left, right]);
+        }
+
+        let lkeys = left.eval_expression_list(sort_keys)?;
+        let rkeys = right.eval_expression_list(sort_keys)?;
+        let comparator = build_multi_array_bicompare(&lkeys.columns, &rkeys.columns, descending)?;
+
+        // Indices into the concatenation of `left` and `right`.
+        let offset = left.len();
+        let mut indices = Vec::with_capacity(left.len() + right.len());
+        let (mut l, mut r) = (0, 0);
+        while l < left.len() && r < right.len() {
+            if comparator(l, r).is_gt() {
+                indices.push((offset + r) as u64);
+                r += 1;
+            } else {
+                indices.push(l as u64);
+                l += 1;
+            }
+        }
+        indices.extend((l..left.len()).map(|i| i as u64));
+        indices.extend((offset + r..offset + right.len()).map(|i| i as u64));
+
+        let indices = UInt64Array::from(("indices", indices)).into_series();
+        Self::concat(&[left, right])?.take(&indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use crate::Table;
+
+    #[test]
+    fn test_top_n_matches_sort_then_head() -> DaftResult<()> {
+        let table = Table::from_nonempty_columns(vec![
+            Int64Array::from_iter(
+                Field::new("a", DataType::Int64),
+                vec![Some(2), None, Some(1), Some(2), Some(3), Some(1)].into_iter(),
+            )
+            .into_series(),
+            Utf8Array::from(("b", ["u", "v", "w", "x", "y", "z"].as_slice())).into_series(),
+        ])?;
+        let sort_keys = [col("a"), col("b")];
+        let descending = [true, false];
+        let nulls_first = [false, false];
+
+        let sorted = table.sort(&sort_keys, &descending, &nulls_first)?;
+        for n in [0, 1, 3, table.len(), table.len() + 2] {
+            let top = table.top_n(&sort_keys, &descending, &nulls_first, n)?;
+            assert_eq!(top, sorted.head(n)?, "n = {n}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_sorted_interleaves_and_is_stable() -> DaftResult<()> {
+        let left = Table::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![1, 3, 3, 7])).into_series(),
+            Utf8Array::from(("side", ["l0", "l1", "l2", "l3"].as_slice())).into_series(),
+        ])?;
+        let right = Table::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![0, 3, 4, 8, 9])).into_series(),
+            Utf8Array::from(("side", ["r0", "r1", "r2", "r3", "r4"].as_slice())).into_series(),
+        ])?;
+
+        let merged = Table::merge_sorted(&left, &right, &[col("a")], &[false])?;
+        assert_eq!(
+            merged.get_column("a")?.i64()?.as_slice(),
+            &[0, 1, 3, 3, 3, 4, 7, 8, 9]
+        );
+        let sides = merged
+            .get_column("side")?
+            .utf8()?
+            .as_arrow()
+            .values_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sides,
+            vec!["r0", "l0", "l1", "l2", "r1", "r2", "l3", "r3", "r4"]
+        );
+
+        // Descending inputs merge into descending output.
+        let left = left.sort(&[col("a")], &[true], &[false])?;
+        let right = right.sort(&[col("a")], &[true], &[false])?;
+        let merged = Table::merge_sorted(&left, &right, &[col("a")], &[true])?;
+        assert_eq!(
+            merged.get_column("a")?.i64()?.as_slice(),
+            &[9, 8, 7, 4, 3, 3, 3, 1, 0]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_sorted_with_empty_side() -> DaftResult<()> {
+        let table =
+            Table::from_nonempty_columns(vec![Int64Array::from(("a", vec![1, 2])).into_series()])?;
+        let empty = Table::empty(Some(table.schema.clone()))?;
+        let merged = Table::merge_sorted(&empty, &table, &[col("a")], &[false])?;
+        assert_eq!(merged, table);
+        assert!(Table::merge_sorted(&table, &table, &[col("a")], &[]).is_err());
+        Ok(())
+    }
 }
@@ -57,3 +57,69 @@ impl Table {
         Self::new_with_size(unpivot_schema, unpivot_series, unpivoted_len)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use crate::Table;
+
+    #[test]
+    fn test_unpivot_wide_to_long() -> DaftResult<()> {
+        let table = Table::from_nonempty_columns(vec![
+            Utf8Array::from(("id", ["x", "y"].as_slice())).into_series(),
+            Int32Array::from(("a", vec![1, 2])).into_series(),
+            Int64Array::from(("b", vec![3, 4])).into_series(),
+            Float64Array::from(("c", vec![5.5, 6.5])).into_series(),
+        ])?;
+
+        let unpivoted = table.unpivot(
+            &[col("id")],
+            &[col("a"), col("b"), col("c")],
+            "variable",
+            "value",
+        )?;
+
+        assert_eq!(unpivoted.len(), table.len() * 3);
+        assert_eq!(unpivoted.column_names(), vec!["id", "variable", "value"]);
+        assert_eq!(
+            unpivoted
+                .get_column("id")?
+                .utf8()?
+                .as_arrow()
+                .values_iter()
+                .collect::<Vec<_>>(),
+            vec!["x", "y", "x", "y", "x", "y"]
+        );
+        assert_eq!(
+            unpivoted
+                .get_column("variable")?
+                .utf8()?
+                .as_arrow()
+                .values_iter()
+                .collect::<Vec<_>>(),
+            vec!["a", "a", "b", "b", "c", "c"]
+        );
+        // The value column takes the supertype of all value columns.
+        let value = unpivoted.get_column("value")?;
+        assert_eq!(value.data_type(), &DataType::Float64);
+        assert_eq!(value.f64()?.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.5, 6.5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpivot_errors_without_common_supertype() -> DaftResult<()> {
+        let table = Table::from_nonempty_columns(vec![
+            Int64Array::from(("id", vec![1])).into_series(),
+            Utf8Array::from(("a", ["x"].as_slice())).into_series(),
+            BinaryArray::from(("b", b"y".as_slice())).into_series(),
+        ])?;
+
+        assert!(table
+            .unpivot(&[col("id")], &[col("a"), col("b")], "variable", "value")
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+/// A blocked bloom filter over row hashes.
+///
+/// Each hash selects a single 512 bit block and sets `NUM_PROBES` bits within it, so that both
+/// inserts and lookups touch a single cache line.
+pub(super) struct BlockedBloomFilter {
+    blocks: Vec<[u64; Self::WORDS_PER_BLOCK]>,
+}
+
+impl BlockedBloomFilter {
+    const WORDS_PER_BLOCK: usize = 8;
+    const BITS_PER_BLOCK: usize = Self::WORDS_PER_BLOCK * 64;
+    const BITS_PER_KEY: usize = 10;
+    const NUM_PROBES: u32 = 6;
+
+    pub(super) fn with_capacity(expected_num_keys: usize) -> Self {
+        let num_blocks = (expected_num_keys * Self::BITS_PER_KEY)
+            .div_ceil(Self::BITS_PER_BLOCK)
+            .max(1);
+        Self {
+            blocks: vec![[0; Self::WORDS_PER_BLOCK]; num_blocks],
+        }
+    }
+
+    #[inline]
+    fn block_idx(&self, hash: u64) -> usize {
+        ((u128::from(hash) * self.blocks.len() as u128) >> 64) as usize
+    }
+
+    /// Yields the (word, bit) positions within a block for `hash`, using 9 bit chunks of a remixed hash.
+    #[inline]
+    fn bit_positions(hash: u64) -> impl Iterator<Item = (usize, u64)> {
+        let mixed = hash.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        (0..Self::NUM_PROBES).map(move |i| {
+            let bit = ((mixed >> (i * 9)) & 0x1FF) as usize;
+            (bit / 64, 1 << (bit % 64))
+        })
+    }
+
+    #[inline]
+    pub(super) fn insert(&mut self, hash: u64) {
+        let block_idx = self.block_idx(hash);
+        let block = &mut self.blocks[block_idx];
+        for (word, mask) in Self::bit_positions(hash) {
+            block[word] |= mask;
+        }
+    }
+
+    #[inline]
+    pub(super) fn might_contain(&self, hash: u64) -> bool {
+        let block = &self.blocks[self.block_idx(hash)];
+        Self::bit_positions(hash).all(|(word, mask)| block[word] & mask != 0)
+    }
+
+    pub(super) fn size_bytes(&self) -> usize {
+        self.blocks.len() * std::mem::size_of::<[u64; Self::WORDS_PER_BLOCK]>()
+    }
+}
@@ -1,17 +1,29 @@
+mod bloom_filter;
+mod partitioned_probe_table;
 mod probe_set;
 mod probe_table;
 
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 use common_error::DaftResult;
 use daft_core::prelude::SchemaRef;
 use probe_set::{ProbeSet, ProbeSetBuilder};
-use probe_table::{ProbeTable, ProbeTableBuilder};
+use probe_table::ProbeTable;
+pub use probe_table::ProbeTableBuilder;
 
 use crate::Table;
 
 struct ArrowTableEntry(Vec<Box<dyn arrow2::array::Array>>);
 
+impl ArrowTableEntry {
+    fn size_bytes(&self) -> usize {
+        self.0
+            .iter()
+            .map(|array| arrow2::compute::aggregate::estimated_bytes_size(array.as_ref()))
+            .sum()
+    }
+}
+
 pub fn make_probeable_builder(
     schema: SchemaRef,
     nulls_equal_aware: Option<&Vec<bool>>,
@@ -38,7 +50,7 @@ pub trait ProbeableBuilder: Send + Sync {
 pub struct IndicesMapper<'a> {
     table_idx_shift: usize,
     lower_mask: u64,
-    idx_iter: Box<dyn Iterator<Item = Option<&'a [u64]>> + 'a>,
+    idx_iter: Box<dyn Iterator<Item = Option<Cow<'a, [u64]>>> + 'a>,
 }
 
 impl<'a> IndicesMapper<'a> {
@@ -50,7 +62,20 @@ impl<'a> IndicesMapper<'a> {
         Self {
             table_idx_shift,
             lower_mask,
-            idx_iter,
+            idx_iter: Box::new(idx_iter.map(|indices| indices.map(Cow::Borrowed))),
+        }
+    }
+
+    /// Creates an [`IndicesMapper`] over indices that were materialized during the probe rather than borrowed from the probe table.
+    pub fn from_owned(
+        indices: Vec<Option<Vec<u64>>>,
+        table_idx_shift: usize,
+        lower_mask: u64,
+    ) -> Self {
+        Self {
+            table_idx_shift,
+            lower_mask,
+            idx_iter: Box::new(indices.into_iter().map(|indices| indices.map(Cow::Owned))),
         }
     }
 
@@ -59,7 +84,8 @@ impl<'a> IndicesMapper<'a> {
         let lower_mask = self.lower_mask;
         self.idx_iter.map(move |indices| match indices {
             Some(indices) => {
-                let inner_iter = indices.iter().map(move |idx| {
+                let inner_iter = (0..indices.len()).map(move |i| {
+                    let idx = indices[i];
                     let table_idx = (idx >> table_idx_shift) as u32;
                     let row_idx = idx & lower_mask;
                     (table_idx, row_idx)
@@ -83,6 +109,26 @@ pub trait Probeable: Send + Sync {
         &'a self,
         table: &'a Table,
     ) -> DaftResult<Box<dyn Iterator<Item = bool> + 'a>>;
+
+    /// Number of distinct keys on the build side, or None if it is not known without a full scan of the build side.
+    fn num_groups(&self) -> Option<usize>;
+
+    /// Number of rows added to the build side.
+    fn num_rows(&self) -> usize;
+
+    /// Estimated number of bytes held in memory for the build side, including the hash table.
+    fn size_bytes(&self) -> usize;
+
+    /// Average number of build rows that share a key, which is a measure of key skew.
+    fn avg_chain_length(&self) -> Option<f64> {
+        self.num_groups().map(|num_groups| {
+            if num_groups == 0 {
+                0.0
+            } else {
+                self.num_rows() as f64 / num_groups as f64
+            }
+        })
+    }
 }
 
 #[derive(Clone)]
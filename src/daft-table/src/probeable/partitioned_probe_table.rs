@@ -0,0 +1,579 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    prelude::{DataType, Field, Schema, SchemaRef, UInt64Array},
+    series::{IntoSeries, Series},
+};
+
+use super::{probe_table::ProbeTable, IndicesMapper, Probeable, ProbeableBuilder};
+use crate::Table;
+
+static NEXT_SPILL_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A single hash partition of the build side. Rows are held in memory until the builder exceeds
+/// its byte threshold, at which point they are written out to `files` as Arrow IPC.
+#[derive(Default)]
+struct SpillPartition {
+    files: Vec<PathBuf>,
+    resident: Vec<(Table, UInt64Array)>,
+}
+
+impl Drop for SpillPartition {
+    fn drop(&mut self) {
+        for file in &self.files {
+            let _ = std::fs::remove_file(file);
+        }
+    }
+}
+
+fn partition_for_hash(hash: u64, num_partitions: usize) -> usize {
+    // Use the upper bits so that rows within a partition still spread across the probe table buckets.
+    ((hash >> 32) as usize) % num_partitions
+}
+
+/// Splits `table` by hash into `num_partitions` tables, returning the source row of every output row.
+fn split_by_hash(table: &Table, num_partitions: usize) -> DaftResult<Vec<(Table, Vec<u64>)>> {
+    let hashes = table.hash_rows()?;
+    let mut partition_rows = vec![vec![]; num_partitions];
+    for (row, hash) in hashes.as_arrow().iter().enumerate() {
+        if let Some(hash) = hash {
+            partition_rows[partition_for_hash(*hash, num_partitions)].push(row as u64);
+        }
+    }
+    partition_rows
+        .into_iter()
+        .map(|rows| {
+            let indices = UInt64Array::from(("idx", rows.clone())).into_series();
+            Ok((table.take(&indices)?, rows))
+        })
+        .collect()
+}
+
+pub struct SpillingProbeTableBuilder {
+    physical_schema: SchemaRef,
+    nulls_equal_aware: Option<Vec<bool>>,
+    store_indices: bool,
+    max_bytes: usize,
+    spill_dir: PathBuf,
+    spill_id: usize,
+    num_spills: usize,
+    num_tables: usize,
+    num_rows: usize,
+    resident_bytes: usize,
+    // Until the first spill, tables are only added to a regular in-memory probe table. Its key tables are
+    // recovered from it if the threshold is crossed, so the build side is never held twice.
+    in_memory: Option<ProbeTable>,
+    partitions: Vec<SpillPartition>,
+}
+
+impl SpillingProbeTableBuilder {
+    // Matches the table index encoding used by `ProbeTable`.
+    const TABLE_IDX_SHIFT: usize = 36;
+    const LOWER_MASK: u64 = (1 << Self::TABLE_IDX_SHIFT) - 1;
+
+    const NUM_PARTITIONS: usize = 16;
+
+    pub(crate) fn new(
+        schema: SchemaRef,
+        nulls_equal_aware: Option<&Vec<bool>>,
+        store_indices: bool,
+        max_bytes: usize,
+        spill_dir: PathBuf,
+    ) -> DaftResult<Self> {
+        std::fs::create_dir_all(&spill_dir)?;
+        let physical_schema = Schema::new(
+            schema
+                .fields
+                .values()
+                .map(|field| Field::new(field.name.as_str(), field.dtype.to_physical()))
+                .collect(),
+        )?;
+        Ok(Self {
+            physical_schema: physical_schema.into(),
+            nulls_equal_aware: nulls_equal_aware.cloned(),
+            store_indices,
+            max_bytes,
+            spill_dir,
+            spill_id: NEXT_SPILL_ID.fetch_add(1, Ordering::Relaxed),
+            num_spills: 0,
+            num_tables: 0,
+            num_rows: 0,
+            resident_bytes: 0,
+            in_memory: Some(ProbeTable::new(schema, nulls_equal_aware)?),
+            partitions: (0..Self::NUM_PARTITIONS)
+                .map(|_| SpillPartition::default())
+                .collect(),
+        })
+    }
+
+    fn partition_table(&mut self, table_idx: usize, table: &Table) -> DaftResult<()> {
+        assert!(table_idx < (1 << (64 - Self::TABLE_IDX_SHIFT)));
+        assert!(table.len() < (1 << Self::TABLE_IDX_SHIFT));
+        let table_offset = (table_idx << Self::TABLE_IDX_SHIFT) as u64;
+        let physical = table.as_physical()?;
+        for (partition, (keys, rows)) in split_by_hash(&physical, Self::NUM_PARTITIONS)?
+            .into_iter()
+            .enumerate()
+        {
+            if rows.is_empty() {
+                continue;
+            }
+            let indices = UInt64Array::from((
+                "idx",
+                rows.into_iter()
+                    .map(|row| table_offset | row)
+                    .collect::<Vec<_>>(),
+            ));
+            self.partitions[partition].resident.push((keys, indices));
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    fn num_spills(&self) -> usize {
+        self.num_spills
+    }
+
+    fn spill(&mut self) -> DaftResult<()> {
+        let mut arrow_schema = self.physical_schema.to_arrow()?;
+        arrow_schema.fields.push(arrow2::datatypes::Field::new(
+            "idx",
+            arrow2::datatypes::DataType::UInt64,
+            false,
+        ));
+        for (partition_idx, partition) in self.partitions.iter_mut().enumerate() {
+            if partition.resident.is_empty() {
+                continue;
+            }
+            let path = self.spill_dir.join(format!(
+                "daft-probe-{}-{}-{partition_idx}-{}.arrow",
+                std::process::id(),
+                self.spill_id,
+                self.num_spills
+            ));
+            // Track the file before writing so that it is cleaned up even if the write fails.
+            partition.files.push(path.clone());
+            let mut writer = arrow2::io::ipc::write::FileWriter::try_new(
+                BufWriter::new(File::create(&path)?),
+                arrow_schema.clone(),
+                None,
+                arrow2::io::ipc::write::WriteOptions { compression: None },
+            )?;
+            for (keys, indices) in partition.resident.drain(..) {
+                let mut arrays = keys.get_inner_arrow_arrays().collect::<Vec<_>>();
+                arrays.push(indices.data().to_boxed());
+                writer.write(&arrow2::chunk::Chunk::try_new(arrays)?, None)?;
+            }
+            writer.finish()?;
+        }
+        self.num_spills += 1;
+        self.resident_bytes = 0;
+        Ok(())
+    }
+}
+
+impl ProbeableBuilder for SpillingProbeTableBuilder {
+    fn add_table(&mut self, table: &Table) -> DaftResult<()> {
+        let table_idx = self.num_tables;
+        self.num_tables += 1;
+        self.num_rows += table.len();
+        self.resident_bytes += table.size_bytes()?;
+
+        if let Some(probe_table) = &mut self.in_memory {
+            probe_table.add_table(table)?;
+            if self.resident_bytes <= self.max_bytes {
+                return Ok(());
+            }
+            // Past the threshold: fall back to the partitioned build for everything seen so far.
+            let tables = self.in_memory.take().unwrap().into_tables()?;
+            for (idx, table) in tables.iter().enumerate() {
+                self.partition_table(idx, table)?;
+            }
+        } else {
+            self.partition_table(table_idx, table)?;
+        }
+
+        if self.resident_bytes > self.max_bytes {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn build(self: Box<Self>) -> Arc<dyn Probeable> {
+        let mut this = *self;
+        if let Some(probe_table) = this.in_memory.take() {
+            return Arc::new(probe_table);
+        }
+        Arc::new(this.into_partitioned())
+    }
+}
+
+impl SpillingProbeTableBuilder {
+    fn into_partitioned(self) -> PartitionedProbeTable {
+        PartitionedProbeTable {
+            physical_schema: self.physical_schema,
+            nulls_equal_aware: self.nulls_equal_aware,
+            store_indices: self.store_indices,
+            num_rows: self.num_rows,
+            max_bytes: self.max_bytes,
+            partitions: self.partitions,
+            loaded: Mutex::new(vec![]),
+        }
+    }
+}
+
+/// The probe table of a single build side partition, along with the build side row of every entry.
+struct LoadedPartition {
+    probe_table: ProbeTable,
+    row_indices: Vec<UInt64Array>,
+}
+
+impl LoadedPartition {
+    fn size_bytes(&self) -> usize {
+        self.probe_table.size_bytes()
+            + self
+                .row_indices
+                .iter()
+                .map(|indices| indices.len() * std::mem::size_of::<u64>())
+                .sum::<usize>()
+    }
+}
+
+/// A probeable whose build side has been hash partitioned, with some partitions spilled to disk.
+/// Probes are routed to partitions by hash, and a partition's probe table is built when a probe row is
+/// first routed to it. Loaded partitions are kept for later probes until they exceed the builder's byte
+/// threshold, at which point the least recently probed ones are dropped and reloaded on demand.
+struct PartitionedProbeTable {
+    physical_schema: SchemaRef,
+    nulls_equal_aware: Option<Vec<bool>>,
+    store_indices: bool,
+    num_rows: usize,
+    max_bytes: usize,
+    partitions: Vec<SpillPartition>,
+    // Loaded partitions by index, least recently probed first.
+    loaded: Mutex<Vec<(usize, Arc<LoadedPartition>)>>,
+}
+
+impl PartitionedProbeTable {
+    fn load_partition(&self, partition: &SpillPartition) -> DaftResult<LoadedPartition> {
+        let mut probe_table = ProbeTable::new(
+            self.physical_schema.clone(),
+            self.nulls_equal_aware.as_ref(),
+        )?;
+        let mut row_indices = vec![];
+        for path in &partition.files {
+            let mut reader = BufReader::new(File::open(path)?);
+            let metadata = arrow2::io::ipc::read::read_file_metadata(&mut reader)?;
+            for chunk in arrow2::io::ipc::read::FileReader::new(reader, metadata, None, None) {
+                let mut arrays = chunk?.into_arrays();
+                let indices = Series::from_arrow(
+                    Field::new("idx", DataType::UInt64).into(),
+                    arrays.pop().unwrap(),
+                )?
+                .u64()?
+                .clone();
+                let columns = self
+                    .physical_schema
+                    .fields
+                    .values()
+                    .zip(arrays)
+                    .map(|(field, array)| Series::from_arrow(field.clone().into(), array))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                let keys =
+                    Table::new_with_size(self.physical_schema.clone(), columns, indices.len())?;
+                probe_table.add_table(&keys)?;
+                row_indices.push(indices);
+            }
+        }
+        for (keys, indices) in &partition.resident {
+            probe_table.add_table(keys)?;
+            row_indices.push(indices.clone());
+        }
+        Ok(LoadedPartition {
+            probe_table,
+            row_indices,
+        })
+    }
+
+    fn get_or_load_partition(&self, partition_idx: usize) -> DaftResult<Arc<LoadedPartition>> {
+        {
+            let mut loaded = self.loaded.lock().unwrap();
+            if let Some(pos) = loaded.iter().position(|(idx, _)| *idx == partition_idx) {
+                let entry = loaded.remove(pos);
+                let partition = entry.1.clone();
+                loaded.push(entry);
+                return Ok(partition);
+            }
+        }
+        // Read without holding the lock so that probes routed to other partitions are not blocked on disk.
+        // Concurrent first probes may both load the partition, in which case only the last copy is kept.
+        let partition = Arc::new(self.load_partition(&self.partitions[partition_idx])?);
+        let mut loaded = self.loaded.lock().unwrap();
+        loaded.retain(|(idx, _)| *idx != partition_idx);
+        loaded.push((partition_idx, partition.clone()));
+        let mut loaded_bytes = loaded
+            .iter()
+            .map(|(_, partition)| partition.size_bytes())
+            .sum::<usize>();
+        // The partition that was just loaded is always kept, even if it alone exceeds the threshold.
+        while loaded_bytes > self.max_bytes && loaded.len() > 1 {
+            let (_, evicted) = loaded.remove(0);
+            loaded_bytes -= evicted.size_bytes();
+        }
+        drop(loaded);
+        Ok(partition)
+    }
+
+    fn probe(&self, input: &Table) -> DaftResult<Vec<Option<Vec<u64>>>> {
+        let input = input.as_physical()?;
+        let mut results = vec![None; input.len()];
+        for (partition_idx, (partition, (input, input_rows))) in self
+            .partitions
+            .iter()
+            .zip(split_by_hash(&input, self.partitions.len())?)
+            .enumerate()
+        {
+            if input_rows.is_empty()
+                || (partition.files.is_empty() && partition.resident.is_empty())
+            {
+                continue;
+            }
+            let loaded = self.get_or_load_partition(partition_idx)?;
+            let LoadedPartition {
+                probe_table,
+                row_indices,
+            } = loaded.as_ref();
+            let idx_mapper = probe_table.probe_indices(&input)?;
+            for (input_row, inner_iter) in input_rows.iter().zip(idx_mapper.make_iter()) {
+                results[*input_row as usize] = inner_iter.map(|inner_iter| {
+                    inner_iter
+                        .map(|(table_idx, row_idx)| {
+                            row_indices[table_idx as usize]
+                                .as_arrow()
+                                .value(row_idx as usize)
+                        })
+                        .collect()
+                });
+            }
+        }
+        Ok(results)
+    }
+}
+
+impl Probeable for PartitionedProbeTable {
+    fn probe_indices<'a>(&'a self, table: &'a Table) -> DaftResult<IndicesMapper<'a>> {
+        if !self.store_indices {
+            return Err(DaftError::UnexpectedProbeTableVariant {
+                expected: "PartitionedProbeTable with indices",
+                got: "PartitionedProbeTable without indices",
+            });
+        }
+        Ok(IndicesMapper::from_owned(
+            self.probe(table)?,
+            SpillingProbeTableBuilder::TABLE_IDX_SHIFT,
+            SpillingProbeTableBuilder::LOWER_MASK,
+        ))
+    }
+
+    fn probe_exists<'a>(
+        &'a self,
+        table: &'a Table,
+    ) -> DaftResult<Box<dyn Iterator<Item = bool> + 'a>> {
+        Ok(Box::new(
+            self.probe(table)?
+                .into_iter()
+                .map(|indices| indices.is_some()),
+        ))
+    }
+
+    fn num_groups(&self) -> Option<usize> {
+        None
+    }
+
+    fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    fn size_bytes(&self) -> usize {
+        let loaded = self.loaded.lock().unwrap();
+        self.partitions
+            .iter()
+            .enumerate()
+            .map(|(partition_idx, partition)| {
+                match loaded.iter().find(|(idx, _)| *idx == partition_idx) {
+                    // A loaded probe table shares its key buffers with the resident tables.
+                    Some((_, loaded)) => loaded.size_bytes(),
+                    None => partition
+                        .resident
+                        .iter()
+                        .map(|(keys, indices)| {
+                            keys.get_inner_arrow_arrays()
+                                .map(|array| {
+                                    arrow2::compute::aggregate::estimated_bytes_size(array.as_ref())
+                                })
+                                .sum::<usize>()
+                                + indices.len() * std::mem::size_of::<u64>()
+                        })
+                        .sum(),
+                }
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+
+    use super::SpillingProbeTableBuilder;
+    use crate::{
+        probeable::{Probeable, ProbeableBuilder},
+        Table,
+    };
+
+    fn keys_table(values: Vec<i64>) -> DaftResult<Table> {
+        Table::from_nonempty_columns(vec![Int64Array::from(("key", values)).into_series()])
+    }
+
+    #[test]
+    fn test_spilling_probe_table_matches_across_spills() -> DaftResult<()> {
+        let spill_dir = tempfile::tempdir()?;
+        let schema = keys_table(vec![])?.schema;
+        let mut builder =
+            SpillingProbeTableBuilder::new(schema, None, true, 16, spill_dir.path().to_path_buf())?;
+        builder.add_table(&keys_table(vec![1, 2, 3])?)?;
+        builder.add_table(&keys_table(vec![3, 4])?)?;
+        builder.add_table(&keys_table(vec![5])?)?;
+        assert!(builder.num_spills() >= 1);
+        assert!(std::fs::read_dir(spill_dir.path())?.next().is_some());
+
+        let probeable = Box::new(builder).build();
+        let probe = keys_table(vec![3, 5, 7, 1])?;
+        let matches = probeable
+            .probe_indices(&probe)?
+            .make_iter()
+            .map(|inner| {
+                inner.map(|inner| {
+                    let mut inner = inner.collect::<Vec<_>>();
+                    inner.sort_unstable();
+                    inner
+                })
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            matches,
+            vec![
+                Some(vec![(0, 2), (1, 0)]),
+                Some(vec![(2, 0)]),
+                None,
+                Some(vec![(0, 0)]),
+            ]
+        );
+
+        let exists = probeable.probe_exists(&probe)?.collect::<Vec<_>>();
+        assert_eq!(exists, vec![true, true, false, true]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_spilling_probe_table_matches_null_keys_when_nulls_equal() -> DaftResult<()> {
+        let spill_dir = tempfile::tempdir()?;
+        let nullable_keys = |values: Vec<Option<i64>>| {
+            Table::from_nonempty_columns(vec![Int64Array::from_iter(
+                Field::new("key", DataType::Int64),
+                values.into_iter(),
+            )
+            .into_series()])
+        };
+        let schema = keys_table(vec![])?.schema;
+        let mut builder = SpillingProbeTableBuilder::new(
+            schema,
+            Some(&vec![true]),
+            true,
+            16,
+            spill_dir.path().to_path_buf(),
+        )?;
+        builder.add_table(&nullable_keys(vec![Some(1), None, Some(2)])?)?;
+        builder.add_table(&nullable_keys(vec![None, Some(3)])?)?;
+        assert!(builder.num_spills() >= 1);
+
+        let probeable = Box::new(builder).build();
+        let exists = probeable
+            .probe_exists(&nullable_keys(vec![None, Some(3), Some(4)])?)?
+            .collect::<Vec<_>>();
+        assert_eq!(exists, vec![true, true, false]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_partitioned_probe_table_bounds_loaded_partitions() -> DaftResult<()> {
+        let spill_dir = tempfile::tempdir()?;
+        let schema = keys_table(vec![])?.schema;
+        let max_bytes = 64;
+        let mut builder = SpillingProbeTableBuilder::new(
+            schema,
+            None,
+            true,
+            max_bytes,
+            spill_dir.path().to_path_buf(),
+        )?;
+        for chunk in (0..256).collect::<Vec<_>>().chunks(16) {
+            builder.add_table(&keys_table(chunk.to_vec())?)?;
+        }
+        assert!(builder.num_spills() >= 1);
+        let probe_table = builder.into_partitioned();
+
+        let mut all_loaded_bytes = 0;
+        let mut max_partition_bytes = 0;
+        for partition in &probe_table.partitions {
+            let partition_bytes = probe_table.load_partition(partition)?.size_bytes();
+            all_loaded_bytes += partition_bytes;
+            max_partition_bytes = max_partition_bytes.max(partition_bytes);
+        }
+
+        // Probe every partition, one probe table at a time.
+        for key in 0..256 {
+            let exists = probe_table
+                .probe_exists(&keys_table(vec![key, -1])?)?
+                .collect::<Vec<_>>();
+            assert_eq!(exists, vec![true, false]);
+            assert!(probe_table.size_bytes() <= max_bytes + max_partition_bytes);
+        }
+        assert!(probe_table.size_bytes() < all_loaded_bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_spilling_probe_table_stays_in_memory_under_threshold() -> DaftResult<()> {
+        let spill_dir = tempfile::tempdir()?;
+        let schema = keys_table(vec![])?.schema;
+        let mut builder = SpillingProbeTableBuilder::new(
+            schema,
+            None,
+            false,
+            1 << 20,
+            spill_dir.path().to_path_buf(),
+        )?;
+        builder.add_table(&keys_table(vec![1, 2, 3])?)?;
+        assert_eq!(builder.num_spills(), 0);
+        assert!(std::fs::read_dir(spill_dir.path())?.next().is_none());
+
+        let probeable = Box::new(builder).build();
+        let exists = probeable
+            .probe_exists(&keys_table(vec![2, 4])?)?
+            .collect::<Vec<_>>();
+        assert_eq!(exists, vec![true, false]);
+        Ok(())
+    }
+}
@@ -166,7 +166,29 @@ impl Probeable for ProbeSet {
     }
 
     fn probe_indices<'a>(&'a self, _table: &'a Table) -> DaftResult<IndicesMapper<'a>> {
-        panic!("Probe indices is not supported for ProbeSet")
+        Err(DaftError::UnexpectedProbeTableVariant {
+            expected: "ProbeTable",
+            got: "ProbeSet",
+        })
+    }
+
+    fn num_groups(&self) -> Option<usize> {
+        Some(self.num_groups)
+    }
+
+    fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    fn size_bytes(&self) -> usize {
+        let tables = self
+            .tables
+            .iter()
+            .map(ArrowTableEntry::size_bytes)
+            .sum::<usize>();
+        let hash_table = self.hash_table.capacity()
+            * (std::mem::size_of::<IndexHash>() + std::mem::size_of::<()>());
+        tables + hash_table
     }
 }
 
@@ -181,3 +203,32 @@ impl ProbeableBuilder for ProbeSetBuilder {
         Arc::new(self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+    use daft_core::prelude::*;
+
+    use super::ProbeSet;
+    use crate::{probeable::Probeable, Table};
+
+    #[test]
+    fn test_probe_indices_returns_typed_error() -> DaftResult<()> {
+        let table =
+            Table::from_nonempty_columns(
+                vec![Int64Array::from(("key", vec![1, 2])).into_series()],
+            )?;
+        let mut probe_set = ProbeSet::new(table.schema.clone(), None)?;
+        probe_set.add_table(&table)?;
+
+        match probe_set.probe_indices(&table) {
+            Err(DaftError::UnexpectedProbeTableVariant { expected, got }) => {
+                assert_eq!(expected, "ProbeTable");
+                assert_eq!(got, "ProbeSet");
+            }
+            Err(e) => panic!("Expected UnexpectedProbeTableVariant, got {e}"),
+            Ok(_) => panic!("Expected probe_indices on a ProbeSet to fail"),
+        }
+        Ok(())
+    }
+}
@@ -1,19 +1,24 @@
 use std::{
     collections::{hash_map::RawEntryMut, HashMap},
+    path::PathBuf,
     sync::Arc,
 };
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{
     array::ops::as_arrow::AsArrow,
-    prelude::SchemaRef,
+    prelude::{Field, Schema, SchemaRef},
+    series::Series,
     utils::{
         dyn_compare::{build_dyn_multi_array_compare, MultiDynArrayComparator},
         identity_hash_set::{IdentityBuildHasher, IndexHash},
     },
 };
 
-use super::{ArrowTableEntry, IndicesMapper, Probeable, ProbeableBuilder};
+use super::{
+    bloom_filter::BlockedBloomFilter, partitioned_probe_table::SpillingProbeTableBuilder,
+    ArrowTableEntry, IndicesMapper, Probeable, ProbeableBuilder,
+};
 use crate::Table;
 
 pub struct ProbeTable {
@@ -23,6 +28,7 @@ pub struct ProbeTable {
     compare_fn: MultiDynArrayComparator,
     num_groups: usize,
     num_rows: usize,
+    bloom_filter: Option<BlockedBloomFilter>,
 }
 
 impl ProbeTable {
@@ -57,9 +63,17 @@ impl ProbeTable {
             compare_fn,
             num_groups: 0,
             num_rows: 0,
+            bloom_filter: None,
         })
     }
 
+    /// Enables a bloom filter sized for `expected_num_rows` build rows, which lets probes skip the
+    /// hash table lookup for rows that cannot match.
+    pub(crate) fn with_bloom_filter(mut self, expected_num_rows: usize) -> Self {
+        self.bloom_filter = Some(BlockedBloomFilter::with_capacity(expected_num_rows));
+        self
+    }
+
     fn probe<'a>(
         &'a self,
         input: &'a Table,
@@ -82,32 +96,43 @@ impl ProbeTable {
 
         let iter = hashes.as_arrow().clone().into_iter();
 
-        Ok(Box::new(iter.enumerate().map(move |(idx, h)| match h {
-            Some(h) => {
-                let indices = if let Some((_, indices)) =
-                    self.hash_table.raw_entry().from_hash(h, |other| {
-                        h == other.hash && {
-                            let other_table_idx = (other.idx >> Self::TABLE_IDX_SHIFT) as usize;
-                            let other_row_idx = (other.idx & Self::LOWER_MASK) as usize;
+        Ok(Box::new(iter.enumerate().map(move |(idx, h)| {
+            match h {
+                Some(h)
+                    if self
+                        .bloom_filter
+                        .as_ref()
+                        .is_some_and(|bloom_filter| !bloom_filter.might_contain(h)) =>
+                {
+                    None
+                }
+                Some(h) => {
+                    let indices = if let Some((_, indices)) =
+                        self.hash_table.raw_entry().from_hash(h, |other| {
+                            h == other.hash && {
+                                let other_table_idx = (other.idx >> Self::TABLE_IDX_SHIFT) as usize;
+                                let other_row_idx = (other.idx & Self::LOWER_MASK) as usize;
 
-                            let other_table = self.tables.get(other_table_idx).unwrap();
+                                let other_table = self.tables.get(other_table_idx).unwrap();
 
-                            let other_refs = other_table.0.as_slice();
+                                let other_refs = other_table.0.as_slice();
 
-                            (self.compare_fn)(other_refs, &input_arrays, other_row_idx, idx).is_eq()
-                        }
-                    }) {
-                    Some(indices.as_slice())
-                } else {
-                    None
-                };
-                indices
+                                (self.compare_fn)(other_refs, &input_arrays, other_row_idx, idx)
+                                    .is_eq()
+                            }
+                        }) {
+                        Some(indices.as_slice())
+                    } else {
+                        None
+                    };
+                    indices
+                }
+                None => None,
             }
-            None => None,
         })))
     }
 
-    fn add_table(&mut self, table: &Table) -> DaftResult<()> {
+    pub(crate) fn add_table(&mut self, table: &Table) -> DaftResult<()> {
         // we have to cast to the join key schema
         assert_eq!(table.schema, self.schema);
         let hashes = table.hash_rows()?;
@@ -116,6 +141,11 @@ impl ProbeTable {
 
         assert!(table_idx < (1 << (64 - Self::TABLE_IDX_SHIFT)));
         assert!(table.len() < (1 << Self::TABLE_IDX_SHIFT));
+        if let Some(bloom_filter) = &mut self.bloom_filter {
+            for h in hashes.as_arrow().values_iter() {
+                bloom_filter.insert(*h);
+            }
+        }
         let current_arrays = table
             .columns
             .iter()
@@ -163,6 +193,29 @@ impl ProbeTable {
         self.num_rows += table.len();
         Ok(())
     }
+
+    /// Consumes the probe table and returns the physical key tables that were added to it, in insertion order.
+    pub(crate) fn into_tables(self) -> DaftResult<Vec<Table>> {
+        let fields = self
+            .schema
+            .fields
+            .values()
+            .map(|field| Field::new(field.name.as_str(), field.dtype.to_physical()))
+            .collect::<Vec<_>>();
+        let physical_schema: SchemaRef = Schema::new(fields.clone())?.into();
+        self.tables
+            .into_iter()
+            .map(|ArrowTableEntry(arrays)| {
+                let num_rows = arrays.first().map_or(0, |array| array.len());
+                let columns = fields
+                    .iter()
+                    .zip(arrays)
+                    .map(|(field, array)| Series::from_arrow(field.clone().into(), array))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                Table::new_with_size(physical_schema.clone(), columns, num_rows)
+            })
+            .collect()
+    }
 }
 
 impl Probeable for ProbeTable {
@@ -182,10 +235,71 @@ impl Probeable for ProbeTable {
         let iter = self.probe(table)?;
         Ok(Box::new(iter.map(|indices| indices.is_some())))
     }
+
+    fn num_groups(&self) -> Option<usize> {
+        Some(self.num_groups)
+    }
+
+    fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    fn size_bytes(&self) -> usize {
+        let tables = self
+            .tables
+            .iter()
+            .map(ArrowTableEntry::size_bytes)
+            .sum::<usize>();
+        let hash_table = self.hash_table.capacity()
+            * (std::mem::size_of::<IndexHash>() + std::mem::size_of::<Vec<u64>>());
+        let indices = self
+            .hash_table
+            .values()
+            .map(|indices| indices.capacity() * std::mem::size_of::<u64>())
+            .sum::<usize>();
+        let bloom_filter = self
+            .bloom_filter
+            .as_ref()
+            .map_or(0, BlockedBloomFilter::size_bytes);
+        tables + hash_table + indices + bloom_filter
+    }
 }
 
 pub struct ProbeTableBuilder(pub ProbeTable);
 
+impl ProbeTableBuilder {
+    /// Creates a builder whose probe table is prefiltered by a bloom filter sized for `expected_num_rows`.
+    /// This is worthwhile when the build side is selective and most probe rows are expected to miss.
+    pub fn new_with_bloom_filter(
+        schema: SchemaRef,
+        nulls_equal_aware: Option<&Vec<bool>>,
+        expected_num_rows: usize,
+    ) -> DaftResult<Self> {
+        Ok(Self(
+            ProbeTable::new(schema, nulls_equal_aware)?.with_bloom_filter(expected_num_rows),
+        ))
+    }
+
+    /// Creates a builder that keeps the build side in memory until it exceeds `max_bytes`, after which
+    /// the build side is hash partitioned and spilled to `spill_dir` as Arrow IPC files.
+    /// Probes against a spilled build side are then performed partition by partition.
+    pub fn new_with_spill(
+        schema: SchemaRef,
+        nulls_equal_aware: Option<&Vec<bool>>,
+        store_indices: bool,
+        max_bytes: usize,
+        spill_dir: PathBuf,
+    ) -> DaftResult<Box<dyn ProbeableBuilder>> {
+        Ok(Box::new(SpillingProbeTableBuilder::new(
+            schema,
+            nulls_equal_aware,
+            store_indices,
+            max_bytes,
+            spill_dir,
+        )?))
+    }
+}
+
 impl ProbeableBuilder for ProbeTableBuilder {
     fn add_table(&mut self, table: &Table) -> DaftResult<()> {
         self.0.add_table(table)
@@ -195,3 +309,116 @@ impl ProbeableBuilder for ProbeTableBuilder {
         Arc::new(self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{array::ops::as_arrow::AsArrow, prelude::*};
+
+    use super::ProbeTable;
+    use crate::{probeable::Probeable, Table};
+
+    #[test]
+    fn test_probe_table_reports_build_stats() -> DaftResult<()> {
+        let first =
+            Table::from_nonempty_columns(vec![
+                Int64Array::from(("key", vec![1, 1, 2, 3])).into_series()
+            ])?;
+        let second =
+            Table::from_nonempty_columns(vec![
+                Int64Array::from(("key", vec![1, 3, 3, 4])).into_series()
+            ])?;
+
+        let mut probe_table = ProbeTable::new(first.schema.clone(), None)?;
+        probe_table.add_table(&first)?;
+        probe_table.add_table(&second)?;
+
+        assert_eq!(probe_table.num_groups(), Some(4));
+        assert_eq!(probe_table.num_rows(), 8);
+        assert_eq!(probe_table.avg_chain_length(), Some(2.0));
+        assert!(probe_table.size_bytes() >= 8 * std::mem::size_of::<i64>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_keys_match_only_when_nulls_equal() -> DaftResult<()> {
+        let build = Table::from_nonempty_columns(vec![Int64Array::from_iter(
+            Field::new("key", DataType::Int64),
+            vec![Some(1), None, Some(2)].into_iter(),
+        )
+        .into_series()])?;
+        let probe = Table::from_nonempty_columns(vec![Int64Array::from_iter(
+            Field::new("key", DataType::Int64),
+            vec![None, Some(2), Some(3)].into_iter(),
+        )
+        .into_series()])?;
+
+        let mut probe_table = ProbeTable::new(build.schema.clone(), None)?;
+        probe_table.add_table(&build)?;
+        let matches = probe_table
+            .probe(&probe)?
+            .map(|indices| indices.map(<[u64]>::to_vec))
+            .collect::<Vec<_>>();
+        assert_eq!(matches, vec![None, Some(vec![2]), None]);
+
+        let mut probe_table = ProbeTable::new(build.schema.clone(), Some(&vec![true]))?;
+        probe_table.add_table(&build)?;
+        let matches = probe_table
+            .probe(&probe)?
+            .map(|indices| indices.map(<[u64]>::to_vec))
+            .collect::<Vec<_>>();
+        assert_eq!(matches, vec![Some(vec![1]), Some(vec![2]), None]);
+        Ok(())
+    }
+
+    fn keys_table(keys: impl Iterator<Item = i64>) -> DaftResult<Table> {
+        Table::from_nonempty_columns(vec![
+            Int64Array::from(("key", keys.collect::<Vec<_>>())).into_series()
+        ])
+    }
+
+    #[test]
+    fn test_bloom_filter_has_no_false_negatives() -> DaftResult<()> {
+        let build = keys_table((0..10_000).map(|i| i * 7))?;
+        let mut probe_table =
+            ProbeTable::new(build.schema.clone(), None)?.with_bloom_filter(build.len());
+        probe_table.add_table(&build)?;
+
+        let probe = keys_table((0..20_000).rev().map(|i| i * 7))?;
+        let matches = probe_table
+            .probe(&probe)?
+            .map(|indices| indices.is_some())
+            .collect::<Vec<_>>();
+        assert_eq!(matches.iter().filter(|m| **m).count(), 10_000);
+        assert!(matches[10_000..].iter().all(|m| *m));
+        Ok(())
+    }
+
+    #[test]
+    fn bench_bloom_filter_skips_hash_table_probes() -> DaftResult<()> {
+        // A selective build side probed by a large, low match rate probe side.
+        let build = keys_table(0..1_000)?;
+        let mut probe_table =
+            ProbeTable::new(build.schema.clone(), None)?.with_bloom_filter(build.len());
+        probe_table.add_table(&build)?;
+        let bloom_filter = probe_table.bloom_filter.as_ref().unwrap();
+
+        let probe = keys_table((0..100_000).map(|i| i * 100))?;
+        let hashes = probe.hash_rows()?;
+        let hash_table_probes = hashes
+            .as_arrow()
+            .values_iter()
+            .filter(|h| bloom_filter.might_contain(**h))
+            .count();
+        let num_matches = probe_table.probe(&probe)?.flatten().count();
+        assert_eq!(num_matches, 10);
+        // Only rows that pass the bloom filter reach the hash table.
+        assert!(hash_table_probes >= num_matches);
+        assert!(
+            hash_table_probes < probe.len() / 20,
+            "expected the bloom filter to skip most of {} probes, but {hash_table_probes} reached the hash table",
+            probe.len()
+        );
+        Ok(())
+    }
+}
@@ -179,17 +179,33 @@ impl PyTable {
                     &right.table,
                     left_exprs.as_slice(),
                     right_exprs.as_slice(),
+                    // Partition-level merge-joins are always planned on ascending sort orders.
+                    &vec![false; left_exprs.len()],
                     is_sorted,
                 )?
                 .into())
         })
     }
 
-    pub fn explode(&self, py: Python, to_explode: Vec<PyExpr>) -> PyResult<Self> {
+    #[pyo3(signature = (to_explode, index_column=None))]
+    pub fn explode(
+        &self,
+        py: Python,
+        to_explode: Vec<PyExpr>,
+        index_column: Option<&str>,
+    ) -> PyResult<Self> {
         let converted_to_explode: Vec<daft_dsl::ExprRef> =
             to_explode.into_iter().map(|e| e.expr).collect();
 
-        py.allow_threads(|| Ok(self.table.explode(converted_to_explode.as_slice())?.into()))
+        py.allow_threads(|| {
+            Ok(match index_column {
+                Some(index_column) => self
+                    .table
+                    .explode_with_index(converted_to_explode.as_slice(), index_column)?,
+                None => self.table.explode(converted_to_explode.as_slice())?,
+            }
+            .into())
+        })
     }
 
     pub fn __repr__(&self) -> PyResult<String> {
@@ -265,11 +281,13 @@ impl PyTable {
         py.allow_threads(|| Ok(self.table.quantiles(num)?.into()))
     }
 
+    #[pyo3(signature = (exprs, num_partitions, seed=None))]
     pub fn partition_by_hash(
         &self,
         py: Python,
         exprs: Vec<PyExpr>,
         num_partitions: i64,
+        seed: Option<u64>,
     ) -> PyResult<Vec<Self>> {
         if num_partitions < 0 {
             return Err(PyValueError::new_err(format!(
@@ -281,7 +299,7 @@ impl PyTable {
         py.allow_threads(|| {
             Ok(self
                 .table
-                .partition_by_hash(exprs.as_slice(), num_partitions as usize)?
+                .partition_by_hash(exprs.as_slice(), num_partitions as usize, seed)?
                 .into_iter()
                 .map(std::convert::Into::into)
                 .collect::<Vec<Self>>())
@@ -315,6 +333,33 @@ impl PyTable {
         })
     }
 
+    pub fn partition_by_round_robin(
+        &self,
+        py: Python,
+        num_partitions: i64,
+        start: i64,
+    ) -> PyResult<Vec<Self>> {
+        if num_partitions < 0 {
+            return Err(PyValueError::new_err(format!(
+                "Can not partition into negative number of partitions: {num_partitions}"
+            )));
+        }
+
+        if start < 0 {
+            return Err(PyValueError::new_err(format!(
+                "Can not have start as negative number: {start}"
+            )));
+        }
+        py.allow_threads(|| {
+            Ok(self
+                .table
+                .partition_by_round_robin(num_partitions as usize, start as usize)?
+                .into_iter()
+                .map(std::convert::Into::into)
+                .collect::<Vec<Self>>())
+        })
+    }
+
     pub fn partition_by_range(
         &self,
         py: Python,
@@ -3,6 +3,7 @@ from __future__ import annotations
 import contextlib
 import csv
 import decimal
+import io
 import json
 import os
 import tempfile
@@ -149,6 +150,15 @@ def test_create_dataframe_pydict_data_longer_than_preview(valid_data: list[dict[
     assert set(df.column_names) == set(COL_NAMES)
 
 
+def test_create_dataframe_pydict_plans_report_num_rows(valid_data: list[dict[str, float]]) -> None:
+    pydict = {k: [item[k] for item in valid_data] for k in valid_data[0].keys()}
+    df = daft.from_pydict(pydict)
+    plans = io.StringIO()
+    df.explain(show_all=True, file=plans)
+    assert f"Number of rows = {len(valid_data)}" in plans.getvalue()
+    assert f"Num rows = {len(valid_data)}" in plans.getvalue()
+
+
 def test_create_dataframe_empty_pydict() -> None:
     df = daft.from_pydict({})
     assert df.column_names == []
@@ -799,6 +799,64 @@ def test_join_semi_anti(join_strategy, join_type, expected, make_df, repartition
     )
 
 
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+@pytest.mark.parametrize(
+    "join_strategy",
+    [None, "hash", "sort_merge", "sort_merge_aligned_boundaries", "broadcast"],
+    indirect=True,
+)
+@pytest.mark.parametrize(
+    "join_type,expected",
+    [
+        (
+            "right_semi",
+            {
+                "id": [2, 3],
+                "values_right": ["b2", "c2"],
+            },
+        ),
+        (
+            "right_anti",
+            {
+                "id": [1, None],
+                "values_right": ["a2", "d2"],
+            },
+        ),
+    ],
+)
+def test_join_right_semi_anti(join_strategy, join_type, expected, make_df, repartition_nparts, with_morsel_size):
+    skip_invalid_join_strategies(join_strategy, join_type)
+
+    daft_df1 = make_df(
+        {
+            "id": [2, 2, 3, 4],
+            "values_left": ["a1", "b1", "c1", "d1"],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df2 = make_df(
+        {
+            "id": [1, 2, 3, None],
+            "values_right": ["a2", "b2", "c2", "d2"],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = (
+        daft_df1.join(
+            daft_df2.with_column("id", daft_df2["id"].cast(DataType.int64())),
+            on="id",
+            how=join_type,
+            strategy=join_strategy,
+        )
+        .sort(["id", "values_right"])
+        .select("id", "values_right")
+    )
+
+    assert sort_arrow_table(pa.Table.from_pydict(daft_df.to_pydict()), "id") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "id"
+    )
+
+
 @pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
 @pytest.mark.parametrize(
     "join_strategy",
@@ -142,3 +142,17 @@ def test_grouped_stddev_with_multiple_partitions(data_and_expected, with_morsel_
         pd.Series(expected["data"]).sort_values(),
         check_index=False,
     )
+
+
+def test_stddev_and_variance_are_stable_for_large_values(with_morsel_size):
+    # Summing squares of values this far from zero would cancel out their spread.
+    df = daft.from_pydict({"a": [1e9 + v for v in [4, 7, 13, 16, 10]]}).into_partitions(2)
+    result = df.agg(
+        daft.col("a").stddev().alias("stddev"),
+        daft.col("a").variance().alias("var_pop"),
+        daft.col("a").variance(ddof=1).alias("var_samp"),
+    ).to_pydict()
+
+    assert result["stddev"][0] == pytest.approx(math.sqrt(18.0))
+    assert result["var_pop"][0] == pytest.approx(18.0)
+    assert result["var_samp"][0] == pytest.approx(22.5)
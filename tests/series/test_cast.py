@@ -1205,3 +1205,13 @@ def test_series_cast_fixed_shape_sparse_to_python() -> None:
     given = series.to_pylist()
     expected = [to_coo_sparse_dict(ndarray) if ndarray is not None else None for ndarray in data]
     np.testing.assert_equal(given, expected)
+
+
+def test_series_cast_string_to_numeric_locale() -> None:
+    s = Series.from_pylist(["1.234,56", "-7,5", "n/a", None])
+    result = s.cast_string_to_numeric(DataType.float64(), decimal_sep=",", thousands_sep=".")
+    assert result.datatype() == DataType.float64()
+    assert result.to_pylist() == [1234.56, -7.5, None, None]
+
+    ints = Series.from_pylist(["1,000", "25"]).cast_string_to_numeric(DataType.int64(), thousands_sep=",")
+    assert ints.to_pylist() == [1000, 25]
@@ -0,0 +1,53 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.exceptions import DaftCoreException, DaftTypeError
+from daft.series import Series
+
+
+@pytest.mark.parametrize(
+    "dtype, expected_sum_dtype",
+    [
+        (DataType.int8(), DataType.int64()),
+        (DataType.int32(), DataType.int64()),
+        (DataType.uint16(), DataType.uint64()),
+        (DataType.float32(), DataType.float32()),
+        (DataType.float64(), DataType.float64()),
+    ],
+)
+def test_series_cumulative_with_interior_nulls(dtype, expected_sum_dtype) -> None:
+    s = Series.from_pylist([3, None, 1, 4, None, 2]).cast(dtype)
+
+    cumsum = s.cumsum()
+    assert cumsum.datatype() == expected_sum_dtype
+    assert cumsum.to_pylist() == [3, None, 4, 8, None, 10]
+
+    cummax = s.cummax()
+    assert cummax.datatype() == dtype
+    assert cummax.to_pylist() == [3, None, 3, 4, None, 4]
+
+    cummin = s.cummin()
+    assert cummin.datatype() == dtype
+    assert cummin.to_pylist() == [3, None, 1, 1, None, 1]
+
+
+def test_series_cumulative_leading_nulls() -> None:
+    s = Series.from_pylist([None, None, 5, -1])
+    assert s.cumsum().to_pylist() == [None, None, 5, 4]
+    assert s.cummax().to_pylist() == [None, None, 5, 5]
+    assert s.cummin().to_pylist() == [None, None, 5, -1]
+
+
+@pytest.mark.parametrize("op", ["cumsum", "cummax", "cummin"])
+def test_series_cumulative_rejects_non_numeric(op) -> None:
+    s = Series.from_pylist(["a", "b"])
+    with pytest.raises(DaftTypeError):
+        getattr(s, op)()
+
+
+def test_series_cumsum_overflow_raises() -> None:
+    s = Series.from_pylist([2**63 - 1, 1])
+    with pytest.raises(DaftCoreException, match="Overflow"):
+        s.cumsum()
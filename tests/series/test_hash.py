@@ -587,3 +587,36 @@ def test_murmur3_32_hash_decimal_full_scaled():
     arr = Series.from_pylist([decimal.Decimal(".00001420"), None])
     hashes = arr.murmur3_32()
     assert hashes.to_pylist() == [-500754589, None]
+
+
+def test_hash_function_murmur3_32_matches_murmur3_32():
+    arr = Series.from_pylist([-1, 34, None])
+    hashes = arr.hash(hash_function="murmur3_32")
+    assert hashes.datatype() == DataType.uint32()
+    assert hashes.to_pylist() == [1651860712, 2017239379, None]
+
+    with pytest.raises(ValueError, match="murmur3_32 does not support a seed"):
+        arr.hash(Series.from_pylist([1, 2, 3]).cast(DataType.uint64()), hash_function="murmur3_32")
+
+
+@pytest.mark.parametrize("dtype", [DataType.int8(), DataType.int32(), DataType.uint64(), DataType.int64()])
+def test_hash_function_xxhash64_int(dtype):
+    arr = Series.from_pylist([34, None]).cast(dtype)
+    hashes = arr.hash(hash_function="xxhash64")
+    assert hashes.datatype() == DataType.uint64()
+    assert hashes.to_pylist() == [xxhash.xxh64_intdigest((34).to_bytes(8, "little")), None]
+
+
+def test_hash_function_xxhash64_string_with_seed():
+    arr = Series.from_pylist(["iceberg", "", None])
+    seed = Series.from_pylist([42, 0, 1]).cast(DataType.uint64())
+    hashes = arr.hash(seed, hash_function="xxhash64")
+    assert hashes.to_pylist() == [xxhash.xxh64_intdigest(b"iceberg", seed=42), xxhash.xxh64_intdigest(b""), None]
+
+
+def test_hash_function_default_and_invalid():
+    arr = Series.from_pylist([1, 2, None])
+    assert arr.hash(hash_function="default").to_pylist() == arr.hash().to_pylist()
+
+    with pytest.raises(ValueError, match="Hash function md5 is not supported"):
+        arr.hash(hash_function="md5")
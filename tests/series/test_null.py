@@ -0,0 +1,38 @@
+from __future__ import annotations
+
+import math
+
+import pyarrow as pa
+import pytest
+
+from daft.series import Series
+
+
+def test_null_masks_on_floats_with_nan():
+    s = Series.from_pylist([1.0, None, math.nan, None])
+
+    assert s.is_null().to_pylist() == [False, True, False, True]
+    assert s.not_null().to_pylist() == [True, False, True, False]
+    assert s.null_count() == 2
+
+
+@pytest.mark.parametrize(
+    "data,expected_is_null",
+    [
+        ([1, 2, 3], [False, False, False]),
+        (["a", None, "c"], [False, True, False]),
+        ([None, None], [True, True]),
+        ([], []),
+    ],
+)
+def test_null_masks(data, expected_is_null):
+    s = Series.from_pylist(data)
+
+    assert s.is_null().to_pylist() == expected_is_null
+    assert s.not_null().to_pylist() == [not v for v in expected_is_null]
+    assert s.null_count() == sum(expected_is_null)
+
+
+def test_null_count_on_arrow_with_validity():
+    s = Series.from_arrow(pa.array([1, None, 3], type=pa.int64()))
+    assert s.null_count() == 1
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import math
+
 import pyarrow as pa
 import pytest
 
@@ -33,3 +35,27 @@ def test_table_abs_bad_input() -> None:
 
     with pytest.raises(ValueError, match="abs not implemented"):
         abs(series)
+
+
+def test_series_log_negative_input_is_nan() -> None:
+    s = Series.from_pylist([-1.0, 1.0, None])
+    result = s.ln().to_pylist()
+    assert math.isnan(result[0])
+    assert result[1:] == [0.0, None]
+
+
+def test_series_log_integer_input() -> None:
+    s = Series.from_pylist([1, 8, 64])
+    result = s.log(2)
+    assert result.datatype() == DataType.float64()
+    assert result.to_pylist() == pytest.approx([0.0, 3.0, 6.0])
+
+
+def test_series_pow_broadcast() -> None:
+    s = Series.from_pylist([2, None, -3, 4])
+    result = s.pow(Series.from_pylist([2]))
+    assert result.datatype() == DataType.float64()
+    assert result.to_pylist() == [4.0, None, 9.0, 16.0]
+
+    elementwise = Series.from_pylist([2.0, 9.0]).pow(Series.from_pylist([-1, 0.5]))
+    assert elementwise.to_pylist() == [0.5, 3.0]
@@ -3,13 +3,15 @@ from __future__ import annotations
 from datetime import date, datetime, time
 from decimal import Decimal
 from itertools import product
+from uuid import UUID
 
 import pandas as pd
 import pyarrow as pa
 import pytest
 
-from daft import DataType, TimeUnit
+from daft import DataType, TimeUnit, col
 from daft.series import Series
+from daft.table import MicroPartition
 
 
 @pytest.mark.parametrize(
@@ -155,6 +157,8 @@ def test_iceberg_bucketing(input, n):
         (pa.array([pd.to_datetime("2017-11-16T14:31:08.000001001-08:00")], type=pa.timestamp("ns")), -1207196810),
         (pa.array(["iceberg"]), 1210000089),
         (pa.array([b"\x00\x01\x02\x03"]), -188683207),
+        (pa.array([b"\x00\x01\x02\x03"], type=pa.binary(4)), -188683207),
+        (pa.array([UUID("f79c3e09-677c-4bbd-a479-3f349cb785e7").bytes], type=pa.binary(16)), 1488055340),
     ],
 )
 def test_iceberg_bucketing_hash(input, expected):
@@ -166,6 +170,22 @@ def test_iceberg_bucketing_hash(input, expected):
     assert buckets.to_pylist() == [(expected & max_buckets) % max_buckets]
 
 
+@pytest.mark.parametrize(
+    "input,expected",
+    [
+        (pa.array([datetime.fromisoformat("2017-11-16T22:31:08")], type=pa.timestamp("us")), -2047944441),
+        (pa.array([time.fromisoformat("22:31:08")]), -662762989),
+        (pa.array([UUID("f79c3e09-677c-4bbd-a479-3f349cb785e7").bytes], type=pa.binary(16)), 1488055340),
+    ],
+)
+def test_iceberg_bucketing_expression(input, expected):
+    max_buckets = 2**31 - 1
+    table = MicroPartition.from_arrow(pa.table({"a": input}))
+    result = table.eval_expression_list([col("a").partitioning.iceberg_bucket(max_buckets)])
+    assert result.column_names() == ["a_bucket"]
+    assert result.get_column("a_bucket").to_pylist() == [(expected & max_buckets) % max_buckets]
+
+
 def test_iceberg_truncate_decimal():
     data = ["12.34", "12.30", "12.29", "0.05", "-0.05"]
     data = [Decimal(v) for v in data] + [None]
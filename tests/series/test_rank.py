@@ -0,0 +1,33 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.series import Series
+
+
+@pytest.mark.parametrize(
+    "method, expected",
+    [
+        ("dense", [3, 1, 4, 3, 2, 3]),
+        ("min", [3, 1, 6, 3, 2, 3]),
+        ("max", [5, 1, 6, 5, 2, 5]),
+        ("average", [4.0, 1.0, 6.0, 4.0, 2.0, 4.0]),
+    ],
+)
+def test_series_rank(method, expected) -> None:
+    s = Series.from_pylist([3, 1, None, 3, 2, 3])
+    ranks = s.rank(method=method)
+    assert ranks.datatype() == (DataType.float64() if method == "average" else DataType.uint64())
+    assert ranks.to_pylist() == expected
+
+
+def test_series_rank_descending_keeps_nulls_last() -> None:
+    s = Series.from_pylist(["b", None, "a", "b"])
+    assert s.rank(descending=True, method="min").to_pylist() == [1, 4, 3, 1]
+
+
+def test_series_rank_bad_method() -> None:
+    s = Series.from_pylist([1, 2, 3])
+    with pytest.raises(ValueError, match="Rank method first is not supported"):
+        s.rank(method="first")
@@ -229,3 +229,23 @@ def test_series_boolean_sorting() -> None:
     taken = s.take(s_argsorted)
     assert len(taken) == len(s)
     assert taken.to_pylist() == sorted_order[::-1]
+
+
+@pytest.mark.parametrize(
+    "data",
+    [
+        pa.array([3, None, 1, 2, None], type=pa.int64()),
+        pa.array([3.0, None, 1.0, 2.0, None], type=pa.float32()),
+        pa.array([3.0, None, 1.0, 2.0, None], type=pa.float64()),
+        pa.array(["c", None, "a", "b", None]),
+    ],
+)
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize("nulls_first", [False, True])
+def test_series_sort_nulls_first(data, descending, nulls_first) -> None:
+    s = Series.from_arrow(data)
+    valid = sorted([v for v in data.to_pylist() if v is not None], reverse=descending)
+    expected = [None, None] + valid if nulls_first else valid + [None, None]
+
+    assert s.sort(descending=descending, nulls_first=nulls_first).to_pylist() == expected
+    assert s.take(s.argsort(descending=descending, nulls_first=nulls_first)).to_pylist() == expected
@@ -456,3 +456,45 @@ def test_series_timestamp_truncate_operation_invalid_relative_to() -> None:
     with pytest.raises(ValueError):
         # Start time must be a series of timestamps
         input_series.dt.truncate("1 second", Series.from_pylist([1]))
+
+
+def test_series_strftime_round_trips_strptime() -> None:
+    format = "%Y-%m-%d %H:%M:%S"
+    strings = Series.from_pylist(["2024-01-02 03:04:05", None])
+
+    parsed = strings.str.strptime(format, "us")
+    assert parsed.dt.strftime(format).to_pylist() == strings.to_pylist()
+    assert parsed.dt.date().dt.strftime("%d/%m/%Y").to_pylist() == ["02/01/2024", None]
+
+
+def test_series_strftime_uses_column_timezone() -> None:
+    from datetime import datetime, timezone
+
+    input_series = Series.from_pylist([datetime(1970, 1, 1, tzinfo=timezone.utc)]).cast(
+        DataType.timestamp(TimeUnit.s(), "America/New_York")
+    )
+    assert input_series.dt.strftime("%Y-%m-%d %H:%M %Z").to_pylist() == ["1969-12-31 19:00 EST"]
+
+
+def test_series_convert_timezone_dst_offsets() -> None:
+    from datetime import datetime, timezone
+
+    utc = Series.from_pylist(
+        [datetime(2024, 1, 15, 12, tzinfo=timezone.utc), datetime(2024, 7, 15, 12, tzinfo=timezone.utc), None]
+    ).cast(DataType.timestamp(TimeUnit.s(), "UTC"))
+
+    new_york = utc.dt.convert_timezone("America/New_York")
+    assert new_york.datatype() == DataType.timestamp(TimeUnit.s(), "America/New_York")
+    assert new_york.dt.strftime("%H:%M %z").to_pylist() == ["07:00 -0500", "08:00 -0400", None]
+
+    with pytest.raises(ValueError):
+        utc.dt.convert_timezone("Not/AZone")
+
+
+def test_series_replace_timezone_keeps_wall_clock() -> None:
+    from datetime import datetime
+
+    naive = Series.from_pylist([datetime(2024, 7, 15, 12)]).cast(DataType.timestamp(TimeUnit.s()))
+    new_york = naive.dt.replace_timezone("America/New_York")
+    assert new_york.dt.strftime("%Y-%m-%d %H:%M:%S %z").to_pylist() == ["2024-07-15 12:00:00 -0400"]
+    assert new_york.dt.replace_timezone().to_pylist() == naive.to_pylist()
@@ -1455,6 +1455,16 @@ def test_series_utf8_to_datetime_different_timeunit(data, format, expected, time
     assert result.datatype() == DataType.timestamp(timeunit)
 
 
+def test_series_utf8_strptime_malformed_rows() -> None:
+    s = Series.from_arrow(pa.array(["2021-01-02 01:07:35", "not a datetime", None], type=pa.string()))
+    result = s.str.strptime("%Y-%m-%d %H:%M:%S", "ms")
+    assert result.datatype() == DataType.timestamp("ms")
+    assert result.to_pylist() == [datetime.datetime(2021, 1, 2, 1, 7, 35), None, None]
+
+    with pytest.raises(ValueError):
+        s.str.strptime("%Y-%m-%d %H:%M:%S", "ms", raise_on_error=True)
+
+
 def test_series_utf8_to_datetime_bad_format() -> None:
     s = Series.from_arrow(pa.array(["2021-01-01", "2021-01-02"]))
     format = "%Y-%m-%"
@@ -104,3 +104,32 @@ def test_having_non_grouped():
     ).to_pydict()
 
     assert actual == {"count": [10]}
+
+
+def test_stddev_variants_sql():
+    df = daft.from_pydict({"values": [1.5, 2.5, 3.5, 4.5, 10.5]})
+    expected = (
+        df.agg(
+            [
+                col("values").stddev().alias("std"),
+                col("values").stddev().alias("std_pop"),
+                col("values").variance(ddof=1).sqrt().alias("std_samp"),
+            ]
+        )
+        .collect()
+        .to_pydict()
+    )
+
+    actual = (
+        daft.sql("""
+    SELECT
+        stddev(values) as std,
+        stddev_pop(values) as std_pop,
+        stddev_samp(values) as std_samp
+    FROM df
+    """)
+        .collect()
+        .to_pydict()
+    )
+
+    assert actual == expected
@@ -1,5 +1,11 @@
 from __future__ import annotations
 
+import io
+
+import numpy as np
+import pytest
+from PIL import Image
+
 import daft
 
 
@@ -12,3 +18,45 @@ def test_decode_all_empty():
         "foo": [b"not an image", None],
         "image": [None, None],
     }
+
+
+def _png_bytes(height: int, width: int, mode: str = "RGB") -> bytes:
+    channels = len(mode)
+    arr = np.arange(height * width * channels, dtype=np.uint8).reshape(height, width, channels)
+    buf = io.BytesIO()
+    Image.fromarray(arr.squeeze(-1) if channels == 1 else arr).save(buf, "PNG")
+    return buf.getvalue()
+
+
+def test_decode_fixed_shape_matching():
+    df = daft.from_pydict({"foo": [_png_bytes(2, 3), None, _png_bytes(2, 3)]})
+    df = daft.sql("SELECT image_decode(foo, shape => (2, 3, 3)) AS image FROM df").collect()
+
+    assert df.schema()["image"].dtype == daft.DataType.image("RGB", 2, 3)
+    images = df.to_pydict()["image"]
+    assert images[1] is None
+    assert [img.shape for img in images if img is not None] == [(2, 3, 3), (2, 3, 3)]
+
+
+def test_decode_fixed_shape_mismatch_raises():
+    df = daft.from_pydict({"foo": [_png_bytes(2, 3), _png_bytes(3, 2)]})
+    df = daft.sql("SELECT image_decode(foo, shape => (2, 3, 3), on_error => 'raise') AS image FROM df")
+
+    with pytest.raises(Exception, match="Expected image of shape"):
+        df.collect()
+
+
+def test_decode_fixed_shape_mismatch_null():
+    df = daft.from_pydict({"foo": [_png_bytes(2, 3), _png_bytes(3, 2), _png_bytes(2, 3, mode="L")]})
+    df = daft.sql("SELECT image_decode(foo, shape => (2, 3, 3), on_error => 'null') AS image FROM df").collect()
+
+    images = df.to_pydict()["image"]
+    assert images[0].shape == (2, 3, 3)
+    assert images[1:] == [None, None]
+
+
+def test_decode_fixed_shape_with_mode_conversion():
+    df = daft.from_pydict({"foo": [_png_bytes(2, 3, mode="L")]})
+    df = daft.sql("SELECT image_decode(foo, mode => 'RGB', shape => (2, 3, 3)) AS image FROM df").collect()
+
+    assert df.to_pydict()["image"][0].shape == (2, 3, 3)
@@ -175,3 +175,47 @@ def test_list_chunk_non_list_type():
         table.eval_expression_list([col("stringcol").list.chunk(2)])
     with pytest.raises(ValueError):
         table.eval_expression_list([col("intcol").list.chunk(2)])
+
+
+def test_list_chunk_keep_remainder():
+    table = MicroPartition.from_pydict(
+        {
+            "col": [[1, 2, 3, 4], [5, 6, None, 8, 9], [], None, [10]],
+            "fixed_col": [["a", "b", "c"], None, ["d", None, "f"], ["g", "h", "i"], ["j", "k", "l"]],
+        }
+    )
+    table = table.eval_expression_list(
+        [col("col"), col("fixed_col").cast(DataType.fixed_size_list(DataType.string(), 3))]
+    )
+
+    result = table.eval_expression_list(
+        [
+            col("col").list.chunk(2, keep_remainder=True).alias("col-2"),
+            col("col").list.chunk(3, keep_remainder=True).alias("col-3"),
+            col("col").list.chunk(10, keep_remainder=True).alias("col-10"),
+            col("fixed_col").list.chunk(2, keep_remainder=True).alias("fixed_col-2"),
+            col("fixed_col").list.chunk(3, keep_remainder=True).alias("fixed_col-3"),
+        ]
+    )
+
+    assert result.schema()["col-2"].dtype == DataType.list(DataType.list(DataType.int64()))
+    assert result.schema()["fixed_col-2"].dtype == DataType.list(DataType.list(DataType.string()))
+    assert result.to_pydict() == {
+        "col-2": [[[1, 2], [3, 4]], [[5, 6], [None, 8], [9]], [], None, [[10]]],
+        "col-3": [[[1, 2, 3], [4]], [[5, 6, None], [8, 9]], [], None, [[10]]],
+        "col-10": [[[1, 2, 3, 4]], [[5, 6, None, 8, 9]], [], None, [[10]]],
+        "fixed_col-2": [
+            [["a", "b"], ["c"]],
+            None,
+            [["d", None], ["f"]],
+            [["g", "h"], ["i"]],
+            [["j", "k"], ["l"]],
+        ],
+        "fixed_col-3": [[["a", "b", "c"]], None, [["d", None, "f"]], [["g", "h", "i"]], [["j", "k", "l"]]],
+    }
+
+
+def test_list_chunk_keep_remainder_empty_series():
+    table = MicroPartition.from_pydict({"col": pa.array([], type=pa.list_(pa.int64()))})
+    result = table.eval_expression_list([col("col").list.chunk(2, keep_remainder=True)])
+    assert result.to_pydict() == {"col": []}
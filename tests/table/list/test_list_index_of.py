@@ -0,0 +1,50 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.exceptions import DaftTypeError
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_list_index_of():
+    table = MicroPartition.from_pydict(
+        {
+            "col1": [["a", "b", "a"], ["b"], [], None, [None, "a"]],
+            "col2": [[1, 2, 3], [3, 2, 1], [1], None, [2, 2]],
+            "value": [3, 3, None, 1, 2],
+        }
+    )
+
+    result = table.eval_expression_list(
+        [
+            col("col1").list.index_of("a").alias("col1"),
+            col("col2").list.index_of(2).alias("col2"),
+            col("col2").list.index_of(4).alias("col2-absent"),
+            col("col2").list.index_of(col("value")).alias("col2-value"),
+        ]
+    )
+
+    assert result.to_pydict() == {
+        "col1": [0, None, None, None, 1],
+        "col2": [1, 1, None, None, 0],
+        "col2-absent": [None, None, None, None, None],
+        "col2-value": [2, 0, None, None, 0],
+    }
+    assert result.get_column("col2").datatype() == DataType.int64()
+
+
+def test_fixed_size_list_index_of():
+    table = MicroPartition.from_pydict({"col": [[1, 2], [3, 1], None, [4, 5]]})
+    table = table.eval_expression_list([col("col").cast(DataType.fixed_size_list(DataType.int64(), 2))])
+
+    result = table.eval_expression_list([col("col").list.index_of(1)])
+    assert result.to_pydict() == {"col": [0, 1, None, None]}
+
+
+def test_list_index_of_rejects_non_list():
+    table = MicroPartition.from_pydict({"col": [1, 2, 3]})
+
+    with pytest.raises(DaftTypeError, match="Expected input to be a list type"):
+        table.eval_expression_list([col("col").list.index_of(1)])
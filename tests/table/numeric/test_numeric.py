@@ -332,7 +332,7 @@ def test_table_numeric_arccosh() -> None:
 
 
 def test_table_numeric_round() -> None:
-    from decimal import ROUND_HALF_UP, Decimal
+    from decimal import ROUND_HALF_EVEN, Decimal
 
     table = MicroPartition.from_pydict(
         {
@@ -342,11 +342,11 @@ def test_table_numeric_round() -> None:
     )
     round_table = table.eval_expression_list([col("a").round(0), col("b").round(2)])
     assert [
-        Decimal(v).to_integral_value(rounding=ROUND_HALF_UP) if v is not None else v
+        Decimal(v).to_integral_value(rounding=ROUND_HALF_EVEN) if v is not None else v
         for v in table.get_column("a").to_pylist()
     ] == round_table.get_column("a").to_pylist()
     assert [
-        (float(Decimal(str(v)).quantize(Decimal("0.00"), rounding=ROUND_HALF_UP)) if v is not None else v)
+        (float(Decimal(str(v)).quantize(Decimal("0.00"), rounding=ROUND_HALF_EVEN)) if v is not None else v)
         for v in table.get_column("b").to_pylist()
     ] == round_table.get_column("b").to_pylist()
 
@@ -357,10 +357,16 @@ def test_table_round_bad_input() -> None:
     with pytest.raises(ValueError, match="Expected input to round to be numeric"):
         table.eval_expression_list([col("a").round()])
 
-    table = MicroPartition.from_pydict({"a": [1, 2, 3]})
 
-    with pytest.raises(ValueError, match="decimal can not be negative: -2"):
-        table.eval_expression_list([col("a").round(-2)])
+def test_table_numeric_round_ties_to_even_and_negative_decimals() -> None:
+    table = MicroPartition.from_pydict(
+        {"a": [0.5, 1.5, 2.5, 3.5, -2.5, None, float("nan")], "b": [1250, 1350, 1234, -1251, -1250, None, 7]}
+    )
+    round_table = table.eval_expression_list([col("a").round(0), col("b").round(-2), col("a").round(-1).alias("c")])
+    assert round_table.get_column("a").to_pylist()[:6] == [0.0, 2.0, 2.0, 4.0, -2.0, None]
+    assert math.isnan(round_table.get_column("a").to_pylist()[6])
+    assert round_table.get_column("b").to_pylist() == [1200, 1400, 1200, -1300, -1200, None, 0]
+    assert round_table.get_column("c").to_pylist()[:6] == [0.0, 0.0, 0.0, 0.0, -0.0, None]
 
 
 def test_table_numeric_log2() -> None:
@@ -3,6 +3,7 @@ from __future__ import annotations
 import pyarrow as pa
 import pytest
 
+from daft.datatype import DataType
 from daft.expressions import col
 from daft.series import Series
 from daft.table import MicroPartition
@@ -102,7 +103,64 @@ def test_explode_eval_expr(data):
     assert table.to_pydict() == {"nested": [1, 2, 3, 4, None, None]}
 
 
+def test_explode_map():
+    data = Series.from_arrow(
+        pa.array([[("a", 1), ("b", 2)], [], None, [("c", 3)]], type=pa.map_(pa.string(), pa.int64()))
+    )
+    table = MicroPartition.from_pydict({"id": [0, 1, 2, 3], "m": data})
+    table = table.explode([col("m")._explode()])
+    assert table.to_pydict() == {
+        "id": [0, 0, 1, 2, 3],
+        "m": [
+            {"key": "a", "value": 1},
+            {"key": "b", "value": 2},
+            None,
+            None,
+            {"key": "c", "value": 3},
+        ],
+    }
+
+
 def test_explode_bad_col_type():
     table = MicroPartition.from_pydict({"a": [1, 2, 3]})
-    with pytest.raises(ValueError, match="to be a List Type, but is"):
+    with pytest.raises(ValueError, match="to be a List or Map Type, but is"):
         table = table.explode([col("a")._explode()])
+
+
+@pytest.mark.parametrize(
+    "data",
+    TEST_DATA,
+)
+def test_explode_with_index(data):
+    table = MicroPartition.from_pydict({"nested": data, "sidecar": ["a", "b", "c", "d"]})
+    table = table.explode([col("nested")._explode()], index_column="idx")
+    assert table.column_names() == ["nested", "sidecar", "idx"]
+    assert table.schema()["idx"].dtype == DataType.uint64()
+    assert table.to_pydict() == {
+        "nested": [1, 2, 3, 4, None, None],
+        "sidecar": ["a", "a", "b", "b", "c", "d"],
+        "idx": [0, 1, 0, 1, None, None],
+    }
+
+
+def test_explode_with_index_resets_per_row():
+    table = MicroPartition.from_pydict(
+        {
+            "nested": [[1, 2, 3], [4], [], None, [5, 6]],
+            "nested2": [["a", "b", "c"], ["d"], [], None, ["e", "f"]],
+            "sidecar": ["a", "b", "c", "d", "e"],
+        }
+    )
+    table = table.explode([col("nested")._explode(), col("nested2")._explode()], index_column="idx")
+    assert table.to_pydict() == {
+        "nested": [1, 2, 3, 4, None, None, 5, 6],
+        "nested2": ["a", "b", "c", "d", None, None, "e", "f"],
+        "sidecar": ["a", "a", "a", "b", "c", "d", "e", "e"],
+        "idx": [0, 1, 2, 0, None, None, 0, 1],
+    }
+
+
+def test_explode_with_index_conflicting_name():
+    table = MicroPartition.from_pydict({"nested": [[1, 2]], "sidecar": ["a"]})
+    with pytest.raises(ValueError, match="sidecar"):
+        table.explode([col("nested")._explode()], index_column="sidecar")
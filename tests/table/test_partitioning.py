@@ -217,6 +217,83 @@ def test_table_partition_by_random(size, k) -> None:
         assert [t.to_pydict() for t in split_tables] != [t.to_pydict() for t in diff_split_tables]
 
 
+@pytest.mark.parametrize("seed", [None, 0, 42])
+def test_table_partition_by_hash_seeded_is_deterministic(seed) -> None:
+    keys = [i % 7 for i in range(100)]
+    table = MicroPartition.from_pydict({"x": keys, "x_ind": list(range(100))})
+    split_tables = table.partition_by_hash([col("x")], 4, seed=seed)
+    assert len(split_tables) == 4
+    assert sum([len(st) for st in split_tables]) == len(keys)
+
+    # identical keys always land in the same partition
+    partition_of_key = {}
+    for i, st in enumerate(split_tables):
+        assert st.schema() == table.schema()
+        for x in st.get_column("x").to_pylist():
+            assert partition_of_key.setdefault(x, i) == i
+
+    re_split_tables = table.partition_by_hash([col("x")], 4, seed=seed)
+    assert [t.to_pydict() for t in split_tables] == [t.to_pydict() for t in re_split_tables]
+
+
+def test_table_partition_by_hash_seed_changes_split() -> None:
+    table = Table.from_pydict({"x": list(range(100))})
+    split_tables = table.partition_by_hash([col("x")], 4, seed=0)
+    diff_split_tables = table.partition_by_hash([col("x")], 4, seed=1)
+    assert [t.to_pydict() for t in split_tables] != [t.to_pydict() for t in diff_split_tables]
+
+
+def test_table_partition_by_hash_seeded_empty_partitions_keep_schema() -> None:
+    table = Table.from_pydict({"x": [1, 1, 1], "y": ["a", "b", "c"]})
+    split_tables = table.partition_by_hash([col("x")], 8, seed=7)
+    assert len(split_tables) == 8
+    assert sum([len(st) for st in split_tables]) == 3
+    assert all(st.schema() == table.schema() for st in split_tables)
+
+
+@pytest.mark.parametrize("size, k", itertools.product([0, 1, 10, 33, 100], [1, 2, 3, 10, 40]))
+def test_table_partition_by_round_robin(size, k) -> None:
+    table = MicroPartition.from_pydict({"x": [i for i in range(size)]})
+    split_tables = table.partition_by_round_robin(k, 0)
+    assert len(split_tables) == k
+
+    sizes = [len(t) for t in split_tables]
+    assert sum(sizes) == size
+    assert max(sizes) - min(sizes) <= 1
+
+    for i, st in enumerate(split_tables):
+        assert st.schema() == table.schema()
+        assert all(x % k == i for x in st.get_column("x").to_pylist())
+
+
+def test_table_partition_by_round_robin_start_shifts_assignment() -> None:
+    table = Table.from_pydict({"x": [0, 1, 2, 3, 4]})
+    assert [t.to_pydict()["x"] for t in table.partition_by_round_robin(3, 0)] == [[0, 3], [1, 4], [2]]
+    assert [t.to_pydict()["x"] for t in table.partition_by_round_robin(3, 1)] == [[2], [0, 3], [1, 4]]
+    assert [t.to_pydict()["x"] for t in table.partition_by_round_robin(3, 4)] == [[2], [0, 3], [1, 4]]
+
+
+def test_micropartition_partition_by_round_robin_continues_across_tables() -> None:
+    mp = MicroPartition.concat(
+        [
+            MicroPartition.from_pydict({"x": [0, 1]}),
+            MicroPartition.from_pydict({"x": [2, 3, 4]}),
+        ]
+    )
+    split_tables = mp.partition_by_round_robin(3, 0)
+    assert [sorted(t.to_pydict()["x"]) for t in split_tables] == [[0, 3], [1, 4], [2]]
+
+
+def test_table_partition_by_round_robin_bad_input() -> None:
+    table = MicroPartition.from_pydict({"x": [1, 2, 3]})
+
+    with pytest.raises(ValueError, match="negative number"):
+        table.partition_by_round_robin(-1, 0)
+
+    with pytest.raises(ValueError, match="0 partitions"):
+        table.partition_by_round_robin(0, 0)
+
+
 def test_table_partition_by_hash_bad_input() -> None:
     table = MicroPartition.from_pydict({"x": [1, 2, 3], "b": [0, 1, 2]})
 